@@ -0,0 +1,19 @@
+// Runs the compare tab's diff off the main thread so large documents don't freeze the page
+import { buildLineDiff, normalizedJson } from "./json-diff.js";
+
+self.onmessage = (event) => {
+  const { left, right } = event.data;
+  try {
+    const leftFormatted = normalizedJson(left);
+    const rightFormatted = normalizedJson(right);
+    self.postMessage({ type: "progress", fraction: 0 });
+    const diff = buildLineDiff(
+      leftFormatted.split("\n"),
+      rightFormatted.split("\n"),
+      (fraction) => self.postMessage({ type: "progress", fraction }),
+    );
+    self.postMessage({ type: "done", leftFormatted, rightFormatted, diff });
+  } catch (error) {
+    self.postMessage({ type: "error", message: String(error) });
+  }
+};
@@ -0,0 +1,50 @@
+// UI strings. Markup names its strings with data-i18n (text content),
+// data-i18n-title and data-i18n-placeholder; scripts call t(). Messages use
+// Fluent-style placeholders, e.g. "Loaded { $name }". Each language pack in
+// locales/ falls back to English key by key, so packs can be partial.
+import en from "./locales/en.js";
+
+export const LANGUAGES = {
+  en: "English",
+  id: "Bahasa Indonesia",
+};
+
+let messages = en;
+
+// The language in use, resolved from the setting
+export let currentLanguage = "en";
+
+export function t(key, params = {}) {
+  const message = messages[key] ?? en[key] ?? key;
+  return message.replace(/\{\s*\$(\w+)\s*\}/g, (placeholder, name) =>
+    name in params ? String(params[name]) : placeholder,
+  );
+}
+
+export function translatePage(root = document) {
+  for (const element of root.querySelectorAll("[data-i18n]")) {
+    element.textContent = t(element.dataset.i18n);
+  }
+  for (const element of root.querySelectorAll("[data-i18n-title]")) {
+    element.title = t(element.dataset.i18nTitle);
+  }
+  for (const element of root.querySelectorAll("[data-i18n-placeholder]")) {
+    element.placeholder = t(element.dataset.i18nPlaceholder);
+  }
+}
+
+// An empty code follows the system language; unknown languages get English
+export async function setLanguage(code) {
+  const wanted = code || navigator.language.split("-")[0];
+  const language = Object.hasOwn(LANGUAGES, wanted) ? wanted : "en";
+  if (language !== currentLanguage) {
+    messages =
+      language === "en"
+        ? en
+        : (await import(`./locales/${language}.js`)).default;
+    currentLanguage = language;
+  }
+  document.documentElement.lang = language;
+  translatePage();
+  return language;
+}
@@ -0,0 +1,84 @@
+// JSON normalization and line diffing, shared by the compare tab and its worker
+
+export function sortKeys(value) {
+  if (Array.isArray(value)) {
+    return value.map(sortKeys);
+  }
+  if (value && typeof value === "object") {
+    return Object.keys(value)
+      .sort()
+      .reduce((acc, key) => {
+        acc[key] = sortKeys(value[key]);
+        return acc;
+      }, {});
+  }
+  return value;
+}
+
+export function normalizedJson(text) {
+  const parsed = JSON.parse(text);
+  return JSON.stringify(sortKeys(parsed), null, 2);
+}
+
+// onProgress gets the completed fraction of the LCS table, about every 5%
+export function buildLineDiff(leftLines, rightLines, onProgress = null) {
+  const m = leftLines.length;
+  const n = rightLines.length;
+  const dp = Array.from({ length: m + 1 }, () => Array(n + 1).fill(0));
+  const progressStep = Math.max(1, Math.floor(m / 20));
+
+  for (let i = 1; i <= m; i += 1) {
+    if (onProgress && i % progressStep === 0) {
+      onProgress(i / m);
+    }
+    for (let j = 1; j <= n; j += 1) {
+      if (leftLines[i - 1] === rightLines[j - 1]) {
+        dp[i][j] = dp[i - 1][j - 1] + 1;
+      } else {
+        dp[i][j] = Math.max(dp[i - 1][j], dp[i][j - 1]);
+      }
+    }
+  }
+
+  const diff = [];
+  let i = m;
+  let j = n;
+
+  while (i > 0 || j > 0) {
+    if (i > 0 && j > 0 && leftLines[i - 1] === rightLines[j - 1]) {
+      diff.push({
+        type: "same",
+        left: leftLines[i - 1],
+        right: rightLines[j - 1],
+      });
+      i -= 1;
+      j -= 1;
+    } else if (j > 0 && (i === 0 || dp[i][j - 1] >= dp[i - 1][j])) {
+      diff.push({ type: "added", left: "", right: rightLines[j - 1] });
+      j -= 1;
+    } else {
+      diff.push({ type: "removed", left: leftLines[i - 1], right: "" });
+      i -= 1;
+    }
+  }
+
+  diff.reverse();
+
+  const merged = [];
+  for (let k = 0; k < diff.length; k += 1) {
+    const current = diff[k];
+    const next = diff[k + 1];
+    if (
+      current &&
+      current.type === "removed" &&
+      next &&
+      next.type === "added"
+    ) {
+      merged.push({ type: "changed", left: current.left, right: next.right });
+      k += 1;
+    } else {
+      merged.push(current);
+    }
+  }
+  return merged;
+}
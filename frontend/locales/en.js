@@ -0,0 +1,467 @@
+// English, the reference pack: every key used by the UI is defined here
+export default {
+  "menu.file": "☰ File",
+  "files.recent": "Recent files",
+
+  "tab.converter": "JSON Converter",
+  "tab.compare": "JSON Compare",
+  "tab.mermaid": "Mermaid Editor",
+  "tab.imageResizer": "Image Resizer",
+  "tab.openssl": "OpenSSL Cert",
+  "tab.traceroute": "Traceroute",
+  "tab.jsonHtml": "JSON to HTML",
+  "tab.stream": "Stream Viewer",
+
+  "theme.title": "Theme",
+  "theme.system": "🖥 System",
+  "theme.light": "☀ Light",
+  "theme.dark": "🌙 Dark",
+  "language.title": "Language",
+  "language.system": "🌐 System language",
+
+  "converter.minify": "Minify JSON",
+  "converter.format": "Format JSON",
+  "converter.pasteFormat": "📋 Paste & Format",
+  "converter.pasteFormatTitle":
+    "Load the clipboard and format it (Ctrl+Shift+V)",
+  "converter.jsonToString": "JSON → String",
+  "converter.stringToJson": "String → JSON",
+  "converter.jsonToMarkdown": "JSON → Markdown",
+  "converter.jsonToMarkdownTitle":
+    "Convert to Markdown and copy it to the clipboard",
+  "converter.jsonToProto": "JSON → Proto",
+  "converter.protoToJson": "Proto → JSON",
+  "converter.dependencyReport": "Dependency Report",
+  "converter.analyze": "Analyze",
+  "converter.lint": "Lint",
+  "converter.lintTitle":
+    "Report mixed arrays, numeric strings, key casing, deep nesting and huge strings",
+  "converter.validateStrict": "Strict Validate",
+  "converter.validateStrictTitle":
+    "Check RFC 8259 conformance: control characters, lone surrogates, duplicate keys and number precision",
+  "converter.compare": "Compare…",
+  "converter.compareTitle":
+    "Compare this tab with the next one in a new window",
+  "converter.convertToClass": "Convert to Class:",
+  "converter.className": "Class name (optional)",
+  "converter.generateClass": "Generate Class",
+
+  "editor.input": "Input",
+  "editor.output": "Output",
+  "editor.open": "📂 Open…",
+  "editor.openTitle": "Open a JSON file (Ctrl+O)",
+  "editor.copy": "📋 Copy",
+  "editor.inputPlaceholder": "Enter or paste JSON here...",
+  "editor.useAsInput": "⤴ Use as Input",
+  "editor.useAsInputTitle": "Use the output as the next input",
+  "editor.saveAs": "💾 Save As…",
+  "editor.saveAsTitle": "Save the output to a file (Ctrl+S)",
+  "editor.exportHtml": "🌐 HTML…",
+  "editor.exportHtmlTitle":
+    "Export as a standalone HTML page with collapsible nodes",
+  "editor.copyAsTitle": "Copy in another form",
+
+  "copyAs.formatted": "Formatted",
+  "copyAs.minified": "Minified",
+  "copyAs.escaped": "Escaped string literal",
+  "copyAs.base64": "Base64",
+  "copyAs.dataUri": "Data URI",
+  "copyAs.copied.formatted": "Formatted output",
+  "copyAs.copied.minified": "Minified output",
+  "copyAs.copied.escaped": "Escaped output",
+  "copyAs.copied.base64": "Base64 output",
+  "copyAs.copied.dataUri": "Data URI",
+
+  "format.json5": "JSON5",
+  "format.ndjson": "NDJSON",
+  "format.yaml": "YAML",
+  "format.xml": "XML",
+  "format.jwt": "a JWT",
+  "format.urlEncoded": "a URL-encoded string",
+  "format.escapedJson": "an escaped JSON string",
+  "suggestion.convertible": "Looks like { $format } — convert to JSON?",
+  "suggestion.unsupported":
+    "Looks like { $format }, which can't be converted to JSON here.",
+  "suggestion.convert": "Convert to JSON",
+  "suggestion.dismiss": "Dismiss",
+
+  "metrics.input": "Input",
+  "metrics.output": "Output",
+  "metrics.nodes": "{ $count } nodes",
+  "metrics.depth": "depth { $depth }",
+  "metrics.parse": "parse { $time }",
+  "metrics.depthNone": "depth —",
+  "metrics.parseNone": "parse —",
+  "metrics.notJson": "not JSON",
+  "metrics.empty": "empty",
+  "timing.none": "No operations timed yet",
+  "timing.summary":
+    "{ $operation } ({ $size }): parse { $parse } · transform { $transform } · serialize { $serialize } · total { $total }",
+  "timing.failed": "{ $operation } (failed)",
+  "timing.slowest": "⏱ Slowest",
+
+  "snippets.title": "Snippets",
+  "snippets.name": "Snippet name",
+  "snippets.tags": "Tags, comma separated",
+  "snippets.save": "Save Input as Snippet",
+  "snippets.search": "Search names and tags (#tag for an exact tag)",
+  "snippets.none": "No snippets saved yet",
+  "snippets.noMatches": "No matching snippets",
+  "snippets.load": "Load into the input",
+  "snippets.delete": "Delete snippet",
+  "snippets.savedAt": "saved { $date }",
+
+  "history.title": "Input history",
+  "history.select": "Select an entry to preview it",
+  "history.restore": "Restore Input",
+  "history.clear": "Clear History",
+  "history.none": "No inputs yet",
+  "history.remove": "Remove from history",
+
+  "settings.open": "Settings (Ctrl+,)",
+  "settings.title": "Settings",
+  "settings.output": "Output",
+  "settings.indent": "Indent:",
+  "settings.twoSpaces": "2 spaces",
+  "settings.fourSpaces": "4 spaces",
+  "settings.tab": "Tab",
+  "settings.sortKeys": "Sort keys",
+  "settings.lineEnding": "Line Ending:",
+  "settings.finalNewline": "Final newline",
+  "settings.bom": "UTF-8 BOM",
+  "settings.limits": "Performance limits",
+  "settings.workerThreads": "Worker threads:",
+  "settings.jobMemory": "Per-job memory (MB):",
+  "settings.maxInput": "Max input (MB):",
+  "settings.maxDepth": "Max nesting depth:",
+  "settings.maxString": "Max string (MB):",
+  "settings.ingest": "Local ingest server",
+  "settings.ingestEnabled": "Accept JSON on 127.0.0.1",
+  "settings.port": "Port:",
+  "settings.token": "Token:",
+  "settings.newToken": "New token",
+  "settings.note":
+    "Theme, language and class options are saved as you change them.",
+  "settings.import": "Import…",
+  "settings.export": "Export…",
+  "settings.reset": "Reset",
+  "settings.save": "Save",
+  "settings.cancel": "Cancel",
+
+  "status.error": "Error: { $error }",
+  "status.formatted": "✓ JSON formatted successfully",
+  "status.minified": "✓ JSON minified successfully",
+  "status.toString": "✓ JSON converted to string successfully",
+  "status.toJson": "✓ String converted to JSON successfully",
+  "status.copied": "✓ { $label } copied to clipboard",
+  "status.outputCopied": "✓ Output copied to clipboard",
+  "status.copyFailed": "Error: Failed to copy - { $error }",
+  "status.clipboardFailed": "Error: Failed to read the clipboard: { $error }",
+  "status.clipboardEmpty": "The clipboard has no text to format",
+  "status.converted": "✓ Converted { $format } to JSON",
+  "status.queryConverted": "✓ Query results converted to JSON",
+  "status.queryTruncated":
+    "✓ Query results converted to JSON - only the first { $rows } rows are shown",
+  "status.settingsSaved": "✓ Settings saved",
+  "status.settingsImported": "✓ Settings imported",
+  "status.settingsExported": "✓ Exported settings to { $name }",
+  "status.snippetName": "Enter a name for the snippet",
+  "status.snippetSaved": "✓ Saved snippet { $name }",
+  "status.snippetUpdated": "✓ Updated snippet { $name }",
+  "status.snippetDeleted": "✓ Deleted snippet { $name }",
+  "status.snippetLoaded": "✓ Loaded snippet { $name }",
+  "status.historyRestored": "✓ Restored input from history",
+  "status.historyCleared": "✓ Input history cleared",
+  "status.shortcutSet": "✓ { $shortcut } summons the window",
+  "status.shortcutOff": "✓ Global shortcut off",
+  "status.clipboardLoaded": "✓ Loaded the clipboard",
+  "status.received": "✓ Received { $name }",
+  "status.noOutputToSave": "No output to save",
+  "status.saved": "✓ Saved { $name }",
+  "status.noJsonToExport": "No JSON to export",
+  "status.exported": "✓ Exported { $name }",
+  "status.sessionRestored":
+    "✓ Restored { $count } document(s) from the last session",
+  "status.nothingToUndo": "Nothing to undo",
+  "status.nothingToRedo": "Nothing to redo",
+  "status.undone": "↶ Undid { $action }",
+  "status.redone": "↷ Redid { $action }",
+  "status.harOpened": "✓ Opened { $part } body of { $method } { $url }",
+  "status.outputNotJson": "Error: Output is not a JSON document",
+  "status.schemaFailures": "{ $count } node(s) fail the schema",
+  "status.schemaMatches": "✓ Document matches schema",
+  "status.bookmarked": "✓ Bookmarked { $pointer }",
+  "status.pointerMissing":
+    "Error: { $pointer } is not in the current document",
+  "status.treeNeedsOutput": "Run a transformation first to browse the tree",
+  "status.tableNeedsArray": "Error: Table view needs an array of objects",
+  "status.csvExported": "✓ Exported { $count } rows to CSV",
+  "status.updated": "✓ Updated { $pointer }",
+  "status.duplicated": "✓ Duplicated { $pointer }",
+  "status.deleted": "✓ Deleted { $pointer }",
+  "status.noOutput": "Run a transformation first",
+  "status.outputMoved": "✓ Output moved to input",
+  "status.compareNeedsOutput": "Run a transformation first to compare",
+  "status.protoGenerated": "✓ JSON converted to Proto schema successfully",
+  "status.protobufDecoded": "✓ Protobuf payload decoded successfully",
+  "status.grpcGenerated": "✓ gRPC service generated successfully",
+  "status.protoConverted": "✓ Proto schema converted to JSON successfully",
+  "status.classGenerated":
+    "✓ JSON converted to { $language } class successfully",
+  "status.filesSaved": "✓ Saved { $count } files to { $directory }",
+  "status.sampleGenerated": "✓ Sample payload generated successfully",
+  "status.tablesFound": "✓ Found { $count } table(s)",
+  "status.fileFormatted": "✓ Formatted into { $path } ({ $size })",
+  "status.fileMinified": "✓ Minified into { $path } ({ $size })",
+  "status.batchDone":
+    "✓ Batch: { $succeeded } succeeded, { $failed } failed in { $ms } ms",
+  "status.batchFailed":
+    "Batch: { $succeeded } succeeded, { $failed } failed in { $ms } ms",
+  "status.watching": "✓ Watching { $path } (updated { $time })",
+  "status.watchStopped": "✓ Stopped watching",
+  "status.httpOk": "✓ { $summary }",
+  "status.httpError": "HTTP { $summary }",
+  "status.profileSaved": "✓ Saved connection profile { $label }",
+  "status.keyFetched": "✓ Fetched { $key }",
+  "status.missingVariables":
+    "Missing variables: { $names } ({ $count } substituted)",
+  "status.substituted": "✓ Substituted { $count } placeholder(s)",
+  "status.configResolved": "✓ Resolved { $count } key(s) across layers",
+  "status.dependencies":
+    "✓ { $manifest }: { $count } dependencies, { $duplicates } declared more than once",
+  "status.noDocumentType": "No known document type detected",
+  "status.documentsDetected": "✓ Detected { $labels } ({ $count } issue(s))",
+  "status.flagsFound": "✓ Found { $count } flag(s)",
+  "status.flagEvaluated": "✓ { $key } = { $value } ({ $reason })",
+  "status.lintClean": "✓ No structural issues found",
+  "status.lintIssues": "Found { $count } lint issue(s)",
+  "status.conforming": "✓ Conforms to RFC 8259",
+  "status.conformanceIssues": "✗ { $count } conformance issue(s)",
+  "http.noContentType": "no content type",
+  "flags.off": "{ $key } (off)",
+
+  "action.paste": "Paste",
+  "action.ingest": "Ingest",
+  "action.convert": "Convert to JSON",
+  "action.load": "Load",
+  "action.loadSnippet": "Load snippet",
+  "action.restoreInput": "Restore input",
+  "action.decode": "Decode",
+  "action.sanitize": "Sanitize",
+  "action.useOutput": "Use output",
+  "action.fetch": "Fetch",
+  "action.replayCurl": "Replay cURL",
+  "action.edit": "Edit",
+  "action.transform": "Transform",
+  "action.clear": "Clear",
+
+  "validation.valid": "✓ Valid",
+  "validation.invalid": "✗ Invalid",
+  "progress.parsing": "Parsing… { $percent }%",
+  "progress.serializing": "Serializing… { $percent }%",
+  "progress.streaming": "Streaming… { $percent }%",
+  "progress.cancelling": "Cancelling…",
+
+  "dialog.openJson": "Open JSON file",
+  "dialog.saveOutput": "Save output as",
+  "dialog.exportHtml": "Export as HTML",
+  "dialog.exportSettings": "Export settings",
+  "dialog.importSettings": "Import settings",
+
+  "lint.title": "Lint",
+  "lint.mixedArray": "Mixed array",
+  "lint.numericString": "Numeric string",
+  "lint.keyCasing": "Key casing",
+  "lint.deepNesting": "Deep nesting",
+  "lint.largeString": "Large string",
+  "lint.truncated": "Only the first { $count } issues are listed.",
+
+  "conformance.pass": "RFC 8259 conformance: pass",
+  "conformance.fail": "RFC 8259 conformance: fail",
+  "conformance.syntax": "RFC 8259 grammar",
+  "conformance.controlCharacter": "No unescaped control characters",
+  "conformance.loneSurrogate": "No lone surrogates",
+  "conformance.duplicateKey": "Unique object keys",
+  "conformance.precisionLoss": "Numbers fit IEEE 754 doubles",
+  "conformance.truncated": "Only the first { $count } findings are listed.",
+
+  "status.loaded": "✓ Loaded { $name }",
+  "status.loadedWithNotes": "✓ Loaded { $name } ({ $notes })",
+  "status.decoded": "✓ { $decoder }: { $name }",
+  "status.opened": "✓ Opened { $name }",
+  "status.surrogatesReplaced":
+    "✓ Replaced { $count } unpaired surrogate escape(s) with U+FFFD",
+  "load.converted": "{ $description }, converted to UTF-8",
+  "load.replacements": "{ $count } replacement(s) with U+FFFD",
+  "binary.notice": "{ $name } looks like { $description }, not JSON text.",
+  "archive.summary": "{ $name }: { $count } file(s), { $json } JSON",
+  "decoder.gunzip": "Decompress gzip",
+  "decoder.archive": "Browse archive",
+  "decoder.protobuf": "Decode protobuf fields",
+  "decoder.image": "Open in Image Tools",
+  "decoder.base64": "Show as Base64",
+
+  "common.clear": "Clear",
+  "common.cancel": "✕ Cancel",
+
+  "compare.beautifyLeft": "Beautify Left",
+  "compare.beautifyRight": "Beautify Right",
+  "compare.compare": "Compare",
+  "compare.copyDiff": "Copy Diff Text",
+  "compare.newWindow": "⧉ New Window",
+  "compare.clearAll": "Clear All",
+  "compare.leftJson": "Left JSON",
+  "compare.rightJson": "Right JSON",
+  "compare.leftPlaceholder": "Enter or paste left JSON...",
+  "compare.rightPlaceholder": "Enter or paste right JSON...",
+  "compare.left": "Left",
+  "compare.right": "Right",
+  "compare.leftInput": "Left input",
+  "compare.rightInput": "Right input",
+  "compare.diff": "Diff",
+  "compare.noDiff": "No diff to copy",
+  "compare.leftBeautified": "✓ Left JSON beautified",
+  "compare.rightBeautified": "✓ Right JSON beautified",
+  "compare.progress": "Comparing… { $percent }%",
+  "compare.complete": "✓ Comparison complete",
+
+  "mermaid.render": "🔄 Render Diagram",
+  "mermaid.downloadPng": "📥 Download PNG",
+  "mermaid.code": "Mermaid Code",
+  "mermaid.preview": "Preview",
+  "mermaid.inputPlaceholder":
+    "Enter Mermaid diagram code here...\n\nExample:\ngraph TD\n    A[Start] --> B{Is it working?}\n    B -->|Yes| C[Great!]\n    B -->|No| D[Debug]\n    D --> B",
+  "mermaid.dragTitle": "Toggle Drag/Pan Mode",
+  "mermaid.zoomOut": "Zoom Out",
+  "mermaid.zoomIn": "Zoom In",
+  "mermaid.zoomReset": "Reset Zoom",
+  "mermaid.placeholder":
+    'Enter Mermaid code and click "Render Diagram" to preview',
+  "mermaid.rendered": "✓ Diagram rendered successfully",
+  "mermaid.renderError": "Error rendering diagram:",
+  "mermaid.renderFailed": "Error rendering Mermaid diagram",
+  "mermaid.nothingToDownload":
+    "No diagram to download. Please render a diagram first.",
+  "mermaid.downloaded": "✓ PNG downloaded successfully",
+  "mermaid.imageFailed": "Error creating image for download",
+  "mermaid.downloadFailed": "Error downloading PNG: { $error }",
+  "mermaid.cleared": "Mermaid editor cleared!",
+
+  "image.select": "📁 Select Image",
+  "image.convertToPng": "🖼️ Convert to PNG",
+  "image.removeBackground": "✂️ Remove Background",
+  "image.download": "📥 Download Resized Image",
+  "image.apply": "🔄 Apply Changes",
+  "image.resizeMode": "Resize Mode:",
+  "image.byPercentage": "By Percentage",
+  "image.byDimensions": "By Dimensions",
+  "image.qualityOnly": "Quality Only",
+  "image.scale": "Scale:",
+  "image.widthLabel": "Width:",
+  "image.heightLabel": "Height:",
+  "image.width": "Width",
+  "image.height": "Height",
+  "image.keepAspect": "Maintain Aspect Ratio",
+  "image.quality": "Quality:",
+  "image.format": "Format:",
+  "image.tolerance": "BG Tolerance:",
+  "image.original": "Original Image",
+  "image.resizedImage": "Resized Image",
+  "image.selectHint": 'Click "Select Image" to load an image',
+  "image.resizedPlaceholder": "Resized image will appear here",
+  "image.applyHint": 'Click "Apply Changes" to resize',
+  "image.invalidFile": "Please select a valid image file",
+  "image.selectFirst": "Please select an image first",
+  "image.loaded": "Image loaded successfully!",
+  "image.resized": "Image resized successfully!",
+  "image.nothingToDownload": "No resized image to download",
+  "image.downloaded": "Image downloaded!",
+  "image.convertedToPng": "Image converted to PNG successfully!",
+  "image.removalInProgress": "Background removal already in progress...",
+  "image.processing": "⏳ Processing...",
+  "image.removingBackground":
+    "Removing background... This may take a moment.",
+  "image.backgroundRemoved": "Background removed successfully!",
+  "image.removalFailed": "Failed to remove background. Please try again.",
+  "image.cleared": "Image resizer cleared!",
+
+  "openssl.showDetail": "Show Cert Detail",
+  "openssl.urlLabel": "URL Input:",
+  "openssl.urlPlaceholder":
+    "Enter URL (e.g. https://maskipli.com or http://maskipli.com)",
+  "openssl.mode": "Mode:",
+  "openssl.leafOnly": "Show leaf only",
+  "openssl.fullChain": "Show full chain",
+  "openssl.checkUrl": "Check URL SSL",
+  "openssl.certificateString": "Certificate String",
+  "openssl.certificateDetail": "Certificate Detail",
+  "openssl.inputPlaceholder":
+    "Paste certificate string (PEM or base64 DER) here...",
+  "openssl.outputPlaceholder": "OpenSSL detail output will appear here...",
+  "openssl.input": "Certificate input",
+  "openssl.output": "Certificate detail",
+  "openssl.generated":
+    "✓ Certificate detail generated successfully ({ $count } certificate(s))",
+  "openssl.urlRequired": "Please enter a URL to check SSL detail",
+  "openssl.fetched": "✓ SSL detail fetched from URL successfully ({ $mode })",
+
+  "traceroute.run": "Run Traceroute",
+  "traceroute.running": "Running...",
+  "traceroute.urlPlaceholder":
+    "Enter URL or host (e.g. https://example.com or example.com)",
+  "traceroute.result": "Traceroute Result",
+  "traceroute.outputPlaceholder": "Traceroute output will appear here...",
+  "traceroute.tracing": "Tracing route...",
+  "traceroute.output": "Traceroute output",
+  "traceroute.urlRequired": "Please enter a URL or host for traceroute",
+  "traceroute.completed": "✓ Traceroute completed successfully",
+
+  "jsonHtml.render": "Render HTML",
+  "jsonHtml.copyResult": "📋 Copy Result",
+  "jsonHtml.jsonData": "JSON Data",
+  "jsonHtml.dataPlaceholder":
+    'Enter JSON data here... e.g. {"name": "John", "age": 30}',
+  "jsonHtml.templateLabel": "HTML Template (use {{key}} for JSON values)",
+  "jsonHtml.templatePlaceholder":
+    "Enter HTML template here... e.g. <h1>{{name}}</h1><p>Age: {{age}}</p>",
+  "jsonHtml.resultPreview": "Result Preview",
+  "jsonHtml.placeholder": "Result will appear here after rendering",
+  "jsonHtml.data": "JSON data",
+  "jsonHtml.template": "HTML template",
+  "jsonHtml.result": "HTML result",
+  "jsonHtml.dataRequired": "Please enter JSON data",
+  "jsonHtml.templateRequired": "Please enter an HTML template",
+  "jsonHtml.rendered": "✓ HTML rendered successfully!",
+  "jsonHtml.formatted": "✓ JSON data formatted successfully",
+  "jsonHtml.cleared": "JSON to HTML cleared!",
+
+  "stream.connect": "Connect",
+  "stream.disconnect": "Disconnect",
+  "stream.pause": "Pause",
+  "stream.resume": "Resume ({ $count })",
+  "stream.export": "Export…",
+  "stream.send": "Send",
+  "stream.endpoint": "Endpoint:",
+  "stream.urlPlaceholder":
+    "wss://… for a WebSocket, https://… for Server-Sent Events",
+  "stream.eventsPlaceholder": "SSE event names (optional)",
+  "stream.eventsTitle":
+    "Comma-separated named events to listen for besides 'message'",
+  "stream.sendLabel": "Send:",
+  "stream.sendPlaceholder": "Message to send over the WebSocket (Enter)",
+  "stream.search": "Search:",
+  "stream.searchPlaceholder": "Show only messages containing…",
+  "stream.empty": "Messages appear here once connected.",
+  "stream.messages": "{ $count } message(s)",
+  "stream.held": "{ $count } held",
+  "stream.connected": "Connected to { $url }",
+  "stream.disconnected": "Disconnected",
+  "stream.closed": "Closed ({ $code })",
+  "stream.socketError": "WebSocket error",
+  "stream.serverClosed": "Stream closed by the server",
+  "stream.reconnecting": "Connection lost, reconnecting…",
+  "stream.invalidUrl": "Enter a ws://, wss://, http:// or https:// URL",
+  "stream.nothingToExport": "No messages to export",
+  "stream.exportTitle": "Export stream messages",
+  "stream.exported": "✓ Exported { $count } message(s)",
+};
@@ -0,0 +1,467 @@
+// Bahasa Indonesia; missing keys fall back to English
+export default {
+  "menu.file": "☰ Berkas",
+  "files.recent": "Berkas terbaru",
+
+  "tab.converter": "Konverter JSON",
+  "tab.compare": "Bandingkan JSON",
+  "tab.mermaid": "Editor Mermaid",
+  "tab.imageResizer": "Ubah Ukuran Gambar",
+  "tab.openssl": "Sertifikat OpenSSL",
+  "tab.traceroute": "Traceroute",
+  "tab.jsonHtml": "JSON ke HTML",
+  "tab.stream": "Penampil Stream",
+
+  "theme.title": "Tema",
+  "theme.system": "🖥 Sistem",
+  "theme.light": "☀ Terang",
+  "theme.dark": "🌙 Gelap",
+  "language.title": "Bahasa",
+  "language.system": "🌐 Bahasa sistem",
+
+  "converter.minify": "Perkecil JSON",
+  "converter.format": "Rapikan JSON",
+  "converter.pasteFormat": "📋 Tempel & Rapikan",
+  "converter.pasteFormatTitle":
+    "Muat isi clipboard lalu rapikan (Ctrl+Shift+V)",
+  "converter.jsonToString": "JSON → String",
+  "converter.stringToJson": "String → JSON",
+  "converter.jsonToMarkdown": "JSON → Markdown",
+  "converter.jsonToMarkdownTitle":
+    "Ubah ke Markdown dan salin ke clipboard",
+  "converter.jsonToProto": "JSON → Proto",
+  "converter.protoToJson": "Proto → JSON",
+  "converter.dependencyReport": "Laporan Dependensi",
+  "converter.analyze": "Analisis",
+  "converter.lint": "Lint",
+  "converter.lintTitle":
+    "Laporkan array campuran, angka sebagai string, gaya kunci, nesting dalam, dan string besar",
+  "converter.validateStrict": "Validasi Ketat",
+  "converter.validateStrictTitle":
+    "Periksa kesesuaian RFC 8259: karakter kontrol, surrogate tunggal, kunci ganda, dan presisi angka",
+  "converter.compare": "Bandingkan…",
+  "converter.compareTitle":
+    "Bandingkan tab ini dengan tab berikutnya di jendela baru",
+  "converter.convertToClass": "Ubah ke Kelas:",
+  "converter.className": "Nama kelas (opsional)",
+  "converter.generateClass": "Buat Kelas",
+
+  "editor.input": "Masukan",
+  "editor.output": "Keluaran",
+  "editor.open": "📂 Buka…",
+  "editor.openTitle": "Buka berkas JSON (Ctrl+O)",
+  "editor.copy": "📋 Salin",
+  "editor.inputPlaceholder": "Ketik atau tempel JSON di sini...",
+  "editor.useAsInput": "⤴ Jadikan Masukan",
+  "editor.useAsInputTitle": "Gunakan keluaran sebagai masukan berikutnya",
+  "editor.saveAs": "💾 Simpan Sebagai…",
+  "editor.saveAsTitle": "Simpan keluaran ke berkas (Ctrl+S)",
+  "editor.exportHtml": "🌐 HTML…",
+  "editor.exportHtmlTitle":
+    "Ekspor sebagai halaman HTML mandiri dengan node yang bisa dilipat",
+  "editor.copyAsTitle": "Salin dalam bentuk lain",
+
+  "copyAs.formatted": "Rapi",
+  "copyAs.minified": "Diperkecil",
+  "copyAs.escaped": "Literal string ter-escape",
+  "copyAs.base64": "Base64",
+  "copyAs.dataUri": "Data URI",
+  "copyAs.copied.formatted": "Keluaran rapi",
+  "copyAs.copied.minified": "Keluaran diperkecil",
+  "copyAs.copied.escaped": "Keluaran ter-escape",
+  "copyAs.copied.base64": "Keluaran Base64",
+  "copyAs.copied.dataUri": "Data URI",
+
+  "format.json5": "JSON5",
+  "format.ndjson": "NDJSON",
+  "format.yaml": "YAML",
+  "format.xml": "XML",
+  "format.jwt": "JWT",
+  "format.urlEncoded": "string URL-encoded",
+  "format.escapedJson": "string JSON ter-escape",
+  "suggestion.convertible": "Sepertinya { $format } — ubah ke JSON?",
+  "suggestion.unsupported":
+    "Sepertinya { $format }, yang belum bisa diubah ke JSON di sini.",
+  "suggestion.convert": "Ubah ke JSON",
+  "suggestion.dismiss": "Tutup",
+
+  "metrics.input": "Masukan",
+  "metrics.output": "Keluaran",
+  "metrics.nodes": "{ $count } node",
+  "metrics.depth": "kedalaman { $depth }",
+  "metrics.parse": "parse { $time }",
+  "metrics.depthNone": "kedalaman —",
+  "metrics.parseNone": "parse —",
+  "metrics.notJson": "bukan JSON",
+  "metrics.empty": "kosong",
+  "timing.none": "Belum ada operasi yang diukur",
+  "timing.summary":
+    "{ $operation } ({ $size }): parse { $parse } · transformasi { $transform } · serialisasi { $serialize } · total { $total }",
+  "timing.failed": "{ $operation } (gagal)",
+  "timing.slowest": "⏱ Paling lambat",
+
+  "snippets.title": "Cuplikan",
+  "snippets.name": "Nama cuplikan",
+  "snippets.tags": "Tag, pisahkan dengan koma",
+  "snippets.save": "Simpan Masukan sebagai Cuplikan",
+  "snippets.search": "Cari nama dan tag (#tag untuk tag persis)",
+  "snippets.none": "Belum ada cuplikan tersimpan",
+  "snippets.noMatches": "Tidak ada cuplikan yang cocok",
+  "snippets.load": "Muat ke masukan",
+  "snippets.delete": "Hapus cuplikan",
+  "snippets.savedAt": "disimpan { $date }",
+
+  "history.title": "Riwayat masukan",
+  "history.select": "Pilih entri untuk melihat isinya",
+  "history.restore": "Pulihkan Masukan",
+  "history.clear": "Hapus Riwayat",
+  "history.none": "Belum ada masukan",
+  "history.remove": "Hapus dari riwayat",
+
+  "settings.open": "Pengaturan (Ctrl+,)",
+  "settings.title": "Pengaturan",
+  "settings.output": "Keluaran",
+  "settings.indent": "Indentasi:",
+  "settings.twoSpaces": "2 spasi",
+  "settings.fourSpaces": "4 spasi",
+  "settings.tab": "Tab",
+  "settings.sortKeys": "Urutkan kunci",
+  "settings.lineEnding": "Akhir baris:",
+  "settings.finalNewline": "Baris baru di akhir",
+  "settings.bom": "BOM UTF-8",
+  "settings.limits": "Batas performa",
+  "settings.workerThreads": "Thread pekerja:",
+  "settings.jobMemory": "Memori per tugas (MB):",
+  "settings.maxInput": "Masukan maks (MB):",
+  "settings.maxDepth": "Kedalaman maks:",
+  "settings.maxString": "String maks (MB):",
+  "settings.ingest": "Server ingest lokal",
+  "settings.ingestEnabled": "Terima JSON di 127.0.0.1",
+  "settings.port": "Port:",
+  "settings.token": "Token:",
+  "settings.newToken": "Token baru",
+  "settings.note":
+    "Tema, bahasa, dan opsi kelas tersimpan saat diubah.",
+  "settings.import": "Impor…",
+  "settings.export": "Ekspor…",
+  "settings.reset": "Atur Ulang",
+  "settings.save": "Simpan",
+  "settings.cancel": "Batal",
+
+  "status.error": "Galat: { $error }",
+  "status.formatted": "✓ JSON berhasil dirapikan",
+  "status.minified": "✓ JSON berhasil diperkecil",
+  "status.toString": "✓ JSON berhasil diubah ke string",
+  "status.toJson": "✓ String berhasil diubah ke JSON",
+  "status.copied": "✓ { $label } disalin ke clipboard",
+  "status.outputCopied": "✓ Keluaran disalin ke clipboard",
+  "status.copyFailed": "Galat: Gagal menyalin - { $error }",
+  "status.clipboardFailed": "Galat: Gagal membaca clipboard: { $error }",
+  "status.clipboardEmpty": "Clipboard tidak berisi teks untuk dirapikan",
+  "status.converted": "✓ { $format } diubah ke JSON",
+  "status.queryConverted": "✓ Hasil kueri diubah ke JSON",
+  "status.queryTruncated":
+    "✓ Hasil kueri diubah ke JSON - hanya { $rows } baris pertama yang ditampilkan",
+  "status.settingsSaved": "✓ Pengaturan disimpan",
+  "status.settingsImported": "✓ Pengaturan diimpor",
+  "status.settingsExported": "✓ Pengaturan diekspor ke { $name }",
+  "status.snippetName": "Masukkan nama untuk cuplikan",
+  "status.snippetSaved": "✓ Cuplikan { $name } disimpan",
+  "status.snippetUpdated": "✓ Cuplikan { $name } diperbarui",
+  "status.snippetDeleted": "✓ Cuplikan { $name } dihapus",
+  "status.snippetLoaded": "✓ Cuplikan { $name } dimuat",
+  "status.historyRestored": "✓ Masukan dipulihkan dari riwayat",
+  "status.historyCleared": "✓ Riwayat masukan dihapus",
+  "status.shortcutSet": "✓ { $shortcut } memanggil jendela",
+  "status.shortcutOff": "✓ Pintasan global nonaktif",
+  "status.clipboardLoaded": "✓ Clipboard dimuat",
+  "status.received": "✓ { $name } diterima",
+  "status.noOutputToSave": "Tidak ada keluaran untuk disimpan",
+  "status.saved": "✓ { $name } disimpan",
+  "status.noJsonToExport": "Tidak ada JSON untuk diekspor",
+  "status.exported": "✓ { $name } diekspor",
+  "status.sessionRestored":
+    "✓ { $count } dokumen dipulihkan dari sesi terakhir",
+  "status.nothingToUndo": "Tidak ada yang bisa dibatalkan",
+  "status.nothingToRedo": "Tidak ada yang bisa diulang",
+  "status.undone": "↶ { $action } dibatalkan",
+  "status.redone": "↷ { $action } diulang",
+  "status.harOpened": "✓ Isi { $part } dari { $method } { $url } dibuka",
+  "status.outputNotJson": "Kesalahan: Keluaran bukan dokumen JSON",
+  "status.schemaFailures": "{ $count } node tidak sesuai skema",
+  "status.schemaMatches": "✓ Dokumen sesuai skema",
+  "status.bookmarked": "✓ { $pointer } ditandai",
+  "status.pointerMissing":
+    "Kesalahan: { $pointer } tidak ada di dokumen saat ini",
+  "status.treeNeedsOutput":
+    "Jalankan transformasi terlebih dahulu untuk menjelajahi pohon",
+  "status.tableNeedsArray":
+    "Kesalahan: Tampilan tabel memerlukan array berisi objek",
+  "status.csvExported": "✓ { $count } baris diekspor ke CSV",
+  "status.updated": "✓ { $pointer } diperbarui",
+  "status.duplicated": "✓ { $pointer } diduplikasi",
+  "status.deleted": "✓ { $pointer } dihapus",
+  "status.noOutput": "Jalankan transformasi terlebih dahulu",
+  "status.outputMoved": "✓ Keluaran dipindahkan ke masukan",
+  "status.compareNeedsOutput":
+    "Jalankan transformasi terlebih dahulu untuk membandingkan",
+  "status.protoGenerated": "✓ JSON berhasil diubah ke skema Proto",
+  "status.protobufDecoded": "✓ Payload Protobuf berhasil didekode",
+  "status.grpcGenerated": "✓ Layanan gRPC berhasil dibuat",
+  "status.protoConverted": "✓ Skema Proto berhasil diubah ke JSON",
+  "status.classGenerated": "✓ JSON berhasil diubah ke kelas { $language }",
+  "status.filesSaved": "✓ { $count } berkas disimpan ke { $directory }",
+  "status.sampleGenerated": "✓ Contoh payload berhasil dibuat",
+  "status.tablesFound": "✓ { $count } tabel ditemukan",
+  "status.fileFormatted": "✓ Diformat ke { $path } ({ $size })",
+  "status.fileMinified": "✓ Diminifikasi ke { $path } ({ $size })",
+  "status.batchDone":
+    "✓ Batch: { $succeeded } berhasil, { $failed } gagal dalam { $ms } ms",
+  "status.batchFailed":
+    "Batch: { $succeeded } berhasil, { $failed } gagal dalam { $ms } ms",
+  "status.watching": "✓ Memantau { $path } (diperbarui { $time })",
+  "status.watchStopped": "✓ Pemantauan dihentikan",
+  "status.httpOk": "✓ { $summary }",
+  "status.httpError": "HTTP { $summary }",
+  "status.profileSaved": "✓ Profil koneksi { $label } disimpan",
+  "status.keyFetched": "✓ { $key } diambil",
+  "status.missingVariables":
+    "Variabel hilang: { $names } ({ $count } diganti)",
+  "status.substituted": "✓ { $count } placeholder diganti",
+  "status.configResolved":
+    "✓ { $count } kunci diselesaikan dari semua lapisan",
+  "status.dependencies":
+    "✓ { $manifest }: { $count } dependensi, { $duplicates } dideklarasikan lebih dari sekali",
+  "status.noDocumentType": "Tidak ada jenis dokumen yang dikenali",
+  "status.documentsDetected": "✓ Terdeteksi { $labels } ({ $count } masalah)",
+  "status.flagsFound": "✓ { $count } flag ditemukan",
+  "status.flagEvaluated": "✓ { $key } = { $value } ({ $reason })",
+  "status.lintClean": "✓ Tidak ada masalah struktur",
+  "status.lintIssues": "{ $count } masalah lint ditemukan",
+  "status.conforming": "✓ Sesuai RFC 8259",
+  "status.conformanceIssues": "✗ { $count } masalah kesesuaian",
+  "http.noContentType": "tanpa tipe konten",
+  "flags.off": "{ $key } (nonaktif)",
+
+  "action.paste": "Tempel",
+  "action.ingest": "Terima",
+  "action.convert": "Ubah ke JSON",
+  "action.load": "Muat",
+  "action.loadSnippet": "Muat cuplikan",
+  "action.restoreInput": "Pulihkan masukan",
+  "action.decode": "Dekode",
+  "action.sanitize": "Bersihkan",
+  "action.useOutput": "Gunakan keluaran",
+  "action.fetch": "Ambil",
+  "action.replayCurl": "Putar ulang cURL",
+  "action.edit": "Edit",
+  "action.transform": "Transformasi",
+  "action.clear": "Bersihkan",
+
+  "validation.valid": "✓ Valid",
+  "validation.invalid": "✗ Tidak valid",
+  "progress.parsing": "Mengurai… { $percent }%",
+  "progress.serializing": "Menyusun… { $percent }%",
+  "progress.streaming": "Mengalirkan… { $percent }%",
+  "progress.cancelling": "Membatalkan…",
+
+  "dialog.openJson": "Buka berkas JSON",
+  "dialog.saveOutput": "Simpan keluaran sebagai",
+  "dialog.exportHtml": "Ekspor sebagai HTML",
+  "dialog.exportSettings": "Ekspor pengaturan",
+  "dialog.importSettings": "Impor pengaturan",
+
+  "lint.title": "Lint",
+  "lint.mixedArray": "Array campuran",
+  "lint.numericString": "String numerik",
+  "lint.keyCasing": "Gaya huruf kunci",
+  "lint.deepNesting": "Sarang terlalu dalam",
+  "lint.largeString": "String besar",
+  "lint.truncated": "Hanya { $count } masalah pertama yang ditampilkan.",
+
+  "conformance.pass": "Kesesuaian RFC 8259: lulus",
+  "conformance.fail": "Kesesuaian RFC 8259: gagal",
+  "conformance.syntax": "Tata bahasa RFC 8259",
+  "conformance.controlCharacter": "Tanpa karakter kontrol yang tidak di-escape",
+  "conformance.loneSurrogate": "Tanpa surrogate tunggal",
+  "conformance.duplicateKey": "Kunci objek unik",
+  "conformance.precisionLoss": "Angka muat dalam double IEEE 754",
+  "conformance.truncated": "Hanya { $count } temuan pertama yang ditampilkan.",
+
+  "status.loaded": "✓ { $name } dimuat",
+  "status.loadedWithNotes": "✓ { $name } dimuat ({ $notes })",
+  "status.opened": "✓ { $name } dibuka",
+  "status.surrogatesReplaced":
+    "✓ { $count } escape surrogate tunggal diganti dengan U+FFFD",
+  "load.converted": "{ $description }, diubah ke UTF-8",
+  "load.replacements": "{ $count } penggantian dengan U+FFFD",
+  "binary.notice": "{ $name } tampaknya { $description }, bukan teks JSON.",
+  "archive.summary": "{ $name }: { $count } berkas, { $json } JSON",
+  "decoder.gunzip": "Dekompresi gzip",
+  "decoder.archive": "Jelajahi arsip",
+  "decoder.protobuf": "Dekode field protobuf",
+  "decoder.image": "Buka di Alat Gambar",
+  "decoder.base64": "Tampilkan sebagai Base64",
+
+  "common.clear": "Bersihkan",
+  "common.cancel": "✕ Batal",
+
+  "compare.beautifyLeft": "Rapikan Kiri",
+  "compare.beautifyRight": "Rapikan Kanan",
+  "compare.compare": "Bandingkan",
+  "compare.copyDiff": "Salin Teks Diff",
+  "compare.newWindow": "⧉ Jendela Baru",
+  "compare.clearAll": "Bersihkan Semua",
+  "compare.leftJson": "JSON Kiri",
+  "compare.rightJson": "JSON Kanan",
+  "compare.leftPlaceholder": "Ketik atau tempel JSON kiri...",
+  "compare.rightPlaceholder": "Ketik atau tempel JSON kanan...",
+  "compare.left": "Kiri",
+  "compare.right": "Kanan",
+  "compare.leftInput": "Masukan kiri",
+  "compare.rightInput": "Masukan kanan",
+  "compare.noDiff": "Tidak ada diff untuk disalin",
+  "compare.leftBeautified": "✓ JSON kiri dirapikan",
+  "compare.rightBeautified": "✓ JSON kanan dirapikan",
+  "compare.progress": "Membandingkan… { $percent }%",
+  "compare.complete": "✓ Perbandingan selesai",
+
+  "mermaid.render": "🔄 Render Diagram",
+  "mermaid.downloadPng": "📥 Unduh PNG",
+  "mermaid.code": "Kode Mermaid",
+  "mermaid.preview": "Pratinjau",
+  "mermaid.inputPlaceholder":
+    "Ketik kode diagram Mermaid di sini...\n\nContoh:\ngraph TD\n    A[Mulai] --> B{Berhasil?}\n    B -->|Ya| C[Bagus!]\n    B -->|Tidak| D[Debug]\n    D --> B",
+  "mermaid.dragTitle": "Mode Geser/Pan",
+  "mermaid.zoomOut": "Perkecil",
+  "mermaid.zoomIn": "Perbesar",
+  "mermaid.zoomReset": "Atur Ulang Zoom",
+  "mermaid.placeholder":
+    'Ketik kode Mermaid lalu klik "Render Diagram" untuk pratinjau',
+  "mermaid.rendered": "✓ Diagram berhasil dirender",
+  "mermaid.renderError": "Gagal merender diagram:",
+  "mermaid.renderFailed": "Gagal merender diagram Mermaid",
+  "mermaid.nothingToDownload":
+    "Tidak ada diagram untuk diunduh. Render diagram terlebih dahulu.",
+  "mermaid.downloaded": "✓ PNG berhasil diunduh",
+  "mermaid.imageFailed": "Gagal membuat gambar untuk diunduh",
+  "mermaid.downloadFailed": "Gagal mengunduh PNG: { $error }",
+  "mermaid.cleared": "Editor Mermaid dibersihkan!",
+
+  "image.select": "📁 Pilih Gambar",
+  "image.convertToPng": "🖼️ Ubah ke PNG",
+  "image.removeBackground": "✂️ Hapus Latar",
+  "image.download": "📥 Unduh Gambar",
+  "image.apply": "🔄 Terapkan",
+  "image.resizeMode": "Mode ubah ukuran:",
+  "image.byPercentage": "Persentase",
+  "image.byDimensions": "Dimensi",
+  "image.qualityOnly": "Kualitas saja",
+  "image.scale": "Skala:",
+  "image.widthLabel": "Lebar:",
+  "image.heightLabel": "Tinggi:",
+  "image.width": "Lebar",
+  "image.height": "Tinggi",
+  "image.keepAspect": "Pertahankan rasio aspek",
+  "image.quality": "Kualitas:",
+  "image.format": "Format:",
+  "image.tolerance": "Toleransi latar:",
+  "image.original": "Gambar Asli",
+  "image.resizedImage": "Gambar Hasil",
+  "image.selectHint": 'Klik "Pilih Gambar" untuk memuat gambar',
+  "image.resizedPlaceholder": "Gambar hasil akan muncul di sini",
+  "image.applyHint": 'Klik "Terapkan" untuk mengubah ukuran',
+  "image.invalidFile": "Pilih berkas gambar yang valid",
+  "image.selectFirst": "Pilih gambar terlebih dahulu",
+  "image.loaded": "Gambar berhasil dimuat!",
+  "image.resized": "Ukuran gambar berhasil diubah!",
+  "image.nothingToDownload": "Tidak ada gambar hasil untuk diunduh",
+  "image.downloaded": "Gambar diunduh!",
+  "image.convertedToPng": "Gambar berhasil diubah ke PNG!",
+  "image.removalInProgress": "Penghapusan latar sedang berjalan...",
+  "image.processing": "⏳ Memproses...",
+  "image.removingBackground": "Menghapus latar... Mohon tunggu sebentar.",
+  "image.backgroundRemoved": "Latar berhasil dihapus!",
+  "image.removalFailed": "Gagal menghapus latar. Silakan coba lagi.",
+  "image.cleared": "Pengubah ukuran gambar dibersihkan!",
+
+  "openssl.showDetail": "Tampilkan Detail Sertifikat",
+  "openssl.urlLabel": "URL:",
+  "openssl.urlPlaceholder":
+    "Masukkan URL (mis. https://maskipli.com atau http://maskipli.com)",
+  "openssl.mode": "Mode:",
+  "openssl.leafOnly": "Sertifikat leaf saja",
+  "openssl.fullChain": "Seluruh rantai",
+  "openssl.checkUrl": "Periksa SSL URL",
+  "openssl.certificateString": "String Sertifikat",
+  "openssl.certificateDetail": "Detail Sertifikat",
+  "openssl.inputPlaceholder":
+    "Tempel string sertifikat (PEM atau DER base64) di sini...",
+  "openssl.outputPlaceholder": "Detail OpenSSL akan muncul di sini...",
+  "openssl.input": "Masukan sertifikat",
+  "openssl.output": "Detail sertifikat",
+  "openssl.generated": "✓ Detail sertifikat berhasil dibuat ({ $count } sertifikat)",
+  "openssl.urlRequired": "Masukkan URL untuk memeriksa detail SSL",
+  "openssl.fetched": "✓ Detail SSL berhasil diambil dari URL ({ $mode })",
+
+  "traceroute.run": "Jalankan Traceroute",
+  "traceroute.running": "Berjalan...",
+  "traceroute.urlPlaceholder":
+    "Masukkan URL atau host (mis. https://example.com atau example.com)",
+  "traceroute.result": "Hasil Traceroute",
+  "traceroute.outputPlaceholder": "Hasil traceroute akan muncul di sini...",
+  "traceroute.tracing": "Menelusuri rute...",
+  "traceroute.output": "Hasil traceroute",
+  "traceroute.urlRequired": "Masukkan URL atau host untuk traceroute",
+  "traceroute.completed": "✓ Traceroute selesai",
+
+  "jsonHtml.render": "Render HTML",
+  "jsonHtml.copyResult": "📋 Salin Hasil",
+  "jsonHtml.jsonData": "Data JSON",
+  "jsonHtml.dataPlaceholder":
+    'Ketik data JSON di sini... mis. {"name": "John", "age": 30}',
+  "jsonHtml.templateLabel":
+    "Templat HTML (gunakan {{key}} untuk nilai JSON)",
+  "jsonHtml.templatePlaceholder":
+    "Ketik templat HTML di sini... mis. <h1>{{name}}</h1><p>Umur: {{age}}</p>",
+  "jsonHtml.resultPreview": "Pratinjau Hasil",
+  "jsonHtml.placeholder": "Hasil akan muncul di sini setelah dirender",
+  "jsonHtml.data": "Data JSON",
+  "jsonHtml.template": "Templat HTML",
+  "jsonHtml.result": "Hasil HTML",
+  "jsonHtml.dataRequired": "Masukkan data JSON",
+  "jsonHtml.templateRequired": "Masukkan templat HTML",
+  "jsonHtml.rendered": "✓ HTML berhasil dirender!",
+  "jsonHtml.formatted": "✓ Data JSON berhasil dirapikan",
+  "jsonHtml.cleared": "JSON ke HTML dibersihkan!",
+
+  "stream.connect": "Hubungkan",
+  "stream.disconnect": "Putuskan",
+  "stream.pause": "Jeda",
+  "stream.resume": "Lanjutkan ({ $count })",
+  "stream.export": "Ekspor…",
+  "stream.send": "Kirim",
+  "stream.endpoint": "Endpoint:",
+  "stream.urlPlaceholder":
+    "wss://… untuk WebSocket, https://… untuk Server-Sent Events",
+  "stream.eventsPlaceholder": "Nama event SSE (opsional)",
+  "stream.eventsTitle":
+    "Event bernama, dipisahkan koma, yang didengarkan selain 'message'",
+  "stream.sendLabel": "Kirim:",
+  "stream.sendPlaceholder": "Pesan untuk dikirim lewat WebSocket (Enter)",
+  "stream.search": "Cari:",
+  "stream.searchPlaceholder": "Tampilkan hanya pesan yang berisi…",
+  "stream.empty": "Pesan muncul di sini setelah terhubung.",
+  "stream.messages": "{ $count } pesan",
+  "stream.held": "{ $count } ditahan",
+  "stream.connected": "Terhubung ke { $url }",
+  "stream.disconnected": "Terputus",
+  "stream.closed": "Ditutup ({ $code })",
+  "stream.socketError": "Galat WebSocket",
+  "stream.serverClosed": "Stream ditutup oleh server",
+  "stream.reconnecting": "Koneksi terputus, menghubungkan ulang…",
+  "stream.invalidUrl": "Masukkan URL ws://, wss://, http:// atau https://",
+  "stream.nothingToExport": "Tidak ada pesan untuk diekspor",
+  "stream.exportTitle": "Ekspor pesan stream",
+  "stream.exported": "✓ { $count } pesan diekspor",
+};
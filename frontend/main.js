@@ -1,11 +1,198 @@
+import { normalizedJson } from "./json-diff.js";
+import { LANGUAGES, setLanguage, t } from "./i18n.js";
+
 // Import Tauri API - v2 uses window.__TAURI_INTERNALS__
 const invoke = window.__TAURI_INTERNALS__.invoke;
+const listen = (event, handler) =>
+  invoke("plugin:event|listen", {
+    event,
+    target: { kind: "Any" },
+    handler: window.__TAURI_INTERNALS__.transformCallback(handler),
+  });
+
+// Compare windows load this page with `?compareWindow=<label>` and show only the
+// compare view
+const compareWindowLabel = new URLSearchParams(location.search).get(
+  "compareWindow",
+);
 
 const inputText = document.getElementById("inputText");
+const inputFile = document.getElementById("inputFile");
+const binaryNotice = document.getElementById("binaryNotice");
+const formatSuggestion = document.getElementById("formatSuggestion");
+const formatSuggestionText = document.getElementById("formatSuggestionText");
+const convertSuggestionBtn = document.getElementById("convertSuggestionBtn");
+const binaryNoticeText = document.getElementById("binaryNoticeText");
+const binaryDecoders = document.getElementById("binaryDecoders");
+const harPanel = document.getElementById("harPanel");
+const harTitle = document.getElementById("harTitle");
+const harEntries = document.getElementById("harEntries");
+const harFilterInput = document.getElementById("harFilterInput");
+const harPartSelect = document.getElementById("harPartSelect");
+const harJsonOnlyCheckbox = document.getElementById("harJsonOnlyCheckbox");
 const outputText = document.getElementById("outputText");
+const outputViewer = document.getElementById("outputViewer");
+const outputViewerSpacer = document.getElementById("outputViewerSpacer");
+const outputViewerLines = document.getElementById("outputViewerLines");
 const statusMessage = document.getElementById("statusMessage");
+const operationProgress = document.getElementById("operationProgress");
+const operationProgressFill = document.getElementById("operationProgressFill");
+const operationProgressLabel = document.getElementById(
+  "operationProgressLabel",
+);
+const cancelOperationBtn = document.getElementById("cancelOperationBtn");
 const language = document.getElementById("languageSelect");
+const markdownModeSelect = document.getElementById("markdownModeSelect");
 const classNameInput = document.getElementById("classNameInputText");
+const fieldPrefixCheckbox = document.getElementById("fieldPrefixCheckbox");
+const pythonStyleSelect = document.getElementById("pythonStyleSelect");
+const csharpSerializerSelect = document.getElementById(
+  "csharpSerializerSelect",
+);
+const csharpRecordsCheckbox = document.getElementById("csharpRecordsCheckbox");
+const kotlinSerializationSelect = document.getElementById(
+  "kotlinSerializationSelect",
+);
+const javaStyleSelect = document.getElementById("javaStyleSelect");
+const javaAnnotationsSelect = document.getElementById("javaAnnotationsSelect");
+const stringFormatsCheckbox = document.getElementById("stringFormatsCheckbox");
+const enumThresholdInput = document.getElementById("enumThresholdInput");
+const namingConventionSelect = document.getElementById(
+  "namingConventionSelect",
+);
+const nullabilitySelect = document.getElementById("nullabilitySelect");
+const immutableCheckbox = document.getElementById("immutableCheckbox");
+const omitAnnotationsCheckbox = document.getElementById(
+  "omitAnnotationsCheckbox",
+);
+const fieldExamplesCheckbox = document.getElementById("fieldExamplesCheckbox");
+const protoOneofCheckbox = document.getElementById("protoOneofCheckbox");
+const rootArrayNameInput = document.getElementById("rootArrayNameInput");
+const namespaceInput = document.getElementById("namespaceInput");
+const javaPackageInput = document.getElementById("javaPackageInput");
+const goPackageInput = document.getElementById("goPackageInput");
+const saveDirectoryInput = document.getElementById("saveDirectoryInput");
+const sampleSeedInput = document.getElementById("sampleSeedInput");
+const streamInputPath = document.getElementById("streamInputPath");
+const streamOutputPath = document.getElementById("streamOutputPath");
+const batchInputDir = document.getElementById("batchInputDir");
+const batchOutputDir = document.getElementById("batchOutputDir");
+const batchOperationSelect = document.getElementById("batchOperationSelect");
+const watchPathInput = document.getElementById("watchPathInput");
+const fetchUrlInput = document.getElementById("fetchUrlInput");
+const fetchHeadersInput = document.getElementById("fetchHeadersInput");
+const watchOperationSelect = document.getElementById("watchOperationSelect");
+const watchFileBtn = document.getElementById("watchFileBtn");
+const globalShortcutInput = document.getElementById("globalShortcutInput");
+const sqlitePathInput = document.getElementById("sqlitePathInput");
+const sqliteTableSelect = document.getElementById("sqliteTableSelect");
+const sqliteQueryInput = document.getElementById("sqliteQueryInput");
+const kvProfileSelect = document.getElementById("kvProfileSelect");
+const kvStoreSelect = document.getElementById("kvStoreSelect");
+const kvEndpointInput = document.getElementById("kvEndpointInput");
+const kvDatabaseInput = document.getElementById("kvDatabaseInput");
+const kvUsernameInput = document.getElementById("kvUsernameInput");
+const kvPasswordInput = document.getElementById("kvPasswordInput");
+const kvKeyInput = document.getElementById("kvKeyInput");
+const envVarsInput = document.getElementById("envVarsInput");
+const flagSelect = document.getElementById("flagSelect");
+const flagContextInput = document.getElementById("flagContextInput");
+const sampleCountInput = document.getElementById("sampleCountInput");
+const lineEndingSelect = document.getElementById("lineEndingSelect");
+const converterEditors = document.querySelector(
+  "#converterSection .editor-container",
+);
+const splitView = document.getElementById("splitView");
+const splitViewBtn = document.getElementById("splitViewBtn");
+const splitOriginal = document.getElementById("splitOriginal");
+const splitTransformed = document.getElementById("splitTransformed");
+const splitSummary = document.getElementById("splitSummary");
+const treeView = document.getElementById("treeView");
+const fileMenu = document.getElementById("fileMenu");
+const copyAsMenu = document.getElementById("copyAsMenu");
+const recentFilesMenu = document.getElementById("recentFilesMenu");
+const snippetsPanel = document.getElementById("snippetsPanel");
+const snippetNameInput = document.getElementById("snippetNameInput");
+const snippetTagsInput = document.getElementById("snippetTagsInput");
+const snippetSearchInput = document.getElementById("snippetSearchInput");
+const snippetList = document.getElementById("snippetList");
+const inputHistoryPanel = document.getElementById("inputHistoryPanel");
+const inputHistoryList = document.getElementById("inputHistoryList");
+const inputHistoryPreview = document.getElementById("inputHistoryPreview");
+const restoreHistoryBtn = document.getElementById("restoreHistoryBtn");
+const startScreen = document.getElementById("startScreen");
+const recentFilesStart = document.getElementById("recentFilesStart");
+const tableView = document.getElementById("tableView");
+const tableViewBtn = document.getElementById("tableViewBtn");
+const tableSummary = document.getElementById("tableSummary");
+const dataTable = document.getElementById("dataTable");
+const paneSplitter = document.getElementById("paneSplitter");
+const paneLayoutBtn = document.getElementById("paneLayoutBtn");
+const liveFormatCheckbox = document.getElementById("liveFormatCheckbox");
+const formatOnOpenCheckbox = document.getElementById("formatOnOpenCheckbox");
+const lossyInputCheckbox = document.getElementById("lossyInputCheckbox");
+const validationState = document.getElementById("validationState");
+const documentTabs = document.getElementById("documentTabs");
+const themeSelect = document.getElementById("themeSelect");
+const uiLanguageSelect = document.getElementById("uiLanguageSelect");
+const findBar = document.getElementById("findBar");
+const findInput = document.getElementById("findInput");
+const findRegexCheckbox = document.getElementById("findRegexCheckbox");
+const findCount = document.getElementById("findCount");
+const findPrevBtn = document.getElementById("findPrevBtn");
+const findNextBtn = document.getElementById("findNextBtn");
+const closeFindBtn = document.getElementById("closeFindBtn");
+const inputHighlight = document.getElementById("inputHighlight");
+const outputHighlight = document.getElementById("outputHighlight");
+const expandTreeBtn = document.getElementById("expandTreeBtn");
+const collapseTreeBtn = document.getElementById("collapseTreeBtn");
+const treeViewBtn = document.getElementById("treeViewBtn");
+const treeContextMenu = document.getElementById("treeContextMenu");
+const bookmarkBar = document.getElementById("bookmarkBar");
+const pathBar = document.getElementById("pathBar");
+const pathBreadcrumb = document.getElementById("pathBreadcrumb");
+const bookmarkSelect = document.getElementById("bookmarkSelect");
+const schemaBar = document.getElementById("schemaBar");
+const schemaInput = document.getElementById("schemaInput");
+const finalNewlineCheckbox = document.getElementById("finalNewlineCheckbox");
+const analysisPanel = document.getElementById("analysisPanel");
+const timingInfo = document.getElementById("timingInfo");
+const documentMetrics = document.getElementById("documentMetrics");
+const metricsSource = document.getElementById("metricsSource");
+const metricsSize = document.getElementById("metricsSize");
+const metricsNodes = document.getElementById("metricsNodes");
+const metricsDepth = document.getElementById("metricsDepth");
+const metricsParse = document.getElementById("metricsParse");
+const timingOverlay = document.getElementById("timingOverlay");
+const timingOverlayBody = document.getElementById("timingOverlayBody");
+const configEnvironmentInput = document.getElementById(
+  "configEnvironmentInput",
+);
+const configLocalInput = document.getElementById("configLocalInput");
+const protoSchemaInput = document.getElementById("protoSchemaInput");
+const protoMessageTypeInput = document.getElementById("protoMessageTypeInput");
+const protoPayloadFormatSelect = document.getElementById(
+  "protoPayloadFormatSelect",
+);
+const grpcMethodInput = document.getElementById("grpcMethodInput");
+const grpcServiceInput = document.getElementById("grpcServiceInput");
+const grpcResponseInput = document.getElementById("grpcResponseInput");
+const configOrigins = document.getElementById("configOrigins");
+const configOriginsBody = document.getElementById("configOriginsBody");
+const workerThreadsInput = document.getElementById("workerThreadsInput");
+const jobMemoryLimitInput = document.getElementById("jobMemoryLimitInput");
+const maxInputSizeInput = document.getElementById("maxInputSizeInput");
+const maxDepthInput = document.getElementById("maxDepthInput");
+const maxStringSizeInput = document.getElementById("maxStringSizeInput");
+const bomCheckbox = document.getElementById("bomCheckbox");
+const indentSelect = document.getElementById("indentSelect");
+const sortKeysCheckbox = document.getElementById("sortKeysCheckbox");
+const settingsDialog = document.getElementById("settingsDialog");
+const settingsError = document.getElementById("settingsError");
+const ingestEnabledCheckbox = document.getElementById("ingestEnabledCheckbox");
+const ingestPortInput = document.getElementById("ingestPortInput");
+const ingestTokenInput = document.getElementById("ingestTokenInput");
+const ingestUsage = document.getElementById("ingestUsage");
 
 const converterTabBtn = document.getElementById("converterTabBtn");
 const compareTabBtn = document.getElementById("compareTabBtn");
@@ -14,6 +201,7 @@ const imageResizerTabBtn = document.getElementById("imageResizerTabBtn");
 const opensslTabBtn = document.getElementById("opensslTabBtn");
 const tracerouteTabBtn = document.getElementById("tracerouteTabBtn");
 const jsonHtmlTabBtn = document.getElementById("jsonHtmlTabBtn");
+const streamTabBtn = document.getElementById("streamTabBtn");
 const converterSection = document.getElementById("converterSection");
 const compareSection = document.getElementById("compareSection");
 const mermaidSection = document.getElementById("mermaidSection");
@@ -21,6 +209,7 @@ const imageResizerSection = document.getElementById("imageResizerSection");
 const opensslSection = document.getElementById("opensslSection");
 const tracerouteSection = document.getElementById("tracerouteSection");
 const jsonHtmlSection = document.getElementById("jsonHtmlSection");
+const streamSection = document.getElementById("streamSection");
 
 const compareLeft = document.getElementById("compareLeft");
 const compareRight = document.getElementById("compareRight");
@@ -36,6 +225,15 @@ const tracerouteLoading = document.getElementById("tracerouteLoading");
 const runTracerouteBtn = document.getElementById("runTracerouteBtn");
 const clearTracerouteBtn = document.getElementById("clearTracerouteBtn");
 const copyTracerouteOutputBtn = document.getElementById("copyTracerouteOutputBtn");
+const streamUrlInput = document.getElementById("streamUrlInput");
+const streamEventsInput = document.getElementById("streamEventsInput");
+const streamSendInput = document.getElementById("streamSendInput");
+const streamFilterInput = document.getElementById("streamFilterInput");
+const streamConnectBtn = document.getElementById("streamConnectBtn");
+const streamPauseBtn = document.getElementById("streamPauseBtn");
+const streamSendBtn = document.getElementById("streamSendBtn");
+const streamStats = document.getElementById("streamStats");
+const streamLog = document.getElementById("streamLog");
 
 // JSON to HTML elements
 const jsonHtmlInput = document.getElementById("jsonHtmlInput");
@@ -73,10 +271,49 @@ let dragStartY = 0;
 let panX = 0;
 let panY = 0;
 
+// Raw converter output; the textarea normalizes CRLF so copy uses this instead
+let lastConverterOutput = "";
+
+// Split view state: line pairs from compare_transform drive the synced scrolling
+let isSplitView = false;
+let splitLineMap = [];
+let isSyncingSplitScroll = false;
+
+// Open documents; the editors show the active one and the others keep a snapshot
+let documents = [];
+let activeDocument = 0;
+let documentCounter = 0;
+
+// Session autosave: documents are written to session.json when they changed since the
+// last save, and once more when the window closes
+const SESSION_AUTOSAVE_MS = 5000;
+let sessionDirty = false;
+// Id of the converter button used last; Ctrl+Enter repeats it
+let lastOperation = null;
+
+// Table view state: the rows of an array-of-objects output and the active sort
+let isTableView = false;
+let tableRows = [];
+let tableColumns = [];
+let tableSort = { column: null, descending: false };
+
+// Tree view state
+let isTreeView = false;
+let contextMenuPointer = null;
+
+// Bookmarks and the associated schema are stored per file or snippet, or per
+// document shape for unsaved input so they survive value edits
+let documentKey = null;
+let bookmarks = [];
+// Node last clicked in the output, shown in the path breadcrumb
+let selectedPath = null;
+let documentSchema = "";
+
 let lastDiffText = "";
 let lastDiffHtml = "";
-const EMPTY_DIFF_HTML =
-  '<div class="diff-header">Left</div><div class="diff-header">Right</div>';
+function emptyDiffHtml() {
+  return `<div class="diff-header" data-i18n="compare.left">${t("compare.left")}</div><div class="diff-header" data-i18n="compare.right">${t("compare.right")}</div>`;
+}
 
 // Image Resizer elements
 const imageFileInput = document.getElementById("imageFileInput");
@@ -127,84 +364,6 @@ function escapeHtml(str) {
     .replace(/'/g, "&#39;");
 }
 
-function sortKeys(value) {
-  if (Array.isArray(value)) {
-    return value.map(sortKeys);
-  }
-  if (value && typeof value === "object") {
-    return Object.keys(value)
-      .sort()
-      .reduce((acc, key) => {
-        acc[key] = sortKeys(value[key]);
-        return acc;
-      }, {});
-  }
-  return value;
-}
-
-function normalizedJson(text) {
-  const parsed = JSON.parse(text);
-  return JSON.stringify(sortKeys(parsed), null, 2);
-}
-
-function buildLineDiff(leftLines, rightLines) {
-  const m = leftLines.length;
-  const n = rightLines.length;
-  const dp = Array.from({ length: m + 1 }, () => Array(n + 1).fill(0));
-
-  for (let i = 1; i <= m; i += 1) {
-    for (let j = 1; j <= n; j += 1) {
-      if (leftLines[i - 1] === rightLines[j - 1]) {
-        dp[i][j] = dp[i - 1][j - 1] + 1;
-      } else {
-        dp[i][j] = Math.max(dp[i - 1][j], dp[i][j - 1]);
-      }
-    }
-  }
-
-  const diff = [];
-  let i = m;
-  let j = n;
-
-  while (i > 0 || j > 0) {
-    if (i > 0 && j > 0 && leftLines[i - 1] === rightLines[j - 1]) {
-      diff.push({
-        type: "same",
-        left: leftLines[i - 1],
-        right: rightLines[j - 1],
-      });
-      i -= 1;
-      j -= 1;
-    } else if (j > 0 && (i === 0 || dp[i][j - 1] >= dp[i - 1][j])) {
-      diff.push({ type: "added", left: "", right: rightLines[j - 1] });
-      j -= 1;
-    } else {
-      diff.push({ type: "removed", left: leftLines[i - 1], right: "" });
-      i -= 1;
-    }
-  }
-
-  diff.reverse();
-
-  const merged = [];
-  for (let k = 0; k < diff.length; k += 1) {
-    const current = diff[k];
-    const next = diff[k + 1];
-    if (
-      current &&
-      current.type === "removed" &&
-      next &&
-      next.type === "added"
-    ) {
-      merged.push({ type: "changed", left: current.left, right: next.right });
-      k += 1;
-    } else {
-      merged.push(current);
-    }
-  }
-  return merged;
-}
-
 function buildDiffHtml(entries) {
   if (!entries.length) {
     return EMPTY_DIFF_HTML;
@@ -250,6 +409,7 @@ function setActiveTab(tab) {
   const isOpenssl = tab === "openssl";
   const isTraceroute = tab === "traceroute";
   const isJsonHtml = tab === "jsonHtml";
+  const isStream = tab === "stream";
 
   converterSection.classList.toggle("hidden", !isConverter);
   compareSection.classList.toggle("hidden", !isCompare);
@@ -258,6 +418,7 @@ function setActiveTab(tab) {
   opensslSection.classList.toggle("hidden", !isOpenssl);
   tracerouteSection.classList.toggle("hidden", !isTraceroute);
   jsonHtmlSection.classList.toggle("hidden", !isJsonHtml);
+  streamSection.classList.toggle("hidden", !isStream);
 
   converterTabBtn.classList.toggle("active", isConverter);
   compareTabBtn.classList.toggle("active", isCompare);
@@ -266,6 +427,7 @@ function setActiveTab(tab) {
   opensslTabBtn.classList.toggle("active", isOpenssl);
   tracerouteTabBtn.classList.toggle("active", isTraceroute);
   jsonHtmlTabBtn.classList.toggle("active", isJsonHtml);
+  streamTabBtn.classList.toggle("active", isStream);
 }
 
 function showStatus(message, isError = false) {
@@ -276,151 +438,3908 @@ function showStatus(message, isError = false) {
   }, 3000);
 }
 
-function renderDiffHtml(html) {
-  if (diffContainer) {
-    diffContainer.innerHTML = html;
+// Commands that may emit format-progress; the bar hides once none are running
+let activeOperations = 0;
+
+// Timed commands answer with { result, timing }; show the timing and return the result
+async function invokeTimed(command, args) {
+  const { result, timing } = await invoke(command, args);
+  showTiming(timing);
+  return result;
+}
+
+async function invokeWithProgress(command, args) {
+  activeOperations++;
+  try {
+    return await invokeTimed(command, args);
+  } finally {
+    activeOperations--;
+    if (activeOperations === 0) operationProgress.classList.add("hidden");
   }
 }
 
-function handleBeautifyCompare(side) {
+function handleOperationProgress(event) {
+  if (activeOperations === 0) return;
+  const { phase, processedBytes, totalBytes } = event.payload;
+  const percent = totalBytes
+    ? Math.min(100, Math.round((processedBytes / totalBytes) * 100))
+    : 0;
+  operationProgressFill.style.width = `${percent}%`;
+  operationProgressLabel.textContent = t(`progress.${phase}`, { percent });
+  cancelOperationBtn.disabled = false;
+  operationProgress.classList.remove("hidden");
+}
+
+async function handleCancelOperation() {
+  cancelOperationBtn.disabled = true;
+  operationProgressLabel.textContent = t("progress.cancelling");
   try {
-    if (side === "left") {
-      compareLeft.value = normalizedJson(compareLeft.value);
-      showStatus("✓ Left JSON beautified");
-    } else {
-      compareRight.value = normalizedJson(compareRight.value);
-      showStatus("✓ Right JSON beautified");
+    await invoke("cancel_operation");
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function getOutputOptions() {
+  return {
+    lineEnding: lineEndingSelect.value,
+    finalNewline: finalNewlineCheckbox.checked,
+    bom: bomCheckbox.checked,
+    indent: indentSelect.value,
+    sortKeys: sortKeysCheckbox.checked,
+  };
+}
+
+function formatMs(ms) {
+  return ms < 1 ? `${ms.toFixed(2)} ms` : `${ms.toFixed(1)} ms`;
+}
+
+function showTiming(timing) {
+  timingInfo.textContent = t("timing.summary", {
+    operation: timing.operation,
+    size: formatFileSize(timing.inputBytes),
+    parse: formatMs(timing.parseMs),
+    transform: formatMs(timing.transformMs),
+    serialize: formatMs(timing.serializeMs),
+    total: formatMs(timing.totalMs),
+  });
+  if (!timingOverlay.classList.contains("hidden")) {
+    renderTimingOverlay().catch((error) =>
+      console.error("Failed to load operation timings:", error),
+    );
+  }
+}
+
+const METRICS_DELAY_MS = 400;
+let metricsTimer = null;
+let metricsRun = 0;
+
+// The status bar describes the output once a command has produced one, else the input
+function scheduleDocumentMetrics(delay = METRICS_DELAY_MS) {
+  clearTimeout(metricsTimer);
+  metricsRun += 1;
+  documentMetrics.classList.add("stale");
+  metricsTimer = setTimeout(refreshDocumentMetrics, delay);
+}
+
+async function refreshDocumentMetrics() {
+  const run = metricsRun;
+  const isOutput = Boolean(lastConverterOutput);
+  const text = isOutput ? lastConverterOutput : inputText.value;
+  metricsSource.textContent = t(isOutput ? "metrics.output" : "metrics.input");
+  let metrics = null;
+  if (text.trim()) {
+    try {
+      metrics = await invoke("document_metrics", { input: text });
+    } catch {
+      // Not JSON (e.g. generated code); only the size is meaningful
+    }
+  }
+  if (run !== metricsRun) return;
+  documentMetrics.classList.remove("stale");
+  metricsSize.textContent = formatFileSize(
+    metrics ? metrics.bytes : new Blob([text]).size,
+  );
+  if (!metrics) {
+    metricsNodes.textContent = t(
+      text.trim() ? "metrics.notJson" : "metrics.empty",
+    );
+    metricsDepth.textContent = t("metrics.depthNone");
+    metricsParse.textContent = t("metrics.parseNone");
+    return;
+  }
+  metricsNodes.textContent = t("metrics.nodes", {
+    count: metrics.nodes.toLocaleString(),
+  });
+  metricsDepth.textContent = t("metrics.depth", { depth: metrics.maxDepth });
+  metricsParse.textContent = t("metrics.parse", {
+    time: formatMs(metrics.parseMs),
+  });
+}
+
+async function renderTimingOverlay() {
+  const timings = await invoke("slowest_operations", { limit: 10 });
+  timingOverlayBody.innerHTML = timings.length
+    ? timings
+        .map((timing) => {
+          const operation = timing.failed
+            ? t("timing.failed", { operation: timing.operation })
+            : timing.operation;
+          return `<tr>
+            <td>${escapeHtml(operation)}</td>
+            <td>${formatFileSize(timing.inputBytes)}</td>
+            <td>${formatMs(timing.parseMs)}</td>
+            <td>${formatMs(timing.transformMs)}</td>
+            <td>${formatMs(timing.serializeMs)}</td>
+            <td>${formatMs(timing.totalMs)}</td>
+          </tr>`;
+        })
+        .join("")
+    : `<tr><td colspan="6">${t("timing.none")}</td></tr>`;
+}
+
+async function handleToggleTimingOverlay() {
+  if (timingOverlay.classList.contains("hidden")) {
+    try {
+      await renderTimingOverlay();
+    } catch (error) {
+      showStatus(t("status.error", { error }), true);
+      return;
     }
+  }
+  timingOverlay.classList.toggle("hidden");
+}
+
+async function loadGlobalShortcut() {
+  try {
+    const saved = localStorage.getItem("globalShortcut");
+    globalShortcutInput.value =
+      saved !== null
+        ? await invoke("set_global_shortcut", { shortcut: saved })
+        : await invoke("get_global_shortcut");
   } catch (error) {
-    showStatus(`Error: ${error}`, true);
+    console.error("Failed to load global shortcut:", error);
   }
 }
 
-function handleCompare() {
+async function handleSaveShortcut() {
+  try {
+    const shortcut = await invoke("set_global_shortcut", {
+      shortcut: globalShortcutInput.value,
+    });
+    localStorage.setItem("globalShortcut", shortcut);
+    globalShortcutInput.value = shortcut;
+    showStatus(
+      shortcut
+        ? t("status.shortcutSet", { shortcut })
+        : t("status.shortcutOff"),
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// Sent by the global shortcut; an input that's already in use keeps its tab
+function handleLoadClipboard(event) {
+  setActiveTab("converter");
+  if (inputText.value.trim()) handleNewDocument();
+  setInputText(event.payload, "paste");
+  showStatus(t("status.clipboardLoaded"));
+  suggestInputFormat();
+}
+
+// JSON pushed to the local ingest server's /open endpoint
+function handleIngestedJson(event) {
+  const { name, text } = event.payload;
+  setActiveTab("converter");
+  if (inputText.value.trim()) handleNewDocument();
+  documents[activeDocument].name = name;
+  setInputText(text, "ingest");
+  renderDocumentTabs();
+  showStatus(t("status.received", { name }));
+}
+
+const systemDarkQuery = window.matchMedia("(prefers-color-scheme: dark)");
+
+function applyTheme(theme) {
+  const dark =
+    theme === "dark" || (theme === "system" && systemDarkQuery.matches);
+  document.documentElement.dataset.theme = dark ? "dark" : "light";
+  themeSelect.value = theme;
+}
+
+// The saved settings profile. Rust keeps it in the app's store; localStorage holds
+// a copy so the theme applies before Rust answers
+let settings = null;
+
+// Class and proto controls saved with the profile, keyed by CodegenOptions field
+const CODEGEN_SETTING_CONTROLS = {
+  fieldPrefix: fieldPrefixCheckbox,
+  pythonStyle: pythonStyleSelect,
+  csharpSerializer: csharpSerializerSelect,
+  csharpRecords: csharpRecordsCheckbox,
+  kotlinSerialization: kotlinSerializationSelect,
+  javaStyle: javaStyleSelect,
+  javaAnnotations: javaAnnotationsSelect,
+  stringFormats: stringFormatsCheckbox,
+  enumThreshold: enumThresholdInput,
+  namingConvention: namingConventionSelect,
+  nullability: nullabilitySelect,
+  immutable: immutableCheckbox,
+  omitAnnotations: omitAnnotationsCheckbox,
+  fieldExamples: fieldExamplesCheckbox,
+  rootArrayName: rootArrayNameInput,
+  namespace: namespaceInput,
+  javaPackage: javaPackageInput,
+  goPackage: goPackageInput,
+};
+
+function showSettings(profile) {
+  indentSelect.value = profile.output.indent;
+  sortKeysCheckbox.checked = profile.output.sortKeys;
+  lineEndingSelect.value = profile.output.lineEnding;
+  finalNewlineCheckbox.checked = profile.output.finalNewline;
+  bomCheckbox.checked = profile.output.bom;
+  workerThreadsInput.value = profile.concurrency.workerThreads;
+  jobMemoryLimitInput.value = profile.concurrency.jobMemoryLimitMb;
+  maxInputSizeInput.value = profile.parseLimits.maxInputMb;
+  maxDepthInput.value = profile.parseLimits.maxDepth;
+  maxStringSizeInput.value = profile.parseLimits.maxStringMb;
+  for (const [key, control] of Object.entries(CODEGEN_SETTING_CONTROLS)) {
+    if (control.type === "checkbox") control.checked = profile.codegen[key];
+    else control.value = profile.codegen[key] || "";
+  }
+  protoOneofCheckbox.checked = !profile.codegen.omitOneofs;
+  // Profiles cached before the ingest server existed have no section for it
+  const ingest = { enabled: false, port: 7475, token: "", ...profile.ingest };
+  ingestEnabledCheckbox.checked = ingest.enabled;
+  ingestPortInput.value = ingest.port;
+  ingestTokenInput.value = ingest.token;
+  showIngestUsage();
+  applyTheme(profile.theme);
+  applyLanguage(profile.language ?? "");
+}
+
+function showIngestUsage() {
+  const url = `http://127.0.0.1:${ingestPortInput.value}`;
+  ingestUsage.textContent = `curl -H "Authorization: Bearer <token>" --data-binary @file.json ${url}/format (or /open to load it here)`;
+}
+
+function handleNewIngestToken() {
+  const bytes = crypto.getRandomValues(new Uint8Array(16));
+  ingestTokenInput.value = Array.from(bytes, (byte) =>
+    byte.toString(16).padStart(2, "0"),
+  ).join("");
+}
+
+function handleIngestEnabledChange() {
+  if (ingestEnabledCheckbox.checked && !ingestTokenInput.value.trim()) {
+    handleNewIngestToken();
+  }
+}
+
+function readSettingsForm() {
+  return {
+    output: getOutputOptions(),
+    theme: themeSelect.value,
+    language: uiLanguageSelect.value,
+    concurrency: {
+      workerThreads: Number(workerThreadsInput.value),
+      jobMemoryLimitMb: Number(jobMemoryLimitInput.value),
+    },
+    parseLimits: {
+      maxInputMb: Number(maxInputSizeInput.value),
+      maxDepth: Number(maxDepthInput.value),
+      maxStringMb: Number(maxStringSizeInput.value),
+    },
+    // Keeps fields without a control, like maxDepth
+    codegen: {
+      ...settings?.codegen,
+      ...getClassOptions(),
+      ...getProtoOptions(),
+    },
+    ingest: {
+      enabled: ingestEnabledCheckbox.checked,
+      port: Number(ingestPortInput.value),
+      token: ingestTokenInput.value.trim(),
+    },
+  };
+}
+
+// Throws Rust's validation error and leaves the saved profile as it was
+async function saveSettings(profile) {
+  settings = await invoke("set_settings", { settings: profile });
+  localStorage.setItem("settings", JSON.stringify(settings));
+  showSettings(settings);
+}
+
+// Before the settings store, theme and limits were saved under keys of their own
+const LEGACY_SETTING_KEYS = ["theme", "concurrencySettings", "parseLimits"];
+
+async function migrateLegacySettings(profile) {
+  if (LEGACY_SETTING_KEYS.every((key) => localStorage.getItem(key) === null)) {
+    return;
+  }
+  const concurrency = localStorage.getItem("concurrencySettings");
+  const parseLimits = localStorage.getItem("parseLimits");
+  await saveSettings({
+    ...profile,
+    theme: localStorage.getItem("theme") || profile.theme,
+    concurrency: concurrency ? JSON.parse(concurrency) : profile.concurrency,
+    parseLimits: parseLimits ? JSON.parse(parseLimits) : profile.parseLimits,
+  });
+  LEGACY_SETTING_KEYS.forEach((key) => localStorage.removeItem(key));
+}
+
+async function loadSettings() {
+  const cached = JSON.parse(localStorage.getItem("settings"));
+  // Apply the saved theme right away so the page doesn't flash while Rust answers
+  const theme = cached?.theme || localStorage.getItem("theme");
+  if (theme) applyTheme(theme);
+  if (cached) applyLanguage(cached.language ?? "");
   try {
-    const leftFormatted = normalizedJson(compareLeft.value);
-    const rightFormatted = normalizedJson(compareRight.value);
+    settings = await invoke("get_settings");
+    localStorage.setItem("settings", JSON.stringify(settings));
+    showSettings(settings);
+    await migrateLegacySettings(settings);
+  } catch (error) {
+    console.error("Failed to load settings:", error);
+    // Without the backend (e.g. in a plain browser) fall back to the cached copy
+    if (cached && !settings) {
+      settings = cached;
+      showSettings(cached);
+    }
+  }
+}
 
-    compareLeft.value = leftFormatted;
-    compareRight.value = rightFormatted;
+async function handleThemeChange() {
+  try {
+    await saveSettings({ ...settings, theme: themeSelect.value });
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
 
-    const diff = buildLineDiff(
-      leftFormatted.split("\n"),
-      rightFormatted.split("\n"),
+// Switch the UI language, then redraw the strings scripts build themselves
+async function applyLanguage(language) {
+  uiLanguageSelect.value = language;
+  try {
+    await setLanguage(language);
+  } catch (error) {
+    console.error("Failed to load the language pack:", error);
+  }
+  refreshDocumentMetrics();
+  renderSnippets();
+  renderInputHistory();
+  showHistoryPreview();
+  // Labels that follow their state
+  if (!validationState.classList.contains("hidden")) {
+    setValidationState(
+      validationState.classList.contains("valid"),
+      validationState.title,
     );
-    lastDiffHtml = buildDiffHtml(diff);
-    lastDiffText = serializeDiff(diff);
-    renderDiffHtml(lastDiffHtml);
-    showStatus("✓ Comparison complete");
+  }
+  updateStreamStats();
+  streamConnectBtn.textContent = t(
+    streamSource ? "stream.disconnect" : "stream.connect",
+  );
+  if (tracerouteUrlInput.disabled) {
+    runTracerouteBtn.textContent = t("traceroute.running");
+  }
+  if (isRemovingBackground) {
+    removeBackgroundBtn.textContent = t("image.processing");
+  }
+}
+
+async function handleLanguageChange() {
+  try {
+    await saveSettings({ ...settings, language: uiLanguageSelect.value });
   } catch (error) {
-    lastDiffText = "";
-    lastDiffHtml = "";
-    renderDiffHtml(EMPTY_DIFF_HTML);
-    showStatus(`Error: ${error}`, true);
+    showStatus(t("status.error", { error }), true);
   }
 }
 
-async function handleMinify() {
+async function handleCodegenSettingChange() {
   try {
-    const result = await invoke("minify_json", { input: inputText.value });
-    outputText.value = result;
-    showStatus("✓ JSON minified successfully");
+    await saveSettings({ ...settings, codegen: readSettingsForm().codegen });
   } catch (error) {
-    outputText.value = "";
-    showStatus(`Error: ${error}`, true);
+    console.error("Failed to save class options:", error);
   }
 }
 
-async function handleFormat() {
+function showSettingsError(error) {
+  settingsError.textContent = `Error: ${error}`;
+  settingsError.classList.remove("hidden");
+}
+
+function openSettingsDialog() {
+  if (settings) showSettings(settings);
+  settingsError.classList.add("hidden");
+  settingsDialog.showModal();
+}
+
+async function handleSaveSettings() {
   try {
-    const result = await invoke("format_json", { input: inputText.value });
-    outputText.value = result;
-    showStatus("✓ JSON formatted successfully");
+    await saveSettings(readSettingsForm());
+    settingsDialog.close();
+    showStatus(t("status.settingsSaved"));
   } catch (error) {
-    outputText.value = "";
-    showStatus(`Error: ${error}`, true);
+    showSettingsError(error);
   }
 }
 
-async function handleJsonToString() {
+async function handleResetSettings() {
   try {
-    const result = await invoke("json_to_string", { input: inputText.value });
-    outputText.value = result;
-    showStatus("✓ JSON converted to string successfully");
+    // Rust fills every missing section with its default
+    await saveSettings({});
+    settingsError.classList.add("hidden");
   } catch (error) {
-    outputText.value = "";
-    showStatus(`Error: ${error}`, true);
+    showSettingsError(error);
   }
 }
 
-async function handleStringToJson() {
+const SETTINGS_FILE_FILTERS = [{ name: "JSON", extensions: ["json"] }];
+
+async function handleExportSettings() {
   try {
-    const result = await invoke("string_to_json", { input: inputText.value });
-    outputText.value = result;
-    showStatus("✓ String converted to JSON successfully");
+    const path = await invoke("plugin:dialog|save", {
+      options: {
+        title: t("dialog.exportSettings"),
+        filters: SETTINGS_FILE_FILTERS,
+        defaultPath: "json-formatter-settings.json",
+      },
+    });
+    if (!path) return;
+    await invoke("export_settings", { path });
+    settingsDialog.close();
+    const name = path.slice(pathSeparatorIndex(path) + 1);
+    showStatus(t("status.settingsExported", { name }));
   } catch (error) {
-    outputText.value = "";
-    showStatus(`Error: ${error}`, true);
+    showSettingsError(error);
   }
 }
 
-async function handleJsonToProto() {
+async function handleImportSettings() {
   try {
-    const result = await invoke("json_to_proto", { input: inputText.value });
-    outputText.value = result;
-    showStatus("✓ JSON converted to Proto schema successfully");
+    const path = await invoke("plugin:dialog|open", {
+      options: {
+        title: t("dialog.importSettings"),
+        filters: SETTINGS_FILE_FILTERS,
+      },
+    });
+    if (!path) return;
+    settings = await invoke("import_settings", { path });
+    localStorage.setItem("settings", JSON.stringify(settings));
+    showSettings(settings);
+    settingsError.classList.add("hidden");
+    showStatus(t("status.settingsImported"));
   } catch (error) {
-    outputText.value = "";
-    showStatus(`Error: ${error}`, true);
+    showSettingsError(error);
   }
 }
 
-async function handleProtoToJson() {
+// Buttons that need an external program, keyed by capabilities feature
+const TOOL_FEATURE_BUTTONS = {
+  opensslCert: ["opensslDetailBtn", "opensslUrlDetailBtn"],
+  traceroute: ["runTracerouteBtn"],
+  httpFetch: ["fetchUrlBtn", "replayCurlBtn"],
+};
+
+async function loadCapabilities() {
   try {
-    const result = await invoke("proto_to_json", { input: inputText.value });
-    outputText.value = result;
-    showStatus("✓ Proto schema converted to JSON successfully");
+    const capabilities = await invoke("capabilities");
+    for (const [feature, buttonIds] of Object.entries(TOOL_FEATURE_BUTTONS)) {
+      if (capabilities.features[feature]) continue;
+      for (const id of buttonIds) {
+        const button = document.getElementById(id);
+        button.disabled = true;
+        button.title = "Required program was not found on this system";
+      }
+    }
   } catch (error) {
-    outputText.value = "";
-    showStatus(`Error: ${error}`, true);
+    console.error("Failed to load capabilities:", error);
   }
 }
 
-async function handleJsonToClass() {
+const DECODERS = ["gunzip", "archive", "protobuf", "image", "base64"];
+
+function decoderLabel(decoder) {
+  return DECODERS.includes(decoder) ? t(`decoder.${decoder}`) : decoder;
+}
+
+// Bytes of the binary input waiting for the user to pick a decoder
+let pendingBinaryInput = null;
+
+// The detection shown in the suggestion banner and the input it was made for
+let suggestedFormat = null;
+
+function hideFormatSuggestion() {
+  suggestedFormat = null;
+  formatSuggestion.classList.add("hidden");
+}
+
+async function suggestInputFormat() {
+  const input = inputText.value;
   try {
-    const languageSelected = language.value;
-    const className = classNameInput.value.trim() || "Root";
+    const detection = await invoke("detect_format", { input });
+    // Ignore a result for text that has since been edited
+    if (inputText.value !== input) return;
+    if (detection.format === "json" || detection.format === "unknown") {
+      hideFormatSuggestion();
+      return;
+    }
+    suggestedFormat = { ...detection, input };
+    const message = detection.convertible
+      ? "suggestion.convertible"
+      : "suggestion.unsupported";
+    formatSuggestionText.textContent = t(message, {
+      format: t(`format.${detection.format}`),
+    });
+    convertSuggestionBtn.classList.toggle("hidden", !detection.convertible);
+    formatSuggestion.classList.remove("hidden");
+  } catch (error) {
+    console.error("Failed to detect the input format:", error);
+  }
+}
 
-    const result = await invoke("json_to_class", {
-      input: inputText.value,
-      language: languageSelected,
-      name: className, // Use snake_case to match Rust parameter
+async function handleConvertSuggestion() {
+  if (!suggestedFormat) return;
+  const { format, input } = suggestedFormat;
+  try {
+    const result = await invokeTimed("convert_to_json", {
+      input,
+      format,
+      outputOptions: getOutputOptions(),
+    });
+    hideFormatSuggestion();
+    setInputText(result, "convert");
+    showStatus(t("status.converted", { format: t(`format.${format}`) }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function hideBinaryNotice() {
+  pendingBinaryInput = null;
+  binaryNotice.classList.add("hidden");
+}
+
+// Files opened from disk are inspected by `path` and `bytes` is null; the path is kept
+// for binary files so archives can be streamed from it
+async function loadInputBytes(bytes, name, path = null) {
+  try {
+    const inspection = await invoke("inspect_input_bytes", {
+      ...inputSource({ bytes, path }),
+      lossy: lossyInputCheckbox.checked,
     });
+    if (!inspection.binary) {
+      hideBinaryNotice();
+      setInputText(inspection.text, "load", path && `file:${path}`);
+      // Plain UTF-8 needs no mention; anything else was transcoded or repaired
+      const notes = [];
+      if (inspection.encoding !== "utf8") {
+        notes.push(
+          t("load.converted", { description: inspection.description }),
+        );
+      }
+      if (inspection.replacements) {
+        notes.push(
+          t("load.replacements", { count: inspection.replacements }),
+        );
+      }
+      showStatus(
+        notes.length
+          ? t("status.loadedWithNotes", { name, notes: notes.join("; ") })
+          : t("status.loaded", { name }),
+      );
+      if (name.toLowerCase().endsWith(".har")) {
+        await openHar(inspection.text, name);
+      }
+      return true;
+    }
 
-    outputText.value = result;
-    showStatus(`✓ JSON converted to ${languageSelected} class successfully`);
+    pendingBinaryInput = { bytes, name, path, type: inspection.detectedType };
+    binaryNoticeText.textContent = t("binary.notice", {
+      name,
+      description: inspection.description,
+    });
+    binaryDecoders.innerHTML = inspection.decoders
+      .map(
+        (decoder) =>
+          `<button class="secondary" data-decoder="${decoder}">${decoderLabel(decoder)}</button>`,
+      )
+      .join("");
+    binaryNotice.classList.remove("hidden");
   } catch (error) {
-    console.timeEnd("Conversion Time");
-    console.error("❌ Conversion Failed:", error);
-    console.groupEnd();
+    showStatus(t("status.error", { error }), true);
+  }
+  return false;
+}
 
-    outputText.value = "";
-    showStatus(`Error: ${error}`, true);
+const JSON_FILE_FILTERS = [
+  { name: "JSON", extensions: ["json", "jsonl", "ndjson", "geojson"] },
+  { name: "HAR", extensions: ["har"] },
+  { name: "All files", extensions: ["*"] },
+];
+
+function pathSeparatorIndex(path) {
+  return Math.max(path.lastIndexOf("/"), path.lastIndexOf("\\"));
+}
+
+// The Open and Save dialogs start in the directory last used by either
+function rememberDirectory(path) {
+  const separator = pathSeparatorIndex(path);
+  if (separator > 0) {
+    localStorage.setItem("lastDirectory", path.slice(0, separator));
   }
 }
 
-function handleCompareClear() {
-  compareLeft.value = "";
-  compareRight.value = "";
-  lastDiffText = "";
-  lastDiffHtml = "";
-  renderDiffHtml(EMPTY_DIFF_HTML);
-  statusMessage.className = "status-message hidden";
+async function handleOpenFile() {
+  let path;
+  try {
+    path = await invoke("plugin:dialog|open", {
+      options: {
+        title: t("dialog.openJson"),
+        filters: JSON_FILE_FILTERS,
+        defaultPath: localStorage.getItem("lastDirectory") || undefined,
+      },
+    });
+  } catch (error) {
+    // Without the native dialog fall back to the webview file picker
+    console.error("Open dialog unavailable:", error);
+    inputFile.click();
+    return;
+  }
+  if (path) await openInputPath(path);
+}
+
+// Resolves to whether the file was loaded as text
+async function openInputPath(path) {
+  rememberDirectory(path);
+  const name = path.slice(pathSeparatorIndex(path) + 1);
+  let loaded = false;
+  try {
+    loaded = await loadInputBytes(null, name, path);
+    documents[activeDocument].name = name;
+    renderDocumentTabs();
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+  loadRecentFiles();
+  return loaded;
+}
+
+// Files from the command line or "Open with…", including ones forwarded by a second launch
+async function openLaunchedFile(path) {
+  setActiveTab("converter");
+  if (inputText.value.trim()) handleNewDocument();
+  if ((await openInputPath(path)) && formatOnOpenCheckbox.checked) {
+    await handleFormat();
+  }
+}
+
+async function loadLaunchFile() {
+  try {
+    const path = await invoke("take_launch_file");
+    if (path) await openLaunchedFile(path);
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// Most recent first, as kept by the backend; pinned files are listed on top
+let recentFiles = [];
+
+function recentFileItem(file) {
+  const separator = pathSeparatorIndex(file.path);
+  const path = escapeHtml(file.path);
+  return `<div class="recent-file"><button class="recent-file-open" data-open="${path}" title="${path}"><span class="recent-file-name">${escapeHtml(file.path.slice(separator + 1))}</span><span class="recent-file-dir">${escapeHtml(file.path.slice(0, Math.max(separator, 0)))}</span></button><button class="recent-file-pin${file.pinned ? " pinned" : ""}" data-pin="${path}" title="${file.pinned ? "Unpin" : "Pin"}">${file.pinned ? "★" : "☆"}</button><button data-remove="${path}" title="Remove from list">×</button></div>`;
+}
+
+function renderRecentFiles() {
+  const ordered = [
+    ...recentFiles.filter((file) => file.pinned),
+    ...recentFiles.filter((file) => !file.pinned),
+  ];
+  const html = ordered.length
+    ? ordered.map(recentFileItem).join("")
+    : '<div class="recent-files-empty">No recent files</div>';
+  recentFilesMenu.innerHTML = html;
+  recentFilesStart.innerHTML = html;
+  refreshStartScreen();
+}
+
+function refreshStartScreen() {
+  startScreen.classList.toggle(
+    "hidden",
+    inputText.value !== "" || recentFiles.length === 0,
+  );
+}
+
+async function loadRecentFiles() {
+  try {
+    recentFiles = await invoke("recent_files");
+    renderRecentFiles();
+  } catch (error) {
+    console.error("Failed to load recent files:", error);
+  }
+}
+
+async function handleRecentFileClick(event) {
+  const target = event.target.closest("[data-open], [data-pin], [data-remove]");
+  if (!target) return;
+  const { open, pin, remove } = target.dataset;
+  if (open !== undefined) {
+    fileMenu.open = false;
+    await openInputPath(open);
+    return;
+  }
+
+  try {
+    if (pin !== undefined) {
+      const file = recentFiles.find((recent) => recent.path === pin);
+      recentFiles = await invoke("pin_recent_file", {
+        path: pin,
+        pinned: !file.pinned,
+      });
+    } else {
+      recentFiles = await invoke("remove_recent_file", { path: remove });
+    }
+    renderRecentFiles();
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// The snippets listed for the current search, sorted by name
+let snippets = [];
+
+function renderSnippets() {
+  snippetList.innerHTML = snippets.length
+    ? snippets
+        .map((snippet) => {
+          const name = escapeHtml(snippet.name);
+          const tags = snippet.tags
+            .map((tag) => `<span class="snippet-tag">#${escapeHtml(tag)}</span>`)
+            .join("");
+          return `<div class="recent-file"><button class="recent-file-open" data-load="${name}" title="${t("snippets.load")}"><span class="recent-file-name">${name}</span><span class="recent-file-dir">${tags}${formatFileSize(snippet.bytes)} · ${t("snippets.savedAt", { date: new Date(snippet.savedAt * 1000).toLocaleString() })}</span></button><button data-delete="${name}" title="${t("snippets.delete")}">×</button></div>`;
+        })
+        .join("")
+    : `<div class="recent-files-empty">${t(snippetSearchInput.value.trim() ? "snippets.noMatches" : "snippets.none")}</div>`;
+}
+
+async function refreshSnippets() {
+  try {
+    snippets = await invoke("list_snippets", {
+      query: snippetSearchInput.value,
+    });
+    renderSnippets();
+  } catch (error) {
+    console.error("Failed to load snippets:", error);
+  }
+}
+
+async function handleSaveSnippet() {
+  const name = snippetNameInput.value.trim();
+  if (!name) {
+    showStatus(t("status.snippetName"), true);
+    snippetNameInput.focus();
+    return;
+  }
+  try {
+    const existing = await invoke("list_snippets", { query: null });
+    const replacing = existing.some((snippet) => snippet.name === name);
+    await invoke("save_snippet", {
+      name,
+      tags: snippetTagsInput.value.split(","),
+      content: inputText.value,
+    });
+    await refreshSnippets();
+    // The input is now the snippet, so its bookmarks and schema move with it
+    documents[activeDocument].source = `snippet:${name}`;
+    if (documentKey) {
+      documentKey = documents[activeDocument].source;
+      saveBookmarks();
+      saveDocumentState("schema", documentSchema);
+    }
+    showStatus(
+      t(replacing ? "status.snippetUpdated" : "status.snippetSaved", { name }),
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleSnippetClick(event) {
+  const target = event.target.closest("[data-load], [data-delete]");
+  if (!target) return;
+  const { load, delete: remove } = target.dataset;
+  try {
+    if (remove !== undefined) {
+      await invoke("delete_snippet", { name: remove });
+      await refreshSnippets();
+      showStatus(t("status.snippetDeleted", { name: remove }));
+      return;
+    }
+    setInputText(
+      await invoke("load_snippet", { name: load }),
+      "loadSnippet",
+      `snippet:${load}`,
+    );
+    // Saving again updates the loaded snippet
+    snippetNameInput.value = load;
+    snippetTagsInput.value = (
+      snippets.find((snippet) => snippet.name === load)?.tags ?? []
+    ).join(", ");
+    showStatus(t("status.snippetLoaded", { name: load }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// Newest first, as kept by the backend; the selected entry's input is loaded on demand
+let inputHistory = [];
+let selectedHistory = null;
+
+// Only the start of huge inputs is shown; restoring uses the whole text
+const HISTORY_PREVIEW_CHARS = 20000;
+
+function renderInputHistory() {
+  inputHistoryList.innerHTML = inputHistory.length
+    ? inputHistory
+        .map(
+          (entry) =>
+            `<div class="recent-file${entry.id === selectedHistory?.id ? " selected" : ""}"><button class="recent-file-open" data-select="${entry.id}"><span class="recent-file-name">${escapeHtml(entry.operation)} · ${new Date(entry.timestamp * 1000).toLocaleString()} · ${formatFileSize(entry.bytes)}</span><span class="recent-file-dir">${escapeHtml(entry.preview)}</span></button><button data-remove="${entry.id}" title="${t("history.remove")}">×</button></div>`,
+        )
+        .join("")
+    : `<div class="recent-files-empty">${t("history.none")}</div>`;
+}
+
+function showHistoryPreview() {
+  restoreHistoryBtn.disabled = !selectedHistory;
+  if (!selectedHistory) {
+    inputHistoryPreview.textContent = t("history.select");
+    return;
+  }
+  const { input } = selectedHistory;
+  inputHistoryPreview.textContent =
+    input.length > HISTORY_PREVIEW_CHARS
+      ? `${input.slice(0, HISTORY_PREVIEW_CHARS)}\n…`
+      : input;
+}
+
+function setInputHistory(entries) {
+  inputHistory = entries;
+  if (
+    selectedHistory &&
+    !entries.some((entry) => entry.id === selectedHistory.id)
+  ) {
+    selectedHistory = null;
+    showHistoryPreview();
+  }
+  renderInputHistory();
+}
+
+async function loadInputHistory() {
+  try {
+    setInputHistory(await invoke("input_history"));
+  } catch (error) {
+    console.error("Failed to load input history:", error);
+  }
+}
+
+async function recordInputHistory(operation) {
+  const input = inputText.value;
+  if (!input.trim()) return;
+  try {
+    const entries = await invoke("record_input_history", { input, operation });
+    if (inputHistoryPanel.open) setInputHistory(entries);
+  } catch (error) {
+    console.error("Failed to record input history:", error);
+  }
+}
+
+async function handleInputHistoryClick(event) {
+  const target = event.target.closest("[data-select], [data-remove]");
+  if (!target) return;
+  const { select, remove } = target.dataset;
+  try {
+    if (remove !== undefined) {
+      setInputHistory(
+        await invoke("remove_input_history", { id: Number(remove) }),
+      );
+      return;
+    }
+    const id = Number(select);
+    const input = await invoke("input_history_entry", { id });
+    selectedHistory = { id, input };
+    showHistoryPreview();
+    renderInputHistory();
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function handleRestoreHistory() {
+  if (!selectedHistory) return;
+  setInputText(selectedHistory.input, "restoreInput");
+  showStatus(t("status.historyRestored"));
+}
+
+async function handleClearHistory() {
+  try {
+    setInputHistory(await invoke("clear_input_history"));
+    showStatus(t("status.historyCleared"));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function handleFileMenuAction(action) {
+  fileMenu.open = false;
+  action();
+}
+
+async function handleSaveOutput() {
+  const contents = lastConverterOutput || outputText.value;
+  if (!contents) {
+    showStatus(t("status.noOutputToSave"), true);
+    return;
+  }
+
+  const directory = localStorage.getItem("lastDirectory");
+  const name = documents[activeDocument].name.replace(/\.json$/i, "") + ".json";
+  try {
+    const path = await invoke("plugin:dialog|save", {
+      options: {
+        title: t("dialog.saveOutput"),
+        filters: JSON_FILE_FILTERS,
+        defaultPath: directory ? `${directory}/${name}` : name,
+      },
+    });
+    if (!path) return;
+    rememberDirectory(path);
+    await invoke("save_output_file", { path, contents });
+    showStatus(
+      t("status.saved", { name: path.slice(pathSeparatorIndex(path) + 1) }),
+    );
+    loadRecentFiles();
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// Nodes closed in the tree view stay closed in the exported page
+async function handleExportHtml() {
+  const input = (
+    lastConverterOutput ||
+    outputText.value ||
+    inputText.value
+  ).replace(/^\uFEFF/, "");
+  if (!input.trim()) {
+    showStatus(t("status.noJsonToExport"), true);
+    return;
+  }
+
+  const directory = localStorage.getItem("lastDirectory");
+  const title = documents[activeDocument].name;
+  const name = title.replace(/\.json$/i, "") + ".html";
+  const collapsed = isTreeView
+    ? Array.from(
+        treeView.querySelectorAll("details:not([open])"),
+        (node) => node.dataset.pointer,
+      )
+    : [];
+  try {
+    const path = await invoke("plugin:dialog|save", {
+      options: {
+        title: t("dialog.exportHtml"),
+        filters: [{ name: "HTML", extensions: ["html"] }],
+        defaultPath: directory ? `${directory}/${name}` : name,
+      },
+    });
+    if (!path) return;
+    rememberDirectory(path);
+    await invoke("export_html", { input, path, title, collapsed });
+    showStatus(
+      t("status.exported", { name: path.slice(pathSeparatorIndex(path) + 1) }),
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleInputFileSelected(event) {
+  const file = event.target.files[0];
+  if (!file) return;
+  const bytes = new Uint8Array(await file.arrayBuffer());
+  inputFile.value = "";
+  await loadInputBytes(bytes, file.name);
+  documents[activeDocument].name = file.name;
+  renderDocumentTabs();
+}
+
+function newDocumentState(name) {
+  documentCounter += 1;
+  return {
+    name: name || `Untitled ${documentCounter}`,
+    input: "",
+    output: "",
+    treeView: false,
+    // "file:<path>" or "snippet:<name>" when the input came from one
+    source: null,
+    inputError: null,
+    undo: [],
+    redo: [],
+  };
+}
+
+function renderDocumentTabs() {
+  markSessionDirty();
+  documentTabs.innerHTML =
+    documents
+      .map(
+        (doc, index) =>
+          `<button class="document-tab${index === activeDocument ? " active" : ""}" data-document="${index}" title="${escapeHtml(doc.name)}"><span class="document-tab-name">${escapeHtml(doc.name)}</span><span class="document-tab-close" data-close="${index}" title="Close (Ctrl+W)">×</span></button>`,
+      )
+      .join("") +
+    '<button class="document-tab" id="newDocumentBtn" title="New document (Ctrl+T)">+</button>';
+}
+
+function saveActiveDocument() {
+  const doc = documents[activeDocument];
+  doc.input = inputText.value;
+  doc.output = lastConverterOutput;
+  doc.treeView = isTreeView;
+  doc.inputError = inputError;
+  doc.undo = undoStack;
+  doc.redo = redoStack;
+}
+
+function showDocument(index) {
+  activeDocument = index;
+  const doc = documents[index];
+  inputText.value = doc.input;
+  inputError = doc.inputError;
+  undoStack = doc.undo;
+  redoStack = doc.redo;
+  typingBurst = false;
+  hideBinaryNotice();
+  hideFormatSuggestion();
+  setSplitViewVisible(false);
+  setTableViewVisible(false);
+  setTreeViewVisible(false);
+  refreshInputHighlight();
+  refreshStartScreen();
+  setConverterOutput(doc.output, false);
+  if (doc.treeView && renderTreeView()) {
+    setTreeViewVisible(true);
+  }
+  renderDocumentTabs();
+  setValidationState(null);
+  scheduleLiveFormat();
+}
+
+function markSessionDirty() {
+  sessionDirty = true;
+}
+
+async function saveSession() {
+  if (!sessionDirty) return;
+  sessionDirty = false;
+  saveActiveDocument();
+  try {
+    await invoke("save_session", {
+      session: {
+        documents: documents.map((doc) => ({
+          name: doc.name,
+          input: doc.input,
+          output: doc.output,
+          treeView: doc.treeView,
+          source: doc.source,
+        })),
+        activeDocument,
+        lastOperation,
+      },
+    });
+  } catch (error) {
+    sessionDirty = true;
+    console.error("Failed to save session:", error);
+  }
+}
+
+async function handleSaveSessionOnClose() {
+  await saveSession();
+  invoke("close_window");
+}
+
+async function restoreSession() {
+  try {
+    const session = await invoke("load_session");
+    if (!session || session.documents.length === 0) return;
+    documents = session.documents.map((doc) => ({
+      ...newDocumentState(doc.name),
+      input: doc.input,
+      output: doc.output,
+      treeView: doc.treeView,
+      source: doc.source ?? null,
+    }));
+    lastOperation = session.lastOperation;
+    showDocument(Math.min(session.activeDocument, documents.length - 1));
+    sessionDirty = false;
+    showStatus(t("status.sessionRestored", { count: documents.length }));
+  } catch (error) {
+    console.error("Failed to restore session:", error);
+  }
+}
+
+// Converter buttons remembered as the last operation
+const OPERATION_BUTTON_IDS = [
+  "minifyBtn",
+  "formatBtn",
+  "jsonToStringBtn",
+  "stringToJsonBtn",
+  "jsonToMarkdownBtn",
+  "jsonToProtoBtn",
+  "protoToJsonBtn",
+  "dependencyReportBtn",
+  "analyzeBtn",
+  "lintBtn",
+  "validateStrictBtn",
+  "jsonToClassBtn",
+];
+
+function handleOperationButtonClick(event) {
+  lastOperation = event.currentTarget.id;
+  recordInputHistory(event.currentTarget.textContent.trim());
+  markSessionDirty();
+}
+
+function switchDocument(index) {
+  if (index === activeDocument || !documents[index]) return;
+  saveActiveDocument();
+  showDocument(index);
+}
+
+function handleNewDocument() {
+  saveActiveDocument();
+  documents.push(newDocumentState());
+  showDocument(documents.length - 1);
+  inputText.focus();
+}
+
+function closeDocument(index) {
+  if (index !== activeDocument) {
+    documents.splice(index, 1);
+    if (index < activeDocument) activeDocument -= 1;
+    renderDocumentTabs();
+    return;
+  }
+  documents.splice(index, 1);
+  // Closing the last tab leaves an empty document rather than no editor at all
+  if (!documents.length) documents.push(newDocumentState());
+  showDocument(Math.min(index, documents.length - 1));
+}
+
+function stepDocument(step) {
+  const count = documents.length;
+  switchDocument((activeDocument + step + count) % count);
+}
+
+// Converter history: snapshots of input and output taken before each change
+const HISTORY_LIMIT = 100;
+// Large documents would otherwise keep up to HISTORY_LIMIT full copies alive
+const HISTORY_MAX_CHARS = 50000000;
+const TYPING_PAUSE_MS = 1000;
+let undoStack = [];
+let redoStack = [];
+let typingBurst = false;
+let typingTimer = null;
+
+function converterSnapshot(label) {
+  return { label, input: inputText.value, output: lastConverterOutput };
+}
+
+function recordHistory(label) {
+  const top = undoStack[undoStack.length - 1];
+  if (
+    top &&
+    top.input === inputText.value &&
+    top.output === lastConverterOutput
+  ) {
+    return;
+  }
+  pushSnapshot(undoStack, converterSnapshot(label));
+  redoStack = [];
+}
+
+// Drops the oldest snapshots past the entry or character limit; the newest always stays
+function pushSnapshot(stack, snapshot) {
+  stack.push(snapshot);
+  let chars = stack.reduce(
+    (total, entry) => total + entry.input.length + entry.output.length,
+    0,
+  );
+  while (
+    stack.length > 1 &&
+    (stack.length > HISTORY_LIMIT || chars > HISTORY_MAX_CHARS)
+  ) {
+    const dropped = stack.shift();
+    chars -= dropped.input.length + dropped.output.length;
+  }
+}
+
+function restoreSnapshot(snapshot) {
+  inputText.value = snapshot.input;
+  handleInputEdited();
+  setTreeViewVisible(isTreeView && Boolean(snapshot.output));
+  setConverterOutput(snapshot.output, false);
+}
+
+function handleUndo() {
+  const snapshot = undoStack.pop();
+  if (!snapshot) {
+    showStatus(t("status.nothingToUndo"), true);
+    return;
+  }
+  pushSnapshot(redoStack, converterSnapshot(snapshot.label));
+  restoreSnapshot(snapshot);
+  typingBurst = false;
+  showStatus(t("status.undone", { action: t(`action.${snapshot.label}`) }));
+}
+
+function handleRedo() {
+  const snapshot = redoStack.pop();
+  if (!snapshot) {
+    showStatus(t("status.nothingToRedo"), true);
+    return;
+  }
+  pushSnapshot(undoStack, converterSnapshot(snapshot.label));
+  restoreSnapshot(snapshot);
+  typingBurst = false;
+  showStatus(t("status.redone", { action: t(`action.${snapshot.label}`) }));
+}
+
+function handleInputBeforeEdit() {
+  // A run of keystrokes without a pause undoes as one step
+  if (!typingBurst) recordHistory("edit");
+  typingBurst = true;
+  clearTimeout(typingTimer);
+  typingTimer = setTimeout(() => {
+    typingBurst = false;
+  }, TYPING_PAUSE_MS);
+}
+
+function isConverterHistoryTarget(target) {
+  if (target === inputText || target === outputText) return true;
+  // Other text fields keep their native undo
+  const editable =
+    target.isContentEditable ||
+    ["INPUT", "TEXTAREA", "SELECT"].includes(target.tagName);
+  return !editable && !converterSection.classList.contains("hidden");
+}
+
+// Native menu items that do exactly what a converter button does
+const MENU_BUTTONS = {
+  format: "formatBtn",
+  "paste-format": "pasteFormatBtn",
+  minify: "minifyBtn",
+  "json-to-string": "jsonToStringBtn",
+  "json-to-markdown": "jsonToMarkdownBtn",
+  "string-to-json": "stringToJsonBtn",
+  "json-to-proto": "jsonToProtoBtn",
+  "proto-to-json": "protoToJsonBtn",
+  "json-to-class": "jsonToClassBtn",
+  "dependency-report": "dependencyReportBtn",
+  analyze: "analyzeBtn",
+  lint: "lintBtn",
+  "validate-strict": "validateStrictBtn",
+  clear: "clearBtn",
+  "tree-view": "treeViewBtn",
+  "table-view": "tableViewBtn",
+  "split-view": "splitViewBtn",
+  "pane-layout": "paneLayoutBtn",
+};
+
+const MENU_ACTIONS = {
+  "new-document": handleNewDocument,
+  "close-document": () => closeDocument(activeDocument),
+  open: handleOpenFile,
+  save: handleSaveOutput,
+  "export-html": handleExportHtml,
+  undo: () => handleMenuHistory(false),
+  redo: () => handleMenuHistory(true),
+  find: openFindBar,
+  settings: openSettingsDialog,
+  "compare-documents": handleCompareDocuments,
+};
+
+// The menu's Undo and Redo keep native undo in text fields outside the converter
+function handleMenuHistory(redo) {
+  const target = document.activeElement || document.body;
+  if (!isConverterHistoryTarget(target)) {
+    document.execCommand(redo ? "redo" : "undo");
+  } else if (redo) {
+    handleRedo();
+  } else {
+    handleUndo();
+  }
+}
+
+function handleMenuAction(event) {
+  const action = event.payload;
+  if (action.startsWith("tab-")) {
+    setActiveTab(action.slice("tab-".length));
+  } else if (MENU_ACTIONS[action]) {
+    MENU_ACTIONS[action]();
+  } else if (MENU_BUTTONS[action]) {
+    setActiveTab("converter");
+    document.getElementById(MENU_BUTTONS[action]).click();
+  }
+}
+
+// Replacing the input also replaces where it came from, which keys its bookmarks
+function setInputText(text, label, source = null) {
+  documents[activeDocument].source = source;
+  recordHistory(label);
+  inputText.value = text;
+  handleInputEdited();
+}
+
+function handleDocumentTabClick(event) {
+  const close = event.target.closest("[data-close]");
+  if (close) {
+    closeDocument(Number(close.dataset.close));
+    return;
+  }
+  if (event.target.closest("#newDocumentBtn")) {
+    handleNewDocument();
+    return;
+  }
+  const tab = event.target.closest("[data-document]");
+  if (tab) switchDocument(Number(tab.dataset.document));
+}
+
+// Files opened from disk are read from their path; others are sent as bytes
+function inputSource({ bytes, path }) {
+  return path ? { path } : { data: Array.from(bytes) };
+}
+
+async function showArchiveEntries(input) {
+  const entries = await invoke("open_archive", inputSource(input));
+  const jsonEntries = entries.filter((entry) => entry.isJson);
+  binaryNoticeText.textContent = t("archive.summary", {
+    name: input.name,
+    count: entries.length,
+    json: jsonEntries.length,
+  });
+  binaryDecoders.innerHTML = jsonEntries
+    .map(
+      (entry) =>
+        `<button class="secondary" data-entry="${escapeHtml(entry.name)}">${escapeHtml(entry.name)} (${formatFileSize(entry.size)})</button>`,
+    )
+    .join("");
+}
+
+async function openArchiveEntry(input, entryName) {
+  const text = await invoke("read_archive_entry", {
+    ...inputSource(input),
+    entryName,
+    outputOptions: getOutputOptions(),
+  });
+  setInputText(text, "load");
+  hideBinaryNotice();
+  showStatus(t("status.opened", { name: entryName }));
+}
+
+// The HAR export shown in the side panel, with its entry summaries
+let harFile = null;
+
+async function openHar(text, name) {
+  try {
+    const entries = await invoke("open_har", { text });
+    harFile = { text, name, entries };
+    harTitle.textContent = `${name} (${entries.length})`;
+    harPanel.classList.remove("hidden");
+    renderHarEntries();
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function closeHar() {
+  harFile = null;
+  harEntries.innerHTML = "";
+  harPanel.classList.add("hidden");
+}
+
+function renderHarEntries() {
+  if (!harFile) return;
+  const filter = harFilterInput.value.trim().toLowerCase();
+  const bodyKey =
+    harPartSelect.value === "request" ? "hasRequestBody" : "hasResponseBody";
+  const entries = harFile.entries.filter(
+    (entry) =>
+      (!filter || entry.url.toLowerCase().includes(filter)) &&
+      (!harJsonOnlyCheckbox.checked || entry.mimeType.includes("json")),
+  );
+  harEntries.innerHTML = entries.length
+    ? entries
+        .map((entry) => {
+          const meta = [
+            entry.status || "failed",
+            entry.mimeType || "no content type",
+            entry.size >= 0 ? formatFileSize(entry.size) : "",
+          ]
+            .filter(Boolean)
+            .join(" · ");
+          const failed = entry.status === 0 || entry.status >= 400;
+          return `<button class="har-entry" data-har-index="${entry.index}"${entry[bodyKey] ? "" : " disabled"} title="${escapeHtml(entry.url)}"><span class="har-entry-url">${escapeHtml(entry.method)} ${escapeHtml(entry.url)}</span><span class="har-entry-meta${failed ? " error" : ""}">${escapeHtml(meta)}</span></button>`;
+        })
+        .join("")
+    : `<div class="recent-files-empty">No matching entries</div>`;
+}
+
+// Each body opens in its own tab so the HAR itself stays loaded
+async function handleHarEntryClick(event) {
+  const button = event.target.closest("[data-har-index]");
+  if (!button || !harFile) return;
+  const entry = harFile.entries[Number(button.dataset.harIndex)];
+  const part = harPartSelect.value;
+  try {
+    const text = await invoke("read_har_entry", {
+      text: harFile.text,
+      index: entry.index,
+      part,
+      outputOptions: getOutputOptions(),
+    });
+    setActiveTab("converter");
+    handleNewDocument();
+    const path = entry.url.split(/[?#]/)[0];
+    const file = path.slice(path.lastIndexOf("/") + 1) || "/";
+    documents[activeDocument].name = `${entry.method} ${file} (${part})`;
+    setInputText(text, "load");
+    renderDocumentTabs();
+    showStatus(
+      t("status.harOpened", { part, method: entry.method, url: entry.url }),
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error: error.message || error }), true);
+  }
+}
+
+async function handleBinaryDecoder(event) {
+  const button = event.target.closest("[data-decoder], [data-entry]");
+  if (!button || !pendingBinaryInput) return;
+
+  const { name, path, type } = pendingBinaryInput;
+  if (button.dataset.entry) {
+    try {
+      await openArchiveEntry(pendingBinaryInput, button.dataset.entry);
+    } catch (error) {
+      showStatus(t("status.error", { error }), true);
+    }
+    return;
+  }
+
+  const decoder = button.dataset.decoder;
+  if (decoder === "archive") {
+    try {
+      await showArchiveEntries(pendingBinaryInput);
+    } catch (error) {
+      showStatus(t("status.error", { error }), true);
+    }
+    return;
+  }
+  let { bytes } = pendingBinaryInput;
+  try {
+    // Files opened from disk were inspected by path; read them once a decoder needs them
+    bytes ??= new Uint8Array(await invoke("read_input_file", { path }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+    return;
+  }
+  if (decoder === "image") {
+    hideBinaryNotice();
+    setActiveTab("imageResizer");
+    loadImageFile(new File([bytes], name, { type }));
+    return;
+  }
+
+  try {
+    const text = await invoke("decode_binary_input", {
+      data: Array.from(bytes),
+      decoder,
+      outputOptions: getOutputOptions(),
+    });
+    setInputText(text, "decode");
+    hideBinaryNotice();
+    showStatus(t("status.decoded", { decoder: decoderLabel(decoder), name }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// In lossy mode unpaired surrogate escapes, which fail to parse, become \ufffd
+async function sanitizeLossyInput() {
+  if (!lossyInputCheckbox.checked) return;
+  try {
+    const result = await invoke("sanitize_input", { input: inputText.value });
+    if (!result.replacements) return;
+    setInputText(result.text, "sanitize");
+    showStatus(t("status.surrogatesReplaced", { count: result.replacements }));
+  } catch (error) {
+    console.error("Failed to sanitize the input:", error);
+  }
+}
+
+function handleInputPaste(event) {
+  const text = event.clipboardData.getData("text");
+  if (!text.includes("\u0000") && !text.startsWith("\uFEFF")) {
+    // The pasted text lands in the textarea after this event
+    setTimeout(async () => {
+      await sanitizeLossyInput();
+      suggestInputFormat();
+    });
+    return;
+  }
+
+  // NUL bytes mean binary data or UTF-16 read as bytes, and a BOM would break
+  // parsing; inspect the text instead of pasting it as is
+  event.preventDefault();
+  loadInputBytes(new TextEncoder().encode(text), "Pasted data");
+}
+
+// Highlighting larger documents on every keystroke would make typing lag
+const HIGHLIGHT_MAX_LENGTH = 500000;
+const HIGHLIGHT_PATTERN =
+  /("(?:\\.|[^"\\\n])*")(\s*:)?|\b(true|false)\b|\b(null)\b|(-?\b\d+(?:\.\d+)?(?:[eE][+-]?\d+)?\b)/g;
+
+// Last parse error in the input, with the text it was reported for
+let inputError = null;
+
+// Find bar state: text ranges for an editor, or elements in the tree view
+let findPane = inputText;
+let findTarget = null;
+let findMatches = [];
+let findIndex = -1;
+
+function highlightJson(text, errorLine = null, matches = [], current = -1) {
+  let next = 0;
+  // Escape text[start, end), wrapping the parts covered by find matches
+  const mark = (start, end) => {
+    let html = "";
+    let pos = start;
+    while (pos < end) {
+      while (next < matches.length && matches[next].end <= pos) next++;
+      const match = matches[next];
+      if (!match || match.start >= end) {
+        html += escapeHtml(text.slice(pos, end));
+        break;
+      }
+      if (match.start > pos) {
+        html += escapeHtml(text.slice(pos, match.start));
+        pos = match.start;
+      }
+      const stop = Math.min(match.end, end);
+      const kind = next === current ? "hl-match hl-current" : "hl-match";
+      html += text
+        .slice(pos, stop)
+        .split("\n")
+        .map((part) => `<mark class="${kind}">${escapeHtml(part)}</mark>`)
+        .join("\n");
+      pos = stop;
+    }
+    return html;
+  };
+
+  let html = "";
+  let last = 0;
+  for (const match of text.matchAll(HIGHLIGHT_PATTERN)) {
+    const [token, string, colon, boolean, nullValue] = match;
+    const start = match.index;
+    html += mark(last, start);
+    if (string !== undefined) {
+      const kind = colon ? "hl-key" : "hl-string";
+      const end = start + string.length;
+      html += `<span class="${kind}">${mark(start, end)}</span>`;
+      html += mark(end, start + token.length);
+    } else if (boolean !== undefined) {
+      html += `<span class="hl-boolean">${mark(start, start + token.length)}</span>`;
+    } else if (nullValue !== undefined) {
+      html += `<span class="hl-null">${mark(start, start + token.length)}</span>`;
+    } else {
+      html += `<span class="hl-number">${mark(start, start + token.length)}</span>`;
+    }
+    last = start + token.length;
+  }
+  html += mark(last, text.length);
+  // Tokens never span a newline, so each line gets its own block for the gutter
+  return html
+    .split("\n")
+    .map((line, index) => {
+      const kind = index + 1 === errorLine ? "hl-line hl-error-line" : "hl-line";
+      return `<span class="${kind}">${line}</span>`;
+    })
+    .join("");
+}
+
+function refreshHighlight(textarea, layer, errorLine = null) {
+  const enabled = textarea.value.length <= HIGHLIGHT_MAX_LENGTH;
+  const searched = textarea === findTarget;
+  textarea.classList.toggle("highlighted", enabled);
+  layer.innerHTML = enabled
+    ? highlightJson(
+        textarea.value,
+        errorLine,
+        searched ? findMatches : [],
+        searched ? findIndex : -1,
+      )
+    : "";
+  syncHighlightScroll(textarea, layer);
+}
+
+function refreshOutputHighlight() {
+  if (isVirtualOutput) {
+    renderVirtualOutput();
+  } else {
+    refreshHighlight(outputText, outputHighlight);
+  }
+}
+
+// Outputs above this size skip the textarea and render only the visible lines
+const VIRTUAL_OUTPUT_THRESHOLD = 2000000;
+const VIRTUAL_LINE_HEIGHT = 21;
+const VIRTUAL_OVERSCAN = 20;
+let isVirtualOutput = false;
+let virtualLineStarts = [];
+
+function editorText(textarea) {
+  return textarea === outputText && isVirtualOutput
+    ? lastConverterOutput
+    : textarea.value;
+}
+
+function indexVirtualOutput(text) {
+  virtualLineStarts = [0];
+  for (let i = text.indexOf("\n"); i !== -1; i = text.indexOf("\n", i + 1)) {
+    virtualLineStarts.push(i + 1);
+  }
+  outputViewerSpacer.style.height = `${
+    virtualLineStarts.length * VIRTUAL_LINE_HEIGHT + 24
+  }px`;
+}
+
+function virtualLineAt(offset) {
+  let low = 0;
+  let high = virtualLineStarts.length - 1;
+  while (low < high) {
+    const mid = (low + high + 1) >> 1;
+    if (virtualLineStarts[mid] <= offset) {
+      low = mid;
+    } else {
+      high = mid - 1;
+    }
+  }
+  return low;
+}
+
+function renderVirtualOutput() {
+  const text = lastConverterOutput;
+  const total = virtualLineStarts.length;
+  const first = Math.max(
+    0,
+    Math.floor((outputViewer.scrollTop - 12) / VIRTUAL_LINE_HEIGHT) -
+      VIRTUAL_OVERSCAN,
+  );
+  const last = Math.min(
+    total,
+    first +
+      Math.ceil(outputViewer.clientHeight / VIRTUAL_LINE_HEIGHT) +
+      VIRTUAL_OVERSCAN * 2,
+  );
+  const start = virtualLineStarts[first];
+  const end = last < total ? virtualLineStarts[last] - 1 : text.length;
+
+  // Rebase the find matches that touch this window onto the window's text
+  let matches = [];
+  let current = -1;
+  if (findTarget === outputText) {
+    findMatches.forEach((match, index) => {
+      if (match.end <= start || match.start >= end) return;
+      if (index === findIndex) current = matches.length;
+      matches.push({ start: match.start - start, end: match.end - start });
+    });
+  }
+
+  outputViewerLines.style.top = `${12 + first * VIRTUAL_LINE_HEIGHT}px`;
+  outputViewerLines.style.counterReset = `line ${first}`;
+  // CRLF output keeps its offsets; the zero-width space stands in for the CR
+  outputViewerLines.innerHTML = highlightJson(
+    text.slice(start, end).replace(/\r/g, "\u200b"),
+    null,
+    matches,
+    current,
+  );
+}
+
+function updateOutputPanes() {
+  outputText.parentElement.classList.toggle(
+    "hidden",
+    isTreeView || isTableView || isVirtualOutput,
+  );
+  outputViewer.classList.toggle(
+    "hidden",
+    isTreeView || isTableView || !isVirtualOutput,
+  );
+}
+
+function syncHighlightScroll(textarea, layer) {
+  // Match the textarea's content box, which shrinks when a scrollbar appears
+  layer.style.width = `${textarea.clientWidth}px`;
+  layer.style.height = `${textarea.clientHeight}px`;
+  layer.scrollTop = textarea.scrollTop;
+  layer.scrollLeft = textarea.scrollLeft;
+}
+
+function syncHighlightLayers() {
+  syncHighlightScroll(inputText, inputHighlight);
+  syncHighlightScroll(outputText, outputHighlight);
+}
+
+function refreshInputHighlight() {
+  // The marker only applies to the text that failed to parse
+  const errorLine =
+    inputError && inputError.text === inputText.value ? inputError.line : null;
+  refreshHighlight(inputText, inputHighlight, errorLine);
+}
+
+const ERROR_LOCATION_PATTERN = /at line (\d+) column (\d+)/;
+
+function showInputError(error) {
+  showStatus(t("status.error", { error }), true);
+  const location = ERROR_LOCATION_PATTERN.exec(String(error));
+  inputError = location
+    ? { line: Number(location[1]), text: inputText.value }
+    : null;
+  refreshInputHighlight();
+  if (location) {
+    revealInputLocation(Number(location[1]), Number(location[2]));
+  }
+}
+
+// Cap the match list so a one-letter query on a huge document stays responsive
+const FIND_MAX_MATCHES = 10000;
+
+function findPattern() {
+  const query = findInput.value;
+  if (!query) return null;
+  if (findRegexCheckbox.checked) return new RegExp(query, "g");
+  return new RegExp(query.replace(/[.*+?^${}()|[\]\\]/g, "\\$&"), "gi");
+}
+
+function collectFindMatches(text, pattern) {
+  const matches = [];
+  for (const match of text.matchAll(pattern)) {
+    if (!match[0]) continue;
+    matches.push({ start: match.index, end: match.index + match[0].length });
+    if (matches.length === FIND_MAX_MATCHES) break;
+  }
+  return matches;
+}
+
+function refreshEditorHighlight(textarea) {
+  if (textarea === inputText) {
+    refreshInputHighlight();
+  } else {
+    refreshOutputHighlight();
+  }
+}
+
+function runFind(reveal = true) {
+  const previous = findTarget;
+  treeView
+    .querySelectorAll(".tree-match")
+    .forEach((el) => el.classList.remove("tree-match", "tree-current"));
+  findTarget = findPane === outputText && isTreeView ? treeView : findPane;
+
+  let pattern = null;
+  try {
+    pattern = findPattern();
+    findInput.classList.remove("find-invalid");
+  } catch {
+    findInput.classList.add("find-invalid");
+  }
+
+  if (!pattern) {
+    findMatches = [];
+  } else if (findTarget === treeView) {
+    findMatches = [...treeView.querySelectorAll(".tree-key, .tree-value")]
+      .filter((el) => el.textContent.search(pattern) !== -1)
+      .slice(0, FIND_MAX_MATCHES);
+    findMatches.forEach((el) => el.classList.add("tree-match"));
+  } else {
+    findMatches = collectFindMatches(editorText(findTarget), pattern);
+  }
+  findIndex = reveal ? 0 : Math.max(findIndex, 0);
+  findIndex = Math.min(findIndex, findMatches.length - 1);
+
+  if (previous && previous !== findTarget && previous !== treeView) {
+    refreshEditorHighlight(previous);
+  }
+  showFindMatch(reveal);
+}
+
+function showFindMatch(reveal) {
+  const total = `${findMatches.length}${
+    findMatches.length === FIND_MAX_MATCHES ? "+" : ""
+  }`;
+  findCount.textContent = findMatches.length
+    ? `${findIndex + 1} of ${total}`
+    : findInput.value
+      ? "No matches"
+      : "";
+
+  if (findTarget === treeView) {
+    treeView
+      .querySelectorAll(".tree-current")
+      .forEach((el) => el.classList.remove("tree-current"));
+    const current = findMatches[findIndex];
+    if (!current) return;
+    current.classList.add("tree-current");
+    if (reveal) {
+      for (
+        let node = current.closest("details");
+        node;
+        node = node.parentElement.closest("details")
+      ) {
+        node.open = true;
+      }
+      current.scrollIntoView({ block: "center" });
+    }
+    return;
+  }
+
+  refreshEditorHighlight(findTarget);
+  if (reveal && findIndex >= 0) {
+    revealFindMatch();
+  }
+}
+
+function revealFindMatch() {
+  const textarea = findTarget;
+  const layer = textarea === inputText ? inputHighlight : outputHighlight;
+  const { start, end } = findMatches[findIndex];
+  if (textarea === outputText && isVirtualOutput) {
+    const line = virtualLineAt(start);
+    outputViewer.scrollTop =
+      line * VIRTUAL_LINE_HEIGHT - outputViewer.clientHeight / 3;
+    renderVirtualOutput();
+    return;
+  }
+  const current = layer.querySelector(".hl-current");
+  if (current) {
+    textarea.setSelectionRange(start, end);
+    const offset =
+      current.getBoundingClientRect().top - layer.getBoundingClientRect().top;
+    textarea.scrollTop += offset - textarea.clientHeight / 3;
+    syncHighlightScroll(textarea, layer);
+    return;
+  }
+  // Too large to highlight: let the browser scroll the selection into view
+  textarea.focus();
+  textarea.setSelectionRange(start, end);
+  findInput.focus();
+}
+
+function stepFind(step) {
+  if (!findMatches.length) return;
+  findIndex = (findIndex + step + findMatches.length) % findMatches.length;
+  showFindMatch(true);
+}
+
+function openFindBar() {
+  findBar.classList.remove("hidden");
+  findInput.focus();
+  findInput.select();
+  runFind();
+}
+
+function closeFindBar() {
+  const previous = findTarget;
+  findBar.classList.add("hidden");
+  treeView
+    .querySelectorAll(".tree-match")
+    .forEach((el) => el.classList.remove("tree-match", "tree-current"));
+  findTarget = null;
+  findMatches = [];
+  findIndex = -1;
+  if (previous && previous !== treeView) {
+    refreshEditorHighlight(previous);
+  }
+}
+
+function setFindPane(pane) {
+  if (findPane === pane) return;
+  findPane = pane;
+  if (findTarget) runFind(false);
+}
+
+function handleInputEdited() {
+  if (findTarget === inputText) {
+    runFind(false);
+  } else {
+    refreshInputHighlight();
+  }
+  refreshStartScreen();
+  scheduleLiveFormat();
+  if (suggestedFormat && inputText.value !== suggestedFormat.input) {
+    hideFormatSuggestion();
+  }
+  if (!lastConverterOutput) scheduleDocumentMetrics();
+  markSessionDirty();
+}
+
+const LIVE_FORMAT_DELAY_MS = 300;
+let liveFormatTimer = null;
+let liveFormatRun = 0;
+
+function scheduleLiveFormat() {
+  clearTimeout(liveFormatTimer);
+  // Bumping the run number cancels any call still in flight
+  liveFormatRun += 1;
+  if (!liveFormatCheckbox.checked) return;
+  liveFormatTimer = setTimeout(runLiveFormat, LIVE_FORMAT_DELAY_MS);
+}
+
+async function runLiveFormat() {
+  const run = liveFormatRun;
+  const input = inputText.value;
+  if (!input.trim()) {
+    setValidationState(null);
+    return;
+  }
+  try {
+    const result = await invokeWithProgress("format_json", {
+      input,
+      outputOptions: getOutputOptions(),
+    });
+    if (run !== liveFormatRun) return;
+    inputError = null;
+    refreshInputHighlight();
+    setConverterOutput(result, false);
+    setValidationState(true);
+  } catch (error) {
+    if (run !== liveFormatRun) return;
+    const location = ERROR_LOCATION_PATTERN.exec(String(error));
+    inputError = location ? { line: Number(location[1]), text: input } : null;
+    refreshInputHighlight();
+    setValidationState(false, String(error));
+  }
+}
+
+function setValidationState(valid, message = "") {
+  validationState.className = `validation-state ${
+    valid === null ? "hidden" : valid ? "valid" : "invalid"
+  }`;
+  validationState.textContent = t(
+    valid ? "validation.valid" : "validation.invalid",
+  );
+  validationState.title = message;
+}
+
+function handleLiveFormatToggle() {
+  localStorage.setItem("liveFormat", liveFormatCheckbox.checked ? "1" : "");
+  if (liveFormatCheckbox.checked) {
+    scheduleLiveFormat();
+  } else {
+    clearTimeout(liveFormatTimer);
+    liveFormatRun += 1;
+    setValidationState(null);
+  }
+}
+
+function refreshOutputFind() {
+  if (findTarget && findTarget !== inputText) {
+    runFind(false);
+  } else {
+    refreshOutputHighlight();
+  }
+}
+
+function handleFindKeydown(event) {
+  if (event.key === "Enter") {
+    event.preventDefault();
+    stepFind(event.shiftKey ? -1 : 1);
+  } else if (event.key === "Escape") {
+    closeFindBar();
+  }
+}
+
+function revealInputLocation(line, column) {
+  const lines = inputText.value.split("\n");
+  let offset = 0;
+  for (let i = 0; i < line - 1 && i < lines.length; i++) {
+    offset += lines[i].length + 1;
+  }
+  // Parser columns count UTF-8 bytes, textarea offsets count UTF-16 units
+  const encoder = new TextEncoder();
+  let bytes = 0;
+  for (const char of lines[line - 1] || "") {
+    if (bytes >= column - 1) break;
+    bytes += encoder.encode(char).length;
+    offset += char.length;
+  }
+  inputText.focus();
+  inputText.setSelectionRange(offset, offset);
+
+  const marker = inputHighlight.querySelector(".hl-error-line");
+  if (marker) {
+    inputText.scrollTop = Math.max(
+      0,
+      marker.offsetTop - inputText.clientHeight / 3,
+    );
+    syncHighlightScroll(inputText, inputHighlight);
+  }
+}
+
+function setConverterOutput(text, record = true) {
+  markSessionDirty();
+  if (record && text !== lastConverterOutput) recordHistory("transform");
+  lastConverterOutput = text;
+  isVirtualOutput = text.length > VIRTUAL_OUTPUT_THRESHOLD;
+  clearSelectedPath();
+  outputText.value = isVirtualOutput ? "" : text;
+  if (isVirtualOutput) {
+    indexVirtualOutput(text);
+    outputViewer.scrollTop = 0;
+  } else {
+    virtualLineStarts = [];
+  }
+  updateOutputPanes();
+  refreshOutputFind();
+  scheduleDocumentMetrics(0);
+  if (isSplitView) {
+    refreshSplitView();
+  }
+  if (isTreeView) {
+    renderTreeView();
+  }
+  if (isTableView) {
+    if (loadTableRows()) {
+      renderTableView();
+    } else {
+      setTableViewVisible(false);
+    }
+  }
+}
+
+function escapePointerToken(key) {
+  return String(key).replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+const TREE_TYPE_ICONS = {
+  object: "{}",
+  array: "[]",
+  string: "\u201c\u201d",
+  number: "#",
+  boolean: "\u25d1",
+  null: "\u2205",
+};
+
+function treeValueType(value) {
+  if (value === null) return "null";
+  return Array.isArray(value) ? "array" : typeof value;
+}
+
+// Nodes built up front; containers past this budget are filled in on first expand
+const TREE_EAGER_NODES = 20000;
+// Children rendered per batch under one container
+const TREE_CHUNK_SIZE = 1000;
+let treeRoot = null;
+
+function buildTreeChildren(value, pointer, offset, budget) {
+  const inArray = Array.isArray(value);
+  const keys = inArray ? null : Object.keys(value);
+  const count = inArray ? value.length : keys.length;
+  const end = Math.min(count, offset + TREE_CHUNK_SIZE);
+  let html = "";
+  for (let i = offset; i < end; i++) {
+    const key = inArray ? String(i) : keys[i];
+    html += buildTreeNode(
+      key,
+      value[key],
+      `${pointer}/${escapePointerToken(key)}`,
+      inArray,
+      budget,
+    );
+  }
+  if (end < count) {
+    html += `<button class="secondary tree-more" data-offset="${end}">Show ${Math.min(TREE_CHUNK_SIZE, count - end)} more (${count - end} remaining)</button>`;
+  }
+  return html;
+}
+
+function buildTreeNode(
+  key,
+  value,
+  pointer,
+  inArray = false,
+  budget = { nodes: Infinity },
+) {
+  const type = treeValueType(value);
+  const icon = `<span class="tree-type tree-type-${type}" title="${type}">${TREE_TYPE_ICONS[type]}</span>`;
+  const label =
+    icon +
+    (key === null ? "" : `<span class="tree-key">${escapeHtml(key)}</span>: `);
+  const attrs = `data-pointer="${escapeHtml(pointer)}"${
+    inArray ? " data-array-item" : ""
+  }`;
+
+  if (value !== null && typeof value === "object") {
+    const count = Array.isArray(value)
+      ? value.length
+      : Object.keys(value).length;
+    const noun = Array.isArray(value) ? "item" : "key";
+    const bracket = `<span class="tree-count">${count} ${noun}${
+      count === 1 ? "" : "s"
+    }</span>`;
+    if (budget.nodes <= 0) {
+      return `<details data-lazy ${attrs}><summary>${label}${bracket}</summary></details>`;
+    }
+    budget.nodes -= Math.min(count, TREE_CHUNK_SIZE);
+    const children = buildTreeChildren(value, pointer, 0, budget);
+    return `<details open ${attrs}><summary>${label}${bracket}</summary>${children}</details>`;
+  }
+
+  return `<div class="tree-leaf" ${attrs}>${label}<span class="tree-value">${escapeHtml(JSON.stringify(value))}</span></div>`;
+}
+
+function renderTreeView() {
+  const text = lastConverterOutput.replace(/^\uFEFF/, "");
+  try {
+    treeRoot = JSON.parse(text);
+    treeView.innerHTML = buildTreeNode(null, treeRoot, "", false, {
+      nodes: TREE_EAGER_NODES,
+    });
+  } catch (error) {
+    showStatus(t("status.outputNotJson"), true);
+    return false;
+  }
+  if (findTarget === treeView) runFind(false);
+  renderPathBreadcrumb();
+  loadDocumentState(text);
+  return true;
+}
+
+async function loadDocumentState(text) {
+  try {
+    documentKey =
+      documents[activeDocument].source ??
+      (await invoke("document_fingerprint", { input: text }));
+    bookmarks = JSON.parse(
+      localStorage.getItem(`bookmarks:${documentKey}`) || "[]",
+    );
+    documentSchema = localStorage.getItem(`schema:${documentKey}`) || "";
+  } catch {
+    documentKey = null;
+    bookmarks = [];
+    documentSchema = "";
+  }
+  schemaInput.value = documentSchema;
+  renderBookmarks();
+  await annotateTreeView(text);
+}
+
+function saveDocumentState(key, value) {
+  if (!documentKey) return;
+  if (value) {
+    localStorage.setItem(`${key}:${documentKey}`, value);
+  } else {
+    localStorage.removeItem(`${key}:${documentKey}`);
+  }
+}
+
+function saveBookmarks() {
+  saveDocumentState(
+    "bookmarks",
+    bookmarks.length ? JSON.stringify(bookmarks) : "",
+  );
+}
+
+async function annotateTreeView(text) {
+  for (const node of treeView.querySelectorAll("[data-pointer]")) {
+    node.classList.remove("tree-valid", "tree-invalid");
+    node.removeAttribute("title");
+  }
+  if (!documentSchema) return;
+
+  try {
+    const annotations = await invoke("annotate_with_schema", {
+      input: text,
+      schema: documentSchema,
+    });
+    const nodes = new Map(
+      [...treeView.querySelectorAll("[data-pointer]")].map((node) => [
+        node.dataset.pointer,
+        node,
+      ]),
+    );
+    for (const annotation of annotations) {
+      const node = nodes.get(annotation.pointer);
+      if (!node) continue;
+      node.classList.add(annotation.valid ? "tree-valid" : "tree-invalid");
+      const tooltip = [
+        annotation.title,
+        annotation.description,
+        ...annotation.errors,
+      ].filter(Boolean);
+      if (tooltip.length) {
+        node.title = tooltip.join("\n");
+      }
+    }
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleApplySchema() {
+  documentSchema = schemaInput.value.trim();
+  saveDocumentState("schema", documentSchema);
+  await annotateTreeView(lastConverterOutput.replace(/^\uFEFF/, ""));
+  if (documentSchema) {
+    const invalid = treeView.querySelectorAll(".tree-invalid").length;
+    showStatus(
+      invalid
+        ? t("status.schemaFailures", { count: invalid })
+        : t("status.schemaMatches"),
+      invalid > 0,
+    );
+  }
+}
+
+function renderBookmarks() {
+  const selected = bookmarkSelect.value;
+  bookmarkSelect.innerHTML = bookmarks
+    .map(
+      (pointer) =>
+        `<option value="${escapeHtml(pointer)}">${escapeHtml(pointer || "/")}</option>`,
+    )
+    .join("");
+  if (bookmarks.includes(selected)) {
+    bookmarkSelect.value = selected;
+  }
+  bookmarkBar.classList.toggle("hidden", !isTreeView || !bookmarks.length);
+
+  for (const node of treeView.querySelectorAll(".tree-bookmarked")) {
+    node.classList.remove("tree-bookmarked");
+  }
+  for (const pointer of bookmarks) {
+    const node = findTreeNode(pointer);
+    if (node) {
+      node.classList.add("tree-bookmarked");
+    }
+  }
+}
+
+function treeValueAt(pointer) {
+  return pointer
+    .split("/")
+    .slice(1)
+    .reduce(
+      (value, token) => value[token.replace(/~1/g, "/").replace(/~0/g, "~")],
+      treeRoot,
+    );
+}
+
+function expandLazyTreeNode(details) {
+  if (!details.hasAttribute("data-lazy")) return;
+  details.removeAttribute("data-lazy");
+  const pointer = details.dataset.pointer;
+  details.insertAdjacentHTML(
+    "beforeend",
+    buildTreeChildren(treeValueAt(pointer), pointer, 0, {
+      nodes: TREE_CHUNK_SIZE,
+    }),
+  );
+  renderBookmarks();
+}
+
+function loadMoreTreeChildren(button) {
+  const pointer = button.parentElement.dataset.pointer;
+  button.insertAdjacentHTML(
+    "afterend",
+    buildTreeChildren(treeValueAt(pointer), pointer, Number(button.dataset.offset), {
+      nodes: TREE_CHUNK_SIZE,
+    }),
+  );
+  button.remove();
+  renderBookmarks();
+}
+
+function handleTreeToggle(event) {
+  if (event.target.tagName === "DETAILS" && event.target.open) {
+    expandLazyTreeNode(event.target);
+  }
+}
+
+function handleTreeShowMore(event) {
+  const button = event.target.closest(".tree-more");
+  if (button) loadMoreTreeChildren(button);
+}
+
+// Build the lazy containers and batches on the way to a pointer that isn't rendered yet
+function materializeTreePath(pointer) {
+  let path = "";
+  for (const token of pointer.split("/").slice(1)) {
+    const parent = findTreeNode(path);
+    if (!parent || parent.tagName !== "DETAILS") return;
+    expandLazyTreeNode(parent);
+    path += `/${token}`;
+    while (!findTreeNode(path)) {
+      const more = parent.querySelector(":scope > .tree-more");
+      if (!more) return;
+      loadMoreTreeChildren(more);
+    }
+  }
+}
+
+function findTreeNode(pointer) {
+  return [...treeView.querySelectorAll("[data-pointer]")].find(
+    (node) => node.dataset.pointer === pointer,
+  );
+}
+
+function addBookmark(pointer) {
+  if (!bookmarks.includes(pointer)) {
+    bookmarks.push(pointer);
+    saveBookmarks();
+  }
+  renderBookmarks();
+  bookmarkSelect.value = pointer;
+  showStatus(t("status.bookmarked", { pointer: pointer || "/" }));
+}
+
+function handleRemoveBookmark() {
+  bookmarks = bookmarks.filter((pointer) => pointer !== bookmarkSelect.value);
+  saveBookmarks();
+  renderBookmarks();
+}
+
+// Expand the tree down to a node and scroll it into view
+function revealTreeNode(pointer) {
+  materializeTreePath(pointer);
+  const node = findTreeNode(pointer);
+  if (!node) {
+    showStatus(t("status.pointerMissing", { pointer: pointer || "/" }), true);
+    return null;
+  }
+
+  for (let parent = node; parent; parent = parent.parentElement) {
+    if (parent.tagName === "DETAILS") {
+      parent.open = true;
+    }
+  }
+  node.scrollIntoView({ block: "center" });
+  return node;
+}
+
+function jumpToBookmark(pointer) {
+  if (revealTreeNode(pointer)) bookmarkSelect.value = pointer;
+}
+
+function pointerTokens(pointer) {
+  return pointer
+    .split("/")
+    .slice(1)
+    .map((token) => token.replace(/~1/g, "/").replace(/~0/g, "~"));
+}
+
+function renderPathBreadcrumb() {
+  pathBar.classList.toggle("hidden", !selectedPath);
+  for (const node of treeView.querySelectorAll(".tree-selected")) {
+    node.classList.remove("tree-selected");
+  }
+  if (!selectedPath) return;
+
+  let pointer = "";
+  const crumbs = [`<button data-crumb="" title="$">$</button>`];
+  for (const token of pointerTokens(selectedPath.pointer)) {
+    pointer += `/${escapePointerToken(token)}`;
+    const label = /^\d+$/.test(token) ? `[${token}]` : token;
+    crumbs.push(
+      `<button data-crumb="${escapeHtml(pointer)}" title="${escapeHtml(pointer)}">${escapeHtml(label)}</button>`,
+    );
+  }
+  pathBreadcrumb.innerHTML = crumbs.join(
+    '<span class="path-separator">›</span>',
+  );
+  if (isTreeView) {
+    findTreeNode(selectedPath.pointer)?.classList.add("tree-selected");
+  }
+}
+
+async function selectPath(pointer, jsonPath) {
+  try {
+    selectedPath = {
+      pointer,
+      jsonPath: jsonPath ?? (await invoke("pointer_to_json_path", { pointer })),
+    };
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+    return;
+  }
+  renderPathBreadcrumb();
+}
+
+function clearSelectedPath() {
+  selectedPath = null;
+  renderPathBreadcrumb();
+}
+
+function handleTreePathClick(event) {
+  if (event.target.closest(".tree-more, input, textarea")) return;
+  const node = event.target.closest("[data-pointer]");
+  if (node) selectPath(node.dataset.pointer);
+}
+
+// Text output: map the click to a line and column and ask Rust which node is there
+async function locateOutputNode(line, column) {
+  try {
+    const node = await invoke("locate_node", {
+      input: lastConverterOutput.replace(/^\uFEFF/, ""),
+      line,
+      column,
+    });
+    if (node) selectPath(node.pointer, node.jsonPath);
+  } catch {
+    // Not JSON output (e.g. generated code), so there is no path to show
+  }
+}
+
+function handleOutputTextPathClick() {
+  const before = outputText.value.slice(0, outputText.selectionStart);
+  const lineStart = before.lastIndexOf("\n") + 1;
+  locateOutputNode(before.split("\n").length, before.length - lineStart + 1);
+}
+
+function handleOutputViewerPathClick(event) {
+  const rect = outputViewer.getBoundingClientRect();
+  const offset = event.clientY - rect.top + outputViewer.scrollTop - 12;
+  locateOutputNode(Math.floor(offset / VIRTUAL_LINE_HEIGHT) + 1, 1);
+}
+
+function handlePathCrumbClick(event) {
+  const crumb = event.target.closest("[data-crumb]");
+  if (!crumb) return;
+  const pointer = crumb.dataset.crumb;
+  selectPath(pointer);
+  if (isTreeView) revealTreeNode(pointer);
+}
+
+async function handleCopyPathSubtree() {
+  try {
+    await copyText(
+      await invoke("pointer_get", {
+        input: lastConverterOutput.replace(/^\uFEFF/, ""),
+        pointer: selectedPath.pointer,
+      }),
+      "Subtree",
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function handleStepBookmark(step) {
+  if (!bookmarks.length) return;
+  const current = bookmarks.indexOf(bookmarkSelect.value);
+  const next = (current + step + bookmarks.length) % bookmarks.length;
+  jumpToBookmark(bookmarks[next]);
+}
+
+function setTreeNodesOpen(open) {
+  for (const node of treeView.querySelectorAll("details")) {
+    node.open = open;
+  }
+}
+
+function setTreeViewVisible(visible) {
+  isTreeView = visible;
+  treeView.classList.toggle("hidden", !visible);
+  expandTreeBtn.classList.toggle("hidden", !visible);
+  collapseTreeBtn.classList.toggle("hidden", !visible);
+  bookmarkBar.classList.toggle("hidden", !visible || !bookmarks.length);
+  schemaBar.classList.toggle("hidden", !visible);
+  updateOutputPanes();
+  treeViewBtn.textContent = visible ? "📝 Text" : "🌳 Tree";
+  if (findTarget) runFind(false);
+}
+
+function handleToggleTreeView() {
+  if (isTreeView) {
+    setTreeViewVisible(false);
+    return;
+  }
+
+  if (!lastConverterOutput) {
+    showStatus(t("status.treeNeedsOutput"), true);
+    return;
+  }
+
+  if (renderTreeView()) {
+    setTableViewVisible(false);
+    setTreeViewVisible(true);
+  }
+}
+
+// Rendering more rows than this makes the table sluggish; export still covers all rows
+const TABLE_RENDER_LIMIT = 5000;
+
+function setTableViewVisible(visible) {
+  if (visible) setTreeViewVisible(false);
+  isTableView = visible;
+  tableView.classList.toggle("hidden", !visible);
+  updateOutputPanes();
+  tableViewBtn.textContent = visible ? "📝 Text" : "▦ Table";
+}
+
+function loadTableRows() {
+  let value;
+  try {
+    value = JSON.parse(lastConverterOutput.replace(/^\uFEFF/, ""));
+  } catch {
+    return false;
+  }
+  const isRow = (item) =>
+    item !== null && typeof item === "object" && !Array.isArray(item);
+  if (!Array.isArray(value) || !value.length || !value.every(isRow)) {
+    return false;
+  }
+  tableRows = value;
+  tableColumns = [...new Set(value.flatMap((row) => Object.keys(row)))];
+  if (!tableColumns.includes(tableSort.column)) {
+    tableSort = { column: null, descending: false };
+  }
+  return true;
+}
+
+function compareTableCells(a, b) {
+  // Missing and null cells always sort last
+  if (a == null) return b == null ? 0 : 1;
+  if (b == null) return -1;
+  if (typeof a === "number" && typeof b === "number") return a - b;
+  return tableCellText(a).localeCompare(tableCellText(b), undefined, {
+    numeric: true,
+  });
+}
+
+function tableCellText(value) {
+  if (value === undefined || value === null) return "";
+  return typeof value === "object" ? JSON.stringify(value) : String(value);
+}
+
+function sortedTableRows() {
+  const { column, descending } = tableSort;
+  if (column === null) return tableRows;
+  const sorted = [...tableRows].sort((a, b) =>
+    compareTableCells(a[column], b[column]),
+  );
+  // Reverse the non-null part only, so nulls stay at the bottom
+  if (descending) {
+    const filled = sorted.filter((row) => row[column] != null).reverse();
+    return filled.concat(sorted.filter((row) => row[column] == null));
+  }
+  return sorted;
+}
+
+function renderTableView() {
+  const rows = sortedTableRows();
+  const header = tableColumns
+    .map((column) => {
+      const arrow =
+        tableSort.column === column ? (tableSort.descending ? " ▼" : " ▲") : "";
+      return `<th data-column="${escapeHtml(column)}">${escapeHtml(column)}${arrow}</th>`;
+    })
+    .join("");
+  const body = rows
+    .slice(0, TABLE_RENDER_LIMIT)
+    .map((row) => {
+      const cells = tableColumns
+        .map((column) => {
+          const value = row[column];
+          const kind =
+            value == null
+              ? "table-null"
+              : typeof value === "number"
+                ? "table-number"
+                : "";
+          const text = value === null ? "null" : tableCellText(value);
+          return `<td class="${kind}">${escapeHtml(text)}</td>`;
+        })
+        .join("");
+      return `<tr>${cells}</tr>`;
+    })
+    .join("");
+  dataTable.innerHTML = `<thead><tr>${header}</tr></thead><tbody>${body}</tbody>`;
+
+  const shown =
+    rows.length > TABLE_RENDER_LIMIT
+      ? ` (showing first ${TABLE_RENDER_LIMIT})`
+      : "";
+  tableSummary.textContent = `${rows.length} row${rows.length === 1 ? "" : "s"} × ${tableColumns.length} column${tableColumns.length === 1 ? "" : "s"}${shown}`;
+}
+
+function handleToggleTableView() {
+  if (isTableView) {
+    setTableViewVisible(false);
+    return;
+  }
+  if (!loadTableRows()) {
+    showStatus(t("status.tableNeedsArray"), true);
+    return;
+  }
+  renderTableView();
+  setTableViewVisible(true);
+}
+
+function handleTableHeaderClick(event) {
+  const th = event.target.closest("th[data-column]");
+  if (!th) return;
+  const column = th.dataset.column;
+  tableSort =
+    tableSort.column === column
+      ? { column, descending: !tableSort.descending }
+      : { column, descending: false };
+  renderTableView();
+}
+
+async function handleExportCsv() {
+  try {
+    const csv = await invokeTimed("json_to_csv", {
+      input: JSON.stringify(sortedTableRows()),
+      columns: tableColumns,
+      outputOptions: getOutputOptions(),
+    });
+    const link = document.createElement("a");
+    link.href = URL.createObjectURL(new Blob([csv], { type: "text/csv" }));
+    link.download = `${documents[activeDocument].name.replace(/\.json$/i, "")}.csv`;
+    link.click();
+    URL.revokeObjectURL(link.href);
+    showStatus(t("status.csvExported", { count: tableRows.length }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function commitTreeEdit(pointer, original, text) {
+  // Strings stay strings unless the user clearly typed another JSON value
+  let value = JSON.stringify(text);
+  if (typeof original !== "string") {
+    try {
+      JSON.parse(text);
+      value = text;
+    } catch {
+      value = JSON.stringify(text);
+    }
+  }
+
+  try {
+    const result = await invoke("pointer_set", {
+      input: lastConverterOutput.replace(/^\uFEFF/, ""),
+      pointer,
+      value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.updated", { pointer: pointer || "/" }));
+  } catch (error) {
+    renderTreeView();
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function copyText(text, label) {
+  try {
+    if (navigator.clipboard && navigator.clipboard.writeText) {
+      await navigator.clipboard.writeText(text);
+    } else {
+      await invoke("plugin:clipboard-manager|write_text", { text });
+    }
+    showStatus(t("status.copied", { label }));
+  } catch (error) {
+    showStatus(t("status.copyFailed", { error }), true);
+  }
+}
+
+function hideTreeContextMenu() {
+  treeContextMenu.classList.add("hidden");
+  contextMenuPointer = null;
+}
+
+function handleTreeContextMenu(event) {
+  const node = event.target.closest("[data-pointer]");
+  if (!node) return;
+
+  event.preventDefault();
+  contextMenuPointer = node.dataset.pointer;
+  treeContextMenu
+    .querySelector('[data-action="duplicate"]')
+    .classList.toggle("hidden", !("arrayItem" in node.dataset));
+  treeContextMenu
+    .querySelector('[data-action="delete"]')
+    .classList.toggle("hidden", contextMenuPointer === "");
+  treeContextMenu.style.left = `${event.clientX}px`;
+  treeContextMenu.style.top = `${event.clientY}px`;
+  treeContextMenu.classList.remove("hidden");
+}
+
+async function handleTreeNodeAction(event) {
+  const button = event.target.closest("[data-action]");
+  if (!button || contextMenuPointer === null) return;
+
+  const pointer = contextMenuPointer;
+  const input = lastConverterOutput.replace(/^\uFEFF/, "");
+  hideTreeContextMenu();
+
+  try {
+    switch (button.dataset.action) {
+      case "copyJsonPath":
+        await copyText(
+          await invoke("pointer_to_json_path", { pointer }),
+          "JSONPath",
+        );
+        break;
+      case "bookmark":
+        addBookmark(pointer);
+        break;
+      case "copyPointer":
+        await copyText(pointer, "JSON Pointer");
+        break;
+      case "copySubtree":
+        await copyText(
+          await invoke("pointer_get", { input, pointer }),
+          "Subtree",
+        );
+        break;
+      case "duplicate":
+        setConverterOutput(
+          await invoke("pointer_duplicate", {
+            input,
+            pointer,
+            outputOptions: getOutputOptions(),
+          }),
+        );
+        showStatus(t("status.duplicated", { pointer }));
+        break;
+      case "delete":
+        setConverterOutput(
+          await invoke("pointer_delete", {
+            input,
+            pointer,
+            outputOptions: getOutputOptions(),
+          }),
+        );
+        showStatus(t("status.deleted", { pointer }));
+        break;
+    }
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function handleTreeValueEdit(event) {
+  const valueSpan = event.target.closest(".tree-value");
+  if (!valueSpan) return;
+
+  const pointer = valueSpan.parentElement.dataset.pointer;
+  const original = JSON.parse(valueSpan.textContent);
+  const input = document.createElement("input");
+  input.className = "tree-value-input";
+  input.value =
+    typeof original === "string" ? original : valueSpan.textContent;
+  valueSpan.replaceWith(input);
+  input.focus();
+  input.select();
+
+  let done = false;
+  const finish = (commit) => {
+    if (done) return;
+    done = true;
+    if (commit && input.value !== String(original)) {
+      commitTreeEdit(pointer, original, input.value);
+    } else {
+      input.replaceWith(valueSpan);
+    }
+  };
+
+  input.addEventListener("keydown", (e) => {
+    if (e.key === "Enter") {
+      e.preventDefault();
+      finish(true);
+    } else if (e.key === "Escape") {
+      e.preventDefault();
+      finish(false);
+    }
+  });
+  input.addEventListener("blur", () => finish(false));
+}
+
+function renderSplitPane(pane, text, changedLines) {
+  const changed = new Set(changedLines);
+  pane.innerHTML = text
+    .split("\n")
+    .map((line, index) => {
+      const className = changed.has(index + 1)
+        ? "split-line split-changed"
+        : "split-line";
+      return `<div class="${className}">${escapeHtml(line || " ")}</div>`;
+    })
+    .join("");
+}
+
+// Converter pane layout: side by side or stacked, and the input's share of the space
+const PANE_SHARE_MIN = 0.15;
+let paneLayout = { stacked: false, share: 0.5 };
+
+function setPaneLayout(changes) {
+  paneLayout = { ...paneLayout, ...changes };
+  converterEditors.classList.toggle("stacked", paneLayout.stacked);
+  converterEditors.style.setProperty("--input-share", `${paneLayout.share}fr`);
+  converterEditors.style.setProperty(
+    "--output-share",
+    `${1 - paneLayout.share}fr`,
+  );
+  paneLayoutBtn.textContent = paneLayout.stacked
+    ? "⇆ Side by Side"
+    : "⇅ Stack Panes";
+  localStorage.setItem("paneLayout", JSON.stringify(paneLayout));
+  syncHighlightLayers();
+}
+
+function loadPaneLayout() {
+  try {
+    setPaneLayout(JSON.parse(localStorage.getItem("paneLayout")) || {});
+  } catch {
+    setPaneLayout({});
+  }
+}
+
+function handleSplitterDragStart(event) {
+  event.preventDefault();
+  paneSplitter.setPointerCapture(event.pointerId);
+  paneSplitter.classList.add("dragging");
+
+  const onMove = (moveEvent) => {
+    const rect = converterEditors.getBoundingClientRect();
+    const share = paneLayout.stacked
+      ? (moveEvent.clientY - rect.top) / rect.height
+      : (moveEvent.clientX - rect.left) / rect.width;
+    setPaneLayout({
+      share: Math.min(Math.max(share, PANE_SHARE_MIN), 1 - PANE_SHARE_MIN),
+    });
+  };
+  const onUp = () => {
+    paneSplitter.classList.remove("dragging");
+    paneSplitter.removeEventListener("pointermove", onMove);
+    paneSplitter.removeEventListener("pointerup", onUp);
+  };
+  paneSplitter.addEventListener("pointermove", onMove);
+  paneSplitter.addEventListener("pointerup", onUp);
+}
+
+function handleUseOutputAsInput() {
+  if (!lastConverterOutput) {
+    showStatus(t("status.noOutput"), true);
+    return;
+  }
+  setInputText(lastConverterOutput.replace(/^\uFEFF/, ""), "useOutput");
+  showStatus(t("status.outputMoved"));
+}
+
+function setSplitViewVisible(visible) {
+  isSplitView = visible;
+  splitView.classList.toggle("hidden", !visible);
+  converterEditors.classList.toggle("hidden", visible);
+  splitViewBtn.textContent = visible ? "Editor View" : "Split View";
+}
+
+async function refreshSplitView() {
+  // Strip an optional BOM so the parser sees the same document the user does
+  const transformed = lastConverterOutput.replace(/^\uFEFF/, "");
+  try {
+    const result = await invoke("compare_transform", {
+      original: inputText.value,
+      transformed,
+    });
+    splitLineMap = result.lineMap;
+    renderSplitPane(
+      splitOriginal,
+      inputText.value,
+      result.originalChangedLines,
+    );
+    renderSplitPane(
+      splitTransformed,
+      transformed,
+      result.transformedChangedLines,
+    );
+    splitSummary.textContent = result.changedPointers.length
+      ? `${result.changedPointers.length} changed node(s)`
+      : "No value changes";
+    return true;
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+    return false;
+  }
+}
+
+async function handleToggleSplitView() {
+  if (isSplitView) {
+    setSplitViewVisible(false);
+    return;
+  }
+
+  if (!lastConverterOutput) {
+    showStatus(t("status.compareNeedsOutput"), true);
+    return;
+  }
+
+  if (await refreshSplitView()) {
+    setSplitViewVisible(true);
+  }
+}
+
+function syncSplitScroll(source, target, fromOriginal) {
+  if (isSyncingSplitScroll) return;
+
+  const firstLine = source.firstElementChild;
+  const lineHeight = firstLine ? firstLine.offsetHeight : 0;
+  if (!lineHeight) return;
+
+  const topLine = Math.floor(source.scrollTop / lineHeight) + 1;
+  let best = null;
+  for (const [originalLine, transformedLine] of splitLineMap) {
+    const from = fromOriginal ? originalLine : transformedLine;
+    const to = fromOriginal ? transformedLine : originalLine;
+    if (from <= topLine && (!best || from >= best.from)) {
+      best = { from, to };
+    }
+  }
+
+  isSyncingSplitScroll = true;
+  target.scrollTop = best ? (best.to - 1) * lineHeight : 0;
+  target.scrollLeft = source.scrollLeft;
+  requestAnimationFrame(() => {
+    isSyncingSplitScroll = false;
+  });
+}
+
+function renderDiffHtml(html) {
+  if (diffContainer) {
+    diffContainer.innerHTML = html;
+  }
+}
+
+function handleBeautifyCompare(side) {
+  try {
+    if (side === "left") {
+      compareLeft.value = normalizedJson(compareLeft.value);
+      showStatus(t("compare.leftBeautified"));
+    } else {
+      compareRight.value = normalizedJson(compareRight.value);
+      showStatus(t("compare.rightBeautified"));
+    }
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// Only one comparison runs at a time; starting another cancels the previous one
+let compareWorker = null;
+
+function handleCompare() {
+  if (compareWorker) compareWorker.terminate();
+  const worker = new Worker(new URL("./diff-worker.js", import.meta.url), {
+    type: "module",
+  });
+  compareWorker = worker;
+  const finish = () => {
+    worker.terminate();
+    if (compareWorker === worker) compareWorker = null;
+  };
+
+  worker.onmessage = (event) => {
+    const message = event.data;
+    if (message.type === "progress") {
+      showStatus(
+        t("compare.progress", { percent: Math.round(message.fraction * 100) }),
+      );
+      return;
+    }
+    finish();
+    if (message.type === "error") {
+      lastDiffText = "";
+      lastDiffHtml = "";
+      renderDiffHtml(emptyDiffHtml());
+      showStatus(t("status.error", { error: message.message }), true);
+      return;
+    }
+    compareLeft.value = message.leftFormatted;
+    compareRight.value = message.rightFormatted;
+    lastDiffHtml = buildDiffHtml(message.diff);
+    lastDiffText = serializeDiff(message.diff);
+    renderDiffHtml(lastDiffHtml);
+    showStatus(t("compare.complete"));
+  };
+  worker.onerror = (event) => {
+    finish();
+    showStatus(t("status.error", { error: event.message }), true);
+  };
+  worker.postMessage({ left: compareLeft.value, right: compareRight.value });
+}
+
+async function handleMinify() {
+  try {
+    const result = await invokeWithProgress("minify_json", {
+      input: inputText.value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.minified"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+async function handleFormat() {
+  try {
+    const result = await invokeWithProgress("format_json", {
+      input: inputText.value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.formatted"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+// The clipboard plugin reads without a permission prompt; the web API is the fallback
+async function readClipboardText() {
+  try {
+    return await invoke("plugin:clipboard-manager|read_text");
+  } catch (error) {
+    if (navigator.clipboard && navigator.clipboard.readText) {
+      return navigator.clipboard.readText();
+    }
+    throw error;
+  }
+}
+
+async function handlePasteAndFormat() {
+  let text;
+  try {
+    text = await readClipboardText();
+  } catch (error) {
+    showStatus(t("status.clipboardFailed", { error }), true);
+    return;
+  }
+  if (!text || !text.trim()) {
+    showStatus(t("status.clipboardEmpty"), true);
+    return;
+  }
+  setActiveTab("converter");
+  setInputText(text, "paste");
+  await sanitizeLossyInput();
+  await handleFormat();
+}
+
+async function handleJsonToString() {
+  try {
+    const result = await invokeTimed("json_to_string", { input: inputText.value });
+    setConverterOutput(result);
+    showStatus(t("status.toString"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+async function handleJsonToMarkdown() {
+  try {
+    const result = await invokeTimed("json_to_markdown", {
+      input: inputText.value,
+      mode: markdownModeSelect.value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    await copyText(result, "Markdown");
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+async function handleStringToJson() {
+  try {
+    const result = await invokeTimed("string_to_json", {
+      input: inputText.value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.toJson"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+function getEnumThreshold() {
+  const threshold = parseInt(enumThresholdInput.value, 10);
+  return Number.isNaN(threshold) || threshold < 0 ? 0 : threshold;
+}
+
+function getProtoOptions() {
+  return {
+    enumThreshold: getEnumThreshold(),
+    nullability: nullabilitySelect.value,
+    fieldExamples: fieldExamplesCheckbox.checked,
+    stringFormats: stringFormatsCheckbox.checked,
+    omitOneofs: !protoOneofCheckbox.checked,
+    namespace: namespaceInput.value.trim(),
+    javaPackage: javaPackageInput.value.trim(),
+    goPackage: goPackageInput.value.trim(),
+  };
+}
+
+async function handleJsonToProto() {
+  try {
+    const result = await invokeTimed("json_to_proto", {
+      input: inputText.value,
+      options: getProtoOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.protoGenerated"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+async function handleDecodeProtobuf() {
+  try {
+    const result = await invokeTimed("decode_protobuf", {
+      schema: protoSchemaInput.value,
+      payload: inputText.value,
+      messageType: protoMessageTypeInput.value.trim() || null,
+      format: protoPayloadFormatSelect.value || null,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.protobufDecoded"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+async function handleGrpcService() {
+  try {
+    const result = await invokeTimed("json_to_grpc_service", {
+      request: inputText.value,
+      response: grpcResponseInput.value,
+      method: grpcMethodInput.value.trim(),
+      service: grpcServiceInput.value.trim() || null,
+      options: getProtoOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.grpcGenerated"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+async function handleProtoToJson() {
+  try {
+    const result = await invokeTimed("proto_to_json", {
+      input: inputText.value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.protoConverted"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+function getClassOptions() {
+  return {
+    fieldPrefix: fieldPrefixCheckbox.checked,
+    pythonStyle: pythonStyleSelect.value,
+    csharpSerializer: csharpSerializerSelect.value,
+    csharpRecords: csharpRecordsCheckbox.checked,
+    kotlinSerialization: kotlinSerializationSelect.value,
+    javaStyle: javaStyleSelect.value,
+    javaAnnotations: javaAnnotationsSelect.value,
+    stringFormats: stringFormatsCheckbox.checked,
+    enumThreshold: getEnumThreshold(),
+    namingConvention: namingConventionSelect.value,
+    nullability: nullabilitySelect.value,
+    immutable: immutableCheckbox.checked,
+    omitAnnotations: omitAnnotationsCheckbox.checked,
+    fieldExamples: fieldExamplesCheckbox.checked,
+    rootArrayName: rootArrayNameInput.value.trim(),
+    namespace: namespaceInput.value.trim(),
+  };
+}
+
+async function handleJsonToClass() {
+  try {
+    const languageSelected = language.value;
+    const className = classNameInput.value.trim() || "Root";
+
+    const result = await invokeTimed("json_to_class", {
+      input: inputText.value,
+      language: languageSelected,
+      name: className, // Use snake_case to match Rust parameter
+      options: getClassOptions(),
+    });
+
+    setConverterOutput(result);
+    showStatus(t("status.classGenerated", { language: languageSelected }));
+  } catch (error) {
+    console.timeEnd("Conversion Time");
+    console.error("❌ Conversion Failed:", error);
+    console.groupEnd();
+
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+async function handleSaveAllFiles() {
+  try {
+    const files = await invokeTimed("json_to_class_files", {
+      input: inputText.value,
+      language: language.value,
+      name: classNameInput.value.trim() || "Root",
+      options: getClassOptions(),
+    });
+    const directory = saveDirectoryInput.value.trim();
+    const written = await invoke("save_generated_files", { directory, files });
+    showStatus(t("status.filesSaved", { count: written.length, directory }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleGenerateSample() {
+  try {
+    const seed = parseInt(sampleSeedInput.value, 10);
+    const count = parseInt(sampleCountInput.value, 10);
+    const result = await invokeTimed("generate_sample_json", {
+      input: inputText.value,
+      seed: Number.isNaN(seed) ? null : seed,
+      count: Number.isNaN(count) ? null : count,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.sampleGenerated"));
+  } catch (error) {
+    setConverterOutput("");
+    showInputError(error);
+  }
+}
+
+async function openCompareWindow(left, right) {
+  try {
+    await invoke("open_compare_window", { left, right });
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// The active tab on the left and the next one on the right, e.g. staging and
+// production responses opened side by side
+function handleCompareDocuments() {
+  saveActiveDocument();
+  const next = documents[(activeDocument + 1) % documents.length];
+  const right = documents.length > 1 ? next.input : "";
+  openCompareWindow(documents[activeDocument].input, right);
+}
+
+async function startCompareWindow() {
+  document.body.classList.add("compare-window");
+  setActiveTab("compare");
+  try {
+    const inputs = await invoke("take_compare_inputs", {
+      window: compareWindowLabel,
+    });
+    if (!inputs) return;
+    compareLeft.value = inputs.left;
+    compareRight.value = inputs.right;
+    if (inputs.left.trim() && inputs.right.trim()) handleCompare();
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function handleCompareClear() {
+  compareLeft.value = "";
+  compareRight.value = "";
+  lastDiffText = "";
+  lastDiffHtml = "";
+  renderDiffHtml(emptyDiffHtml());
+  statusMessage.className = "status-message hidden";
+}
+
+async function handleSqliteListTables() {
+  try {
+    const tables = await invoke("sqlite_list_tables", {
+      path: sqlitePathInput.value,
+    });
+    sqliteTableSelect.innerHTML = tables
+      .map(
+        (table) =>
+          `<option value="${escapeHtml(table)}">${escapeHtml(table)}</option>`,
+      )
+      .join("");
+    if (tables.length) {
+      handleSqliteTableSelected();
+    }
+    showStatus(t("status.tablesFound", { count: tables.length }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function handleSqliteTableSelected() {
+  const table = sqliteTableSelect.value.replace(/"/g, '""');
+  sqliteQueryInput.value = `SELECT * FROM "${table}" LIMIT 100`;
+}
+
+// `doneKey` is the status shown once the file is written
+async function handleStreamFile(command, doneKey) {
+  try {
+    const written = await invokeWithProgress(command, {
+      inputPath: streamInputPath.value,
+      outputPath: streamOutputPath.value,
+      outputOptions: getOutputOptions(),
+    });
+    showStatus(
+      t(doneKey, {
+        path: streamOutputPath.value,
+        size: formatFileSize(written),
+      }),
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleBatchRun() {
+  try {
+    const report = await invoke("batch_process", {
+      inputDir: batchInputDir.value,
+      outputDir: batchOutputDir.value,
+      operation: batchOperationSelect.value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(JSON.stringify(report, null, 2));
+    showStatus(
+      t(report.failed ? "status.batchFailed" : "status.batchDone", {
+        succeeded: report.succeeded,
+        failed: report.failed,
+        ms: Math.round(report.elapsedMs),
+      }),
+      report.failed > 0,
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+let watchedPath = null;
+
+function setWatchedPath(path) {
+  watchedPath = path;
+  watchFileBtn.textContent = path ? "Stop Watching" : "Watch File";
+  watchPathInput.disabled = Boolean(path);
+  watchOperationSelect.disabled = Boolean(path);
+}
+
+function applyFileWatchUpdate(update) {
+  if (update.error) {
+    setValidationState(false, update.error);
+    showStatus(t("status.error", { error: update.error }), true);
+    return;
+  }
+  setConverterOutput(update.output, false);
+  if (update.timing) showTiming(update.timing);
+  setValidationState(true);
+  showStatus(
+    t("status.watching", {
+      path: update.path,
+      time: new Date().toLocaleTimeString(),
+    }),
+  );
+}
+
+function handleFileWatchEvent(event) {
+  if (event.payload.path === watchedPath) applyFileWatchUpdate(event.payload);
+}
+
+async function handleWatchFile() {
+  try {
+    if (watchedPath) {
+      await invoke("unwatch_file");
+      setWatchedPath(null);
+      setValidationState(null);
+      showStatus(t("status.watchStopped"));
+      return;
+    }
+    const update = await invoke("watch_file", {
+      path: watchPathInput.value,
+      operation: watchOperationSelect.value,
+      outputOptions: getOutputOptions(),
+    });
+    setWatchedPath(update.path);
+    applyFileWatchUpdate(update);
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// "Name: value" lines; blank lines are skipped
+function parseRequestHeaders(text) {
+  const headers = {};
+  for (const line of text.split("\n")) {
+    if (!line.trim()) continue;
+    const separator = line.indexOf(":");
+    if (separator <= 0) {
+      throw new Error(`Header "${line.trim()}" needs a colon`);
+    }
+    headers[line.slice(0, separator).trim()] = line.slice(separator + 1).trim();
+  }
+  return headers;
+}
+
+async function handleFetchUrl() {
+  const fetchUrlBtn = document.getElementById("fetchUrlBtn");
+  fetchUrlBtn.disabled = true;
+  try {
+    const response = await invoke("fetch_json", {
+      url: fetchUrlInput.value,
+      headers: parseRequestHeaders(fetchHeadersInput.value),
+    });
+    setActiveTab("converter");
+    if (inputText.value.trim()) handleNewDocument();
+    const path = new URL(response.url).pathname;
+    documents[activeDocument].name =
+      path.slice(path.lastIndexOf("/") + 1) || "response";
+    setInputText(response.body, "fetch");
+    renderDocumentTabs();
+    showHttpStatus(response);
+  } catch (error) {
+    showStatus(t("status.error", { error: error.message || error }), true);
+  } finally {
+    fetchUrlBtn.disabled = false;
+  }
+}
+
+function showHttpStatus(response) {
+  const summary = [
+    response.status,
+    response.contentType || t("http.noContentType"),
+    formatFileSize(new Blob([response.body]).size),
+  ].join(" · ");
+  showStatus(
+    t(response.status < 400 ? "status.httpOk" : "status.httpError", {
+      summary,
+    }),
+    response.status >= 400,
+  );
+}
+
+// The curl command stays in its tab; the response opens next to it
+async function handleReplayCurl() {
+  try {
+    const { request, response } = await invoke("replay_curl", {
+      command: inputText.value,
+      outputOptions: getOutputOptions(),
+    });
+    handleNewDocument();
+    documents[activeDocument].name = `${request.method} response`;
+    setInputText(response.body, "replayCurl");
+    renderDocumentTabs();
+    showHttpStatus(response);
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleSqliteQuery() {
+  try {
+    const result = await invoke("sqlite_query", {
+      path: sqlitePathInput.value,
+      query: sqliteQueryInput.value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result.json);
+    showStatus(
+      result.truncated
+        ? t("status.queryTruncated", { rows: result.rows })
+        : t("status.queryConverted"),
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function loadKvProfiles() {
+  try {
+    return JSON.parse(localStorage.getItem("kvProfiles") || "[]");
+  } catch {
+    return [];
+  }
+}
+
+function kvProfileLabel(profile) {
+  const database = profile.database == null ? "" : `/${profile.database}`;
+  return `${profile.store} ${profile.endpoint || "local"}${database}`;
+}
+
+function renderKvProfiles() {
+  kvProfileSelect.innerHTML =
+    '<option value="">New profile</option>' +
+    loadKvProfiles()
+      .map(
+        (profile, index) =>
+          `<option value="${index}">${escapeHtml(kvProfileLabel(profile))}</option>`,
+      )
+      .join("");
+}
+
+function readKvProfile() {
+  const database = kvDatabaseInput.value.trim();
+  return {
+    store: kvStoreSelect.value,
+    endpoint: kvEndpointInput.value.trim(),
+    username: kvUsernameInput.value.trim() || null,
+    password: kvPasswordInput.value || null,
+    database:
+      kvStoreSelect.value === "redis" && database !== ""
+        ? Number(database)
+        : null,
+  };
+}
+
+function handleKvStoreChanged() {
+  kvEndpointInput.placeholder =
+    kvStoreSelect.value === "redis" ? "127.0.0.1:6379" : "127.0.0.1:2379";
+  kvDatabaseInput.disabled = kvStoreSelect.value !== "redis";
+}
+
+function handleKvProfileSelected() {
+  const profile = loadKvProfiles()[kvProfileSelect.value];
+  if (!profile) {
+    return;
+  }
+  kvStoreSelect.value = profile.store;
+  kvEndpointInput.value = profile.endpoint;
+  kvDatabaseInput.value = profile.database ?? "";
+  kvUsernameInput.value = profile.username || "";
+  kvPasswordInput.value = "";
+  handleKvStoreChanged();
+}
+
+function handleKvSaveProfile() {
+  // Passwords are never written to local storage
+  const { password, ...profile } = readKvProfile();
+  const label = kvProfileLabel(profile);
+  const profiles = loadKvProfiles().filter(
+    (saved) => kvProfileLabel(saved) !== label,
+  );
+  profiles.push(profile);
+  localStorage.setItem("kvProfiles", JSON.stringify(profiles));
+  renderKvProfiles();
+  kvProfileSelect.value = String(profiles.length - 1);
+  showStatus(t("status.profileSaved", { label }));
+}
+
+async function handleKvFetch() {
+  try {
+    const result = await invoke("fetch_key_value", {
+      profile: readKvProfile(),
+      key: kvKeyInput.value,
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(result);
+    showStatus(t("status.keyFetched", { key: kvKeyInput.value }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function parseEnvVars(text) {
+  if (!text.trim()) {
+    return null;
+  }
+  const variables = {};
+  for (const entry of text.split(";")) {
+    const separator = entry.indexOf("=");
+    if (separator > 0) {
+      variables[entry.slice(0, separator).trim()] = entry.slice(separator + 1);
+    }
+  }
+  return variables;
+}
+
+async function handleRenderEnv() {
+  try {
+    const rendered = await invoke("render_env", {
+      input: inputText.value,
+      variables: parseEnvVars(envVarsInput.value),
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(rendered.output);
+    if (rendered.missing.length) {
+      showStatus(
+        t("status.missingVariables", {
+          names: rendered.missing.join(", "),
+          count: rendered.substituted,
+        }),
+        true,
+      );
+    } else {
+      showStatus(t("status.substituted", { count: rendered.substituted }));
+    }
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleResolveConfig() {
+  try {
+    const resolved = await invoke("resolve_config", {
+      layers: [
+        { name: "base", content: inputText.value },
+        { name: "environment", content: configEnvironmentInput.value },
+        { name: "local", content: configLocalInput.value },
+      ],
+      outputOptions: getOutputOptions(),
+    });
+    setConverterOutput(resolved.output);
+    configOriginsBody.innerHTML = resolved.origins
+      .map(
+        (origin) => `<tr>
+          <td>${escapeHtml(origin.pointer || "/")}</td>
+          <td>${escapeHtml(origin.layer)}</td>
+        </tr>`,
+      )
+      .join("");
+    configOrigins.classList.remove("hidden");
+    showStatus(t("status.configResolved", { count: resolved.origins.length }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleDependencyReport() {
+  try {
+    const report = await invoke("dependency_report", {
+      input: inputText.value,
+    });
+    setConverterOutput(report.report);
+    showStatus(
+      t("status.dependencies", {
+        manifest: report.manifest,
+        count: report.dependencies.length,
+        duplicates: report.duplicates.length,
+      }),
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function renderAnalysis(analyses) {
+  const list = (items, className) =>
+    items.length
+      ? `<ul class="${className}">${items
+          .map((item) => `<li>${escapeHtml(item)}</li>`)
+          .join("")}</ul>`
+      : "";
+  analysisPanel.innerHTML = analyses
+    .map(
+      (analysis) => `<div class="analysis-card">
+        <strong>${escapeHtml(analysis.label)}</strong>
+        ${list(analysis.summary, "analysis-summary")}
+        ${list(analysis.issues, "analysis-issues")}
+        ${analysis.actions
+          .map(
+            (action) =>
+              `<button class="secondary" data-action="${action.id}" data-pointer="${escapeHtml(action.pointer || "")}">${escapeHtml(action.label)}</button>`,
+          )
+          .join(" ")}
+      </div>`,
+    )
+    .join("");
+  analysisPanel.classList.toggle("hidden", analyses.length === 0);
+}
+
+async function handleAnalyze() {
+  try {
+    const analyses = await invoke("analyze_document", {
+      input: inputText.value,
+    });
+    renderAnalysis(analyses);
+    if (!analyses.length) {
+      showStatus(t("status.noDocumentType"));
+      return;
+    }
+    const issues = analyses.reduce((n, a) => n + a.issues.length, 0);
+    showStatus(
+      t("status.documentsDetected", {
+        labels: analyses.map((a) => a.label).join(", "),
+        count: issues,
+      }),
+      issues > 0,
+    );
+  } catch (error) {
+    analysisPanel.classList.add("hidden");
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+// Lint issues reuse the analysis panel; each pointer button shows its subtree
+function renderLintReport(report) {
+  analysisPanel.innerHTML = `<div class="analysis-card">
+      <strong>${t("lint.title")}</strong>
+      <ul class="analysis-issues">${report.issues
+        .map(
+          (issue) =>
+            `<li><button class="secondary" data-action="showPointer" data-pointer="${escapeHtml(issue.pointer)}">${escapeHtml(issue.pointer || "/")}</button> ${t(`lint.${issue.rule}`)}: ${escapeHtml(issue.message)} <span class="lint-suggestion">${escapeHtml(issue.suggestion)}</span></li>`,
+        )
+        .join("")}</ul>
+      ${report.truncated ? `<p>${t("lint.truncated", { count: report.issues.length })}</p>` : ""}
+    </div>`;
+  analysisPanel.classList.toggle("hidden", report.issues.length === 0);
+}
+
+async function handleLint() {
+  try {
+    const report = await invoke("lint_json", { input: inputText.value });
+    renderLintReport(report);
+    if (!report.issues.length) {
+      showStatus(t("status.lintClean"));
+      return;
+    }
+    showStatus(
+      t("status.lintIssues", {
+        count: `${report.issues.length}${report.truncated ? "+" : ""}`,
+      }),
+      true,
+    );
+  } catch (error) {
+    analysisPanel.classList.add("hidden");
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+function renderConformanceReport(report) {
+  analysisPanel.innerHTML = `<div class="analysis-card">
+      <strong>${t(report.conforming ? "conformance.pass" : "conformance.fail")}</strong>
+      <ul class="analysis-summary">${report.checks
+        .map(
+          (check) =>
+            `<li>${check.violations ? "✗" : "✓"} ${t(`conformance.${check.rule}`)}${check.violations ? ` (${check.violations})` : ""}</li>`,
+        )
+        .join("")}</ul>
+      <ul class="analysis-issues">${report.findings
+        .map(
+          (finding) =>
+            `<li><button class="secondary" data-action="showPointer" data-pointer="${escapeHtml(finding.pointer)}">${escapeHtml(finding.pointer || "/")}</button> ${finding.line}:${finding.column} ${escapeHtml(finding.message)}</li>`,
+        )
+        .join("")}</ul>
+      ${report.truncated ? `<p>${t("conformance.truncated", { count: report.findings.length })}</p>` : ""}
+    </div>`;
+  analysisPanel.classList.remove("hidden");
+}
+
+async function handleValidateStrict() {
+  try {
+    const report = await invoke("validate_strict", { input: inputText.value });
+    renderConformanceReport(report);
+    const violations = report.checks.reduce((n, c) => n + c.violations, 0);
+    showStatus(
+      report.conforming
+        ? t("status.conforming")
+        : t("status.conformanceIssues", { count: violations }),
+      !report.conforming,
+    );
+  } catch (error) {
+    analysisPanel.classList.add("hidden");
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleAnalysisAction(event) {
+  const button = event.target.closest("button[data-action]");
+  if (!button) return;
+  try {
+    switch (button.dataset.action) {
+      case "showPointer":
+        setConverterOutput(
+          await invoke("pointer_get", {
+            input: inputText.value,
+            pointer: button.dataset.pointer,
+          }),
+        );
+        break;
+      case "dependencyReport":
+        await handleDependencyReport();
+        break;
+      case "listFlags":
+        await handleListFlags();
+        break;
+    }
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleListFlags() {
+  try {
+    const flags = await invoke("list_feature_flags", {
+      input: inputText.value,
+    });
+    flagSelect.innerHTML = flags
+      .map(
+        (flag) =>
+          `<option value="${escapeHtml(flag.key)}">${escapeHtml(flag.enabled ? flag.key : t("flags.off", { key: flag.key }))}</option>`,
+      )
+      .join("");
+    setConverterOutput(JSON.stringify(flags, null, 2));
+    showStatus(t("status.flagsFound", { count: flags.length }));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
+async function handleEvaluateFlag() {
+  try {
+    const evaluation = await invoke("evaluate_feature_flag", {
+      input: inputText.value,
+      flagKey: flagSelect.value,
+      context: flagContextInput.value,
+    });
+    setConverterOutput(JSON.stringify(evaluation, null, 2));
+    showStatus(
+      t("status.flagEvaluated", {
+        key: evaluation.flagKey,
+        value: JSON.stringify(evaluation.value),
+        reason: evaluation.reason,
+      }),
+    );
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
 }
 
 function handleClear() {
+  recordHistory("clear");
   inputText.value = "";
-  outputText.value = "";
+  handleInputEdited();
+  hideBinaryNotice();
+  setSplitViewVisible(false);
+  setTableViewVisible(false);
+  setTreeViewVisible(false);
+  setConverterOutput("", false);
   classNameInput.value = "";
   handleCompareClear();
   handleClearOpenssl();
@@ -434,18 +4353,17 @@ async function handleOpensslDetail() {
     });
     opensslOutput.value = result;
     const certCount = (opensslInput.value.match(/-----BEGIN CERTIFICATE-----/g) || []).length;
-    const label = certCount > 1 ? `${certCount} certificates` : "certificate";
-    showStatus(`✓ Certificate detail generated successfully (${label || "1 certificate"})`);
+    showStatus(t("openssl.generated", { count: Math.max(certCount, 1) }));
   } catch (error) {
     opensslOutput.value = "";
-    showStatus(`Error: ${error}`, true);
+    showStatus(t("status.error", { error }), true);
   }
 }
 
 async function handleOpensslDetailFromUrl() {
   const urlInput = opensslUrlInput.value.trim();
   if (!urlInput) {
-    showStatus("Please enter a URL to check SSL detail", true);
+    showStatus(t("openssl.urlRequired"), true);
     return;
   }
 
@@ -457,12 +4375,14 @@ async function handleOpensslDetailFromUrl() {
     opensslInput.value = result.pem;
     opensslOutput.value = result.detail;
     showStatus(
-      `✓ SSL detail fetched from URL successfully (${opensslChainMode.value} mode)`
+      t("openssl.fetched", {
+        mode: opensslChainMode.selectedOptions[0].textContent,
+      }),
     );
   } catch (error) {
     opensslInput.value = "";
     opensslOutput.value = "";
-    showStatus(`Error: ${error}`, true);
+    showStatus(t("status.error", { error }), true);
   }
 }
 
@@ -480,13 +4400,15 @@ function setTracerouteLoadingState(isLoading) {
   runTracerouteBtn.disabled = isLoading;
   clearTracerouteBtn.disabled = isLoading;
   copyTracerouteOutputBtn.disabled = isLoading || !tracerouteOutput.value;
-  runTracerouteBtn.textContent = isLoading ? "Running..." : "Run Traceroute";
+  runTracerouteBtn.textContent = t(
+    isLoading ? "traceroute.running" : "traceroute.run",
+  );
 }
 
 async function handleTraceroute() {
   const urlInput = tracerouteUrlInput.value.trim();
   if (!urlInput) {
-    showStatus("Please enter a URL or host for traceroute", true);
+    showStatus(t("traceroute.urlRequired"), true);
     return;
   }
 
@@ -496,10 +4418,10 @@ async function handleTraceroute() {
   try {
     const result = await invoke("run_traceroute", { urlInput });
     tracerouteOutput.value = result;
-    showStatus("✓ Traceroute completed successfully");
+    showStatus(t("traceroute.completed"));
   } catch (error) {
     tracerouteOutput.value = "";
-    showStatus(`Error: ${error}`, true);
+    showStatus(t("status.error", { error }), true);
   } finally {
     setTracerouteLoadingState(false);
   }
@@ -513,131 +4435,348 @@ function handleClearTraceroute() {
 
 async function handleCopyTracerouteOutput() {
   if (!tracerouteOutput.value) return;
-  try {
-    if (navigator.clipboard && navigator.clipboard.writeText) {
-      await navigator.clipboard.writeText(tracerouteOutput.value);
-      showStatus("✓ Traceroute output copied to clipboard");
-    } else {
-      await invoke("plugin:clipboard-manager|write_text", {
-        text: tracerouteOutput.value,
+  await copyText(tracerouteOutput.value, t("traceroute.output"));
+}
+
+// Messages kept in the stream log; older ones are dropped as new ones arrive
+const STREAM_LOG_LIMIT = 2000;
+
+// The open WebSocket or EventSource, if any
+let streamSource = null;
+let streamPaused = false;
+// Entries waiting for Resume, and ones waiting to be formatted
+let streamHeld = [];
+let streamPending = [];
+let streamFlushing = false;
+let streamEntries = [];
+
+function streamEntryMatches(entry) {
+  const filter = streamFilterInput.value.trim().toLowerCase();
+  return !filter || entry.data.toLowerCase().includes(filter);
+}
+
+function streamEntryElement(entry) {
+  const element = document.createElement("div");
+  element.className = `stream-entry stream-${entry.kind}`;
+  const meta = document.createElement("div");
+  meta.className = "stream-entry-meta";
+  meta.textContent = `${entry.time.slice(11, 23)} · ${entry.event}`;
+  const body = document.createElement("pre");
+  body.textContent = entry.formatted ?? entry.data;
+  element.append(meta, body);
+  return element;
+}
+
+function updateStreamStats() {
+  const messages = streamEntries.filter((entry) => entry.kind === "message");
+  let stats = t("stream.messages", { count: messages.length });
+  if (streamPaused) {
+    stats += ` · ${t("stream.held", { count: streamHeld.length })}`;
+  }
+  streamStats.textContent = stats;
+  streamPauseBtn.textContent = streamPaused
+    ? t("stream.resume", { count: streamHeld.length })
+    : t("stream.pause");
+}
+
+function renderStreamLog() {
+  streamLog.innerHTML = "";
+  for (const entry of streamEntries) {
+    entry.element = streamEntryMatches(entry)
+      ? streamEntryElement(entry)
+      : null;
+    if (entry.element) streamLog.append(entry.element);
+  }
+  if (!streamEntries.length) {
+    streamLog.innerHTML = `<div class="stream-empty" data-i18n="stream.empty">${t("stream.empty")}</div>`;
+  }
+  streamLog.scrollTop = streamLog.scrollHeight;
+  updateStreamStats();
+}
+
+function appendStreamEntries(entries) {
+  if (!streamEntries.length) streamLog.innerHTML = "";
+  // Only follow new messages when the log is already scrolled to the bottom
+  const following =
+    streamLog.scrollHeight - streamLog.scrollTop - streamLog.clientHeight < 24;
+  for (const entry of entries) {
+    streamEntries.push(entry);
+    if (!streamEntryMatches(entry)) continue;
+    entry.element = streamEntryElement(entry);
+    streamLog.append(entry.element);
+  }
+  for (const dropped of streamEntries.splice(
+    0,
+    Math.max(streamEntries.length - STREAM_LOG_LIMIT, 0),
+  )) {
+    dropped.element?.remove();
+  }
+  if (following) streamLog.scrollTop = streamLog.scrollHeight;
+  updateStreamStats();
+}
+
+// Messages are formatted in batches and appended in the order they arrived
+async function flushStreamEntries() {
+  streamFlushing = true;
+  while (streamPending.length) {
+    const batch = streamPending;
+    streamPending = [];
+    const messages = batch.filter(
+      (entry) => entry.kind === "message" || entry.kind === "sent",
+    );
+    try {
+      const formatted = await invoke("format_stream_messages", {
+        messages: messages.map((entry) => entry.data),
+        outputOptions: getOutputOptions(),
       });
-      showStatus("✓ Traceroute output copied to clipboard");
+      messages.forEach((entry, i) => (entry.formatted = formatted[i]));
+    } catch (error) {
+      console.error("Failed to format stream messages:", error);
     }
-  } catch (error) {
-    showStatus(`Error: Failed to copy - ${error}`, true);
+    appendStreamEntries(batch);
   }
+  streamFlushing = false;
 }
 
-async function handleCopyOpensslInput() {
-  if (!opensslInput.value) return;
-  try {
-    if (navigator.clipboard && navigator.clipboard.writeText) {
-      await navigator.clipboard.writeText(opensslInput.value);
-      showStatus("✓ Certificate input copied to clipboard");
+function queueStreamEntries(entries) {
+  streamPending.push(...entries);
+  if (!streamFlushing) flushStreamEntries();
+}
+
+function logStreamEntry(kind, event, data) {
+  const entry = { time: new Date().toISOString(), kind, event, data };
+  if (streamPaused && kind === "message") {
+    streamHeld.push(entry);
+    updateStreamStats();
+  } else {
+    queueStreamEntries([entry]);
+  }
+}
+
+function setStreamConnected(connected, canSend = false) {
+  streamConnectBtn.textContent = t(
+    connected ? "stream.disconnect" : "stream.connect",
+  );
+  streamPauseBtn.disabled = !connected && !streamPaused;
+  streamUrlInput.disabled = connected;
+  streamEventsInput.disabled = connected;
+  streamSendInput.disabled = !canSend;
+  streamSendBtn.disabled = !canSend;
+}
+
+function connectWebSocket(url) {
+  const socket = new WebSocket(url);
+  socket.binaryType = "arraybuffer";
+  socket.addEventListener("open", () => {
+    logStreamEntry("status", "open", t("stream.connected", { url }));
+    setStreamConnected(true, true);
+  });
+  socket.addEventListener("message", (event) => {
+    const data =
+      typeof event.data === "string"
+        ? event.data
+        : new TextDecoder().decode(event.data);
+    logStreamEntry("message", "message", data);
+  });
+  socket.addEventListener("error", () => {
+    logStreamEntry("error", "error", t("stream.socketError"));
+  });
+  socket.addEventListener("close", (event) => {
+    const reason = event.reason ? ` ${event.reason}` : "";
+    logStreamEntry(
+      "status",
+      "close",
+      t("stream.closed", { code: `${event.code}${reason}` }),
+    );
+    // A socket closed by Disconnect may report after a new connection opened
+    if (streamSource === socket) streamSource = null;
+    if (!streamSource) setStreamConnected(false);
+  });
+  return socket;
+}
+
+function connectEventSource(url) {
+  const source = new EventSource(url);
+  const onMessage = (event) =>
+    logStreamEntry("message", event.type, event.data);
+  source.addEventListener("message", onMessage);
+  for (const name of streamEventsInput.value.split(",")) {
+    if (name.trim()) source.addEventListener(name.trim(), onMessage);
+  }
+  source.addEventListener("open", () => {
+    logStreamEntry("status", "open", t("stream.connected", { url }));
+  });
+  // EventSource reconnects by itself unless the server refused the stream
+  source.addEventListener("error", () => {
+    if (source.readyState === EventSource.CLOSED) {
+      logStreamEntry("error", "error", t("stream.serverClosed"));
+      if (streamSource === source) streamSource = null;
+      if (!streamSource) setStreamConnected(false);
     } else {
-      await invoke("plugin:clipboard-manager|write_text", {
-        text: opensslInput.value,
-      });
-      showStatus("✓ Certificate input copied to clipboard");
+      logStreamEntry("error", "error", t("stream.reconnecting"));
     }
-  } catch (error) {
-    showStatus(`Error: Failed to copy - ${error}`, true);
+  });
+  return source;
+}
+
+function handleStreamConnect() {
+  if (streamSource) {
+    const source = streamSource;
+    streamSource = null;
+    source.close();
+    // EventSource has no close event of its own
+    if (source instanceof EventSource) {
+      logStreamEntry("status", "close", t("stream.disconnected"));
+    }
+    setStreamConnected(false);
+    return;
+  }
+
+  const url = streamUrlInput.value.trim();
+  let protocol;
+  try {
+    protocol = new URL(url).protocol;
+  } catch {
+    showStatus(t("stream.invalidUrl"), true);
+    return;
+  }
+  if (protocol === "ws:" || protocol === "wss:") {
+    streamSource = connectWebSocket(url);
+  } else if (protocol === "http:" || protocol === "https:") {
+    streamSource = connectEventSource(url);
+  } else {
+    showStatus(t("stream.invalidUrl"), true);
+    return;
   }
+  setStreamConnected(true);
 }
 
-async function handleCopyOpensslOutput() {
-  if (!opensslOutput.value) return;
+function handleStreamSend() {
+  const text = streamSendInput.value;
+  if (!text || !(streamSource instanceof WebSocket)) return;
+  streamSource.send(text);
+  logStreamEntry("sent", "sent", text);
+  streamSendInput.value = "";
+}
+
+function handleStreamPause() {
+  streamPaused = !streamPaused;
+  if (!streamPaused) {
+    queueStreamEntries(streamHeld);
+    streamHeld = [];
+  }
+  streamPauseBtn.disabled = !streamSource && !streamPaused;
+  updateStreamStats();
+}
+
+function handleStreamClear() {
+  streamEntries = [];
+  streamHeld = [];
+  renderStreamLog();
+}
+
+// Exports the received messages as a JSON array, parsing the ones that are JSON
+async function handleStreamExport() {
+  const messages = streamEntries
+    .filter((entry) => entry.kind === "message" || entry.kind === "sent")
+    .map((entry) => ({
+      time: entry.time,
+      event: entry.event,
+      data: entry.formatted == null ? entry.data : JSON.parse(entry.data),
+    }));
+  if (!messages.length) {
+    showStatus(t("stream.nothingToExport"), true);
+    return;
+  }
   try {
-    if (navigator.clipboard && navigator.clipboard.writeText) {
-      await navigator.clipboard.writeText(opensslOutput.value);
-      showStatus("✓ Certificate detail copied to clipboard");
-    } else {
-      await invoke("plugin:clipboard-manager|write_text", {
-        text: opensslOutput.value,
-      });
-      showStatus("✓ Certificate detail copied to clipboard");
-    }
+    const path = await invoke("plugin:dialog|save", {
+      options: {
+        title: t("stream.exportTitle"),
+        filters: JSON_FILE_FILTERS,
+        defaultPath: "stream-messages.json",
+      },
+    });
+    if (!path) return;
+    await invoke("save_output_file", {
+      path,
+      contents: JSON.stringify(messages, null, 2),
+    });
+    showStatus(t("stream.exported", { count: messages.length }));
   } catch (error) {
-    showStatus(`Error: Failed to copy - ${error}`, true);
+    showStatus(t("status.error", { error }), true);
   }
 }
 
+async function handleCopyOpensslInput() {
+  if (!opensslInput.value) return;
+  await copyText(opensslInput.value, t("openssl.input"));
+}
+
+async function handleCopyOpensslOutput() {
+  if (!opensslOutput.value) return;
+  await copyText(opensslOutput.value, t("openssl.output"));
+}
+
 async function handleCopyInput() {
-  if (inputText.value) {
-    try {
-      // Try using native clipboard API first
-      if (navigator.clipboard && navigator.clipboard.writeText) {
-        await navigator.clipboard.writeText(inputText.value);
-        showStatus("✓ Input copied to clipboard");
-      } else {
-        // Fallback to Tauri clipboard plugin
-        await invoke("plugin:clipboard-manager|write_text", {
-          text: inputText.value,
-        });
-        showStatus("✓ Input copied to clipboard");
-      }
-    } catch (error) {
-      showStatus(`Error: Failed to copy - ${error}`, true);
-    }
-  }
+  if (!inputText.value) return;
+  await copyText(inputText.value, t("editor.input"));
 }
 
 async function handleCopyCompare(side) {
   const value = side === "left" ? compareLeft.value : compareRight.value;
   if (!value) return;
-  try {
-    if (navigator.clipboard && navigator.clipboard.writeText) {
-      await navigator.clipboard.writeText(value);
-      showStatus(`✓ ${side === "left" ? "Left" : "Right"} input copied`);
-    } else {
-      await invoke("plugin:clipboard-manager|write_text", { text: value });
-      showStatus(`✓ ${side === "left" ? "Left" : "Right"} input copied`);
-    }
-  } catch (error) {
-    showStatus(`Error: Failed to copy - ${error}`, true);
-  }
+  await copyText(
+    value,
+    t(side === "left" ? "compare.leftInput" : "compare.rightInput"),
+  );
 }
 
 async function handleCopyDiff() {
   if (!lastDiffText) {
-    showStatus("No diff to copy", true);
+    showStatus(t("compare.noDiff"), true);
     return;
   }
-  try {
-    if (navigator.clipboard && navigator.clipboard.writeText) {
-      await navigator.clipboard.writeText(lastDiffText);
-      showStatus("✓ Diff copied to clipboard");
-    } else {
-      await invoke("plugin:clipboard-manager|write_text", {
-        text: lastDiffText,
-      });
-      showStatus("✓ Diff copied to clipboard");
-    }
-  } catch (error) {
-    showStatus(`Error: Failed to copy diff - ${error}`, true);
-  }
+  await copyText(lastDiffText, t("compare.diff"));
 }
 
 async function handleCopyOutput() {
-  if (outputText.value) {
+  const text = lastConverterOutput || outputText.value;
+  if (text) {
     try {
       // Try using native clipboard API first
       if (navigator.clipboard && navigator.clipboard.writeText) {
-        await navigator.clipboard.writeText(outputText.value);
-        showStatus("✓ Output copied to clipboard");
+        await navigator.clipboard.writeText(text);
+        showStatus(t("status.outputCopied"));
       } else {
         // Fallback to Tauri clipboard plugin
         await invoke("plugin:clipboard-manager|write_text", {
-          text: outputText.value,
+          text,
         });
-        showStatus("✓ Output copied to clipboard");
+        showStatus(t("status.outputCopied"));
       }
     } catch (error) {
-      showStatus(`Error: Failed to copy - ${error}`, true);
+      showStatus(t("status.copyFailed", { error }), true);
     }
   }
 }
 
+async function handleCopyAs(event) {
+  const target = event.target.closest("[data-copy-as]");
+  if (!target) return;
+  copyAsMenu.open = false;
+  const form = target.dataset.copyAs;
+  try {
+    const text = await invoke("copy_output_as", {
+      input: (lastConverterOutput || outputText.value).replace(/^\uFEFF/, ""),
+      form,
+      outputOptions: getOutputOptions(),
+    });
+    await copyText(text, t(`copyAs.copied.${form}`));
+  } catch (error) {
+    showStatus(t("status.error", { error }), true);
+  }
+}
+
 // Zoom functions
 function updateZoom() {
   const content = mermaidPreview.querySelector(".mermaid-preview-content");
@@ -720,7 +4859,7 @@ async function handleRenderMermaid() {
 
   if (!code) {
     mermaidPreview.innerHTML =
-      '<div class="mermaid-placeholder">Enter Mermaid code and click "Render Diagram" to preview</div>';
+      `<div class="mermaid-placeholder" data-i18n="mermaid.placeholder">${t("mermaid.placeholder")}</div>`;
     return;
   }
 
@@ -736,10 +4875,10 @@ async function handleRenderMermaid() {
 
     // Wrap SVG in a container for zoom and pan functionality
     mermaidPreview.innerHTML = `<div class="mermaid-preview-content" style="transform: scale(${currentZoom / 100}) translate(${panX}px, ${panY}px)">${svg}</div>`;
-    showStatus("✓ Diagram rendered successfully");
+    showStatus(t("mermaid.rendered"));
   } catch (error) {
-    mermaidPreview.innerHTML = `<div class="mermaid-error">Error rendering diagram:<br>${escapeHtml(error.message || String(error))}</div>`;
-    showStatus("Error rendering Mermaid diagram", true);
+    mermaidPreview.innerHTML = `<div class="mermaid-error">${t("mermaid.renderError")}<br>${escapeHtml(error.message || String(error))}</div>`;
+    showStatus(t("mermaid.renderFailed"), true);
   }
 }
 
@@ -747,7 +4886,7 @@ async function handleDownloadMermaidPng() {
   const svgElement = mermaidPreview.querySelector("svg");
 
   if (!svgElement) {
-    showStatus("No diagram to download. Please render a diagram first.", true);
+    showStatus(t("mermaid.nothingToDownload"), true);
     return;
   }
 
@@ -791,29 +4930,29 @@ async function handleDownloadMermaidPng() {
       // Cleanup
       URL.revokeObjectURL(url);
 
-      showStatus("✓ PNG downloaded successfully");
+      showStatus(t("mermaid.downloaded"));
     };
 
     img.onerror = function () {
       URL.revokeObjectURL(url);
-      showStatus("Error creating image for download", true);
+      showStatus(t("mermaid.imageFailed"), true);
     };
 
     img.src = url;
   } catch (error) {
-    showStatus(`Error downloading PNG: ${error}`, true);
+    showStatus(t("mermaid.downloadFailed", { error }), true);
   }
 }
 
 function handleClearMermaid() {
   mermaidInput.value = "";
   mermaidPreview.innerHTML =
-    '<div class="mermaid-placeholder">Enter Mermaid code and click "Render Diagram" to preview</div>';
+    `<div class="mermaid-placeholder" data-i18n="mermaid.placeholder">${t("mermaid.placeholder")}</div>`;
   currentZoom = 100;
   panX = 0;
   panY = 0;
   zoomLevelDisplay.textContent = "100%";
-  showStatus("Mermaid editor cleared!");
+  showStatus(t("mermaid.cleared"));
 }
 
 // Image Resizer Functions
@@ -824,9 +4963,12 @@ function handleSelectImage() {
 function handleImageSelected(event) {
   const file = event.target.files[0];
   if (!file) return;
+  loadImageFile(file);
+}
 
+function loadImageFile(file) {
   if (!file.type.startsWith("image/")) {
-    showStatus("Please select a valid image file", true);
+    showStatus(t("image.invalidFile"), true);
     return;
   }
 
@@ -854,12 +4996,12 @@ function handleImageSelected(event) {
       // Clear resized preview
       resizedImagePreview.classList.remove("has-image", "transparent-bg");
       resizedImagePreview.innerHTML =
-        '<div class="image-placeholder">Click "Apply Changes" to resize</div>';
+        `<div class="image-placeholder" data-i18n="image.applyHint">${t("image.applyHint")}</div>`;
       resizedImageInfo.textContent = "";
       downloadResizedBtn.disabled = true;
       resizedImageData = null;
 
-      showStatus("Image loaded successfully!");
+      showStatus(t("image.loaded"));
     };
     img.src = e.target.result;
   };
@@ -907,7 +5049,7 @@ function handleHeightChange() {
 
 function handleApplyResize() {
   if (!originalImage) {
-    showStatus("Please select an image first", true);
+    showStatus(t("image.selectFirst"), true);
     return;
   }
 
@@ -972,12 +5114,12 @@ function handleApplyResize() {
   resizedImageInfo.textContent = `${newWidth} × ${newHeight} px | ~${formatFileSize(approximateSize)}`;
 
   downloadResizedBtn.disabled = false;
-  showStatus("Image resized successfully!");
+  showStatus(t("image.resized"));
 }
 
 function handleDownloadResized() {
   if (!resizedImageData) {
-    showStatus("No resized image to download", true);
+    showStatus(t("image.nothingToDownload"), true);
     return;
   }
 
@@ -989,12 +5131,12 @@ function handleDownloadResized() {
   link.href = resizedImageData;
   link.click();
 
-  showStatus("Image downloaded!");
+  showStatus(t("image.downloaded"));
 }
 
 function handleConvertToPng() {
   if (!originalImage) {
-    showStatus("Please select an image first", true);
+    showStatus(t("image.selectFirst"), true);
     return;
   }
 
@@ -1025,27 +5167,27 @@ function handleConvertToPng() {
   outputFormat.value = "png";
 
   downloadResizedBtn.disabled = false;
-  showStatus("Image converted to PNG successfully!");
+  showStatus(t("image.convertedToPng"));
 }
 
 async function handleRemoveBackground() {
   if (!originalImage) {
-    showStatus("Please select an image first", true);
+    showStatus(t("image.selectFirst"), true);
     return;
   }
 
   if (isRemovingBackground) {
-    showStatus("Background removal already in progress...", true);
+    showStatus(t("image.removalInProgress"), true);
     return;
   }
 
   isRemovingBackground = true;
   removeBackgroundBtn.disabled = true;
-  removeBackgroundBtn.textContent = "⏳ Processing...";
+  removeBackgroundBtn.textContent = t("image.processing");
 
   resizedImagePreview.classList.remove("has-image", "transparent-bg");
   resizedImagePreview.innerHTML =
-    '<div class="image-placeholder">Removing background... This may take a moment.</div>';
+    `<div class="image-placeholder" data-i18n="image.removingBackground">${t("image.removingBackground")}</div>`;
 
   try {
     // Get tolerance from slider
@@ -1074,16 +5216,19 @@ async function handleRemoveBackground() {
     outputFormat.value = "png";
 
     downloadResizedBtn.disabled = false;
-    showStatus("Background removed successfully!");
+    showStatus(t("image.backgroundRemoved"));
   } catch (error) {
     console.error("Background removal error:", error);
     resizedImagePreview.innerHTML =
-      '<div class="image-placeholder">Failed to remove background. Please try again.</div>';
-    showStatus(`Error: ${error || "Failed to remove background"}`, true);
+      `<div class="image-placeholder" data-i18n="image.removalFailed">${t("image.removalFailed")}</div>`;
+    showStatus(
+      t("status.error", { error: error || t("image.removalFailed") }),
+      true,
+    );
   } finally {
     isRemovingBackground = false;
     removeBackgroundBtn.disabled = false;
-    removeBackgroundBtn.textContent = "✂️ Remove Background";
+    removeBackgroundBtn.textContent = t("image.removeBackground");
   }
 }
 
@@ -1094,11 +5239,11 @@ function handleClearImage() {
 
   originalImagePreview.classList.remove("has-image", "transparent-bg");
   originalImagePreview.innerHTML =
-    '<div class="image-placeholder">Click "Select Image" to load an image</div>';
+    `<div class="image-placeholder" data-i18n="image.selectHint">${t("image.selectHint")}</div>`;
 
   resizedImagePreview.classList.remove("has-image", "transparent-bg");
   resizedImagePreview.innerHTML =
-    '<div class="image-placeholder">Resized image will appear here</div>';
+    `<div class="image-placeholder" data-i18n="image.resizedPlaceholder">${t("image.resizedPlaceholder")}</div>`;
 
   originalImageInfo.textContent = "";
   resizedImageInfo.textContent = "";
@@ -1119,25 +5264,12 @@ function handleClearImage() {
 
   imageFileInput.value = "";
 
-  showStatus("Image resizer cleared!");
+  showStatus(t("image.cleared"));
 }
 
 async function handleCopyMermaid() {
-  if (mermaidInput.value) {
-    try {
-      if (navigator.clipboard && navigator.clipboard.writeText) {
-        await navigator.clipboard.writeText(mermaidInput.value);
-        showStatus("✓ Mermaid code copied to clipboard");
-      } else {
-        await invoke("plugin:clipboard-manager|write_text", {
-          text: mermaidInput.value,
-        });
-        showStatus("✓ Mermaid code copied to clipboard");
-      }
-    } catch (error) {
-      showStatus(`Error: Failed to copy - ${error}`, true);
-    }
-  }
+  if (!mermaidInput.value) return;
+  await copyText(mermaidInput.value, t("mermaid.code"));
 }
 
 // JSON to HTML Render Functions
@@ -1146,12 +5278,12 @@ function renderJsonToHtml() {
   const templateText = htmlTemplate.value.trim();
 
   if (!jsonText) {
-    showStatus("Please enter JSON data", true);
+    showStatus(t("jsonHtml.dataRequired"), true);
     return;
   }
 
   if (!templateText) {
-    showStatus("Please enter an HTML template", true);
+    showStatus(t("jsonHtml.templateRequired"), true);
     return;
   }
 
@@ -1194,10 +5326,11 @@ function renderJsonToHtml() {
 
     // Display result
     htmlResult.innerHTML = resultHtml;
-    showStatus("✓ HTML rendered successfully!");
+    showStatus(t("jsonHtml.rendered"));
   } catch (error) {
-    showStatus(`Error: ${error.message}`, true);
-    htmlResult.innerHTML = `<div class="placeholder"><span style="color: red;">Error: ${escapeHtml(error.message)}</span></div>`;
+    const message = t("status.error", { error: error.message });
+    showStatus(message, true);
+    htmlResult.innerHTML = `<div class="placeholder"><span style="color: red;">${escapeHtml(message)}</span></div>`;
   }
 }
 
@@ -1209,16 +5342,16 @@ async function handleFormatJsonHtml() {
   const jsonText = jsonHtmlInput.value.trim();
 
   if (!jsonText) {
-    showStatus("Please enter JSON data", true);
+    showStatus(t("jsonHtml.dataRequired"), true);
     return;
   }
 
   try {
-    const result = await invoke("format_json", { input: jsonText });
+    const result = await invokeTimed("format_json", { input: jsonText });
     jsonHtmlInput.value = result;
-    showStatus("✓ JSON data formatted successfully");
+    showStatus(t("jsonHtml.formatted"));
   } catch (error) {
-    showStatus(`Error: ${error}`, true);
+    showStatus(t("status.error", { error }), true);
   }
 }
 
@@ -1226,62 +5359,29 @@ function handleClearHtml() {
   jsonHtmlInput.value = "";
   htmlTemplate.value = "";
   htmlResult.innerHTML =
-    '<div class="placeholder">Result will appear here after rendering</div>';
-  showStatus("JSON to HTML cleared!");
+    `<div class="placeholder" data-i18n="jsonHtml.placeholder">${t("jsonHtml.placeholder")}</div>`;
+  showStatus(t("jsonHtml.cleared"));
 }
 
 async function handleCopyJsonHtml() {
-  if (jsonHtmlInput.value) {
-    try {
-      if (navigator.clipboard && navigator.clipboard.writeText) {
-        await navigator.clipboard.writeText(jsonHtmlInput.value);
-        showStatus("✓ JSON data copied to clipboard");
-      } else {
-        await invoke("plugin:clipboard-manager|write_text", {
-          text: jsonHtmlInput.value,
-        });
-        showStatus("✓ JSON data copied to clipboard");
-      }
-    } catch (error) {
-      showStatus(`Error: Failed to copy - ${error}`, true);
-    }
-  }
+  if (!jsonHtmlInput.value) return;
+  await copyText(jsonHtmlInput.value, t("jsonHtml.data"));
 }
 
 async function handleCopyTemplate() {
-  if (htmlTemplate.value) {
-    try {
-      if (navigator.clipboard && navigator.clipboard.writeText) {
-        await navigator.clipboard.writeText(htmlTemplate.value);
-        showStatus("✓ HTML template copied to clipboard");
-      } else {
-        await invoke("plugin:clipboard-manager|write_text", {
-          text: htmlTemplate.value,
-        });
-        showStatus("✓ HTML template copied to clipboard");
-      }
-    } catch (error) {
-      showStatus(`Error: Failed to copy - ${error}`, true);
-    }
-  }
+  if (!htmlTemplate.value) return;
+  await copyText(htmlTemplate.value, t("jsonHtml.template"));
 }
 
 async function handleCopyResultHtml() {
-  if (htmlResult.innerHTML && htmlResult.innerHTML !== '<div class="placeholder">Result will appear here after rendering</div>') {
-    try {
-      if (navigator.clipboard && navigator.clipboard.writeText) {
-        await navigator.clipboard.writeText(htmlResult.innerHTML);
-        showStatus("✓ HTML result copied to clipboard");
-      } else {
-        await invoke("plugin:clipboard-manager|write_text", {
-          text: htmlResult.innerHTML,
-        });
-        showStatus("✓ HTML result copied to clipboard");
-      }
-    } catch (error) {
-      showStatus(`Error: Failed to copy - ${error}`, true);
-    }
+  // The placeholder and render errors are not results
+  if (
+    !htmlResult.innerHTML ||
+    htmlResult.querySelector(":scope > .placeholder")
+  ) {
+    return;
   }
+  await copyText(htmlResult.innerHTML, t("jsonHtml.result"));
 }
 
 async function handleCopyHtmlResult() {
@@ -1290,13 +5390,20 @@ async function handleCopyHtmlResult() {
 
 // Event listeners
 document.getElementById("minifyBtn").addEventListener("click", handleMinify);
+cancelOperationBtn.addEventListener("click", handleCancelOperation);
 document.getElementById("formatBtn").addEventListener("click", handleFormat);
+document
+  .getElementById("pasteFormatBtn")
+  .addEventListener("click", handlePasteAndFormat);
 document
   .getElementById("jsonToStringBtn")
   .addEventListener("click", handleJsonToString);
 document
   .getElementById("stringToJsonBtn")
   .addEventListener("click", handleStringToJson);
+document
+  .getElementById("jsonToMarkdownBtn")
+  .addEventListener("click", handleJsonToMarkdown);
 document
   .getElementById("jsonToProtoBtn")
   .addEventListener("click", handleJsonToProto);
@@ -1306,7 +5413,271 @@ document
 document
   .getElementById("jsonToClassBtn")
   .addEventListener("click", handleJsonToClass);
+document
+  .getElementById("saveAllFilesBtn")
+  .addEventListener("click", handleSaveAllFiles);
+document
+  .getElementById("generateSampleBtn")
+  .addEventListener("click", handleGenerateSample);
 document.getElementById("clearBtn").addEventListener("click", handleClear);
+document
+  .getElementById("formatFileBtn")
+  .addEventListener("click", () =>
+    handleStreamFile("format_json_file", "status.fileFormatted"),
+  );
+document
+  .getElementById("minifyFileBtn")
+  .addEventListener("click", () =>
+    handleStreamFile("minify_json_file", "status.fileMinified"),
+  );
+document.getElementById("batchRunBtn").addEventListener("click", handleBatchRun);
+document
+  .getElementById("saveShortcutBtn")
+  .addEventListener("click", handleSaveShortcut);
+for (const id of OPERATION_BUTTON_IDS) {
+  document
+    .getElementById(id)
+    .addEventListener("click", handleOperationButtonClick);
+}
+watchFileBtn.addEventListener("click", handleWatchFile);
+document
+  .getElementById("fetchUrlBtn")
+  .addEventListener("click", handleFetchUrl);
+document
+  .getElementById("replayCurlBtn")
+  .addEventListener("click", handleReplayCurl);
+fetchUrlInput.addEventListener("keydown", (e) => {
+  if (e.key === "Enter") handleFetchUrl();
+});
+document
+  .getElementById("sqliteTablesBtn")
+  .addEventListener("click", handleSqliteListTables);
+document
+  .getElementById("sqliteQueryBtn")
+  .addEventListener("click", handleSqliteQuery);
+sqliteTableSelect.addEventListener("change", handleSqliteTableSelected);
+document
+  .getElementById("kvSaveProfileBtn")
+  .addEventListener("click", handleKvSaveProfile);
+document.getElementById("kvFetchBtn").addEventListener("click", handleKvFetch);
+kvProfileSelect.addEventListener("change", handleKvProfileSelected);
+kvStoreSelect.addEventListener("change", handleKvStoreChanged);
+document
+  .getElementById("renderEnvBtn")
+  .addEventListener("click", handleRenderEnv);
+document
+  .getElementById("decodeProtobufBtn")
+  .addEventListener("click", handleDecodeProtobuf);
+document
+  .getElementById("grpcServiceBtn")
+  .addEventListener("click", handleGrpcService);
+document
+  .getElementById("resolveConfigBtn")
+  .addEventListener("click", handleResolveConfig);
+document.getElementById("analyzeBtn").addEventListener("click", handleAnalyze);
+document.getElementById("lintBtn").addEventListener("click", handleLint);
+document
+  .getElementById("validateStrictBtn")
+  .addEventListener("click", handleValidateStrict);
+analysisPanel.addEventListener("click", handleAnalysisAction);
+document
+  .getElementById("dependencyReportBtn")
+  .addEventListener("click", handleDependencyReport);
+document
+  .getElementById("listFlagsBtn")
+  .addEventListener("click", handleListFlags);
+document
+  .getElementById("evaluateFlagBtn")
+  .addEventListener("click", handleEvaluateFlag);
+document.getElementById("loadFileBtn").addEventListener("click", handleOpenFile);
+document
+  .getElementById("menuOpenBtn")
+  .addEventListener("click", () => handleFileMenuAction(handleOpenFile));
+document
+  .getElementById("menuSaveBtn")
+  .addEventListener("click", () => handleFileMenuAction(handleSaveOutput));
+recentFilesMenu.addEventListener("click", handleRecentFileClick);
+snippetsPanel.addEventListener("toggle", () => {
+  if (snippetsPanel.open) refreshSnippets();
+});
+snippetSearchInput.addEventListener("input", refreshSnippets);
+snippetList.addEventListener("click", handleSnippetClick);
+document
+  .getElementById("saveSnippetBtn")
+  .addEventListener("click", handleSaveSnippet);
+inputHistoryPanel.addEventListener("toggle", () => {
+  if (inputHistoryPanel.open) loadInputHistory();
+});
+inputHistoryList.addEventListener("click", handleInputHistoryClick);
+restoreHistoryBtn.addEventListener("click", handleRestoreHistory);
+document
+  .getElementById("clearHistoryBtn")
+  .addEventListener("click", handleClearHistory);
+recentFilesStart.addEventListener("click", handleRecentFileClick);
+document.addEventListener("click", (e) => {
+  if (fileMenu.open && !fileMenu.contains(e.target)) fileMenu.open = false;
+  if (copyAsMenu.open && !copyAsMenu.contains(e.target)) {
+    copyAsMenu.open = false;
+  }
+});
+document
+  .getElementById("saveOutputBtn")
+  .addEventListener("click", handleSaveOutput);
+document
+  .getElementById("exportHtmlBtn")
+  .addEventListener("click", handleExportHtml);
+inputFile.addEventListener("change", handleInputFileSelected);
+binaryDecoders.addEventListener("click", handleBinaryDecoder);
+harEntries.addEventListener("click", handleHarEntryClick);
+harFilterInput.addEventListener("input", renderHarEntries);
+harPartSelect.addEventListener("change", renderHarEntries);
+harJsonOnlyCheckbox.addEventListener("change", renderHarEntries);
+document.getElementById("closeHarBtn").addEventListener("click", closeHar);
+inputText.addEventListener("paste", handleInputPaste);
+splitViewBtn.addEventListener("click", handleToggleSplitView);
+document
+  .getElementById("slowestOpsBtn")
+  .addEventListener("click", handleToggleTimingOverlay);
+treeViewBtn.addEventListener("click", handleToggleTreeView);
+tableViewBtn.addEventListener("click", handleToggleTableView);
+dataTable.addEventListener("click", handleTableHeaderClick);
+document
+  .getElementById("exportCsvBtn")
+  .addEventListener("click", handleExportCsv);
+treeView.addEventListener("dblclick", handleTreeValueEdit);
+inputText.addEventListener("beforeinput", handleInputBeforeEdit);
+inputText.addEventListener("input", handleInputEdited);
+convertSuggestionBtn.addEventListener("click", handleConvertSuggestion);
+document
+  .getElementById("dismissSuggestionBtn")
+  .addEventListener("click", hideFormatSuggestion);
+inputText.addEventListener("focus", () => setFindPane(inputText));
+outputText.addEventListener("focus", () => setFindPane(outputText));
+treeView.addEventListener("click", () => setFindPane(outputText));
+treeView.addEventListener("click", handleTreeShowMore);
+// toggle doesn't bubble, so listen in the capture phase
+treeView.addEventListener("toggle", handleTreeToggle, true);
+outputViewer.addEventListener("click", () => setFindPane(outputText));
+outputViewer.addEventListener("scroll", renderVirtualOutput);
+findInput.addEventListener("input", () => runFind());
+findInput.addEventListener("keydown", handleFindKeydown);
+findRegexCheckbox.addEventListener("change", () => runFind());
+findPrevBtn.addEventListener("click", () => stepFind(-1));
+findNextBtn.addEventListener("click", () => stepFind(1));
+closeFindBtn.addEventListener("click", closeFindBar);
+liveFormatCheckbox.addEventListener("change", handleLiveFormatToggle);
+formatOnOpenCheckbox.addEventListener("change", () =>
+  localStorage.setItem("formatOnOpen", formatOnOpenCheckbox.checked ? "1" : ""),
+);
+lossyInputCheckbox.addEventListener("change", () =>
+  localStorage.setItem("lossyInput", lossyInputCheckbox.checked ? "1" : ""),
+);
+documentTabs.addEventListener("click", handleDocumentTabClick);
+themeSelect.addEventListener("change", handleThemeChange);
+uiLanguageSelect.addEventListener("change", handleLanguageChange);
+for (const control of [
+  ...Object.values(CODEGEN_SETTING_CONTROLS),
+  protoOneofCheckbox,
+]) {
+  control.addEventListener("change", handleCodegenSettingChange);
+}
+document
+  .getElementById("settingsBtn")
+  .addEventListener("click", openSettingsDialog);
+document
+  .getElementById("saveSettingsBtn")
+  .addEventListener("click", handleSaveSettings);
+document
+  .getElementById("closeSettingsBtn")
+  .addEventListener("click", () => settingsDialog.close());
+document
+  .getElementById("resetSettingsBtn")
+  .addEventListener("click", handleResetSettings);
+document
+  .getElementById("exportSettingsBtn")
+  .addEventListener("click", handleExportSettings);
+document
+  .getElementById("importSettingsBtn")
+  .addEventListener("click", handleImportSettings);
+document
+  .getElementById("newIngestTokenBtn")
+  .addEventListener("click", handleNewIngestToken);
+ingestEnabledCheckbox.addEventListener("change", handleIngestEnabledChange);
+ingestPortInput.addEventListener("input", showIngestUsage);
+// Escape and Cancel drop unsaved edits
+settingsDialog.addEventListener("close", () => {
+  if (settings) showSettings(settings);
+});
+systemDarkQuery.addEventListener("change", () => applyTheme(themeSelect.value));
+inputText.addEventListener("scroll", () =>
+  syncHighlightScroll(inputText, inputHighlight),
+);
+outputText.addEventListener("scroll", () =>
+  syncHighlightScroll(outputText, outputHighlight),
+);
+window.addEventListener("resize", () => {
+  syncHighlightLayers();
+  if (isVirtualOutput) renderVirtualOutput();
+});
+paneSplitter.addEventListener("pointerdown", handleSplitterDragStart);
+paneSplitter.addEventListener("dblclick", () => setPaneLayout({ share: 0.5 }));
+paneLayoutBtn.addEventListener("click", () =>
+  setPaneLayout({ stacked: !paneLayout.stacked }),
+);
+document
+  .getElementById("useOutputBtn")
+  .addEventListener("click", handleUseOutputAsInput);
+expandTreeBtn.addEventListener("click", () => setTreeNodesOpen(true));
+// Keep the root open so collapsing shows its top-level entries
+collapseTreeBtn.addEventListener("click", () => {
+  setTreeNodesOpen(false);
+  treeView.querySelector("details")?.setAttribute("open", "");
+});
+treeView.addEventListener("contextmenu", handleTreeContextMenu);
+treeView.addEventListener("click", handleTreePathClick);
+outputText.addEventListener("click", handleOutputTextPathClick);
+outputViewer.addEventListener("click", handleOutputViewerPathClick);
+pathBreadcrumb.addEventListener("click", handlePathCrumbClick);
+document
+  .getElementById("copyPathBtn")
+  .addEventListener("click", () =>
+    copyText(selectedPath.jsonPath, "JSONPath"),
+  );
+document
+  .getElementById("copyPathPointerBtn")
+  .addEventListener("click", () =>
+    copyText(selectedPath.pointer, "JSON Pointer"),
+  );
+document
+  .getElementById("copyPathSubtreeBtn")
+  .addEventListener("click", handleCopyPathSubtree);
+treeContextMenu.addEventListener("click", handleTreeNodeAction);
+bookmarkSelect.addEventListener("change", () =>
+  jumpToBookmark(bookmarkSelect.value),
+);
+document
+  .getElementById("prevBookmarkBtn")
+  .addEventListener("click", () => handleStepBookmark(-1));
+document
+  .getElementById("nextBookmarkBtn")
+  .addEventListener("click", () => handleStepBookmark(1));
+document
+  .getElementById("removeBookmarkBtn")
+  .addEventListener("click", handleRemoveBookmark);
+document
+  .getElementById("applySchemaBtn")
+  .addEventListener("click", handleApplySchema);
+document.addEventListener("click", (e) => {
+  if (!treeContextMenu.contains(e.target)) {
+    hideTreeContextMenu();
+  }
+});
+splitOriginal.addEventListener("scroll", () =>
+  syncSplitScroll(splitOriginal, splitTransformed, true),
+);
+splitTransformed.addEventListener("scroll", () =>
+  syncSplitScroll(splitTransformed, splitOriginal, false),
+);
 document
   .getElementById("clearCompareBtn")
   .addEventListener("click", handleCompareClear);
@@ -1316,6 +5687,7 @@ document
 document
   .getElementById("copyOutputBtn")
   .addEventListener("click", handleCopyOutput);
+copyAsMenu.addEventListener("click", handleCopyAs);
 document
   .getElementById("copyLeftCompareBtn")
   .addEventListener("click", () => handleCopyCompare("left"));
@@ -1334,6 +5706,14 @@ document
 document.getElementById("compareBtn").addEventListener("click", handleCompare);
 converterTabBtn.addEventListener("click", () => setActiveTab("converter"));
 compareTabBtn.addEventListener("click", () => setActiveTab("compare"));
+document
+  .getElementById("compareDocumentsBtn")
+  .addEventListener("click", handleCompareDocuments);
+document
+  .getElementById("compareInWindowBtn")
+  .addEventListener("click", () =>
+    openCompareWindow(compareLeft.value, compareRight.value),
+  );
 mermaidTabBtn.addEventListener("click", () => setActiveTab("mermaid"));
 imageResizerTabBtn.addEventListener("click", () =>
   setActiveTab("imageResizer"),
@@ -1341,6 +5721,7 @@ imageResizerTabBtn.addEventListener("click", () =>
 opensslTabBtn.addEventListener("click", () => setActiveTab("openssl"));
 tracerouteTabBtn.addEventListener("click", () => setActiveTab("traceroute"));
 jsonHtmlTabBtn.addEventListener("click", () => setActiveTab("jsonHtml"));
+streamTabBtn.addEventListener("click", () => setActiveTab("stream"));
 
 // Mermaid event listeners
 document
@@ -1446,6 +5827,29 @@ document
   copyTracerouteOutputBtn
   .addEventListener("click", handleCopyTracerouteOutput);
 
+streamConnectBtn.addEventListener("click", handleStreamConnect);
+streamPauseBtn.addEventListener("click", handleStreamPause);
+streamSendBtn.addEventListener("click", handleStreamSend);
+document
+  .getElementById("streamClearBtn")
+  .addEventListener("click", handleStreamClear);
+document
+  .getElementById("streamExportBtn")
+  .addEventListener("click", handleStreamExport);
+streamFilterInput.addEventListener("input", renderStreamLog);
+streamSendInput.addEventListener("keydown", (e) => {
+  if (e.key === "Enter") {
+    e.preventDefault();
+    handleStreamSend();
+  }
+});
+streamUrlInput.addEventListener("keydown", (e) => {
+  if (e.key === "Enter" && !streamSource) {
+    e.preventDefault();
+    handleStreamConnect();
+  }
+});
+
 opensslUrlInput.addEventListener("keydown", (e) => {
   if (e.key === "Enter") {
     e.preventDefault();
@@ -1515,20 +5919,105 @@ mermaidPreview.addEventListener("wheel", (e) => {
 
 // Keyboard shortcuts
 document.addEventListener("keydown", (e) => {
+  if (e.ctrlKey && e.key === "Tab") {
+    e.preventDefault();
+    stepDocument(e.shiftKey ? -1 : 1);
+    return;
+  }
+  if ((e.ctrlKey || e.metaKey) && e.key >= "1" && e.key <= "9") {
+    e.preventDefault();
+    // Ctrl+9 always jumps to the last tab, as in browsers
+    switchDocument(e.key === "9" ? documents.length - 1 : Number(e.key) - 1);
+    return;
+  }
   if (e.ctrlKey || e.metaKey) {
-    switch (e.key) {
+    switch (e.key.toLowerCase()) {
+      case "t":
+        e.preventDefault();
+        handleNewDocument();
+        break;
+      case "z":
+        if (!isConverterHistoryTarget(e.target)) break;
+        e.preventDefault();
+        if (e.shiftKey) {
+          handleRedo();
+        } else {
+          handleUndo();
+        }
+        break;
+      case "y":
+        if (!isConverterHistoryTarget(e.target)) break;
+        e.preventDefault();
+        handleRedo();
+        break;
+      case "w":
+        e.preventDefault();
+        closeDocument(activeDocument);
+        break;
+      case "o":
+        e.preventDefault();
+        handleOpenFile();
+        break;
+      case "s":
+        e.preventDefault();
+        handleSaveOutput();
+        break;
       case "m":
         e.preventDefault();
         handleMinify();
         break;
+      case "enter":
+        if (!lastOperation || converterSection.classList.contains("hidden")) {
+          break;
+        }
+        e.preventDefault();
+        document.getElementById(lastOperation).click();
+        break;
+      case "v":
+        if (!e.shiftKey) break;
+        e.preventDefault();
+        handlePasteAndFormat();
+        break;
       case "f":
         e.preventDefault();
-        handleFormat();
+        if (e.shiftKey) {
+          handleFormat();
+        } else {
+          openFindBar();
+        }
         break;
     }
   }
 });
 
 setActiveTab("converter");
-renderDiffHtml(EMPTY_DIFF_HTML);
+documents.push(newDocumentState());
+renderDocumentTabs();
+liveFormatCheckbox.checked = Boolean(localStorage.getItem("liveFormat"));
+formatOnOpenCheckbox.checked = Boolean(localStorage.getItem("formatOnOpen"));
+lossyInputCheckbox.checked = Boolean(localStorage.getItem("lossyInput"));
+loadPaneLayout();
+for (const [code, name] of Object.entries(LANGUAGES)) {
+  uiLanguageSelect.add(new Option(name, code));
+}
+loadSettings();
+listen("format-progress", handleOperationProgress);
+// App-wide events, the session and the launch file belong to the main window
+if (compareWindowLabel) {
+  startCompareWindow();
+} else {
+  loadGlobalShortcut();
+  loadRecentFiles();
+  listen("file-watch", handleFileWatchEvent);
+  listen("open-file", (event) => openLaunchedFile(event.payload));
+  listen("menu-action", handleMenuAction);
+  listen("load-clipboard", handleLoadClipboard);
+  listen("ingest-json", handleIngestedJson);
+  listen("save-session", handleSaveSessionOnClose);
+  restoreSession().then(loadLaunchFile);
+  setInterval(saveSession, SESSION_AUTOSAVE_MS);
+}
+loadCapabilities();
+renderKvProfiles();
+renderDiffHtml(emptyDiffHtml());
 setTracerouteLoadingState(false);
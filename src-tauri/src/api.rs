@@ -0,0 +1,600 @@
+//! Request and response contracts of the Tauri commands.
+//!
+//! Request structs mirror the named arguments the frontend passes to `invoke`, in their
+//! camelCase wire form. Responses are the command's success type; every fallible command
+//! reports errors as a plain string. Timed commands wrap their result as `{ result, timing }`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::JsonSchema;
+use serde_json::{json, Map, Value};
+
+use crate::settings::Settings;
+use crate::snippets::SnippetSummary;
+use crate::{
+    ArchiveEntry, BatchOperation, BatchReport, Capabilities, CodegenOptions, CompareInputs,
+    ConcurrencySettings, ConfigLayer, ConformanceReport, CopyForm, CurlReplay, DependencyReport,
+    DocumentAnalysis, DocumentMetrics, FeatureFlagSummary, FetchedJson, FileWatchUpdate,
+    FlagEvaluation, FormatDetection, HarEntry, HarPart, InputFormat, InputHistorySummary,
+    InputInspection, KeyValueProfile, LintReport, MarkdownMode, NodeLocation, OperationTiming,
+    OutputOptions, ParseLimits, RecentFile, RenderedEnv, ResolvedConfig, SanitizedText,
+    SchemaAnnotation, Session, SqliteRows, SslUrlCheckResult, Theme, Timed, TransformComparison,
+    WatchOperation,
+};
+
+/// Commands without arguments
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct NoArguments {}
+
+/// Commands that take a single JSON (or other text) document
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct InputRequest {
+    pub input: String,
+}
+
+/// Commands that transform a document and serialize JSON output
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FormatRequest {
+    pub input: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchProcessRequest {
+    pub input_dir: String,
+    pub output_dir: String,
+    pub operation: BatchOperation,
+    pub output_options: Option<OutputOptions>,
+}
+
+/// Commands that stream a JSON file into another file
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamFileRequest {
+    pub input_path: String,
+    pub output_path: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveBackgroundRequest {
+    pub image_data: String,
+    pub tolerance: u32,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CertDetailRequest {
+    pub cert_input: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CertDetailFromUrlRequest {
+    pub url_input: String,
+    pub chain_mode: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TracerouteRequest {
+    pub url_input: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyOutputAsRequest {
+    pub input: String,
+    pub form: CopyForm,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertToJsonRequest {
+    pub input: String,
+    pub format: InputFormat,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SlowestOperationsRequest {
+    pub limit: Option<usize>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct ConcurrencySettingsRequest {
+    pub settings: ConcurrencySettings,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct ParseLimitsRequest {
+    pub limits: ParseLimits,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonToCsvRequest {
+    pub input: String,
+    pub columns: Option<Vec<String>>,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonToMarkdownRequest {
+    pub input: String,
+    pub mode: Option<MarkdownMode>,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SetThemeRequest {
+    pub theme: Theme,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct CompareTransformRequest {
+    pub original: String,
+    pub transformed: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PointerSetRequest {
+    pub input: String,
+    pub pointer: String,
+    pub value: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct PointerGetRequest {
+    pub input: String,
+    pub pointer: String,
+}
+
+/// `pointer_delete` and `pointer_duplicate`
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PointerEditRequest {
+    pub input: String,
+    pub pointer: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct LocateNodeRequest {
+    pub input: String,
+    /// 1-based line and column of the click
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct PointerToJsonPathRequest {
+    pub pointer: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct AnnotateWithSchemaRequest {
+    pub input: String,
+    pub schema: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct InspectInputBytesRequest {
+    /// A file to read; `data` is used when there is no path
+    pub path: Option<String>,
+    pub data: Option<Vec<u8>>,
+    pub lossy: Option<bool>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodeBinaryInputRequest {
+    pub data: Vec<u8>,
+    pub decoder: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+/// An archive is read from `path` or, when no path is given, from `data`
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct OpenArchiveRequest {
+    pub path: Option<String>,
+    pub data: Option<Vec<u8>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadArchiveEntryRequest {
+    pub path: Option<String>,
+    pub data: Option<Vec<u8>>,
+    pub entry_name: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct ReadInputFileRequest {
+    pub path: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SaveOutputFileRequest {
+    pub path: String,
+    pub contents: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct PinRecentFileRequest {
+    pub path: String,
+    pub pinned: bool,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct RemoveRecentFileRequest {
+    pub path: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SaveSnippetRequest {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub content: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct ListSnippetsRequest {
+    pub query: Option<String>,
+}
+
+/// Commands that act on one snippet
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SnippetNameRequest {
+    pub name: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct RecordInputHistoryRequest {
+    pub input: String,
+    pub operation: String,
+}
+
+/// Commands that act on one input history entry
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct InputHistoryIdRequest {
+    pub id: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchFileRequest {
+    pub path: String,
+    pub operation: WatchOperation,
+    pub output_options: Option<OutputOptions>,
+}
+
+/// An empty `shortcut` turns the global shortcut off
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SetGlobalShortcutRequest {
+    pub shortcut: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SaveSessionRequest {
+    pub session: Session,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct OpenCompareWindowRequest {
+    pub left: String,
+    pub right: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct TakeCompareInputsRequest {
+    /// Label of the compare window, from its `compareWindow` query parameter
+    pub window: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct FetchJsonRequest {
+    pub url: String,
+    /// Extra request headers by name
+    pub headers: Option<BTreeMap<String, String>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct ExportHtmlRequest {
+    pub input: String,
+    pub path: String,
+    pub title: Option<String>,
+    /// JSON pointers of nodes that start collapsed
+    pub collapsed: Option<Vec<String>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FormatStreamMessagesRequest {
+    pub messages: Vec<String>,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayCurlRequest {
+    /// A `curl …` command line, e.g. from a browser's "Copy as cURL"
+    pub command: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct OpenHarRequest {
+    /// The text of a `.har` export
+    pub text: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadHarEntryRequest {
+    pub text: String,
+    pub index: usize,
+    pub part: HarPart,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SetSettingsRequest {
+    pub settings: Settings,
+}
+
+/// Settings export and import
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SettingsFileRequest {
+    pub path: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SqliteListTablesRequest {
+    pub path: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SqliteQueryRequest {
+    pub path: String,
+    pub query: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchKeyValueRequest {
+    pub profile: KeyValueProfile,
+    pub key: String,
+    pub output_options: Option<OutputOptions>,
+}
+
+/// Without `variables` placeholders are resolved from the process environment
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderEnvRequest {
+    pub input: String,
+    pub variables: Option<HashMap<String, String>>,
+    pub output_options: Option<OutputOptions>,
+}
+
+/// Layers are merged in order, later layers overriding earlier ones
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveConfigRequest {
+    pub layers: Vec<ConfigLayer>,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateFeatureFlagRequest {
+    pub input: String,
+    pub flag_key: String,
+    pub context: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct JsonToProtoRequest {
+    pub input: String,
+    pub options: Option<CodegenOptions>,
+}
+
+/// An empty `request` or `response` is `google.protobuf.Empty`
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct JsonToGrpcServiceRequest {
+    pub request: String,
+    pub response: String,
+    pub method: String,
+    pub service: Option<String>,
+    pub options: Option<CodegenOptions>,
+}
+
+/// Without `format` the payload format (`base64`, `hex` or `text`) is detected
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodeProtobufRequest {
+    pub schema: String,
+    pub payload: String,
+    pub message_type: Option<String>,
+    pub format: Option<String>,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateSampleJsonRequest {
+    pub input: String,
+    pub seed: Option<u64>,
+    pub count: Option<usize>,
+    pub output_options: Option<OutputOptions>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct JsonToClassRequest {
+    pub input: String,
+    pub language: String,
+    pub name: String,
+    pub options: Option<CodegenOptions>,
+}
+
+/// File names are paths relative to `directory`
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SaveGeneratedFilesRequest {
+    pub directory: String,
+    pub files: BTreeMap<String, String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateApiSchemaRequest {
+    pub output_options: Option<OutputOptions>,
+}
+
+fn add_command<Request: JsonSchema, Response: JsonSchema>(
+    generator: &mut SchemaGenerator,
+    commands: &mut Map<String, Value>,
+    name: &str,
+) {
+    commands.insert(
+        name.to_string(),
+        json!({
+            "request": generator.subschema_for::<Request>(),
+            "response": generator.subschema_for::<Response>(),
+        }),
+    );
+}
+
+/// JSON Schemas (draft-07) for every command's request and response, sharing one
+/// `definitions` map
+pub fn api_schema() -> Value {
+    let mut generator = SchemaSettings::draft07().into_generator();
+    let mut commands = Map::new();
+    let g = &mut generator;
+    let c = &mut commands;
+
+    add_command::<FormatRequest, Timed<String>>(g, c, "minify_json");
+    add_command::<FormatRequest, Timed<String>>(g, c, "format_json");
+    add_command::<StreamFileRequest, Timed<u64>>(g, c, "format_json_file");
+    add_command::<StreamFileRequest, Timed<u64>>(g, c, "minify_json_file");
+    add_command::<BatchProcessRequest, BatchReport>(g, c, "batch_process");
+    add_command::<InputRequest, Timed<String>>(g, c, "json_to_string");
+    add_command::<JsonToCsvRequest, Timed<String>>(g, c, "json_to_csv");
+    add_command::<JsonToMarkdownRequest, Timed<String>>(g, c, "json_to_markdown");
+    add_command::<FormatRequest, Timed<String>>(g, c, "string_to_json");
+    add_command::<CompareTransformRequest, TransformComparison>(g, c, "compare_transform");
+    add_command::<PointerSetRequest, String>(g, c, "pointer_set");
+    add_command::<PointerGetRequest, String>(g, c, "pointer_get");
+    add_command::<PointerEditRequest, String>(g, c, "pointer_delete");
+    add_command::<PointerEditRequest, String>(g, c, "pointer_duplicate");
+    add_command::<PointerToJsonPathRequest, String>(g, c, "pointer_to_json_path");
+    add_command::<LocateNodeRequest, Option<NodeLocation>>(g, c, "locate_node");
+    add_command::<InputRequest, String>(g, c, "document_fingerprint");
+    add_command::<AnnotateWithSchemaRequest, Vec<SchemaAnnotation>>(g, c, "annotate_with_schema");
+    add_command::<SlowestOperationsRequest, Vec<OperationTiming>>(g, c, "slowest_operations");
+    add_command::<InputRequest, DocumentMetrics>(g, c, "document_metrics");
+    add_command::<InputRequest, FormatDetection>(g, c, "detect_format");
+    add_command::<ConvertToJsonRequest, Timed<String>>(g, c, "convert_to_json");
+    add_command::<CopyOutputAsRequest, String>(g, c, "copy_output_as");
+    add_command::<NoArguments, ConcurrencySettings>(g, c, "get_concurrency_settings");
+    add_command::<ConcurrencySettingsRequest, ConcurrencySettings>(
+        g,
+        c,
+        "set_concurrency_settings",
+    );
+    add_command::<NoArguments, ParseLimits>(g, c, "get_parse_limits");
+    add_command::<ParseLimitsRequest, ParseLimits>(g, c, "set_parse_limits");
+    add_command::<NoArguments, Theme>(g, c, "get_theme");
+    add_command::<NoArguments, ()>(g, c, "cancel_operation");
+    add_command::<SetThemeRequest, Theme>(g, c, "set_theme");
+    add_command::<NoArguments, Settings>(g, c, "get_settings");
+    add_command::<SetSettingsRequest, Settings>(g, c, "set_settings");
+    add_command::<SettingsFileRequest, ()>(g, c, "export_settings");
+    add_command::<SettingsFileRequest, Settings>(g, c, "import_settings");
+    add_command::<InspectInputBytesRequest, InputInspection>(g, c, "inspect_input_bytes");
+    add_command::<InputRequest, SanitizedText>(g, c, "sanitize_input");
+    add_command::<DecodeBinaryInputRequest, String>(g, c, "decode_binary_input");
+    add_command::<OpenArchiveRequest, Vec<ArchiveEntry>>(g, c, "open_archive");
+    add_command::<ReadArchiveEntryRequest, String>(g, c, "read_archive_entry");
+    add_command::<ReadInputFileRequest, Vec<u8>>(g, c, "read_input_file");
+    add_command::<SaveOutputFileRequest, ()>(g, c, "save_output_file");
+    add_command::<NoArguments, Vec<RecentFile>>(g, c, "recent_files");
+    add_command::<PinRecentFileRequest, Vec<RecentFile>>(g, c, "pin_recent_file");
+    add_command::<RemoveRecentFileRequest, Vec<RecentFile>>(g, c, "remove_recent_file");
+    add_command::<RecordInputHistoryRequest, Vec<InputHistorySummary>>(
+        g,
+        c,
+        "record_input_history",
+    );
+    add_command::<NoArguments, Vec<InputHistorySummary>>(g, c, "input_history");
+    add_command::<InputHistoryIdRequest, String>(g, c, "input_history_entry");
+    add_command::<InputHistoryIdRequest, Vec<InputHistorySummary>>(g, c, "remove_input_history");
+    add_command::<NoArguments, Vec<InputHistorySummary>>(g, c, "clear_input_history");
+    add_command::<SaveSnippetRequest, Vec<SnippetSummary>>(g, c, "save_snippet");
+    add_command::<ListSnippetsRequest, Vec<SnippetSummary>>(g, c, "list_snippets");
+    add_command::<SnippetNameRequest, String>(g, c, "load_snippet");
+    add_command::<SnippetNameRequest, Vec<SnippetSummary>>(g, c, "delete_snippet");
+    add_command::<WatchFileRequest, FileWatchUpdate>(g, c, "watch_file");
+    add_command::<NoArguments, ()>(g, c, "unwatch_file");
+    add_command::<NoArguments, Option<String>>(g, c, "take_launch_file");
+    add_command::<NoArguments, String>(g, c, "get_global_shortcut");
+    add_command::<SetGlobalShortcutRequest, String>(g, c, "set_global_shortcut");
+    add_command::<SaveSessionRequest, ()>(g, c, "save_session");
+    add_command::<NoArguments, Option<Session>>(g, c, "load_session");
+    add_command::<NoArguments, ()>(g, c, "close_window");
+    add_command::<OpenCompareWindowRequest, String>(g, c, "open_compare_window");
+    add_command::<TakeCompareInputsRequest, Option<CompareInputs>>(g, c, "take_compare_inputs");
+    add_command::<SqliteListTablesRequest, Vec<String>>(g, c, "sqlite_list_tables");
+    add_command::<SqliteQueryRequest, SqliteRows>(g, c, "sqlite_query");
+    add_command::<FetchKeyValueRequest, String>(g, c, "fetch_key_value");
+    add_command::<FetchJsonRequest, FetchedJson>(g, c, "fetch_json");
+    add_command::<FormatStreamMessagesRequest, Vec<Option<String>>>(g, c, "format_stream_messages");
+    add_command::<ReplayCurlRequest, CurlReplay>(g, c, "replay_curl");
+    add_command::<ExportHtmlRequest, ()>(g, c, "export_html");
+    add_command::<OpenHarRequest, Vec<HarEntry>>(g, c, "open_har");
+    add_command::<ReadHarEntryRequest, String>(g, c, "read_har_entry");
+    add_command::<RenderEnvRequest, RenderedEnv>(g, c, "render_env");
+    add_command::<ResolveConfigRequest, ResolvedConfig>(g, c, "resolve_config");
+    add_command::<InputRequest, Vec<FeatureFlagSummary>>(g, c, "list_feature_flags");
+    add_command::<EvaluateFeatureFlagRequest, FlagEvaluation>(g, c, "evaluate_feature_flag");
+    add_command::<InputRequest, DependencyReport>(g, c, "dependency_report");
+    add_command::<InputRequest, Vec<DocumentAnalysis>>(g, c, "analyze_document");
+    add_command::<InputRequest, LintReport>(g, c, "lint_json");
+    add_command::<InputRequest, ConformanceReport>(g, c, "validate_strict");
+    add_command::<JsonToProtoRequest, Timed<String>>(g, c, "json_to_proto");
+    add_command::<JsonToGrpcServiceRequest, Timed<String>>(g, c, "json_to_grpc_service");
+    add_command::<FormatRequest, Timed<String>>(g, c, "proto_to_json");
+    add_command::<DecodeProtobufRequest, Timed<String>>(g, c, "decode_protobuf");
+    add_command::<GenerateSampleJsonRequest, Timed<String>>(g, c, "generate_sample_json");
+    add_command::<JsonToClassRequest, Timed<String>>(g, c, "json_to_class");
+    add_command::<JsonToClassRequest, Timed<BTreeMap<String, String>>>(g, c, "json_to_class_files");
+    add_command::<SaveGeneratedFilesRequest, Vec<String>>(g, c, "save_generated_files");
+    add_command::<RemoveBackgroundRequest, String>(g, c, "remove_background");
+    add_command::<CertDetailRequest, String>(g, c, "openssl_cert_detail");
+    add_command::<CertDetailFromUrlRequest, SslUrlCheckResult>(
+        g,
+        c,
+        "openssl_cert_detail_from_url",
+    );
+    add_command::<TracerouteRequest, String>(g, c, "run_traceroute");
+    add_command::<GenerateApiSchemaRequest, String>(g, c, "generate_api_schema");
+    add_command::<NoArguments, Capabilities>(g, c, "capabilities");
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "json-formatter command API",
+        "version": env!("CARGO_PKG_VERSION"),
+        "commands": commands,
+        "definitions": generator.definitions(),
+    })
+}
@@ -0,0 +1,368 @@
+//! An opt-in HTTP server on 127.0.0.1 that lets scripts push JSON into the running app.
+//!
+//! `POST /format` answers with the body formatted by the current output settings;
+//! `POST /open` opens the body in a new tab. Every request needs the configured token
+//! as `Authorization: Bearer <token>`. The server handles one request per connection and
+//! a bounded number of connections at once.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use log::{info, warn};
+use serde_json::Value;
+use tauri::Emitter;
+
+use crate::{check_parse_limits, parse_limits, serialize_json, show_main_window, APP_HANDLE};
+
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+const WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Connections handled at once; further clients get a 503 until a handler finishes
+const MAX_CONNECTIONS: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct IngestSettings {
+    pub enabled: bool,
+    pub port: u16,
+    pub token: String,
+}
+
+impl Default for IngestSettings {
+    fn default() -> Self {
+        IngestSettings {
+            enabled: false,
+            port: 7475,
+            token: String::new(),
+        }
+    }
+}
+
+impl IngestSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.port == 0 {
+            return Err("Ingest server port must be between 1 and 65535".to_string());
+        }
+        if self.token.trim().len() < 16 {
+            return Err("Ingest server token must be at least 16 characters".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A JSON document pushed to `/open`, emitted to the frontend as `ingest-json`
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+pub struct IngestedJson {
+    pub name: String,
+    pub text: String,
+}
+
+struct RunningServer {
+    settings: IngestSettings,
+    stop: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl RunningServer {
+    fn shut_down(self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Wake the blocking accept so the thread sees the flag and drops the listener
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, self.settings.port));
+        let _ = self.thread.join();
+        info!("Ingest server stopped");
+    }
+}
+
+static SERVER: Mutex<Option<RunningServer>> = Mutex::new(None);
+
+/// The settings of the server if it is running
+pub fn running() -> Option<IngestSettings> {
+    let server = SERVER.lock().unwrap_or_else(|e| e.into_inner());
+    server.as_ref().map(|running| running.settings.clone())
+}
+
+/// Start, restart or stop the server to match `settings`
+pub fn configure(settings: &IngestSettings) -> Result<(), String> {
+    settings.validate()?;
+    let mut server = SERVER.lock().unwrap_or_else(|e| e.into_inner());
+    if server.as_ref().map(|running| &running.settings) == Some(settings) {
+        return Ok(());
+    }
+    if let Some(running) = server.take() {
+        running.shut_down();
+    }
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, settings.port))
+        .map_err(|e| format!("Failed to listen on port {}: {}", settings.port, e))?;
+    info!("Ingest server listening on 127.0.0.1:{}", settings.port);
+    let stop = Arc::new(AtomicBool::new(false));
+    let thread = {
+        let stop = Arc::clone(&stop);
+        let token = settings.token.clone();
+        std::thread::spawn(move || accept_loop(listener, &stop, token))
+    };
+    *server = Some(RunningServer {
+        settings: settings.clone(),
+        stop,
+        thread,
+    });
+    Ok(())
+}
+
+/// Holds one of the `MAX_CONNECTIONS` handler slots until dropped
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn acquire(active: &Arc<AtomicUsize>) -> Option<ConnectionSlot> {
+        active
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                (count < MAX_CONNECTIONS).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| ConnectionSlot(Arc::clone(active)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn accept_loop(listener: TcpListener, stop: &AtomicBool, token: String) {
+    let active = Arc::new(AtomicUsize::new(0));
+    // Busy replies go out on their own thread so a slow client can't stall accepting;
+    // when even that queue is full the connection is just closed
+    let (busy_sender, busy_receiver) = std::sync::mpsc::sync_channel::<TcpStream>(MAX_CONNECTIONS);
+    std::thread::spawn(move || {
+        let body = error_body("Too many concurrent requests");
+        for stream in busy_receiver {
+            let _ = write_response(&stream, 503, &body);
+        }
+    });
+    for stream in listener.incoming() {
+        if stop.load(Ordering::SeqCst) {
+            break;
+        }
+        match stream {
+            Ok(stream) => {
+                // Slow or idle clients must not hold a handler forever
+                let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+                let _ = stream.set_write_timeout(Some(WRITE_TIMEOUT));
+                let Some(slot) = ConnectionSlot::acquire(&active) else {
+                    warn!("Ingest server is busy - rejecting connection");
+                    let _ = busy_sender.try_send(stream);
+                    continue;
+                };
+                let token = token.clone();
+                std::thread::spawn(move || {
+                    let _slot = slot;
+                    handle_connection(stream, &token);
+                });
+            }
+            Err(e) => warn!("Ingest server failed to accept a connection - {}", e),
+        }
+    }
+}
+
+fn handle_connection(stream: TcpStream, token: &str) {
+    let mut reader = BufReader::new(&stream);
+    let (status, body) = match read_request(&mut reader, token) {
+        Ok(request) => {
+            let (status, body) = respond(&request);
+            info!("Ingest {} {} - {}", request.method, request.path, status);
+            (status, body)
+        }
+        Err(error) => error,
+    };
+    if let Err(e) = write_response(&stream, status, &body) {
+        warn!("Ingest server failed to respond - {}", e);
+    }
+}
+
+#[derive(Debug)]
+pub struct IngestRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl IngestRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+/// Read an HTTP/1.1 request with a `Content-Length` body; errors carry the status to send.
+/// The token is checked before the body is read, so unauthorized clients can't make the
+/// server buffer large bodies.
+pub fn read_request<R: BufRead>(
+    reader: &mut R,
+    token: &str,
+) -> Result<IngestRequest, (u16, String)> {
+    let bad_request = |message: &str| (400, error_body(message));
+    let mut head = Vec::new();
+    while !head.ends_with(b"\r\n\r\n") {
+        let read = reader
+            .by_ref()
+            .take((MAX_HEADER_BYTES - head.len()) as u64)
+            .read_until(b'\n', &mut head)
+            .map_err(|_| bad_request("Failed to read request"))?;
+        if read == 0 {
+            return Err(if head.len() >= MAX_HEADER_BYTES {
+                (431, error_body("Request headers are too large"))
+            } else {
+                bad_request("Incomplete request")
+            });
+        }
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Err(bad_request("Malformed request line"));
+    };
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect();
+    let mut request = IngestRequest {
+        method: method.to_string(),
+        path: target.split('?').next().unwrap_or_default().to_string(),
+        headers,
+        body: Vec::new(),
+    };
+
+    let authorized = request
+        .header("authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| token_matches(given.trim(), token));
+    if !authorized {
+        return Err((401, error_body("Missing or invalid token")));
+    }
+    if request.header("transfer-encoding").is_some() {
+        return Err((411, error_body("Send the body with a Content-Length")));
+    }
+    let length: u64 = match request.header("content-length") {
+        Some(length) => length
+            .parse()
+            .map_err(|_| bad_request("Invalid Content-Length"))?,
+        None => 0,
+    };
+    if length > parse_limits().max_input_mb * 1024 * 1024 {
+        return Err((413, error_body("Body exceeds the maximum input size")));
+    }
+    reader
+        .take(length)
+        .read_to_end(&mut request.body)
+        .map_err(|_| bad_request("Failed to read request body"))?;
+    if request.body.len() as u64 != length {
+        return Err(bad_request("Incomplete request body"));
+    }
+    Ok(request)
+}
+
+/// Compare tokens without returning early on the first differing byte
+fn token_matches(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Route a request read by `read_request` and return the status and JSON body to send
+pub fn respond(request: &IngestRequest) -> (u16, String) {
+    if !matches!(request.path.as_str(), "/format" | "/open") {
+        return (404, error_body("Unknown endpoint; use /format or /open"));
+    }
+    if request.method != "POST" {
+        return (405, error_body("Use POST"));
+    }
+
+    let Ok(text) = std::str::from_utf8(&request.body) else {
+        return (400, error_body("Body is not UTF-8 text"));
+    };
+    let value = match check_parse_limits(text).and_then(|_| {
+        serde_json::from_str::<Value>(text).map_err(|e| format!("Invalid JSON: {}", e))
+    }) {
+        Ok(value) => value,
+        Err(e) => return (400, error_body(&e)),
+    };
+
+    if request.path == "/format" {
+        let output = crate::settings::get_settings().output;
+        return match serialize_json(&value, true, &output) {
+            Ok(formatted) => (200, formatted),
+            Err(e) => (500, error_body(&e)),
+        };
+    }
+
+    let Some(app) = APP_HANDLE.get() else {
+        return (503, error_body("The app window is not available"));
+    };
+    let document = IngestedJson {
+        name: request
+            .header("x-document-name")
+            .unwrap_or("Ingested")
+            .to_string(),
+        text: text.to_string(),
+    };
+    if let Err(e) = app.emit("ingest-json", document) {
+        return (
+            500,
+            error_body(&format!("Failed to open the document: {}", e)),
+        );
+    }
+    show_main_window(app);
+    (202, serde_json::json!({ "opened": true }).to_string())
+}
+
+fn write_response(mut stream: &TcpStream, status: u16, body: &str) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    let mut response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        status,
+        reason,
+        body.len()
+    );
+    if status == 401 {
+        response.push_str("WWW-Authenticate: Bearer\r\n");
+    }
+    response.push_str("\r\n");
+    stream.write_all(response.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
@@ -1,17 +1,31 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod api;
+mod ingest;
+mod settings;
+mod snippets;
+
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use image::{GenericImageView, ImageFormat, Rgba};
 use log::{debug, error, info, warn};
 use serde_json::Value;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::fs;
-use std::io::{Cursor, Write};
+use std::io::{Cursor, Read, Seek, Write};
 use std::net::IpAddr;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::menu::{Menu, MenuBuilder, MenuEvent, MenuItemBuilder, SubmenuBuilder};
+use tauri::tray::TrayIconBuilder;
+use tauri::{Emitter, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_notification::NotificationExt;
 
 /// Remove background using flood-fill algorithm from edges
 #[tauri::command]
@@ -267,7 +281,7 @@ fn openssl_cert_detail(cert_input: String) -> Result<String, String> {
     Ok(sections.join("\n\n"))
 }
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, schemars::JsonSchema)]
 struct SslUrlCheckResult {
     pem: String,
     detail: String,
@@ -1054,1144 +1068,16999 @@ fn format_sha256_fingerprint(digest: &[u8]) -> String {
         .join(":")
 }
 
-/// Minify JSON by removing all unnecessary whitespace
-#[tauri::command]
-fn minify_json(input: String) -> Result<String, String> {
-    info!("minify_json called - input_len: {}", input.len());
+/// Line ending used when writing serialized output
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+enum LineEnding {
+    #[default]
+    Lf,
+    Crlf,
+}
 
-    if input.trim().is_empty() {
-        warn!("minify_json: Input is empty");
-        return Err("Input is empty".to_string());
-    }
+/// Indentation of pretty-printed output
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum Indent {
+    #[default]
+    TwoSpaces,
+    FourSpaces,
+    Tab,
+}
 
-    let parsed: Value = serde_json::from_str(&input).map_err(|e| {
-        error!("minify_json: Invalid JSON - {}", e);
-        format!("Invalid JSON: {}", e)
-    })?;
+impl Indent {
+    fn as_str(self) -> &'static str {
+        match self {
+            Indent::TwoSpaces => "  ",
+            Indent::FourSpaces => "    ",
+            Indent::Tab => "\t",
+        }
+    }
+}
 
-    let result = serde_json::to_string(&parsed).map_err(|e| {
-        error!("minify_json: Failed to minify - {}", e);
-        format!("Failed to minify: {}", e)
-    })?;
+/// Output options for consumers that need CRLF, a trailing newline, or a UTF-8 BOM
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+struct OutputOptions {
+    line_ending: LineEnding,
+    final_newline: bool,
+    bom: bool,
+    indent: Indent,
+    /// Sort object keys instead of keeping the input order
+    sort_keys: bool,
+}
 
-    info!("minify_json: Success - output_len: {}", result.len());
-    Ok(result)
+impl Default for OutputOptions {
+    /// Keys are sorted by default, as they were before input order could be kept
+    fn default() -> Self {
+        OutputOptions {
+            line_ending: LineEnding::default(),
+            final_newline: false,
+            bom: false,
+            indent: Indent::default(),
+            sort_keys: true,
+        }
+    }
 }
 
-/// Format JSON with pretty printing (indented)
-#[tauri::command]
-fn format_json(input: String) -> Result<String, String> {
-    if input.trim().is_empty() {
-        return Err("Input is empty".to_string());
+/// Write a JSON value (compact or pretty) with the indent and key order from `options`
+fn write_json<W: Write>(
+    writer: W,
+    value: &Value,
+    pretty: bool,
+    options: &OutputOptions,
+) -> serde_json::Result<()> {
+    let sorted;
+    let value = if options.sort_keys {
+        let mut copy = value.clone();
+        copy.sort_all_objects();
+        sorted = copy;
+        &sorted
+    } else {
+        value
+    };
+
+    if pretty {
+        let formatter =
+            serde_json::ser::PrettyFormatter::with_indent(options.indent.as_str().as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(writer, formatter);
+        serde::Serialize::serialize(value, &mut serializer)
+    } else {
+        serde_json::to_writer(writer, value)
     }
+}
 
-    let parsed: Value = serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+/// Serialize a JSON value (compact or pretty) and apply the requested output options
+fn serialize_json(value: &Value, pretty: bool, options: &OutputOptions) -> Result<String, String> {
+    let mut buffer = Vec::new();
+    write_json(&mut buffer, value, pretty, options)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+    let text = String::from_utf8(buffer).map_err(|e| format!("Failed to serialize: {}", e))?;
 
-    serde_json::to_string_pretty(&parsed).map_err(|e| format!("Failed to format: {}", e))
+    Ok(apply_output_options(text, options))
 }
 
-/// Convert JSON to an escaped string (as a JSON string literal)
-#[tauri::command]
-fn json_to_string(input: String) -> Result<String, String> {
-    if input.trim().is_empty() {
-        return Err("Input is empty".to_string());
+fn apply_output_options(text: String, options: &OutputOptions) -> String {
+    let mut output = match options.line_ending {
+        LineEnding::Lf => text,
+        LineEnding::Crlf => text.replace("\r\n", "\n").replace('\n', "\r\n"),
+    };
+
+    if options.final_newline && !output.ends_with('\n') {
+        output.push_str(match options.line_ending {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+        });
     }
 
-    // Validate that input is valid JSON first
-    let _: Value = serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    if options.bom {
+        output.insert(0, '\u{feff}');
+    }
 
-    // Convert the JSON to an escaped string
-    let escaped = serde_json::to_string(&input).map_err(|e| format!("Failed to convert: {}", e))?;
+    output
+}
 
-    Ok(escaped)
+const OPERATION_TIMING_HISTORY: usize = 200;
+
+/// Recent operation timings, oldest first
+static OPERATION_TIMINGS: Mutex<VecDeque<OperationTiming>> = Mutex::new(VecDeque::new());
+
+/// Phase timings for one command invocation, in milliseconds
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct OperationTiming {
+    operation: String,
+    input_bytes: usize,
+    parse_ms: f64,
+    transform_ms: f64,
+    serialize_ms: f64,
+    total_ms: f64,
+    /// The command returned an error before finishing
+    failed: bool,
 }
 
-/// Convert an escaped string back to JSON (parse JSON string literal)
-#[tauri::command]
-fn string_to_json(input: String) -> Result<String, String> {
-    let trimmed = input.trim();
+/// A command result together with the timing of the call that produced it
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+struct Timed<T> {
+    result: T,
+    timing: OperationTiming,
+}
 
-    if trimmed.is_empty() {
-        return Err("Input is empty".to_string());
+/// Measures the parse/transform/serialize phases of a command. Each mark
+/// attributes the time since the previous mark to that phase. A timer dropped
+/// without `finish` is recorded as failed.
+struct OperationTimer {
+    timing: OperationTiming,
+    started: Instant,
+    last_mark: Instant,
+    recorded: bool,
+}
+
+impl OperationTimer {
+    fn start(operation: &str, input_bytes: usize) -> Self {
+        let now = Instant::now();
+        OperationTimer {
+            timing: OperationTiming {
+                operation: operation.to_string(),
+                input_bytes,
+                parse_ms: 0.0,
+                transform_ms: 0.0,
+                serialize_ms: 0.0,
+                total_ms: 0.0,
+                failed: false,
+            },
+            started: now,
+            last_mark: now,
+            recorded: false,
+        }
     }
 
-    // Accept multiple common inputs:
-    // 1) regular JSON object/array
-    // 2) JSON string literal containing escaped JSON
-    // 3) escaped JSON without wrapping quotes, e.g. {\"a\":1}
-    let candidates = [trimmed, trimmed.trim_matches('"')];
+    fn lap(&mut self) -> f64 {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_mark).as_secs_f64() * 1000.0;
+        self.last_mark = now;
+        elapsed
+    }
 
-    for candidate in candidates {
-        if candidate.is_empty() {
-            continue;
-        }
+    fn parsed(&mut self) {
+        self.timing.parse_ms += self.lap();
+    }
 
-        if let Ok(value) = serde_json::from_str::<Value>(candidate) {
-            let parsed = match value {
-                Value::String(unescaped) => serde_json::from_str::<Value>(unescaped.trim())
-                    .map_err(|e| format!("String content is not valid JSON: {}", e))?,
-                other => other,
-            };
+    fn transformed(&mut self) {
+        self.timing.transform_ms += self.lap();
+    }
 
-            return serde_json::to_string_pretty(&parsed)
-                .map_err(|e| format!("Failed to format: {}", e));
+    /// Attribute the remaining time to serialization and return it with the result
+    fn finish<T>(mut self, result: T) -> Timed<T> {
+        self.timing.serialize_ms += self.lap();
+        Timed {
+            result,
+            timing: self.record(false),
         }
+    }
 
-        let wrapped = format!("\"{}\"", candidate);
-        if let Ok(unescaped) = serde_json::from_str::<String>(&wrapped) {
-            if let Ok(parsed) = serde_json::from_str::<Value>(unescaped.trim()) {
-                return serde_json::to_string_pretty(&parsed)
-                    .map_err(|e| format!("Failed to format: {}", e));
-            }
+    /// Add the timing to the history kept for the slowest-operations overlay
+    fn record(&mut self, failed: bool) -> OperationTiming {
+        self.recorded = true;
+        self.timing.total_ms = self.started.elapsed().as_secs_f64() * 1000.0;
+        self.timing.failed = failed;
+        debug!("operation timing: {:?}", self.timing);
+
+        let mut timings = OPERATION_TIMINGS.lock().unwrap_or_else(|e| e.into_inner());
+        if timings.len() == OPERATION_TIMING_HISTORY {
+            timings.pop_front();
         }
+        timings.push_back(self.timing.clone());
+        self.timing.clone()
     }
+}
 
-    Err("Input must be valid JSON or escaped JSON string".to_string())
+impl Drop for OperationTimer {
+    fn drop(&mut self) {
+        if !self.recorded {
+            self.record(true);
+        }
+    }
 }
 
-/// Convert JSON to Protocol Buffers (proto3) schema
+/// Slowest operations among the recent history, slowest first
 #[tauri::command]
-fn json_to_proto(input: String) -> Result<String, String> {
+fn slowest_operations(limit: Option<usize>) -> Vec<OperationTiming> {
+    let timings = OPERATION_TIMINGS.lock().unwrap_or_else(|e| e.into_inner());
+    let mut slowest: Vec<OperationTiming> = timings.iter().cloned().collect();
+    slowest.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+    slowest.truncate(limit.unwrap_or(10));
+    slowest
+}
+
+/// Size and shape of a document, shown in the status bar
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct DocumentMetrics {
+    bytes: usize,
+    nodes: usize,
+    max_depth: usize,
+    parse_ms: f64,
+}
+
+/// Count every value in the document, containers included, without recursion
+fn json_node_count(value: &Value) -> usize {
+    let mut count = 0;
+    let mut pending = vec![value];
+    while let Some(value) = pending.pop() {
+        count += 1;
+        match value {
+            Value::Array(items) => pending.extend(items),
+            Value::Object(map) => pending.extend(map.values()),
+            _ => {}
+        }
+    }
+    count
+}
+
+/// Byte size, node count, nesting depth and parse time of `input`
+#[tauri::command(async)]
+fn document_metrics(input: String) -> Result<DocumentMetrics, String> {
     if input.trim().is_empty() {
         return Err("Input is empty".to_string());
     }
+    check_job_memory("document_metrics", input.len())?;
+    // A cancel left over from an earlier operation must not fail this one, even on a cache hit
+    CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+    let started = Instant::now();
+    let parsed = parse_json_tracked("document_metrics", &input)?;
+    let parse_ms = started.elapsed().as_secs_f64() * 1000.0;
+    Ok(DocumentMetrics {
+        bytes: input.len(),
+        nodes: json_node_count(&parsed),
+        max_depth: json_depth(&parsed),
+        parse_ms,
+    })
+}
 
-    let parsed: Value = serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+/// Rough ratio of in-memory `Value` size to JSON text size, used to estimate job memory
+const PARSED_MEMORY_FACTOR: u64 = 8;
 
-    let mut proto = String::from("syntax = \"proto3\";\n\n");
-    let mut message_counter = 0;
+static CONCURRENCY_SETTINGS: Mutex<Option<ConcurrencySettings>> = Mutex::new(None);
 
-    match &parsed {
-        Value::Object(_) => {
-            generate_proto_message(&parsed, "Root", &mut proto, &mut message_counter, 0);
-        }
-        Value::Array(arr) => {
-            if let Some(first) = arr.first() {
-                if first.is_object() {
-                    generate_proto_message(first, "Root", &mut proto, &mut message_counter, 0);
-                } else {
-                    return Err("Array must contain objects to generate proto schema".to_string());
-                }
-            } else {
-                return Err("Cannot generate proto schema from empty array".to_string());
-            }
-        }
-        _ => {
-            return Err("Input must be a JSON object or array of objects".to_string());
+/// Limits for batch and parallel work so the app fits both laptops and large workstations
+#[derive(
+    Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+struct ConcurrencySettings {
+    worker_threads: usize,
+    job_memory_limit_mb: u64,
+}
+
+impl Default for ConcurrencySettings {
+    fn default() -> Self {
+        ConcurrencySettings {
+            worker_threads: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(4),
+            job_memory_limit_mb: 2048,
         }
     }
+}
 
-    Ok(proto)
+fn concurrency_settings() -> ConcurrencySettings {
+    let mut settings = CONCURRENCY_SETTINGS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    *settings.get_or_insert_with(ConcurrencySettings::default)
 }
 
-/// Convert Protocol Buffers (proto3) schema to JSON sample
 #[tauri::command]
-fn proto_to_json(input: String) -> Result<String, String> {
-    info!("proto_to_json called - input_len: {}", input.len());
+fn get_concurrency_settings() -> ConcurrencySettings {
+    concurrency_settings()
+}
 
-    if input.trim().is_empty() {
-        warn!("proto_to_json: Input is empty");
-        return Err("Input is empty".to_string());
+#[tauri::command]
+fn set_concurrency_settings(settings: ConcurrencySettings) -> Result<ConcurrencySettings, String> {
+    info!("set_concurrency_settings called - {:?}", settings);
+
+    if !(1..=256).contains(&settings.worker_threads) {
+        return Err("Worker threads must be between 1 and 256".to_string());
+    }
+    if settings.job_memory_limit_mb < 16 {
+        return Err("Per-job memory limit must be at least 16 MB".to_string());
     }
 
-    let messages = parse_proto_messages(&input)?;
+    *CONCURRENCY_SETTINGS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner()) = Some(settings);
+    Ok(settings)
+}
 
-    if messages.is_empty() {
-        return Err("No message definitions found in proto file".to_string());
-    }
+static PARSE_LIMITS: Mutex<Option<ParseLimits>> = Mutex::new(None);
+
+/// Guards checked before a document is parsed, so a pathological paste fails with a
+/// message instead of exhausting memory
+#[derive(
+    Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+struct ParseLimits {
+    max_input_mb: u64,
+    max_depth: usize,
+    max_string_mb: u64,
+}
 
-    // Find the root message (first non-nested message or one named "Root")
-    let root_message = messages
-        .iter()
-        .find(|m| m.name == "Root")
-        .or_else(|| messages.first())
-        .ok_or("No messages found")?;
+impl Default for ParseLimits {
+    fn default() -> Self {
+        ParseLimits {
+            max_input_mb: 512,
+            max_depth: 127,
+            max_string_mb: 64,
+        }
+    }
+}
 
-    let json_value = proto_message_to_json(root_message, &messages)?;
-    let formatted = serde_json::to_string_pretty(&json_value)
-        .map_err(|e| format!("Failed to format JSON: {}", e))?;
+fn parse_limits() -> ParseLimits {
+    let mut limits = PARSE_LIMITS.lock().unwrap_or_else(|e| e.into_inner());
+    *limits.get_or_insert_with(ParseLimits::default)
+}
 
-    info!("proto_to_json: Success - output_len: {}", formatted.len());
-    Ok(formatted)
+#[tauri::command]
+fn get_parse_limits() -> ParseLimits {
+    parse_limits()
 }
 
-#[derive(Debug, Clone)]
-struct ProtoMessage {
-    name: String,
-    fields: Vec<ProtoField>,
+#[tauri::command]
+fn set_parse_limits(limits: ParseLimits) -> Result<ParseLimits, String> {
+    info!("set_parse_limits called - {:?}", limits);
+
+    if limits.max_input_mb == 0 || limits.max_string_mb == 0 {
+        return Err("Size limits must be at least 1 MB".to_string());
+    }
+    // serde_json refuses anything deeper than 127 levels regardless
+    if !(1..=127).contains(&limits.max_depth) {
+        return Err("Maximum nesting depth must be between 1 and 127".to_string());
+    }
+
+    *PARSE_LIMITS.lock().unwrap_or_else(|e| e.into_inner()) = Some(limits);
+    PARSE_CACHE.clear();
+    Ok(limits)
 }
 
-#[derive(Debug, Clone)]
-struct ProtoField {
-    field_type: String,
-    name: String,
-    #[allow(dead_code)]
-    number: i32,
-    is_repeated: bool,
+static THEME: Mutex<Theme> = Mutex::new(Theme::System);
+
+/// Colour scheme preference; `System` follows the OS setting
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+enum Theme {
+    Light,
+    Dark,
+    #[default]
+    System,
 }
 
-fn parse_proto_messages(input: &str) -> Result<Vec<ProtoMessage>, String> {
-    let mut messages = Vec::new();
-    let lines: Vec<&str> = input.lines().collect();
-    let mut i = 0;
+#[tauri::command]
+fn get_theme() -> Theme {
+    *THEME.lock().unwrap_or_else(|e| e.into_inner())
+}
 
-    while i < lines.len() {
-        let line = lines[i].trim();
+#[tauri::command]
+fn set_theme(theme: Theme) -> Theme {
+    info!("set_theme called - {:?}", theme);
+    *THEME.lock().unwrap_or_else(|e| e.into_inner()) = theme;
+    theme
+}
 
-        // Look for message definitions
-        if line.starts_with("message ") {
-            let message_name = line
-                .trim_start_matches("message ")
-                .trim_end_matches(" {")
-                .trim_end_matches('{')
-                .trim()
-                .to_string();
+/// Reject a job whose estimated parsed size exceeds the configured per-job memory ceiling
+fn check_job_memory(operation: &str, input_bytes: usize) -> Result<(), String> {
+    let limit_mb = concurrency_settings().job_memory_limit_mb;
+    let estimated_mb = (input_bytes as u64 * PARSED_MEMORY_FACTOR).div_ceil(1024 * 1024);
+    if estimated_mb > limit_mb {
+        warn!(
+            "{}: estimated {} MB exceeds job memory limit of {} MB",
+            operation, estimated_mb, limit_mb
+        );
+        return Err(format!(
+            "Input needs about {} MB to process, above the {} MB per-job limit",
+            estimated_mb, limit_mb
+        ));
+    }
+    Ok(())
+}
 
-            let mut fields = Vec::new();
-            i += 1;
+/// Check `input` against the configured parse limits
+fn check_parse_limits(input: &str) -> Result<(), String> {
+    parse_limits().check(input)
+}
 
-            // Parse fields until we hit the closing brace
-            while i < lines.len() {
-                let field_line = lines[i].trim();
+impl ParseLimits {
+    /// Scan `input` without building anything, reporting where a limit is crossed in the
+    /// same "at line L column C" form as parse errors
+    fn check(&self, input: &str) -> Result<(), String> {
+        if input.len() as u64 > self.max_input_mb * 1024 * 1024 {
+            return Err(format!(
+                "Input is {:.1} MB, above the {} MB input limit",
+                input.len() as f64 / (1024.0 * 1024.0),
+                self.max_input_mb
+            ));
+        }
 
-                if field_line == "}" {
-                    break;
+        let located = |offset: usize, message: String| {
+            let before = &input.as_bytes()[..offset];
+            let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+            let line_start = before
+                .iter()
+                .rposition(|&b| b == b'\n')
+                .map_or(0, |i| i + 1);
+            format!(
+                "{} at line {} column {}",
+                message,
+                line,
+                offset - line_start + 1
+            )
+        };
+        let max_string = (self.max_string_mb * 1024 * 1024) as usize;
+        let mut depth: usize = 0;
+        let mut string_start = None;
+        let mut escaped = false;
+        for (offset, &byte) in input.as_bytes().iter().enumerate() {
+            if let Some(start) = string_start {
+                if offset - start > max_string {
+                    let message = format!("String longer than {} MB", self.max_string_mb);
+                    return Err(located(start, message));
                 }
-
-                if !field_line.is_empty()
-                    && !field_line.starts_with("//")
-                    && !field_line.starts_with("syntax")
-                {
-                    if let Some(field) = parse_proto_field(field_line) {
-                        fields.push(field);
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => string_start = None,
+                    _ => {}
+                }
+                continue;
+            }
+            match byte {
+                b'"' => string_start = Some(offset),
+                b'{' | b'[' => {
+                    depth += 1;
+                    if depth > self.max_depth {
+                        let message = format!("Nesting deeper than {} levels", self.max_depth);
+                        return Err(located(offset, message));
                     }
                 }
-
-                i += 1;
+                b'}' | b']' => depth = depth.saturating_sub(1),
+                _ => {}
             }
-
-            messages.push(ProtoMessage {
-                name: message_name,
-                fields,
-            });
         }
-
-        i += 1;
+        Ok(())
     }
+}
 
-    Ok(messages)
+/// Inputs at least this large parse with simd-json when it is available
+#[cfg(all(feature = "simd", not(target_arch = "wasm32")))]
+const SIMD_MIN_BYTES: usize = 64 * 1024;
+
+/// Inputs larger than this are not kept in the parse cache
+const PARSE_CACHE_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+/// Shared by every command, so running several operations on the same document parses it once
+static PARSE_CACHE: ParseCache = ParseCache::new();
+
+/// Holds the most recently parsed input together with its text; any other input replaces it
+struct ParseCache(Mutex<Option<ParsedDocument>>);
+
+struct ParsedDocument {
+    input: Arc<str>,
+    value: Arc<Value>,
 }
 
-fn parse_proto_field(line: &str) -> Option<ProtoField> {
-    // Format: [repeated] type name = number;
-    let parts: Vec<&str> = line.split_whitespace().collect();
+impl ParseCache {
+    const fn new() -> Self {
+        ParseCache(Mutex::new(None))
+    }
 
-    if parts.len() < 4 {
-        return None;
+    /// Return the cached document for `input`, or parse it with `parse` and cache the result
+    fn get_or_parse(
+        &self,
+        input: &str,
+        parse: impl FnOnce(&str) -> Result<Value, String>,
+    ) -> Result<Arc<Value>, String> {
+        if input.len() > PARSE_CACHE_MAX_BYTES {
+            return parse(input).map(Arc::new);
+        }
+
+        if let Some(document) = self.0.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            if *document.input == *input {
+                debug!("parse cache hit - input_len: {}", input.len());
+                return Ok(document.value.clone());
+            }
+        }
+
+        let value = Arc::new(parse(input)?);
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(ParsedDocument {
+            input: Arc::from(input),
+            value: value.clone(),
+        });
+        Ok(value)
     }
 
-    let mut idx = 0;
-    let is_repeated = parts[idx] == "repeated";
-    if is_repeated {
-        idx += 1;
+    /// Drop the cached document, e.g. when the limits it was checked against change
+    fn clear(&self) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = None;
     }
+}
 
-    if parts.len() < idx + 3 {
-        return None;
+/// Parse a JSON document through the parse cache
+fn parse_json(input: &str) -> Result<Arc<Value>, String> {
+    PARSE_CACHE.get_or_parse(input, parse_json_uncached)
+}
+
+/// Parse a JSON document, using simd-json for large inputs when built with the
+/// `simd` feature and serde_json otherwise
+fn parse_json_uncached(input: &str) -> Result<Value, String> {
+    check_parse_limits(input)?;
+    #[cfg(all(feature = "simd", not(target_arch = "wasm32")))]
+    if input.len() >= SIMD_MIN_BYTES {
+        let mut bytes = input.as_bytes().to_vec();
+        if let Ok(value) = simd_json::serde::from_slice::<Value>(&mut bytes) {
+            return Ok(value);
+        }
+        // simd-json errors carry no line/column, so fall through for the message
     }
 
-    let field_type = parts[idx].to_string();
-    let name = parts[idx + 1].to_string();
+    serde_json::from_str(input).map_err(|e| format!("Invalid JSON: {}", e))
+}
 
-    // Parse field number (format: "= number;")
-    let number_str = parts.get(idx + 3)?.trim_end_matches(';').trim();
-    let number = number_str.parse::<i32>().ok()?;
+/// Inputs at least this large report progress and can be cancelled
+const PROGRESS_MIN_BYTES: usize = 16 * 1024 * 1024;
+/// Bytes processed between progress events and cancel checks
+const PROGRESS_INTERVAL_BYTES: usize = 4 * 1024 * 1024;
+
+/// Set once the app starts so commands can emit events
+static APP_HANDLE: OnceLock<tauri::AppHandle> = OnceLock::new();
+static CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Progress of a long-running command, emitted as the `format-progress` event
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct OperationProgress {
+    operation: String,
+    phase: String,
+    processed_bytes: usize,
+    total_bytes: usize,
+}
 
-    Some(ProtoField {
-        field_type,
-        name,
-        number,
-        is_repeated,
-    })
+/// Ask the running long operation to stop at its next progress check
+#[tauri::command]
+fn cancel_operation() {
+    info!("cancel_operation called");
+    CANCEL_REQUESTED.store(true, Ordering::SeqCst);
 }
 
-fn proto_message_to_json(
-    message: &ProtoMessage,
-    all_messages: &[ProtoMessage],
-) -> Result<Value, String> {
-    let mut map = serde_json::Map::new();
+/// Counts the bytes flowing through a reader or writer, emitting progress and
+/// failing with "Operation cancelled" once a cancel is requested
+struct ProgressTracker<T> {
+    inner: T,
+    operation: &'static str,
+    phase: &'static str,
+    processed: usize,
+    total: usize,
+    next_report: usize,
+}
 
-    for field in &message.fields {
-        let value = proto_field_to_json_value(&field, all_messages)?;
-        map.insert(field.name.clone(), value);
+impl<T> ProgressTracker<T> {
+    fn new(inner: T, operation: &'static str, phase: &'static str, total: usize) -> Self {
+        ProgressTracker {
+            inner,
+            operation,
+            phase,
+            processed: 0,
+            total,
+            next_report: 0,
+        }
     }
 
-    Ok(Value::Object(map))
-}
+    fn advance(&mut self, bytes: usize) -> std::io::Result<()> {
+        self.processed += bytes;
+        if self.processed < self.next_report {
+            return Ok(());
+        }
+        self.next_report = self.processed + PROGRESS_INTERVAL_BYTES;
+        if CANCEL_REQUESTED.load(Ordering::SeqCst) {
+            return Err(std::io::Error::other("Operation cancelled"));
+        }
 
-fn proto_field_to_json_value(
-    field: &ProtoField,
-    all_messages: &[ProtoMessage],
-) -> Result<Value, String> {
-    let base_value = match field.field_type.as_str() {
-        "string" => Value::String("".to_string()),
-        "int32" | "int64" | "uint32" | "uint64" | "sint32" | "sint64" | "fixed32" | "fixed64"
-        | "sfixed32" | "sfixed64" => Value::Number(serde_json::Number::from(0)),
-        "float" | "double" => Value::Number(serde_json::Number::from_f64(0.0).unwrap()),
-        "bool" => Value::Bool(false),
-        "bytes" => Value::String("".to_string()),
-        _ => {
-            // Check if it's a nested message type
-            if let Some(nested_msg) = all_messages.iter().find(|m| m.name == field.field_type) {
-                proto_message_to_json(nested_msg, all_messages)?
-            } else {
-                Value::Null
+        if let Some(app) = APP_HANDLE.get() {
+            let progress = OperationProgress {
+                operation: self.operation.to_string(),
+                phase: self.phase.to_string(),
+                processed_bytes: self.processed,
+                total_bytes: self.total,
+            };
+            if let Err(e) = app.emit("format-progress", progress) {
+                warn!("{}: failed to emit progress - {}", self.operation, e);
             }
         }
-    };
+        Ok(())
+    }
+}
 
-    if field.is_repeated {
-        Ok(Value::Array(vec![base_value]))
-    } else {
-        Ok(base_value)
+impl<R: Read> Read for ProgressTracker<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.advance(read)?;
+        Ok(read)
     }
 }
 
-fn generate_proto_message(
+impl<W: Write> Write for ProgressTracker<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.advance(written)?;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Parse a JSON document through the parse cache; large inputs stream through a
+/// `ProgressTracker`
+fn parse_json_tracked(operation: &'static str, input: &str) -> Result<Arc<Value>, String> {
+    if input.len() < PROGRESS_MIN_BYTES {
+        return parse_json(input);
+    }
+
+    PARSE_CACHE.get_or_parse(input, |input| {
+        check_parse_limits(input)?;
+        let tracker = ProgressTracker::new(input.as_bytes(), operation, "parsing", input.len());
+        serde_json::from_reader(std::io::BufReader::with_capacity(64 * 1024, tracker)).map_err(
+            |e| {
+                if e.is_io() {
+                    e.to_string()
+                } else {
+                    format!("Invalid JSON: {}", e)
+                }
+            },
+        )
+    })
+}
+
+/// `serialize_json` for large documents: the output streams through a `ProgressTracker`
+fn serialize_json_tracked(
+    operation: &'static str,
     value: &Value,
-    message_name: &str,
-    output: &mut String,
-    counter: &mut i32,
-    indent: usize,
-) {
-    let indent_str = "  ".repeat(indent);
+    pretty: bool,
+    options: &OutputOptions,
+    estimated_bytes: usize,
+) -> Result<String, String> {
+    if estimated_bytes < PROGRESS_MIN_BYTES {
+        return serialize_json(value, pretty, options);
+    }
 
-    if let Value::Object(map) = value {
-        output.push_str(&format!("{}message {} {{\n", indent_str, message_name));
+    let buffer = Vec::with_capacity(estimated_bytes);
+    let mut tracker = ProgressTracker::new(buffer, operation, "serializing", estimated_bytes);
+    write_json(&mut tracker, value, pretty, options).map_err(|e| {
+        if e.is_io() {
+            e.to_string()
+        } else {
+            format!("Failed to serialize: {}", e)
+        }
+    })?;
+    let text =
+        String::from_utf8(tracker.inner).map_err(|e| format!("Failed to serialize: {}", e))?;
+    Ok(apply_output_options(text, options))
+}
 
-        let mut field_number = 1;
-        let mut nested_messages = Vec::new();
+/// Minify JSON by removing all unnecessary whitespace
+#[tauri::command(async)]
+fn minify_json(
+    input: String,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    info!("minify_json called - input_len: {}", input.len());
 
-        for (key, val) in map {
-            let field_name = to_snake_case(key);
-            let (field_type, nested_msg) = infer_proto_type(val, key, counter);
+    if input.trim().is_empty() {
+        warn!("minify_json: Input is empty");
+        return Err("Input is empty".to_string());
+    }
 
-            output.push_str(&format!(
-                "{}  {} {} = {};\n",
-                indent_str, field_type, field_name, field_number
-            ));
+    check_job_memory("minify_json", input.len())?;
+    let mut timer = OperationTimer::start("minify_json", input.len());
+    CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+    let parsed = parse_json_tracked("minify_json", &input).map_err(|e| {
+        error!("minify_json: {}", e);
+        e
+    })?;
+    timer.parsed();
 
-            if let Some(msg) = nested_msg {
-                nested_messages.push((msg, val.clone()));
-            }
+    let options = output_options.unwrap_or_default();
+    let result = serialize_json_tracked("minify_json", &parsed, false, &options, input.len())
+        .map_err(|e| {
+            error!("minify_json: Failed to minify - {}", e);
+            e
+        })?;
 
-            field_number += 1;
+    info!("minify_json: Success - output_len: {}", result.len());
+    Ok(timer.finish(result))
+}
+
+/// Format JSON with pretty printing (indented)
+#[tauri::command(async)]
+fn format_json(
+    input: String,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    if input.trim().is_empty() {
+        return Err("Input is empty".to_string());
+    }
+
+    check_job_memory("format_json", input.len())?;
+    let mut timer = OperationTimer::start("format_json", input.len());
+    CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+    let parsed = parse_json_tracked("format_json", &input)?;
+    timer.parsed();
+
+    let options = output_options.unwrap_or_default();
+    let result = serialize_json_tracked("format_json", &parsed, true, &options, input.len())?;
+    Ok(timer.finish(result))
+}
+
+/// Pretty-print a batch of messages from the stream viewer. Messages that aren't JSON come
+/// back as `None` and are shown as received; only indent and key order apply to the log.
+#[tauri::command(async)]
+fn format_stream_messages(
+    messages: Vec<String>,
+    output_options: Option<OutputOptions>,
+) -> Vec<Option<String>> {
+    let options = output_options.unwrap_or_default();
+    messages
+        .iter()
+        .map(|message| {
+            check_parse_limits(message).ok()?;
+            let value: Value = serde_json::from_str(message).ok()?;
+            let mut buffer = Vec::new();
+            write_json(&mut buffer, &value, true, &options).ok()?;
+            String::from_utf8(buffer).ok()
+        })
+        .collect()
+}
+
+/// Pull parser behind `format_json_file` and `minify_json_file`: tokens are validated and
+/// copied straight from `input` to `output`, so memory use is bounded by the nesting depth.
+/// Strings and numbers are written exactly as they appear in the input, and keys keep their
+/// input order even when `sort_keys` is set.
+struct JsonStreamFormatter<R: Read, W: Write> {
+    input: R,
+    buffer: Vec<u8>,
+    start: usize,
+    end: usize,
+    line: usize,
+    column: usize,
+    output: W,
+    pretty: bool,
+    newline: &'static str,
+    indent: &'static str,
+    stack: Vec<u8>,
+    number: Vec<u8>,
+}
+
+impl<R: Read, W: Write> JsonStreamFormatter<R, W> {
+    fn new(input: R, output: W, pretty: bool, options: &OutputOptions) -> Self {
+        JsonStreamFormatter {
+            input,
+            buffer: vec![0; 64 * 1024],
+            start: 0,
+            end: 0,
+            line: 1,
+            column: 0,
+            output,
+            pretty,
+            newline: match options.line_ending {
+                LineEnding::Lf => "\n",
+                LineEnding::Crlf => "\r\n",
+            },
+            indent: options.indent.as_str(),
+            stack: Vec::new(),
+            number: Vec::new(),
         }
+    }
 
-        output.push_str(&format!("{}}}\n", indent_str));
+    fn peek(&mut self) -> Result<Option<u8>, String> {
+        if self.start == self.end {
+            self.end = self
+                .input
+                .read(&mut self.buffer)
+                .map_err(|e| e.to_string())?;
+            self.start = 0;
+        }
+        Ok(self.buffer[self.start..self.end].first().copied())
+    }
 
-        // Generate nested messages
-        for (msg_name, msg_value) in nested_messages {
-            output.push('\n');
-            if msg_value.is_object() {
-                generate_proto_message(&msg_value, &msg_name, output, counter, indent);
-            } else if let Value::Array(arr) = msg_value {
-                if let Some(first) = arr.first() {
-                    if first.is_object() {
-                        generate_proto_message(first, &msg_name, output, counter, indent);
-                    }
-                }
+    fn bump(&mut self) {
+        if self.buffer[self.start] == b'\n' {
+            self.line += 1;
+            self.column = 0;
+        } else {
+            self.column += 1;
+        }
+        self.start += 1;
+    }
+
+    /// Consume the next byte, failing with `eof` at the end of the input
+    fn next_byte(&mut self, eof: &str) -> Result<u8, String> {
+        let byte = self.peek()?.ok_or_else(|| self.error(eof))?;
+        self.bump();
+        Ok(byte)
+    }
+
+    fn skip_whitespace(&mut self) -> Result<Option<u8>, String> {
+        while let Some(byte) = self.peek()? {
+            if !matches!(byte, b' ' | b'\t' | b'\n' | b'\r') {
+                return Ok(Some(byte));
             }
+            self.bump();
         }
+        Ok(None)
     }
-}
 
-fn infer_proto_type(
-    value: &Value,
-    field_name: &str,
-    counter: &mut i32,
-) -> (String, Option<String>) {
-    match value {
-        Value::Null => ("string".to_string(), None),
-        Value::Bool(_) => ("bool".to_string(), None),
-        Value::Number(n) => {
-            if n.is_f64() {
-                ("double".to_string(), None)
-            } else if n.is_i64() {
-                let num = n.as_i64().unwrap();
-                if num >= i32::MIN as i64 && num <= i32::MAX as i64 {
-                    ("int32".to_string(), None)
-                } else {
-                    ("int64".to_string(), None)
+    fn error(&self, message: &str) -> String {
+        format!(
+            "Invalid JSON: {} at line {} column {}",
+            message, self.line, self.column
+        )
+    }
+
+    /// Report an error at the byte about to be read
+    fn unexpected(&mut self, message: &str) -> String {
+        self.bump();
+        self.error(message)
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), String> {
+        self.output
+            .write_all(bytes)
+            .map_err(|e| format!("Failed to write output: {}", e))
+    }
+
+    fn write_indent(&mut self) -> Result<(), String> {
+        if self.pretty {
+            self.write(self.newline.as_bytes())?;
+            for _ in 0..self.stack.len() {
+                self.write(self.indent.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Stream the whole document and return the output writer
+    fn run(mut self, options: &OutputOptions) -> Result<W, String> {
+        if self.peek()? == Some(0xEF) {
+            for expected in [0xEF, 0xBB, 0xBF] {
+                if self.next_byte("EOF while parsing a value")? != expected {
+                    return Err(self.error("expected value"));
                 }
-            } else {
-                ("uint64".to_string(), None)
             }
         }
-        Value::String(_) => ("string".to_string(), None),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                ("repeated string".to_string(), None)
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    *counter += 1;
-                    let nested_name = to_pascal_case(field_name);
-                    (format!("repeated {}", nested_name), Some(nested_name))
-                } else {
-                    let (inner_type, _) = infer_proto_type(first, field_name, counter);
-                    let base_type = inner_type.replace("repeated ", "");
-                    (format!("repeated {}", base_type), None)
+        if options.bom {
+            self.write("\u{feff}".as_bytes())?;
+        }
+
+        loop {
+            match self.skip_whitespace()? {
+                Some(open @ (b'{' | b'[')) => {
+                    self.bump();
+                    self.write(&[open])?;
+                    let close = if open == b'{' { b'}' } else { b']' };
+                    if self.skip_whitespace()? == Some(close) {
+                        self.bump();
+                        self.write(&[close])?;
+                    } else {
+                        self.stack.push(open);
+                        self.write_indent()?;
+                        if open == b'{' {
+                            self.key()?;
+                        }
+                        continue;
+                    }
+                }
+                Some(b'"') => self.string()?,
+                Some(b'-' | b'0'..=b'9') => self.number()?,
+                Some(b't') => self.literal(b"true")?,
+                Some(b'f') => self.literal(b"false")?,
+                Some(b'n') => self.literal(b"null")?,
+                Some(_) => return Err(self.unexpected("expected value")),
+                None => return Err(self.error("EOF while parsing a value")),
+            }
+
+            // A value is complete: close finished containers until a comma starts the next one
+            loop {
+                let Some(&open) = self.stack.last() else {
+                    if self.skip_whitespace()?.is_some() {
+                        return Err(self.unexpected("trailing characters"));
+                    }
+                    if options.final_newline {
+                        self.write(self.newline.as_bytes())?;
+                    }
+                    return Ok(self.output);
+                };
+                let close = if open == b'{' { b'}' } else { b']' };
+                match self.skip_whitespace()? {
+                    Some(b',') => {
+                        self.bump();
+                        self.write(b",")?;
+                        if self.skip_whitespace()? == Some(close) {
+                            return Err(self.unexpected("trailing comma"));
+                        }
+                        self.write_indent()?;
+                        if open == b'{' {
+                            self.key()?;
+                        }
+                        break;
+                    }
+                    Some(byte) if byte == close => {
+                        self.bump();
+                        self.stack.pop();
+                        self.write_indent()?;
+                        self.write(&[close])?;
+                    }
+                    Some(_) if open == b'{' => return Err(self.unexpected("expected `,` or `}`")),
+                    Some(_) => return Err(self.unexpected("expected `,` or `]`")),
+                    None if open == b'{' => return Err(self.error("EOF while parsing an object")),
+                    None => return Err(self.error("EOF while parsing a list")),
                 }
             }
         }
-        Value::Object(_) => {
-            *counter += 1;
-            let nested_name = to_pascal_case(field_name);
-            (nested_name.clone(), Some(nested_name))
+    }
+
+    fn key(&mut self) -> Result<(), String> {
+        match self.skip_whitespace()? {
+            Some(b'"') => self.string()?,
+            Some(_) => return Err(self.unexpected("key must be a string")),
+            None => return Err(self.error("EOF while parsing an object")),
+        }
+        match self.skip_whitespace()? {
+            Some(b':') => self.bump(),
+            Some(_) => return Err(self.unexpected("expected `:`")),
+            None => return Err(self.error("EOF while parsing an object")),
         }
+        self.write(if self.pretty { b": " } else { b":" })
     }
-}
 
-fn to_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    let mut prev_is_upper = false;
+    fn string(&mut self) -> Result<(), String> {
+        const EOF: &str = "EOF while parsing a string";
+        self.bump();
+        self.write(b"\"")?;
+        loop {
+            if self.peek()?.is_none() {
+                return Err(self.error(EOF));
+            }
+            // Copy the run of plain ASCII up to the next byte that needs a closer look
+            let remaining = self.end - self.start;
+            let run = self.buffer[self.start..self.end]
+                .iter()
+                .position(|&b| b == b'"' || b == b'\\' || !(0x20..0x80).contains(&b))
+                .unwrap_or(remaining);
+            self.output
+                .write_all(&self.buffer[self.start..self.start + run])
+                .map_err(|e| format!("Failed to write output: {}", e))?;
+            self.start += run;
+            self.column += run;
+            if run == remaining {
+                continue;
+            }
 
-    for (i, c) in s.chars().enumerate() {
-        if c.is_uppercase() {
-            if i > 0 && !prev_is_upper {
-                result.push('_');
+            let byte = self.buffer[self.start];
+            match byte {
+                b'"' => {
+                    self.bump();
+                    return self.write(b"\"");
+                }
+                b'\\' => {
+                    self.bump();
+                    let escape = self.next_byte(EOF)?;
+                    self.write(&[b'\\', escape])?;
+                    match escape {
+                        b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' => {}
+                        b'u' => {
+                            for _ in 0..4 {
+                                let digit = self.next_byte(EOF)?;
+                                if !digit.is_ascii_hexdigit() {
+                                    return Err(self.error("invalid escape"));
+                                }
+                                self.write(&[digit])?;
+                            }
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    }
+                }
+                0x00..=0x1F => {
+                    return Err(self.unexpected(
+                        "control character (\\u0000-\\u001F) found while parsing a string",
+                    ))
+                }
+                _ => {
+                    let continuation = match byte {
+                        0xC2..=0xDF => 1,
+                        0xE0..=0xEF => 2,
+                        0xF0..=0xF4 => 3,
+                        _ => return Err(self.unexpected("invalid unicode code point")),
+                    };
+                    // RFC 3629 section 4: the second byte rules out overlong forms,
+                    // surrogates and code points past U+10FFFF
+                    let second = match byte {
+                        0xE0 => 0xA0..=0xBF,
+                        0xED => 0x80..=0x9F,
+                        0xF0 => 0x90..=0xBF,
+                        0xF4 => 0x80..=0x8F,
+                        _ => 0x80..=0xBF,
+                    };
+                    self.bump();
+                    self.write(&[byte])?;
+                    for i in 0..continuation {
+                        let next = self.next_byte(EOF)?;
+                        let valid = if i == 0 { second.clone() } else { 0x80..=0xBF };
+                        if !valid.contains(&next) {
+                            return Err(self.error("invalid unicode code point"));
+                        }
+                        self.write(&[next])?;
+                    }
+                }
             }
-            result.push(c.to_lowercase().next().unwrap());
-            prev_is_upper = true;
-        } else {
-            result.push(c);
-            prev_is_upper = false;
         }
     }
 
-    result
-}
+    fn number(&mut self) -> Result<(), String> {
+        self.number.clear();
+        while let Some(byte @ (b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')) = self.peek()? {
+            self.bump();
+            self.number.push(byte);
+        }
+        if !is_json_number(&self.number) {
+            return Err(self.error("invalid number"));
+        }
+        self.output
+            .write_all(&self.number)
+            .map_err(|e| format!("Failed to write output: {}", e))
+    }
 
-fn to_pascal_case(s: &str) -> String {
-    let s = s.replace('_', " ");
-    s.split_whitespace()
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+    fn literal(&mut self, word: &'static [u8]) -> Result<(), String> {
+        for &expected in word {
+            if self.next_byte("EOF while parsing a value")? != expected {
+                return Err(self.error("expected ident"));
             }
-        })
-        .collect()
+        }
+        self.write(word)
+    }
 }
 
-fn to_camel_case(s: &str) -> String {
-    let pascal = to_pascal_case(s);
-    let mut chars = pascal.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+/// Whether `bytes` matches the JSON number grammar
+fn is_json_number(bytes: &[u8]) -> bool {
+    fn digits(bytes: &[u8], mut i: usize) -> usize {
+        while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+        }
+        i
+    }
+
+    let mut i = usize::from(bytes.first() == Some(&b'-'));
+    match bytes.get(i) {
+        Some(b'0') => i += 1,
+        Some(b'1'..=b'9') => i = digits(bytes, i),
+        _ => return false,
     }
+    if bytes.get(i) == Some(&b'.') {
+        let end = digits(bytes, i + 1);
+        if end == i + 1 {
+            return false;
+        }
+        i = end;
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        let end = digits(bytes, i);
+        if end == i {
+            return false;
+        }
+        i = end;
+    }
+    i == bytes.len()
 }
 
-/// Convert JSON to class definition in various programming languages
-#[tauri::command]
-fn json_to_class(input: String, language: String, name: String) -> Result<String, String> {
+/// Format or minify `input_path` into `output_path` without loading the document into memory;
+/// returns the size of the written file
+fn stream_json_file(
+    operation: &'static str,
+    input_path: &str,
+    output_path: &str,
+    pretty: bool,
+    options: &OutputOptions,
+) -> Result<Timed<u64>, String> {
     info!(
-        "json_to_class called - language: {}, class_name: '{}', input_len: {}",
-        language,
-        name,
-        input.len()
+        "{} called - input: '{}', output: '{}'",
+        operation, input_path, output_path
     );
 
-    if input.trim().is_empty() {
-        warn!("json_to_class: Input is empty");
-        return Err("Input is empty".to_string());
+    let input = fs::File::open(input_path)
+        .map_err(|e| format!("Failed to open '{}': {}", input_path, e))?;
+    let total = input.metadata().map(|m| m.len() as usize).unwrap_or(0);
+    if let (Ok(source), Ok(target)) = (fs::canonicalize(input_path), fs::canonicalize(output_path))
+    {
+        if source == target {
+            return Err("Output file must differ from the input file".to_string());
+        }
+    }
+    let output = fs::File::create(output_path)
+        .map_err(|e| format!("Failed to create '{}': {}", output_path, e))?;
+
+    let timer = OperationTimer::start(operation, total);
+    CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+    let reader = ProgressTracker::new(input, operation, "streaming", total);
+    let writer = std::io::BufWriter::with_capacity(64 * 1024, output);
+    let result = JsonStreamFormatter::new(reader, writer, pretty, options)
+        .run(options)
+        .and_then(|mut writer| {
+            writer
+                .flush()
+                .map_err(|e| format!("Failed to write '{}': {}", output_path, e))
+        });
+    if let Err(e) = result {
+        error!("{}: {}", operation, e);
+        let _ = fs::remove_file(output_path);
+        return Err(e);
     }
 
-    let parsed: Value = serde_json::from_str(&input).map_err(|e| {
-        error!("json_to_class: Failed to parse JSON - {}", e);
-        format!("Invalid JSON: {}", e)
-    })?;
+    let written = fs::metadata(output_path)
+        .map(|m| m.len())
+        .map_err(|e| format!("Failed to read '{}': {}", output_path, e))?;
+    Ok(timer.finish(written))
+}
 
-    let final_class_name = if name.is_empty() {
-        "Root".to_string()
-    } else {
-        name
-    };
+/// Pretty-print a JSON file into another file, streaming so files larger than memory work
+#[tauri::command(async)]
+fn format_json_file(
+    input_path: String,
+    output_path: String,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<u64>, String> {
+    let options = output_options.unwrap_or_default();
+    stream_json_file(
+        "format_json_file",
+        &input_path,
+        &output_path,
+        true,
+        &options,
+    )
+}
 
-    info!(
-        "json_to_class: Converting to {} with class name '{}'",
-        language, final_class_name
-    );
+/// Minify a JSON file into another file, streaming so files larger than memory work
+#[tauri::command(async)]
+fn minify_json_file(
+    input_path: String,
+    output_path: String,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<u64>, String> {
+    let options = output_options.unwrap_or_default();
+    stream_json_file(
+        "minify_json_file",
+        &input_path,
+        &output_path,
+        false,
+        &options,
+    )
+}
 
-    let result = match language.to_lowercase().as_str() {
-        "typescript" => generate_typescript_class(&parsed, &final_class_name),
-        "javascript" => generate_javascript_class(&parsed, &final_class_name),
-        "python" => generate_python_class(&parsed, &final_class_name),
-        "rust" => generate_rust_struct(&parsed, &final_class_name),
-        "java" => generate_java_class(&parsed, &final_class_name),
-        "csharp" | "c#" => generate_csharp_class(&parsed, &final_class_name),
-        "go" => generate_go_struct(&parsed, &final_class_name),
-        "kotlin" => generate_kotlin_class(&parsed, &final_class_name),
-        "swift" => generate_swift_struct(&parsed, &final_class_name),
-        _ => {
-            error!("json_to_class: Unsupported language: {}", language);
-            Err(format!("Unsupported language: {}", language))
-        }
-    };
+/// Conversion applied to every file of a batch
+#[derive(
+    Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+enum BatchOperation {
+    Format,
+    Minify,
+    Csv,
+}
 
-    match &result {
-        Ok(output) => {
-            info!(
-                "json_to_class: Successfully generated {} code ({} chars)",
-                language,
-                output.len()
-            );
-            debug!("Generated code:\n{}", output);
-        }
-        Err(e) => {
-            error!(
-                "json_to_class: Failed to generate {} code - {}",
-                language, e
-            );
+impl BatchOperation {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "format" => Some(BatchOperation::Format),
+            "minify" => Some(BatchOperation::Minify),
+            "csv" => Some(BatchOperation::Csv),
+            _ => None,
         }
     }
 
-    result
+    fn extension(self) -> &'static str {
+        match self {
+            BatchOperation::Format | BatchOperation::Minify => "json",
+            BatchOperation::Csv => "csv",
+        }
+    }
+
+    /// Files are parsed and written directly: batch jobs run side by side on worker
+    /// threads, so they stay out of the parse cache, operation timings, progress events
+    /// and the cancel flag that belong to the editor's commands
+    fn apply(self, input: String, options: &OutputOptions) -> Result<String, String> {
+        if input.trim().is_empty() {
+            return Err("Input is empty".to_string());
+        }
+        check_job_memory("batch_process", input.len())?;
+        let value = parse_json_uncached(&input)?;
+        match self {
+            BatchOperation::Format => serialize_json(&value, true, options),
+            BatchOperation::Minify => serialize_json(&value, false, options),
+            BatchOperation::Csv => {
+                csv_from_value(&value, None).map(|csv| apply_output_options(csv, options))
+            }
+        }
+    }
 }
 
-fn generate_typescript_class(value: &Value, class_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output = format!("interface {} {{\n", class_name);
-        let mut nested_interfaces = Vec::new();
+/// Outcome for one file of a batch; `error` is set when it failed
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct BatchFileResult {
+    input: String,
+    output: Option<String>,
+    error: Option<String>,
+}
 
-        for (key, val) in map {
-            let ts_type = infer_typescript_type(val, key, &mut nested_interfaces);
-            output.push_str(&format!("  {}: {};\n", key, ts_type));
-        }
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct BatchReport {
+    succeeded: usize,
+    failed: usize,
+    elapsed_ms: f64,
+    files: Vec<BatchFileResult>,
+}
 
-        output.push_str("}\n");
+/// Convert every `.json` file directly inside `input_dir` into `output_dir`, spreading the
+/// files over `worker_threads` threads
+fn run_batch(
+    input_dir: &Path,
+    output_dir: &Path,
+    operation: BatchOperation,
+    options: &OutputOptions,
+) -> Result<BatchReport, String> {
+    use rayon::prelude::*;
+
+    let started = Instant::now();
+    let mut inputs: Vec<PathBuf> = fs::read_dir(input_dir)
+        .map_err(|e| format!("Failed to read '{}': {}", input_dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    inputs.sort();
+    fs::create_dir_all(output_dir)
+        .map_err(|e| format!("Failed to create '{}': {}", output_dir.display(), e))?;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(concurrency_settings().worker_threads)
+        .build()
+        .map_err(|e| format!("Failed to start worker threads: {}", e))?;
+    let files: Vec<BatchFileResult> = pool.install(|| {
+        inputs
+            .par_iter()
+            .map(|input| {
+                let output = output_dir
+                    .join(input.file_stem().unwrap_or_default())
+                    .with_extension(operation.extension());
+                let result = if output == input.as_path() {
+                    Err("Output file would overwrite the input".to_string())
+                } else {
+                    fs::read(input)
+                        .map_err(|e| format!("Failed to read: {}", e))
+                        .and_then(|bytes| {
+                            decode_text(&bytes)
+                                .map(|(text, _)| text)
+                                .ok_or_else(|| "Not text in a supported encoding".to_string())
+                        })
+                        .and_then(|text| operation.apply(text, options))
+                        .and_then(|text| {
+                            fs::write(&output, text).map_err(|e| format!("Failed to write: {}", e))
+                        })
+                };
+                BatchFileResult {
+                    input: input.display().to_string(),
+                    output: result.is_ok().then(|| output.display().to_string()),
+                    error: result.err(),
+                }
+            })
+            .collect()
+    });
+
+    let failed = files.iter().filter(|file| file.error.is_some()).count();
+    Ok(BatchReport {
+        succeeded: files.len() - failed,
+        failed,
+        elapsed_ms: started.elapsed().as_secs_f64() * 1000.0,
+        files,
+    })
+}
 
-        for (name, nested_val) in nested_interfaces {
-            output.push('\n');
-            output.push_str(&generate_typescript_class(&nested_val, &name)?);
-        }
+/// Format, minify or convert to CSV every JSON file in a directory, in parallel
+#[tauri::command(async)]
+fn batch_process(
+    input_dir: String,
+    output_dir: String,
+    operation: BatchOperation,
+    output_options: Option<OutputOptions>,
+) -> Result<BatchReport, String> {
+    info!(
+        "batch_process called - {:?}: '{}' -> '{}'",
+        operation, input_dir, output_dir
+    );
 
-        Ok(output)
-    } else {
-        Err("Input must be a JSON object".to_string())
-    }
+    let report = run_batch(
+        Path::new(&input_dir),
+        Path::new(&output_dir),
+        operation,
+        &output_options.unwrap_or_default(),
+    )?;
+    info!(
+        "batch_process: {} succeeded, {} failed in {:.0} ms",
+        report.succeeded, report.failed, report.elapsed_ms
+    );
+    Ok(report)
 }
 
-fn infer_typescript_type(
-    value: &Value,
-    field_name: &str,
-    nested: &mut Vec<(String, Value)>,
-) -> String {
-    match value {
-        Value::Null => "any".to_string(),
-        Value::Bool(_) => "boolean".to_string(),
-        Value::Number(n) => {
-            if n.is_f64() {
-                "number".to_string()
-            } else {
-                "number".to_string()
-            }
-        }
-        Value::String(_) => "string".to_string(),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                "any[]".to_string()
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    let nested_name = to_pascal_case(field_name);
-                    nested.push((nested_name.clone(), first.clone()));
-                    format!("{}[]", nested_name)
-                } else {
-                    format!("{}[]", infer_typescript_type(first, field_name, nested))
+/// `json-formatter batch <format|minify|csv> <input-dir> <output-dir>`; returns the exit code
+fn run_batch_cli(args: &[String]) -> i32 {
+    let [operation, input_dir, output_dir] = args else {
+        eprintln!("Usage: json-formatter batch <format|minify|csv> <input-dir> <output-dir>");
+        return 2;
+    };
+    let Some(operation) = BatchOperation::parse(operation) else {
+        eprintln!("Unknown batch operation '{}'", operation);
+        return 2;
+    };
+
+    match run_batch(
+        Path::new(input_dir),
+        Path::new(output_dir),
+        operation,
+        &OutputOptions::default(),
+    ) {
+        Ok(report) => {
+            for file in &report.files {
+                match (&file.output, &file.error) {
+                    (Some(output), _) => println!("ok    {} -> {}", file.input, output),
+                    (None, Some(error)) => println!("FAIL  {}: {}", file.input, error),
+                    (None, None) => {}
                 }
             }
+            println!(
+                "{} succeeded, {} failed in {:.0} ms",
+                report.succeeded, report.failed, report.elapsed_ms
+            );
+            i32::from(report.failed > 0)
         }
-        Value::Object(_) => {
-            let nested_name = to_pascal_case(field_name);
-            nested.push((nested_name.clone(), value.clone()));
-            nested_name
+        Err(e) => {
+            eprintln!("{}", e);
+            2
         }
     }
 }
 
-fn generate_javascript_class(value: &Value, class_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output = format!("class {} {{\n", class_name);
-        output.push_str("  constructor(data) {\n");
+/// Convert JSON to an escaped string (as a JSON string literal)
+#[tauri::command(async)]
+fn json_to_string(input: String) -> Result<Timed<String>, String> {
+    if input.trim().is_empty() {
+        return Err("Input is empty".to_string());
+    }
 
-        for (key, _) in map {
-            output.push_str(&format!("    this.{} = data.{};\n", key, key));
-        }
+    check_job_memory("json_to_string", input.len())?;
 
-        output.push_str("  }\n");
-        output.push_str("}\n");
+    // Validate that input is valid JSON first
+    let mut timer = OperationTimer::start("json_to_string", input.len());
+    parse_json(&input)?;
+    timer.parsed();
 
-        Ok(output)
-    } else {
-        Err("Input must be a JSON object".to_string())
-    }
+    // Convert the JSON to an escaped string
+    let escaped = serde_json::to_string(&input).map_err(|e| format!("Failed to convert: {}", e))?;
+    Ok(timer.finish(escaped))
 }
 
-fn generate_python_class(value: &Value, class_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output = String::from(
-            "from dataclasses import dataclass\nfrom typing import List, Optional, Any\n\n",
-        );
-        let mut nested_classes = Vec::new();
-
-        output.push_str("@dataclass\n");
-        output.push_str(&format!("class {}:\n", class_name));
+/// Forms the output can be copied in
+#[derive(
+    Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum CopyForm {
+    Formatted,
+    Minified,
+    /// Minified JSON as a JSON string literal
+    Escaped,
+    /// Minified JSON encoded as base64
+    Base64,
+    /// Minified JSON as an `application/json` base64 data URI
+    DataUri,
+}
 
-        for (key, val) in map {
-            let py_type = infer_python_type(val, key, &mut nested_classes);
-            output.push_str(&format!("    {}: {}\n", to_snake_case(key), py_type));
-        }
+/// Render a document in one of the copy forms. Only the formatted form follows the
+/// output options; the others are plain compact JSON without a BOM, CRLF or final
+/// newline, since they get embedded in code, URLs and headers.
+#[tauri::command(async)]
+fn copy_output_as(
+    input: String,
+    form: CopyForm,
+    output_options: Option<OutputOptions>,
+) -> Result<String, String> {
+    if input.trim().is_empty() {
+        return Err("Nothing to copy".to_string());
+    }
+    check_job_memory("copy_output_as", input.len())?;
+    let parsed = parse_json(&input)?;
+    let options = output_options.unwrap_or_default();
+    if form == CopyForm::Formatted {
+        return serialize_json(&parsed, true, &options);
+    }
 
-        for (name, nested_val) in nested_classes {
-            output.push('\n');
-            output.push_str(&generate_python_class(&nested_val, &name)?);
+    let minified =
+        serde_json::to_string(&*parsed).map_err(|e| format!("Failed to convert: {}", e))?;
+    Ok(match form {
+        CopyForm::Escaped => {
+            serde_json::to_string(&minified).map_err(|e| format!("Failed to convert: {}", e))?
         }
+        CopyForm::Base64 => BASE64.encode(minified),
+        CopyForm::DataUri => format!("data:application/json;base64,{}", BASE64.encode(minified)),
+        CopyForm::Formatted | CopyForm::Minified => minified,
+    })
+}
 
-        Ok(output)
-    } else {
-        Err("Input must be a JSON object".to_string())
+/// Convert an array of objects to CSV; `columns` fixes the column order, otherwise the
+/// header is every key seen across the rows
+#[tauri::command(async)]
+fn json_to_csv(
+    input: String,
+    columns: Option<Vec<String>>,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    if input.trim().is_empty() {
+        return Err("Input is empty".to_string());
     }
+
+    check_job_memory("json_to_csv", input.len())?;
+    let mut timer = OperationTimer::start("json_to_csv", input.len());
+    let value = parse_json(&input)?;
+    timer.parsed();
+
+    let csv = csv_from_value(&value, columns)?;
+    let result = apply_output_options(csv, &output_options.unwrap_or_default());
+    Ok(timer.finish(result))
 }
 
-fn infer_python_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
-    match value {
-        Value::Null => "Optional[Any]".to_string(),
-        Value::Bool(_) => "bool".to_string(),
-        Value::Number(n) => {
-            if n.is_f64() {
-                "float".to_string()
-            } else {
-                "int".to_string()
-            }
-        }
-        Value::String(_) => "str".to_string(),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                "List[Any]".to_string()
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    let nested_name = to_pascal_case(field_name);
-                    nested.push((nested_name.clone(), first.clone()));
-                    format!("List[{}]", nested_name)
-                } else {
-                    format!("List[{}]", infer_python_type(first, field_name, nested))
-                }
+/// CSV text for an array of objects; without `columns` every key seen becomes a column
+fn csv_from_value(value: &Value, columns: Option<Vec<String>>) -> Result<String, String> {
+    let rows = value
+        .as_array()
+        .and_then(|items| {
+            items
+                .iter()
+                .map(Value::as_object)
+                .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| "CSV export needs an array of objects".to_string())?;
+    let columns = columns.unwrap_or_else(|| {
+        let mut seen = Vec::new();
+        for key in rows.iter().flat_map(|row| row.keys()) {
+            if !seen.contains(key) {
+                seen.push(key.clone());
             }
         }
-        Value::Object(_) => {
-            let nested_name = to_pascal_case(field_name);
-            nested.push((nested_name.clone(), value.clone()));
-            nested_name
-        }
+        seen
+    });
+
+    let mut lines = vec![columns
+        .iter()
+        .map(|c| csv_field(c))
+        .collect::<Vec<_>>()
+        .join(",")];
+    for row in rows {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| match row.get(column) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(s)) => csv_field(s),
+                Some(other) => csv_field(&other.to_string()),
+            })
+            .collect();
+        lines.push(cells.join(","));
     }
+    Ok(lines.join("\n"))
 }
 
-fn generate_rust_struct(value: &Value, struct_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output = String::from("use serde::{Deserialize, Serialize};\n\n");
-        let mut nested_structs = Vec::new();
+/// What `json_to_markdown` produces
+#[derive(
+    Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum MarkdownMode {
+    /// The formatted JSON in a fenced ```json block
+    #[default]
+    CodeBlock,
+    /// A table with one row per object of an array of flat objects
+    Table,
+}
 
-        output.push_str("#[derive(Debug, Serialize, Deserialize)]\n");
-        output.push_str(&format!("pub struct {} {{\n", struct_name));
+/// Convert JSON to Markdown for pasting into issues and wikis
+#[tauri::command(async)]
+fn json_to_markdown(
+    input: String,
+    mode: Option<MarkdownMode>,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    if input.trim().is_empty() {
+        return Err("Input is empty".to_string());
+    }
 
-        for (key, val) in map {
-            let rust_type = infer_rust_type(val, key, &mut nested_structs);
-            output.push_str(&format!("    pub {}: {},\n", to_snake_case(key), rust_type));
+    check_job_memory("json_to_markdown", input.len())?;
+    let mut timer = OperationTimer::start("json_to_markdown", input.len());
+    let value = parse_json(&input)?;
+    timer.parsed();
+
+    let options = output_options.unwrap_or_default();
+    let markdown = match mode.unwrap_or_default() {
+        MarkdownMode::CodeBlock => {
+            let mut buffer = Vec::new();
+            write_json(&mut buffer, &value, true, &options)
+                .map_err(|e| format!("Failed to serialize: {}", e))?;
+            let json =
+                String::from_utf8(buffer).map_err(|e| format!("Failed to serialize: {}", e))?;
+            // The fence has to be longer than any run of backticks in the content
+            let longest_run = json.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+            let fence = "`".repeat(longest_run.max(2) + 1);
+            format!("{}json\n{}\n{}", fence, json, fence)
         }
+        MarkdownMode::Table => markdown_table(&value)?,
+    };
 
-        output.push_str("}\n");
+    let result = apply_output_options(markdown, &options);
+    Ok(timer.finish(result))
+}
 
-        for (name, nested_val) in nested_structs {
-            output.push('\n');
-            output.push_str(&generate_rust_struct(&nested_val, &name)?);
+fn markdown_table(value: &Value) -> Result<String, String> {
+    let not_flat = || {
+        "Markdown tables need an array of flat objects; use the code block mode for nested JSON"
+            .to_string()
+    };
+    let rows = value
+        .as_array()
+        .filter(|items| !items.is_empty())
+        .and_then(|items| {
+            items
+                .iter()
+                .map(Value::as_object)
+                .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(not_flat)?;
+    if rows
+        .iter()
+        .flat_map(|row| row.values())
+        .any(|v| v.is_object() || v.is_array())
+    {
+        return Err(not_flat());
+    }
+
+    let mut columns: Vec<&String> = Vec::new();
+    for key in rows.iter().flat_map(|row| row.keys()) {
+        if !columns.contains(&key) {
+            columns.push(key);
         }
+    }
+    let cell = |text: &str| {
+        text.replace('\\', "\\\\")
+            .replace('|', "\\|")
+            .replace("\r\n", "<br>")
+            .replace('\n', "<br>")
+    };
 
-        Ok(output)
-    } else {
-        Err("Input must be a JSON object".to_string())
+    let mut lines = vec![
+        format!(
+            "| {} |",
+            columns
+                .iter()
+                .map(|c| cell(c))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        ),
+        format!(
+            "|{}|",
+            columns
+                .iter()
+                .map(|column| {
+                    // Right-align columns that only hold numbers
+                    let numeric = rows
+                        .iter()
+                        .filter_map(|row| row.get(*column))
+                        .filter(|v| !v.is_null())
+                        .all(Value::is_number);
+                    if numeric {
+                        " ---: "
+                    } else {
+                        " --- "
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("|")
+        ),
+    ];
+    for row in rows {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| match row.get(*column) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(s)) => cell(s),
+                Some(other) => other.to_string(),
+            })
+            .collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
+    Ok(lines.join("\n"))
 }
 
-fn infer_rust_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
-    match value {
-        Value::Null => "Option<String>".to_string(),
-        Value::Bool(_) => "bool".to_string(),
-        Value::Number(n) => {
-            if n.is_f64() {
-                "f64".to_string()
-            } else {
-                "i64".to_string()
-            }
-        }
-        Value::String(_) => "String".to_string(),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                "Vec<serde_json::Value>".to_string()
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    let nested_name = to_pascal_case(field_name);
-                    nested.push((nested_name.clone(), first.clone()));
-                    format!("Vec<{}>", nested_name)
-                } else {
-                    format!("Vec<{}>", infer_rust_type(first, field_name, nested))
-                }
-            }
-        }
-        Value::Object(_) => {
-            let nested_name = to_pascal_case(field_name);
-            nested.push((nested_name.clone(), value.clone()));
-            nested_name
-        }
+/// Quote a CSV field when it contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
     }
 }
 
-fn generate_java_class(value: &Value, class_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output = String::from(
-            "import com.fasterxml.jackson.annotation.JsonProperty;\nimport java.util.List;\n\n",
-        );
-        let mut nested_classes = Vec::new();
+/// Convert an escaped string back to JSON (parse JSON string literal)
+#[tauri::command(async)]
+fn string_to_json(
+    input: String,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    let options = output_options.unwrap_or_default();
+    let trimmed = input.trim();
 
-        output.push_str(&format!("public class {} {{\n", class_name));
+    if trimmed.is_empty() {
+        return Err("Input is empty".to_string());
+    }
 
-        for (key, val) in map {
-            let java_type = infer_java_type(val, key, &mut nested_classes);
-            output.push_str(&format!("    @JsonProperty(\"{}\")\n", key));
-            output.push_str(&format!(
-                "    private {} {};\n\n",
-                java_type,
-                to_camel_case(key)
-            ));
-        }
+    check_job_memory("string_to_json", input.len())?;
+    let mut timer = OperationTimer::start("string_to_json", input.len());
+    let parsed = parse_escaped_json(trimmed)?;
+    timer.parsed();
 
-        // Generate getters and setters
-        for (key, val) in map {
-            let java_type = infer_java_type(val, key, &mut Vec::new());
-            let field_name = to_camel_case(key);
-            let getter_name = format!("get{}", to_pascal_case(key));
-            let setter_name = format!("set{}", to_pascal_case(key));
+    let result = serialize_json(&parsed, true, &options)?;
+    Ok(timer.finish(result))
+}
 
-            output.push_str(&format!("    public {} {}() {{\n", java_type, getter_name));
-            output.push_str(&format!("        return {};\n", field_name));
-            output.push_str("    }\n\n");
+fn parse_escaped_json(trimmed: &str) -> Result<Value, String> {
+    // Accept multiple common inputs:
+    // 1) regular JSON object/array
+    // 2) JSON string literal containing escaped JSON
+    // 3) escaped JSON without wrapping quotes, e.g. {\"a\":1}
+    let candidates = [trimmed, trimmed.trim_matches('"')];
 
-            output.push_str(&format!(
-                "    public void {}({} {}) {{\n",
-                setter_name, java_type, field_name
-            ));
-            output.push_str(&format!("        this.{} = {};\n", field_name, field_name));
-            output.push_str("    }\n\n");
+    for candidate in candidates {
+        if candidate.is_empty() {
+            continue;
         }
 
-        output.push_str("}\n");
-
-        for (name, nested_val) in nested_classes {
-            output.push('\n');
-            output.push_str(&generate_java_class(&nested_val, &name)?);
+        if let Ok(value) = serde_json::from_str::<Value>(candidate) {
+            return match value {
+                Value::String(unescaped) => serde_json::from_str::<Value>(unescaped.trim())
+                    .map_err(|e| format!("String content is not valid JSON: {}", e)),
+                other => Ok(other),
+            };
         }
 
-        Ok(output)
-    } else {
-        Err("Input must be a JSON object".to_string())
+        let wrapped = format!("\"{}\"", candidate);
+        if let Ok(unescaped) = serde_json::from_str::<String>(&wrapped) {
+            if let Ok(parsed) = serde_json::from_str::<Value>(unescaped.trim()) {
+                return Ok(parsed);
+            }
+        }
     }
+
+    Err("Input must be valid JSON or escaped JSON string".to_string())
 }
 
-fn infer_java_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
-    match value {
-        Value::Null => "Object".to_string(),
-        Value::Bool(_) => "Boolean".to_string(),
-        Value::Number(n) => {
-            if n.is_f64() {
-                "Double".to_string()
-            } else {
-                "Integer".to_string()
-            }
-        }
-        Value::String(_) => "String".to_string(),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                "List<Object>".to_string()
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    let nested_name = to_pascal_case(field_name);
-                    nested.push((nested_name.clone(), first.clone()));
-                    format!("List<{}>", nested_name)
-                } else {
-                    format!("List<{}>", infer_java_type(first, field_name, nested))
-                }
-            }
-        }
-        Value::Object(_) => {
-            let nested_name = to_pascal_case(field_name);
-            nested.push((nested_name.clone(), value.clone()));
-            nested_name
+/// Inputs larger than this are judged by their first lines instead of being parsed
+const FORMAT_DETECTION_PARSE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Text formats recognized in the converter input
+#[derive(
+    Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum InputFormat {
+    Json,
+    Json5,
+    Ndjson,
+    Yaml,
+    Xml,
+    Jwt,
+    UrlEncoded,
+    EscapedJson,
+    Unknown,
+}
+
+impl InputFormat {
+    fn label(self) -> &'static str {
+        match self {
+            InputFormat::Json => "JSON",
+            InputFormat::Json5 => "JSON5",
+            InputFormat::Ndjson => "NDJSON",
+            InputFormat::Yaml => "YAML",
+            InputFormat::Xml => "XML",
+            InputFormat::Jwt => "a JWT",
+            InputFormat::UrlEncoded => "a URL-encoded string",
+            InputFormat::EscapedJson => "an escaped JSON string",
+            InputFormat::Unknown => "unknown text",
         }
     }
+
+    /// Whether `convert_to_json` turns this format into JSON
+    fn convertible(self) -> bool {
+        !matches!(
+            self,
+            InputFormat::Json | InputFormat::Yaml | InputFormat::Xml | InputFormat::Unknown
+        )
+    }
 }
 
-fn generate_csharp_class(value: &Value, class_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output =
-            String::from("using System.Collections.Generic;\nusing Newtonsoft.Json;\n\n");
-        let mut nested_classes = Vec::new();
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct FormatDetection {
+    format: InputFormat,
+    label: &'static str,
+    convertible: bool,
+}
+
+/// At least two lines, each a JSON value; only the first lines are checked
+fn looks_like_ndjson(text: &str) -> bool {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .take(20)
+        .collect();
+    lines.len() >= 2
+        && lines
+            .iter()
+            .all(|line| serde_json::from_str::<Value>(line).is_ok())
+}
 
-        output.push_str(&format!("public class {}\n{{\n", class_name));
+/// A signed JWT: three dot-separated base64url segments with a JSON header naming `alg`
+fn looks_like_jwt(text: &str) -> bool {
+    let segments: Vec<&str> = text.split('.').collect();
+    segments.len() == 3
+        && segments.iter().all(|segment| {
+            segment
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'='))
+        })
+        && decode_jwt_segment(segments[0]).is_ok_and(|header| header.get("alg").is_some())
+}
 
-        for (key, val) in map {
-            let cs_type = infer_csharp_type(val, key, &mut nested_classes);
-            output.push_str(&format!("    [JsonProperty(\"{}\")]\n", key));
-            output.push_str(&format!(
-                "    public {} {} {{ get; set; }}\n\n",
-                cs_type,
-                to_pascal_case(key)
-            ));
+fn looks_like_xml(text: &str) -> bool {
+    if !text.ends_with('>') {
+        return false;
+    }
+    let Some(tag) = text.strip_prefix('<') else {
+        return false;
+    };
+    tag.starts_with("?xml")
+        || tag.starts_with("!--")
+        || tag.starts_with("!DOCTYPE")
+        || tag
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_')
+            && text.contains("</")
+}
+
+/// A query string (`a=1&b=2`) or percent-encoded text, on a single line
+fn looks_like_url_encoded(text: &str) -> bool {
+    if text.contains(char::is_whitespace) {
+        return false;
+    }
+    let percent_encoded = text.as_bytes().windows(3).any(|window| {
+        window[0] == b'%' && window[1].is_ascii_hexdigit() && window[2].is_ascii_hexdigit()
+    });
+    let query = url_query(text);
+    let pairs = query.contains('=')
+        && query
+            .split('&')
+            .all(|pair| pair.split_once('=').is_some_and(|(key, _)| !key.is_empty()));
+    percent_encoded || pairs
+}
+
+/// Block-style YAML: every top-level line is a `key: value` mapping, a `- item` or a
+/// document marker, with at least two of them
+fn looks_like_yaml(text: &str) -> bool {
+    if text.starts_with(['{', '[', '<']) {
+        return false;
+    }
+    let mut entries = 0;
+    for line in text.lines().take(200) {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed == "..." {
+            continue;
+        }
+        if trimmed == "---" {
+            entries += 1;
+            continue;
+        }
+        let item = trimmed
+            .strip_prefix("- ")
+            .or((trimmed == "-").then_some(""));
+        let mapping = item
+            .unwrap_or(trimmed)
+            .split_once(':')
+            .is_some_and(|(key, rest)| {
+                !key.trim().is_empty() && (rest.is_empty() || rest.starts_with(' '))
+            });
+        if mapping || item.is_some() {
+            entries += 1;
+        } else if !line.starts_with([' ', '\t']) {
+            // Indented lines may continue a multi-line scalar
+            return false;
         }
+    }
+    entries >= 2
+}
 
-        output.push_str("}\n");
+/// Guess the format of pasted text, most specific first
+fn detect_input_format(input: &str) -> InputFormat {
+    let text = input.trim_start_matches('\u{feff}').trim();
+    if text.is_empty() {
+        return InputFormat::Unknown;
+    }
+    let bracketed = text.starts_with(['{', '[']);
+    if text.len() > FORMAT_DETECTION_PARSE_BYTES {
+        return if looks_like_ndjson(text) {
+            InputFormat::Ndjson
+        } else if bracketed {
+            InputFormat::Json
+        } else {
+            InputFormat::Unknown
+        };
+    }
 
-        for (name, nested_val) in nested_classes {
-            output.push('\n');
-            output.push_str(&generate_csharp_class(&nested_val, &name)?);
+    match serde_json::from_str::<Value>(text) {
+        Ok(Value::String(inner)) => {
+            let nested = serde_json::from_str::<Value>(inner.trim());
+            return if matches!(nested, Ok(Value::Object(_) | Value::Array(_))) {
+                InputFormat::EscapedJson
+            } else {
+                InputFormat::Json
+            };
         }
+        Ok(_) => return InputFormat::Json,
+        Err(_) => {}
+    }
 
-        Ok(output)
+    if looks_like_ndjson(text) {
+        InputFormat::Ndjson
+    } else if bracketed && text.contains("\\\"") && parse_escaped_json(text).is_ok() {
+        InputFormat::EscapedJson
+    } else if bracketed && parse_json5(text).is_ok() {
+        InputFormat::Json5
+    } else if looks_like_jwt(text) {
+        InputFormat::Jwt
+    } else if looks_like_xml(text) {
+        InputFormat::Xml
+    } else if looks_like_url_encoded(text) {
+        InputFormat::UrlEncoded
+    } else if looks_like_yaml(text) {
+        InputFormat::Yaml
     } else {
-        Err("Input must be a JSON object".to_string())
+        InputFormat::Unknown
     }
 }
 
-fn infer_csharp_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
-    match value {
-        Value::Null => "object".to_string(),
-        Value::Bool(_) => "bool".to_string(),
-        Value::Number(n) => {
-            if n.is_f64() {
-                "double".to_string()
-            } else {
-                "int".to_string()
-            }
+/// Detect what kind of text was pasted, so the frontend can offer a conversion
+#[tauri::command(async)]
+fn detect_format(input: String) -> FormatDetection {
+    let format = detect_input_format(&input);
+    debug!("detect_format: {:?} ({} bytes)", format, input.len());
+    FormatDetection {
+        format,
+        label: format.label(),
+        convertible: format.convertible(),
+    }
+}
+
+/// Convert input in a detected format to pretty-printed JSON
+#[tauri::command(async)]
+fn convert_to_json(
+    input: String,
+    format: InputFormat,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    info!(
+        "convert_to_json called - format: {:?}, size: {}",
+        format,
+        input.len()
+    );
+    let text = input.trim_start_matches('\u{feff}').trim();
+    if text.is_empty() {
+        return Err("Input is empty".to_string());
+    }
+
+    check_job_memory("convert_to_json", input.len())?;
+    let mut timer = OperationTimer::start("convert_to_json", input.len());
+    let value = match format {
+        InputFormat::Json => {
+            check_parse_limits(text)?;
+            serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {}", e))?
         }
-        Value::String(_) => "string".to_string(),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                "List<object>".to_string()
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    let nested_name = to_pascal_case(field_name);
-                    nested.push((nested_name.clone(), first.clone()));
-                    format!("List<{}>", nested_name)
-                } else {
-                    format!("List<{}>", infer_csharp_type(first, field_name, nested))
-                }
-            }
+        InputFormat::Json5 => parse_json5(text)?,
+        InputFormat::Ndjson => ndjson_to_array(text)?,
+        InputFormat::Jwt => decode_jwt(text)?,
+        InputFormat::UrlEncoded => url_encoded_to_json(text)?,
+        InputFormat::EscapedJson => parse_escaped_json(text)?,
+        InputFormat::Yaml | InputFormat::Xml | InputFormat::Unknown => {
+            return Err(format!("Converting {} isn't supported", format.label()));
         }
-        Value::Object(_) => {
-            let nested_name = to_pascal_case(field_name);
-            nested.push((nested_name.clone(), value.clone()));
-            nested_name
+    };
+    timer.parsed();
+
+    let result = serialize_json(&value, true, &output_options.unwrap_or_default())?;
+    Ok(timer.finish(result))
+}
+
+/// One array element per non-blank line
+fn ndjson_to_array(text: &str) -> Result<Value, String> {
+    let mut items = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
         }
+        check_parse_limits(line).map_err(|e| format!("Line {}: {}", index + 1, e))?;
+        let value = serde_json::from_str(line)
+            .map_err(|e| format!("Line {} is not valid JSON: {}", index + 1, e))?;
+        items.push(value);
     }
+    Ok(Value::Array(items))
 }
 
-fn generate_go_struct(value: &Value, struct_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output = String::from("package main\n\n");
-        let mut nested_structs = Vec::new();
+fn decode_jwt_segment(segment: &str) -> Result<Value, String> {
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(segment.trim_end_matches('='))
+        .map_err(|e| format!("Invalid base64url segment: {}", e))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("Segment is not JSON: {}", e))
+}
 
-        output.push_str(&format!("type {} struct {{\n", struct_name));
+/// Header and claims of a JWT. The signature is passed through, not verified.
+fn decode_jwt(text: &str) -> Result<Value, String> {
+    let segments: Vec<&str> = text.split('.').collect();
+    let [header, payload, signature] = segments[..] else {
+        return Err("A JWT has three dot-separated segments".to_string());
+    };
+    let header = decode_jwt_segment(header).map_err(|e| format!("JWT header: {}", e))?;
+    let payload = decode_jwt_segment(payload).map_err(|e| format!("JWT payload: {}", e))?;
+    Ok(serde_json::json!({
+        "header": header,
+        "payload": payload,
+        "signature": signature,
+    }))
+}
 
-        for (key, val) in map {
-            let go_type = infer_go_type(val, key, &mut nested_structs);
-            output.push_str(&format!(
-                "    {} {} `json:\"{}\"`\n",
-                to_pascal_case(key),
-                go_type,
-                key
-            ));
+/// The query of a URL, or the text itself without a leading `?`
+fn url_query(text: &str) -> &str {
+    match text.split_once('?') {
+        Some((_, query)) if text.contains("://") || text.starts_with('?') => query,
+        _ => text,
+    }
+}
+
+/// Decode `%XX` escapes and `+` as a space
+fn percent_decode(text: &str) -> Result<String, String> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let byte = bytes
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| format!("Invalid percent escape at position {}", i + 1))?;
+                decoded.push(byte);
+                i += 3;
+            }
+            b'+' => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
         }
+    }
+    String::from_utf8(decoded).map_err(|_| "Decoded text is not UTF-8".to_string())
+}
 
-        output.push_str("}\n");
+/// Percent-encoded JSON becomes that JSON; a query string becomes an object of its
+/// parameters, with repeated keys collected into arrays
+fn url_encoded_to_json(text: &str) -> Result<Value, String> {
+    let decoded = percent_decode(text)?;
+    if let Ok(value) = serde_json::from_str::<Value>(decoded.trim()) {
+        return Ok(value);
+    }
 
-        for (name, nested_val) in nested_structs {
-            output.push('\n');
-            output.push_str(&generate_go_struct(&nested_val, &name)?);
+    let query = url_query(text);
+    if !query.contains('=') {
+        return Ok(Value::String(decoded));
+    }
+    let mut params = serde_json::Map::new();
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let key = percent_decode(key)?;
+        let value = Value::String(percent_decode(value)?);
+        match params.get_mut(&key) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+            None => {
+                params.insert(key, value);
+            }
         }
+    }
+    Ok(Value::Object(params))
+}
 
-        Ok(output)
-    } else {
-        Err("Input must be a JSON object".to_string())
+/// Parse JSON5: comments, trailing commas, single-quoted strings, unquoted keys,
+/// hexadecimal numbers and leading or trailing decimal points
+fn parse_json5(text: &str) -> Result<Value, String> {
+    let limits = parse_limits();
+    if text.len() as u64 > limits.max_input_mb * 1024 * 1024 {
+        return Err(format!(
+            "Input is above the {} MB input limit",
+            limits.max_input_mb
+        ));
     }
+    let mut parser = Json5Parser {
+        text,
+        pos: 0,
+        depth: 0,
+        max_depth: limits.max_depth,
+    };
+    let value = parser.value()?;
+    parser.skip_trivia()?;
+    if parser.pos < text.len() {
+        return Err(parser.error("Unexpected text after the value"));
+    }
+    Ok(value)
 }
 
-fn infer_go_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
-    match value {
-        Value::Null => "interface{}".to_string(),
-        Value::Bool(_) => "bool".to_string(),
-        Value::Number(n) => {
-            if n.is_f64() {
-                "float64".to_string()
+struct Json5Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Json5Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self, message: &str) -> String {
+        let before = &self.text[..self.pos];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+        format!("{} at line {} column {}", message, line, column)
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), String> {
+        loop {
+            let rest = &self.text[self.pos..];
+            if rest.starts_with("//") {
+                self.pos += rest.find('\n').unwrap_or(rest.len());
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                let end = comment
+                    .find("*/")
+                    .ok_or_else(|| self.error("Unclosed block comment"))?;
+                self.pos += end + 4;
+            } else if self.peek().is_some_and(char::is_whitespace) {
+                self.bump();
             } else {
-                "int".to_string()
+                return Ok(());
             }
         }
-        Value::String(_) => "string".to_string(),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                "[]interface{}".to_string()
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    let nested_name = to_pascal_case(field_name);
-                    nested.push((nested_name.clone(), first.clone()));
-                    format!("[]{}", nested_name)
-                } else {
-                    format!("[]{}", infer_go_type(first, field_name, nested))
-                }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_trivia()?;
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some(quote @ ('"' | '\'')) => {
+                self.bump();
+                self.string(quote).map(Value::String)
             }
-        }
-        Value::Object(_) => {
-            let nested_name = to_pascal_case(field_name);
-            nested.push((nested_name.clone(), value.clone()));
-            nested_name
+            Some(_) => self.scalar(),
+            None => Err(self.error("Unexpected end of input")),
         }
     }
-}
-
-fn generate_kotlin_class(value: &Value, class_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output = String::from("import com.google.gson.annotations.SerializedName\n\n");
-        let mut nested_classes = Vec::new();
 
-        output.push_str(&format!("data class {}(\n", class_name));
+    fn enter(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        if self.depth > self.max_depth {
+            return Err(self.error(&format!("Nesting deeper than {} levels", self.max_depth)));
+        }
+        self.bump();
+        Ok(())
+    }
 
-        let entries: Vec<_> = map.iter().collect();
-        for (i, (key, val)) in entries.iter().enumerate() {
-            let kt_type = infer_kotlin_type(val, key, &mut nested_classes);
-            output.push_str(&format!("    @SerializedName(\"{}\")\n", key));
-            output.push_str(&format!("    val {}: {}", to_camel_case(key), kt_type));
-            if i < entries.len() - 1 {
-                output.push(',');
+    fn object(&mut self) -> Result<Value, String> {
+        self.enter()?;
+        let mut map = serde_json::Map::new();
+        loop {
+            self.skip_trivia()?;
+            let key = match self.peek() {
+                Some('}') => break,
+                Some(quote @ ('"' | '\'')) => {
+                    self.bump();
+                    self.string(quote)?
+                }
+                _ => self.identifier()?,
+            };
+            self.skip_trivia()?;
+            if self.bump() != Some(':') {
+                return Err(self.error("Expected ':' after the key"));
+            }
+            let value = self.value()?;
+            map.insert(key, value);
+            self.skip_trivia()?;
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some('}') => break,
+                _ => return Err(self.error("Expected ',' or '}'")),
             }
-            output.push('\n');
         }
+        self.bump();
+        self.depth -= 1;
+        Ok(Value::Object(map))
+    }
 
-        output.push_str(")\n");
+    fn array(&mut self) -> Result<Value, String> {
+        self.enter()?;
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia()?;
+            if self.peek() == Some(']') {
+                break;
+            }
+            items.push(self.value()?);
+            self.skip_trivia()?;
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(']') => break,
+                _ => return Err(self.error("Expected ',' or ']'")),
+            }
+        }
+        self.bump();
+        self.depth -= 1;
+        Ok(Value::Array(items))
+    }
 
-        for (name, nested_val) in nested_classes {
-            output.push('\n');
-            output.push_str(&generate_kotlin_class(&nested_val, &name)?);
+    fn identifier(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        {
+            self.bump();
+        }
+        let name = &self.text[start..self.pos];
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(self.error("Expected a key"));
         }
+        Ok(name.to_string())
+    }
 
-        Ok(output)
-    } else {
-        Err("Input must be a JSON object".to_string())
+    fn hex_escape(&mut self, digits: usize) -> Result<u32, String> {
+        let start = self.pos;
+        let hex = self
+            .text
+            .get(start..start + digits)
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("Invalid hex escape"))?;
+        self.pos += digits;
+        Ok(u32::from_str_radix(hex, 16).unwrap_or_default())
     }
-}
 
-fn infer_kotlin_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
-    match value {
-        Value::Null => "Any?".to_string(),
-        Value::Bool(_) => "Boolean".to_string(),
-        Value::Number(n) => {
-            if n.is_f64() {
-                "Double".to_string()
-            } else {
-                "Int".to_string()
-            }
-        }
-        Value::String(_) => "String".to_string(),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                "List<Any>".to_string()
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    let nested_name = to_pascal_case(field_name);
-                    nested.push((nested_name.clone(), first.clone()));
-                    format!("List<{}>", nested_name)
-                } else {
-                    format!("List<{}>", infer_kotlin_type(first, field_name, nested))
+    /// The rest of a string literal whose opening `quote` has been consumed
+    fn string(&mut self, quote: char) -> Result<String, String> {
+        let mut string = String::new();
+        loop {
+            let c = self
+                .bump()
+                .ok_or_else(|| self.error("Unterminated string"))?;
+            match c {
+                c if c == quote => return Ok(string),
+                '\n' | '\r' => return Err(self.error("Unescaped line break in a string")),
+                '\\' => {
+                    let escaped = self
+                        .bump()
+                        .ok_or_else(|| self.error("Unterminated string"))?;
+                    match escaped {
+                        'b' => string.push('\u{8}'),
+                        'f' => string.push('\u{c}'),
+                        'n' => string.push('\n'),
+                        'r' => string.push('\r'),
+                        't' => string.push('\t'),
+                        'v' => string.push('\u{b}'),
+                        '0' => string.push('\0'),
+                        'x' => {
+                            let code = self.hex_escape(2)?;
+                            string.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                        }
+                        'u' => {
+                            let mut code = self.hex_escape(4)?;
+                            // A surrogate pair spans two escapes
+                            if (0xD800..0xDC00).contains(&code)
+                                && self.text[self.pos..].starts_with("\\u")
+                            {
+                                self.pos += 2;
+                                let low = self.hex_escape(4)?;
+                                code = if (0xDC00..0xE000).contains(&low) {
+                                    0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00)
+                                } else {
+                                    0xFFFD
+                                };
+                            }
+                            string.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                        }
+                        // A backslash before a line break continues the string
+                        '\r' => {
+                            if self.peek() == Some('\n') {
+                                self.bump();
+                            }
+                        }
+                        '\n' | '\u{2028}' | '\u{2029}' => {}
+                        other => string.push(other),
+                    }
                 }
+                c => string.push(c),
             }
         }
-        Value::Object(_) => {
-            let nested_name = to_pascal_case(field_name);
-            nested.push((nested_name.clone(), value.clone()));
-            nested_name
+    }
+
+    /// A number, `true`, `false` or `null`
+    fn scalar(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        {
+            self.bump();
+        }
+        let token = &self.text[start..self.pos];
+        match token {
+            "true" => return Ok(Value::Bool(true)),
+            "false" => return Ok(Value::Bool(false)),
+            "null" => return Ok(Value::Null),
+            _ => {}
         }
+
+        let (negative, unsigned) = match token.as_bytes().first() {
+            Some(b'-') => (true, &token[1..]),
+            Some(b'+') => (false, &token[1..]),
+            _ => (false, token),
+        };
+        if matches!(unsigned, "Infinity" | "NaN") {
+            return Err(self.error(&format!("{} has no JSON equivalent", token)));
+        }
+        let number = if let Some(hex) = unsigned
+            .strip_prefix("0x")
+            .or_else(|| unsigned.strip_prefix("0X"))
+        {
+            let magnitude = i64::from_str_radix(hex, 16)
+                .map_err(|_| self.error(&format!("Invalid hex number '{}'", token)))?;
+            Value::from(if negative { -magnitude } else { magnitude })
+        } else {
+            let mut decimal = unsigned.replace(".e", "e").replace(".E", "E");
+            if decimal.starts_with('.') {
+                decimal.insert(0, '0');
+            }
+            if decimal.ends_with('.') {
+                decimal.pop();
+            }
+            if negative {
+                decimal.insert(0, '-');
+            }
+            match serde_json::from_str::<Value>(&decimal) {
+                Ok(number @ Value::Number(_)) => number,
+                _ => {
+                    let message = if token.is_empty() {
+                        "Unexpected character".to_string()
+                    } else {
+                        format!("Unexpected '{}'", token)
+                    };
+                    return Err(self.error(&message));
+                }
+            }
+        };
+        Ok(number)
     }
 }
 
-fn generate_swift_struct(value: &Value, struct_name: &str) -> Result<String, String> {
-    if let Value::Object(map) = value {
-        let mut output = String::from("import Foundation\n\n");
-        let mut nested_structs = Vec::new();
+/// Location of a JSON node (object, array, or scalar) inside a source text
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonNodePosition {
+    pointer: String,
+    line: usize,
+    column: usize,
+    /// Scalar value at this node; `None` for objects and arrays
+    value: Option<Value>,
+}
 
-        output.push_str(&format!("struct {}: Codable {{\n", struct_name));
+/// Changed regions between an original document and its transformed output
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct TransformComparison {
+    changed_pointers: Vec<String>,
+    original_changed_lines: Vec<usize>,
+    transformed_changed_lines: Vec<usize>,
+    /// Pairs of (original line, transformed line) for nodes present in both documents
+    line_map: Vec<(usize, usize)>,
+}
+
+/// Compare an original JSON document with its transformed output node by node,
+/// reporting changed values and a line mapping used for synced scrolling
+#[tauri::command(async)]
+fn compare_transform(original: String, transformed: String) -> Result<TransformComparison, String> {
+    info!(
+        "compare_transform called - original_len: {}, transformed_len: {}",
+        original.len(),
+        transformed.len()
+    );
+
+    let original_positions =
+        scan_json_positions(&original).map_err(|e| format!("Original: {}", e))?;
+    let transformed_positions =
+        scan_json_positions(&transformed).map_err(|e| format!("Transformed: {}", e))?;
+
+    let transformed_by_pointer: HashMap<&str, &JsonNodePosition> = transformed_positions
+        .iter()
+        .map(|node| (node.pointer.as_str(), node))
+        .collect();
+    let original_pointers: HashSet<&str> = original_positions
+        .iter()
+        .map(|node| node.pointer.as_str())
+        .collect();
+
+    let mut changed_pointers = Vec::new();
+    let mut original_changed_lines = Vec::new();
+    let mut transformed_changed_lines = Vec::new();
+    let mut line_map = Vec::new();
+
+    for node in &original_positions {
+        match transformed_by_pointer.get(node.pointer.as_str()) {
+            Some(other) => {
+                line_map.push((node.line, other.line));
+                if node.value != other.value {
+                    changed_pointers.push(node.pointer.clone());
+                    original_changed_lines.push(node.line);
+                    transformed_changed_lines.push(other.line);
+                }
+            }
+            None => {
+                changed_pointers.push(node.pointer.clone());
+                original_changed_lines.push(node.line);
+            }
+        }
+    }
+
+    for node in &transformed_positions {
+        if !original_pointers.contains(node.pointer.as_str()) {
+            changed_pointers.push(node.pointer.clone());
+            transformed_changed_lines.push(node.line);
+        }
+    }
+
+    original_changed_lines.sort_unstable();
+    original_changed_lines.dedup();
+    transformed_changed_lines.sort_unstable();
+    transformed_changed_lines.dedup();
+
+    info!(
+        "compare_transform: {} changed node(s)",
+        changed_pointers.len()
+    );
+
+    Ok(TransformComparison {
+        changed_pointers,
+        original_changed_lines,
+        transformed_changed_lines,
+        line_map,
+    })
+}
+
+/// Scan a JSON document and record the line/column of every node, keyed by JSON Pointer
+fn scan_json_positions(text: &str) -> Result<Vec<JsonNodePosition>, String> {
+    // Validate first so the scanner below only ever sees well-formed, depth-limited input
+    let _: Value = serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    let mut scanner = JsonPositionScanner {
+        bytes: text.as_bytes(),
+        text,
+        pos: 0,
+        line: 1,
+        column: 1,
+        nodes: Vec::new(),
+    };
+    scanner.skip_whitespace();
+    scanner.scan_value(String::new());
+    Ok(scanner.nodes)
+}
+
+fn escape_json_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+struct JsonPositionScanner<'a> {
+    bytes: &'a [u8],
+    text: &'a str,
+    pos: usize,
+    line: usize,
+    column: usize,
+    nodes: Vec<JsonNodePosition>,
+}
+
+impl<'a> JsonPositionScanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) {
+        if let Some(byte) = self.peek() {
+            if byte == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else if byte & 0xC0 != 0x80 {
+                // Count characters, not UTF-8 continuation bytes
+                self.column += 1;
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.advance();
+        }
+    }
+
+    fn scan_value(&mut self, pointer: String) {
+        let (line, column) = (self.line, self.column);
+
+        match self.peek() {
+            Some(b'{') => {
+                self.nodes.push(JsonNodePosition {
+                    pointer: pointer.clone(),
+                    line,
+                    column,
+                    value: None,
+                });
+                self.advance();
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(b'}') => {
+                            self.advance();
+                            break;
+                        }
+                        Some(b',') => {
+                            self.advance();
+                        }
+                        Some(b'"') => {
+                            let key = self.scan_string();
+                            let key: String = serde_json::from_str(key).unwrap_or_default();
+                            self.skip_whitespace();
+                            // Skip ':'
+                            self.advance();
+                            self.skip_whitespace();
+                            self.scan_value(format!(
+                                "{}/{}",
+                                pointer,
+                                escape_json_pointer_token(&key)
+                            ));
+                        }
+                        _ => break,
+                    }
+                }
+            }
+            Some(b'[') => {
+                self.nodes.push(JsonNodePosition {
+                    pointer: pointer.clone(),
+                    line,
+                    column,
+                    value: None,
+                });
+                self.advance();
+                let mut index = 0;
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(b']') => {
+                            self.advance();
+                            break;
+                        }
+                        Some(b',') => {
+                            self.advance();
+                        }
+                        Some(_) => {
+                            self.scan_value(format!("{}/{}", pointer, index));
+                            index += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+            Some(b'"') => {
+                let raw = self.scan_string();
+                self.nodes.push(JsonNodePosition {
+                    pointer,
+                    line,
+                    column,
+                    value: serde_json::from_str(raw).ok(),
+                });
+            }
+            Some(_) => {
+                let start = self.pos;
+                while let Some(byte) = self.peek() {
+                    if matches!(byte, b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r') {
+                        break;
+                    }
+                    self.advance();
+                }
+                self.nodes.push(JsonNodePosition {
+                    pointer,
+                    line,
+                    column,
+                    value: serde_json::from_str(&self.text[start..self.pos]).ok(),
+                });
+            }
+            None => {}
+        }
+    }
+
+    /// Consume a string literal and return its raw source, quotes included
+    fn scan_string(&mut self) -> &'a str {
+        let start = self.pos;
+        self.advance();
+        while let Some(byte) = self.peek() {
+            self.advance();
+            match byte {
+                b'\\' => self.advance(),
+                b'"' => break,
+                _ => {}
+            }
+        }
+        let text = self.text;
+        &text[start..self.pos]
+    }
+}
+
+/// Set the value at a JSON Pointer and return the updated document.
+/// `value` is JSON text; missing object keys are created and `-` appends to arrays.
+#[tauri::command]
+fn pointer_set(
+    input: String,
+    pointer: String,
+    value: String,
+    output_options: Option<OutputOptions>,
+) -> Result<String, String> {
+    info!(
+        "pointer_set called - pointer: '{}', input_len: {}",
+        pointer,
+        input.len()
+    );
+
+    check_parse_limits(&input)?;
+    let mut document: Value =
+        serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let new_value: Value =
+        serde_json::from_str(&value).map_err(|e| format!("Invalid value: {}", e))?;
+
+    set_json_pointer(&mut document, &pointer, new_value)?;
+
+    serialize_json(&document, true, &output_options.unwrap_or_default())
+}
+
+fn unescape_json_pointer_token(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+/// Split a JSON Pointer into its parent pointer and unescaped last token
+fn split_json_pointer(pointer: &str) -> Result<(&str, String), String> {
+    if !pointer.starts_with('/') {
+        return Err(format!("Invalid JSON Pointer '{}'", pointer));
+    }
+    let (parent, last) = pointer.rsplit_once('/').unwrap_or(("", pointer));
+    Ok((parent, unescape_json_pointer_token(last)))
+}
+
+fn set_json_pointer(document: &mut Value, pointer: &str, value: Value) -> Result<(), String> {
+    if pointer.is_empty() {
+        *document = value;
+        return Ok(());
+    }
+
+    if let Some(target) = document.pointer_mut(pointer) {
+        *target = value;
+        return Ok(());
+    }
+
+    let (parent_pointer, key) = split_json_pointer(pointer)?;
+    match document.pointer_mut(parent_pointer) {
+        Some(Value::Object(map)) => {
+            map.insert(key, value);
+            Ok(())
+        }
+        Some(Value::Array(arr)) if key == "-" || key == arr.len().to_string() => {
+            arr.push(value);
+            Ok(())
+        }
+        Some(Value::Array(_)) => Err(format!("Array index out of range at '{}'", pointer)),
+        Some(_) => Err(format!("Parent of '{}' is not an object or array", pointer)),
+        None => Err(format!("Path '{}' does not exist", parent_pointer)),
+    }
+}
+
+/// Return the subtree at a JSON Pointer as pretty-printed JSON
+#[tauri::command]
+fn pointer_get(input: String, pointer: String) -> Result<String, String> {
+    info!("pointer_get called - pointer: '{}'", pointer);
+
+    check_parse_limits(&input)?;
+    let document: Value =
+        serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let target = document
+        .pointer(&pointer)
+        .ok_or_else(|| format!("Path '{}' does not exist", pointer))?;
+
+    serde_json::to_string_pretty(target).map_err(|e| format!("Failed to format JSON: {}", e))
+}
+
+/// Delete the node at a JSON Pointer and return the updated document
+#[tauri::command]
+fn pointer_delete(
+    input: String,
+    pointer: String,
+    output_options: Option<OutputOptions>,
+) -> Result<String, String> {
+    info!("pointer_delete called - pointer: '{}'", pointer);
+
+    check_parse_limits(&input)?;
+    let mut document: Value =
+        serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    remove_json_pointer(&mut document, &pointer)?;
+
+    serialize_json(&document, true, &output_options.unwrap_or_default())
+}
+
+/// Insert a copy of an array element directly after it and return the updated document
+#[tauri::command]
+fn pointer_duplicate(
+    input: String,
+    pointer: String,
+    output_options: Option<OutputOptions>,
+) -> Result<String, String> {
+    info!("pointer_duplicate called - pointer: '{}'", pointer);
+
+    check_parse_limits(&input)?;
+    let mut document: Value =
+        serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let (parent_pointer, key) = split_json_pointer(&pointer)?;
+
+    match document.pointer_mut(parent_pointer) {
+        Some(Value::Array(arr)) => {
+            let index = key
+                .parse::<usize>()
+                .ok()
+                .filter(|index| *index < arr.len())
+                .ok_or_else(|| format!("Array index out of range at '{}'", pointer))?;
+            let copy = arr[index].clone();
+            arr.insert(index + 1, copy);
+        }
+        Some(_) => return Err(format!("'{}' is not an array element", pointer)),
+        None => return Err(format!("Path '{}' does not exist", parent_pointer)),
+    }
+
+    serialize_json(&document, true, &output_options.unwrap_or_default())
+}
+
+/// A node picked in the output, for the path breadcrumb
+#[derive(Debug, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct NodeLocation {
+    pointer: String,
+    json_path: String,
+}
+
+/// Find the node at a 1-based line and column of `input`: the last node starting on that
+/// line at or before the column, or the first on the line when the click is left of it
+/// (on a key). Lines where no node starts, like closing brackets, give `None`.
+#[tauri::command(async)]
+fn locate_node(input: String, line: usize, column: usize) -> Result<Option<NodeLocation>, String> {
+    let nodes = scan_json_positions(&input)?;
+    let on_line: Vec<&JsonNodePosition> = nodes.iter().filter(|node| node.line == line).collect();
+    let Some(node) = on_line
+        .iter()
+        .rev()
+        .find(|node| node.column <= column)
+        .or(on_line.first())
+    else {
+        return Ok(None);
+    };
+    Ok(Some(NodeLocation {
+        json_path: pointer_to_json_path(node.pointer.clone())?,
+        pointer: node.pointer.clone(),
+    }))
+}
+
+/// Convert a JSON Pointer into the equivalent JSONPath expression
+#[tauri::command]
+fn pointer_to_json_path(pointer: String) -> Result<String, String> {
+    if pointer.is_empty() {
+        return Ok("$".to_string());
+    }
+    if !pointer.starts_with('/') {
+        return Err(format!("Invalid JSON Pointer '{}'", pointer));
+    }
+
+    let mut path = String::from("$");
+    for token in pointer[1..].split('/').map(unescape_json_pointer_token) {
+        let is_identifier = token
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+            && token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+        if !token.is_empty() && token.chars().all(|c| c.is_ascii_digit()) {
+            path.push_str(&format!("[{}]", token));
+        } else if is_identifier {
+            path.push('.');
+            path.push_str(&token);
+        } else {
+            path.push_str(&format!(
+                "['{}']",
+                token.replace('\\', "\\\\").replace('\'', "\\'")
+            ));
+        }
+    }
+
+    Ok(path)
+}
+
+fn remove_json_pointer(document: &mut Value, pointer: &str) -> Result<Value, String> {
+    if pointer.is_empty() {
+        return Err("Cannot delete the document root".to_string());
+    }
+
+    let (parent_pointer, key) = split_json_pointer(pointer)?;
+    match document.pointer_mut(parent_pointer) {
+        Some(Value::Object(map)) => map
+            .remove(&key)
+            .ok_or_else(|| format!("Path '{}' does not exist", pointer)),
+        Some(Value::Array(arr)) => match key.parse::<usize>() {
+            Ok(index) if index < arr.len() => Ok(arr.remove(index)),
+            _ => Err(format!("Array index out of range at '{}'", pointer)),
+        },
+        Some(_) => Err(format!("Parent of '{}' is not an object or array", pointer)),
+        None => Err(format!("Path '{}' does not exist", parent_pointer)),
+    }
+}
+
+/// Fingerprint a document by its shape (object key paths, array indexes collapsed)
+/// so per-document state such as bookmarks survives value edits
+#[tauri::command(async)]
+fn document_fingerprint(input: String) -> Result<String, String> {
+    let document = parse_json(&input)?;
+
+    let mut paths = BTreeSet::new();
+    collect_shape_paths(&document, String::new(), &mut paths);
+
+    // FNV-1a keeps the fingerprint stable across builds, unlike DefaultHasher
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in paths.iter().flat_map(|path| path.bytes().chain([b'\n'])) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    Ok(format!("{:016x}", hash))
+}
+
+fn collect_shape_paths(value: &Value, path: String, paths: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_path = format!("{}/{}", path, escape_json_pointer_token(key));
+                collect_shape_paths(child, child_path.clone(), paths);
+                paths.insert(child_path);
+            }
+        }
+        Value::Array(arr) => {
+            for child in arr {
+                collect_shape_paths(child, format!("{}/*", path), paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[derive(serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct SchemaAnnotation {
+    pointer: String,
+    valid: bool,
+    errors: Vec<String>,
+    title: Option<String>,
+    description: Option<String>,
+}
+
+/// Validate a document against a JSON Schema and return per-node validity plus
+/// the schema's title/description for every node
+#[tauri::command(async)]
+fn annotate_with_schema(input: String, schema: String) -> Result<Vec<SchemaAnnotation>, String> {
+    info!(
+        "annotate_with_schema called - input_len: {}, schema_len: {}",
+        input.len(),
+        schema.len()
+    );
+
+    let document = parse_json(&input)?;
+    let schema: Value =
+        serde_json::from_str(&schema).map_err(|e| format!("Invalid schema: {}", e))?;
+
+    let mut annotations = Vec::new();
+    annotate_schema_node(
+        &document,
+        Some(&schema),
+        &schema,
+        String::new(),
+        &mut annotations,
+    )?;
+    Ok(annotations)
+}
+
+/// Annotate a node and its children, returning whether the whole subtree is valid
+fn annotate_schema_node(
+    value: &Value,
+    schema: Option<&Value>,
+    root: &Value,
+    pointer: String,
+    annotations: &mut Vec<SchemaAnnotation>,
+) -> Result<bool, String> {
+    let schema = schema.map(|schema| resolve_schema_ref(schema, root));
+    let errors = match schema {
+        Some(schema) => schema_keyword_errors(value, schema, root, 0)?,
+        None => Vec::new(),
+    };
+    let text_keyword = |keyword: &str| {
+        schema
+            .and_then(|schema| schema.get(keyword))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+
+    let index = annotations.len();
+    annotations.push(SchemaAnnotation {
+        pointer: pointer.clone(),
+        valid: errors.is_empty(),
+        errors,
+        title: text_keyword("title"),
+        description: text_keyword("description"),
+    });
+
+    let mut children_valid = true;
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_schema = schema.and_then(|schema| property_schema(schema, key));
+                let child_pointer = format!("{}/{}", pointer, escape_json_pointer_token(key));
+                children_valid &=
+                    annotate_schema_node(child, child_schema, root, child_pointer, annotations)?;
+            }
+        }
+        Value::Array(arr) => {
+            for (i, child) in arr.iter().enumerate() {
+                let child_schema = schema.and_then(|schema| item_schema(schema, i));
+                let child_pointer = format!("{}/{}", pointer, i);
+                children_valid &=
+                    annotate_schema_node(child, child_schema, root, child_pointer, annotations)?;
+            }
+        }
+        _ => {}
+    }
+
+    annotations[index].valid &= children_valid;
+    Ok(annotations[index].valid)
+}
+
+/// Follow local `$ref`s such as `#/definitions/User` or `#/$defs/User`
+fn resolve_schema_ref<'a>(mut schema: &'a Value, root: &'a Value) -> &'a Value {
+    for _ in 0..32 {
+        match schema.get("$ref").and_then(Value::as_str) {
+            Some(reference) if reference.starts_with('#') => match root.pointer(&reference[1..]) {
+                Some(target) => schema = target,
+                None => break,
+            },
+            _ => break,
+        }
+    }
+    schema
+}
+
+fn property_schema<'a>(schema: &'a Value, key: &str) -> Option<&'a Value> {
+    schema
+        .get("properties")
+        .and_then(|properties| properties.get(key))
+        .or_else(|| schema.get("additionalProperties").filter(|s| s.is_object()))
+}
+
+fn item_schema(schema: &Value, index: usize) -> Option<&Value> {
+    if let Some(prefix) = schema.get("prefixItems").and_then(Value::as_array) {
+        if let Some(item) = prefix.get(index) {
+            return Some(item);
+        }
+    }
+    match schema.get("items") {
+        Some(Value::Array(items)) => items.get(index),
+        Some(items) => Some(items),
+        None => None,
+    }
+}
+
+/// Nested allOf/anyOf/oneOf/not checks on one value before the schema is treated as a `$ref` cycle
+const SCHEMA_MAX_DEPTH: usize = 64;
+
+/// Check whether a value satisfies a schema, including all of its children
+fn schema_accepts(
+    value: &Value,
+    schema: &Value,
+    root: &Value,
+    depth: usize,
+) -> Result<bool, String> {
+    if depth > SCHEMA_MAX_DEPTH {
+        return Err(format!(
+            "Schema nesting exceeds {} levels; check for a recursive $ref",
+            SCHEMA_MAX_DEPTH
+        ));
+    }
+    let schema = resolve_schema_ref(schema, root);
+    if !schema_keyword_errors(value, schema, root, depth)?.is_empty() {
+        return Ok(false);
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                if let Some(s) = property_schema(schema, key) {
+                    if !schema_accepts(child, s, root, 0)? {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+        Value::Array(arr) => {
+            for (i, child) in arr.iter().enumerate() {
+                if let Some(s) = item_schema(schema, i) {
+                    if !schema_accepts(child, s, root, 0)? {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(true)
+}
+
+fn json_type_matches(value: &Value, type_name: &str) -> bool {
+    match type_name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => false,
+    }
+}
+
+/// Validate the keywords that apply to this node only; children are checked separately
+fn schema_keyword_errors(
+    value: &Value,
+    schema: &Value,
+    root: &Value,
+    depth: usize,
+) -> Result<Vec<String>, String> {
+    let mut errors = Vec::new();
+
+    match schema {
+        Value::Bool(false) => return Ok(vec!["Schema does not allow any value here".to_string()]),
+        Value::Object(_) => {}
+        _ => return Ok(errors),
+    }
+
+    match schema.get("type") {
+        Some(Value::String(type_name)) if !json_type_matches(value, type_name) => {
+            errors.push(format!("Expected type {}", type_name));
+        }
+        Some(Value::Array(types))
+            if !types
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|type_name| json_type_matches(value, type_name)) =>
+        {
+            let names: Vec<&str> = types.iter().filter_map(Value::as_str).collect();
+            errors.push(format!("Expected one of types {}", names.join(", ")));
+        }
+        _ => {}
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            errors.push("Value is not one of the allowed enum values".to_string());
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            errors.push(format!("Expected constant {}", expected));
+        }
+    }
+
+    if let Some(n) = value.as_f64() {
+        let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+        if let Some(min) = bound("minimum").filter(|min| n < *min) {
+            errors.push(format!("Must be >= {}", min));
+        }
+        if let Some(max) = bound("maximum").filter(|max| n > *max) {
+            errors.push(format!("Must be <= {}", max));
+        }
+        if let Some(min) = bound("exclusiveMinimum").filter(|min| n <= *min) {
+            errors.push(format!("Must be > {}", min));
+        }
+        if let Some(max) = bound("exclusiveMaximum").filter(|max| n >= *max) {
+            errors.push(format!("Must be < {}", max));
+        }
+    }
+
+    let limit = |keyword: &str| schema.get(keyword).and_then(Value::as_u64);
+    if let Some(s) = value.as_str() {
+        let length = s.chars().count() as u64;
+        if let Some(min) = limit("minLength").filter(|min| length < *min) {
+            errors.push(format!("Must be at least {} characters", min));
+        }
+        if let Some(max) = limit("maxLength").filter(|max| length > *max) {
+            errors.push(format!("Must be at most {} characters", max));
+        }
+    }
+
+    if let Some(arr) = value.as_array() {
+        let length = arr.len() as u64;
+        if let Some(min) = limit("minItems").filter(|min| length < *min) {
+            errors.push(format!("Must have at least {} items", min));
+        }
+        if let Some(max) = limit("maxItems").filter(|max| length > *max) {
+            errors.push(format!("Must have at most {} items", max));
+        }
+    }
+
+    if let Some(map) = value.as_object() {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !map.contains_key(key) {
+                    errors.push(format!("Missing required property '{}'", key));
+                }
+            }
+        }
+        if schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for key in map.keys() {
+                if !properties.is_some_and(|properties| properties.contains_key(key)) {
+                    errors.push(format!("Property '{}' is not allowed", key));
+                }
+            }
+        }
+    }
+
+    let subschemas = |keyword: &str| -> Result<Option<(usize, usize)>, String> {
+        let Some(schemas) = schema.get(keyword).and_then(Value::as_array) else {
+            return Ok(None);
+        };
+        let mut matched = 0;
+        for s in schemas {
+            if schema_accepts(value, s, root, depth + 1)? {
+                matched += 1;
+            }
+        }
+        Ok(Some((matched, schemas.len())))
+    };
+    if let Some((matched, total)) = subschemas("allOf")? {
+        if matched != total {
+            errors.push("Does not match all schemas in allOf".to_string());
+        }
+    }
+    if let Some((matched, _)) = subschemas("anyOf")? {
+        if matched == 0 {
+            errors.push("Does not match any schema in anyOf".to_string());
+        }
+    }
+    if let Some((matched, _)) = subschemas("oneOf")? {
+        if matched != 1 {
+            errors.push(format!(
+                "Matches {} schemas in oneOf, expected exactly 1",
+                matched
+            ));
+        }
+    }
+    if let Some(not) = schema.get("not") {
+        if schema_accepts(value, not, root, depth + 1)? {
+            errors.push("Must not match the schema in not".to_string());
+        }
+    }
+
+    Ok(errors)
+}
+
+const PROTOBUF_MAX_DEPTH: usize = 16;
+
+/// Result of inspecting raw input bytes before treating them as text
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct InputInspection {
+    binary: bool,
+    detected_type: String,
+    description: String,
+    /// Decoded text when the input is text
+    text: Option<String>,
+    /// Encoding the text was decoded from
+    encoding: Option<TextEncoding>,
+    /// Invalid UTF-8 sequences and unpaired surrogate escapes replaced in lossy mode
+    replacements: usize,
+    /// Decoders accepted by `decode_binary_input`, plus "archive" for `open_archive`
+    /// and "image" for the image tools
+    decoders: Vec<String>,
+}
+
+/// Inspect a file opened from disk, or bytes dropped or pasted into the app, and report
+/// whether they are text or a recognizable binary format. Files are read here by path so
+/// their bytes don't make a round trip through the frontend.
+#[tauri::command(async)]
+fn inspect_input_bytes(
+    path: Option<String>,
+    data: Option<Vec<u8>>,
+    lossy: Option<bool>,
+) -> Result<InputInspection, String> {
+    let data = match (path, data) {
+        (Some(path), _) => read_input_path(&path)?,
+        (None, Some(data)) => data,
+        (None, None) => return Err("Either a path or data is required".to_string()),
+    };
+    info!(
+        "inspect_input_bytes called - size: {}, lossy: {:?}",
+        data.len(),
+        lossy
+    );
+    Ok(inspect_bytes(&data, lossy.unwrap_or(false)))
+}
+
+/// In `lossy` mode invalid UTF-8 and unpaired surrogate escapes become U+FFFD instead of
+/// making the input unreadable
+fn inspect_bytes(data: &[u8], lossy: bool) -> InputInspection {
+    let mut decoded = decode_text(data).map(|(text, encoding)| (text, encoding, 0));
+    // Lossy mode reads broken UTF-8 as UTF-8 rather than guessing Latin-1
+    if lossy
+        && matches!(decoded, None | Some((_, TextEncoding::Latin1, _)))
+        && detect_binary_type(data).0 == "binary"
+    {
+        let (encoding, bytes) = match data.strip_prefix(b"\xEF\xBB\xBF") {
+            Some(rest) => (TextEncoding::Utf8Bom, rest),
+            None => (TextEncoding::Utf8, data),
+        };
+        let (text, replacements) = decode_utf8_lossy(bytes);
+        if !text.contains('\0') {
+            decoded = Some((text, encoding, replacements));
+        }
+    }
+    if let Some((text, encoding, invalid_sequences)) = decoded {
+        let (text, lone_surrogates) = if lossy {
+            replace_lone_surrogate_escapes(&text)
+        } else {
+            (text, 0)
+        };
+        return InputInspection {
+            binary: false,
+            detected_type: "text".to_string(),
+            description: format!("{} text", encoding.label()),
+            text: Some(text),
+            encoding: Some(encoding),
+            replacements: invalid_sequences + lone_surrogates,
+            decoders: Vec::new(),
+        };
+    }
+
+    let (detected_type, description) = detect_binary_type(data);
+    let mut decoders: Vec<String> = match detected_type {
+        "gzip" => vec!["gunzip".to_string()],
+        "zip" | "tar" | "tar.gz" => vec!["archive".to_string()],
+        "protobuf" => vec!["protobuf".to_string()],
+        t if t.starts_with("image/") => vec!["image".to_string()],
+        _ => Vec::new(),
+    };
+    decoders.push("base64".to_string());
+
+    warn!(
+        "inspect_input_bytes: binary input detected as {} ({} bytes)",
+        detected_type,
+        data.len()
+    );
+    InputInspection {
+        binary: true,
+        detected_type: detected_type.to_string(),
+        description: description.to_string(),
+        text: None,
+        encoding: None,
+        replacements: 0,
+        decoders,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum TextEncoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+}
+
+impl TextEncoding {
+    fn label(self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "UTF-8",
+            TextEncoding::Utf8Bom => "UTF-8 with BOM",
+            TextEncoding::Utf16Le => "UTF-16LE",
+            TextEncoding::Utf16Be => "UTF-16BE",
+            TextEncoding::Latin1 => "Latin-1",
+        }
+    }
+}
+
+/// Characters for bytes 0x80-0x9F. Like browsers, Latin-1 is read as Windows-1252, whose
+/// five unassigned bytes keep their C1 code points.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž', '\u{8f}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}', 'ž', 'Ÿ',
+];
+
+fn decode_utf16(data: &[u8], unit: fn([u8; 2]) -> u16) -> Option<String> {
+    let pairs = data.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    let units = pairs.map(|pair| unit([pair[0], pair[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .ok()
+}
+
+/// UTF-8 with each invalid sequence replaced by U+FFFD, and the number replaced
+fn decode_utf8_lossy(data: &[u8]) -> (String, usize) {
+    let mut text = String::with_capacity(data.len());
+    let mut replacements = 0;
+    for chunk in data.utf8_chunks() {
+        text.push_str(chunk.valid());
+        if !chunk.invalid().is_empty() {
+            text.push('\u{FFFD}');
+            replacements += 1;
+        }
+    }
+    (text, replacements)
+}
+
+/// Replace `\u` escapes of unpaired surrogates, which the parser rejects, with `\ufffd`.
+/// Returns the text and the number replaced.
+fn replace_lone_surrogate_escapes(text: &str) -> (String, usize) {
+    let bytes = text.as_bytes();
+    let hex_escape = |at: usize| {
+        let escape = bytes.get(at..at + 6)?;
+        let hex = std::str::from_utf8(escape.strip_prefix(b"\\u")?).ok()?;
+        u32::from_str_radix(hex, 16).ok()
+    };
+    let mut sanitized = String::with_capacity(text.len());
+    let (mut copied, mut pos, mut replacements) = (0, 0, 0);
+    while let Some(offset) = text[pos..].find('\\') {
+        let at = pos + offset;
+        match hex_escape(at) {
+            Some(0xD800..=0xDBFF) if matches!(hex_escape(at + 6), Some(0xDC00..=0xDFFF)) => {
+                pos = at + 12;
+            }
+            Some(0xD800..=0xDFFF) => {
+                sanitized.push_str(&text[copied..at]);
+                sanitized.push_str("\\ufffd");
+                replacements += 1;
+                pos = at + 6;
+                copied = pos;
+            }
+            // Skip the escaped character so an escaped backslash can't start an escape
+            _ => pos = at + 1 + text[at + 1..].chars().next().map_or(0, char::len_utf8),
+        }
+    }
+    sanitized.push_str(&text[copied..]);
+    (sanitized, replacements)
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct SanitizedText {
+    text: String,
+    replacements: usize,
+}
+
+/// Lossy mode for text already in the editor: unpaired surrogate escapes become `\ufffd`
+/// so the text parses
+#[tauri::command(async)]
+fn sanitize_input(input: String) -> SanitizedText {
+    info!("sanitize_input called - input_len: {}", input.len());
+    let (text, replacements) = replace_lone_surrogate_escapes(&input);
+    SanitizedText { text, replacements }
+}
+
+/// Decode input bytes as text. The encoding comes from a byte order mark, else from the
+/// NUL bytes UTF-16 puts next to ASCII (RFC 4627, section 3), else UTF-8, falling back to
+/// Latin-1. `None` when the bytes don't read as text in any of them.
+fn decode_text(data: &[u8]) -> Option<(String, TextEncoding)> {
+    // Guessed encodings must not produce control characters; declared ones only no NULs
+    let (text, encoding, guessed) = match data {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => (
+            String::from_utf8(rest.to_vec()).ok()?,
+            TextEncoding::Utf8Bom,
+            false,
+        ),
+        [0xFF, 0xFE, rest @ ..] => (
+            decode_utf16(rest, u16::from_le_bytes)?,
+            TextEncoding::Utf16Le,
+            false,
+        ),
+        [0xFE, 0xFF, rest @ ..] => (
+            decode_utf16(rest, u16::from_be_bytes)?,
+            TextEncoding::Utf16Be,
+            false,
+        ),
+        [1..=0x7F, 0, ..] => (
+            decode_utf16(data, u16::from_le_bytes)?,
+            TextEncoding::Utf16Le,
+            true,
+        ),
+        [0, 1..=0x7F, ..] => (
+            decode_utf16(data, u16::from_be_bytes)?,
+            TextEncoding::Utf16Be,
+            true,
+        ),
+        _ => match std::str::from_utf8(data) {
+            Ok(text) => (text.to_string(), TextEncoding::Utf8, false),
+            Err(_) => {
+                let text = data
+                    .iter()
+                    .map(|&byte| match byte {
+                        0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+                        _ => char::from(byte),
+                    })
+                    .collect();
+                (text, TextEncoding::Latin1, true)
+            }
+        },
+    };
+    let binary = if guessed {
+        text.chars().any(|c| c.is_control() && !c.is_whitespace())
+    } else {
+        text.contains('\0')
+    };
+    (!binary).then_some((text, encoding))
+}
+
+/// Decode binary input into text using one of the decoders offered by `inspect_input_bytes`
+#[tauri::command(async)]
+fn decode_binary_input(
+    data: Vec<u8>,
+    decoder: String,
+    output_options: Option<OutputOptions>,
+) -> Result<String, String> {
+    info!(
+        "decode_binary_input called - decoder: {}, size: {}",
+        decoder,
+        data.len()
+    );
+    let options = output_options.unwrap_or_default();
+
+    match decoder.as_str() {
+        "gunzip" => {
+            // Stop one byte past the input limit so a gzip bomb cannot exhaust memory
+            let max_bytes = parse_limits().max_input_mb * 1024 * 1024;
+            let mut decompressed = Vec::new();
+            flate2::read::MultiGzDecoder::new(data.as_slice())
+                .take(max_bytes + 1)
+                .read_to_end(&mut decompressed)
+                .map_err(|e| format!("Failed to decompress gzip data: {}", e))?;
+            if decompressed.len() as u64 > max_bytes {
+                return Err(format!(
+                    "Decompressed data is larger than the {} MB input limit",
+                    parse_limits().max_input_mb
+                ));
+            }
+            check_job_memory("decode_binary_input", decompressed.len())?;
+            let text = String::from_utf8(decompressed).map_err(|_| {
+                let (_, description) = detect_binary_type(&data);
+                format!("Decompressed data is not UTF-8 text ({})", description)
+            })?;
+            check_parse_limits(&text)?;
+            match serde_json::from_str::<Value>(&text) {
+                Ok(parsed) => serialize_json(&parsed, true, &options),
+                // NDJSON and other text payloads are returned as-is
+                Err(_) => Ok(text),
+            }
+        }
+        "protobuf" => {
+            let decoded = decode_protobuf_wire(&data, 0)
+                .ok_or("Input is not a valid protobuf wire-format message")?;
+            serialize_json(&decoded, true, &options)
+        }
+        "base64" => Ok(BASE64.encode(&data)),
+        _ => Err(format!("Unsupported decoder: {}", decoder)),
+    }
+}
+
+fn detect_binary_type(data: &[u8]) -> (&'static str, &'static str) {
+    match data {
+        [0x1f, 0x8b, ..] if gzip_contains_tar(data) => ("tar.gz", "gzip-compressed tar archive"),
+        [0x1f, 0x8b, ..] => ("gzip", "gzip-compressed data"),
+        [b'P', b'K', 0x03, 0x04, ..] | [b'P', b'K', 0x05, 0x06, ..] => ("zip", "zip archive"),
+        [0x89, b'P', b'N', b'G', ..] => ("image/png", "PNG image"),
+        [0xff, 0xd8, 0xff, ..] => ("image/jpeg", "JPEG image"),
+        [b'G', b'I', b'F', b'8', ..] => ("image/gif", "GIF image"),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => {
+            ("image/webp", "WebP image")
+        }
+        [b'B', b'M', ..] => ("image/bmp", "BMP image"),
+        [b'%', b'P', b'D', b'F', ..] => ("pdf", "PDF document"),
+        _ if is_tar_header(data) => ("tar", "tar archive"),
+        _ if decode_protobuf_wire(data, 0).is_some() => {
+            ("protobuf", "protobuf-like binary message")
+        }
+        _ => ("binary", "unrecognized binary data"),
+    }
+}
+
+fn read_protobuf_varint(data: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut result = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*pos)?;
+        *pos += 1;
+        result |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(result);
+        }
+    }
+    None
+}
+
+/// Decode a protobuf message without a schema, keyed by field number.
+/// Returns `None` unless the whole buffer parses as well-formed fields.
+fn decode_protobuf_wire(data: &[u8], depth: usize) -> Option<Value> {
+    if data.is_empty() || depth > PROTOBUF_MAX_DEPTH {
+        return None;
+    }
+
+    let mut fields: serde_json::Map<String, Value> = serde_json::Map::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        let key = read_protobuf_varint(data, &mut pos)?;
+        let field_number = key >> 3;
+        if field_number == 0 || field_number > 536_870_911 {
+            return None;
+        }
+
+        let value = match key & 0x07 {
+            0 => Value::from(read_protobuf_varint(data, &mut pos)?),
+            1 => {
+                let bytes = data.get(pos..pos + 8)?;
+                pos += 8;
+                Value::from(u64::from_le_bytes(bytes.try_into().ok()?))
+            }
+            2 => {
+                let length = usize::try_from(read_protobuf_varint(data, &mut pos)?).ok()?;
+                let bytes = data.get(pos..pos.checked_add(length)?)?;
+                pos += length;
+                // Printable UTF-8 reads best as a string; otherwise try a nested message
+                std::str::from_utf8(bytes)
+                    .ok()
+                    .filter(|s| !s.chars().any(|c| c.is_control() && !c.is_whitespace()))
+                    .map(|s| Value::String(s.to_string()))
+                    .or_else(|| decode_protobuf_wire(bytes, depth + 1))
+                    .unwrap_or_else(|| Value::String(BASE64.encode(bytes)))
+            }
+            5 => {
+                let bytes = data.get(pos..pos + 4)?;
+                pos += 4;
+                Value::from(u32::from_le_bytes(bytes.try_into().ok()?))
+            }
+            _ => return None,
+        };
+
+        // Repeated fields collect into an array
+        match fields.get_mut(&field_number.to_string()) {
+            Some(Value::Array(items)) => items.push(value),
+            Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+            None => {
+                fields.insert(field_number.to_string(), value);
+            }
+        }
+    }
+
+    Some(Value::Object(fields))
+}
+
+/// One file inside a zip or tar archive
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ArchiveEntry {
+    name: String,
+    size: u64,
+    /// Whether the entry looks like JSON or NDJSON and can be opened directly
+    is_json: bool,
+}
+
+/// An archive read straight from disk or from bytes loaded by the frontend
+trait ArchiveSource: Read + Seek {}
+
+impl<T: Read + Seek> ArchiveSource for T {}
+
+/// Open the archive file for streaming, or wrap the bytes loaded by the frontend
+fn archive_source(
+    path: Option<String>,
+    data: Option<Vec<u8>>,
+) -> Result<Box<dyn ArchiveSource>, String> {
+    match (path, data) {
+        (Some(path), _) => {
+            let file = fs::File::open(&path)
+                .map_err(|e| format!("Failed to read archive '{}': {}", path, e))?;
+            Ok(Box::new(std::io::BufReader::new(file)))
+        }
+        (None, Some(data)) => Ok(Box::new(Cursor::new(data))),
+        (None, None) => Err("No archive provided".to_string()),
+    }
+}
+
+/// Detect the archive type from its first block, then rewind the source
+fn archive_type(source: &mut dyn ArchiveSource) -> Result<&'static str, String> {
+    let io_error = |e: std::io::Error| format!("Failed to read archive: {}", e);
+    let mut header = Vec::with_capacity(512);
+    (&mut *source)
+        .take(512)
+        .read_to_end(&mut header)
+        .map_err(io_error)?;
+    source.rewind().map_err(io_error)?;
+
+    if !header.starts_with(&[0x1f, 0x8b]) {
+        return Ok(detect_binary_type(&header).0);
+    }
+    let mut tar_header = Vec::with_capacity(512);
+    let is_tar = flate2::read::GzDecoder::new(&mut *source)
+        .take(512)
+        .read_to_end(&mut tar_header)
+        .is_ok()
+        && is_tar_header(&tar_header);
+    source.rewind().map_err(io_error)?;
+    Ok(if is_tar { "tar.gz" } else { "gzip" })
+}
+
+fn is_json_entry_name(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    [".json", ".ndjson", ".jsonl", ".geojson"]
+        .iter()
+        .any(|ext| lower.ends_with(ext))
+}
+
+fn is_tar_header(data: &[u8]) -> bool {
+    data.get(257..262) == Some(b"ustar".as_slice())
+}
+
+fn gzip_contains_tar(data: &[u8]) -> bool {
+    let mut header = Vec::with_capacity(512);
+    flate2::read::GzDecoder::new(data)
+        .take(512)
+        .read_to_end(&mut header)
+        .is_ok()
+        && is_tar_header(&header)
+}
+
+/// List the entries of a zip, tar, or tar.gz archive
+#[tauri::command(async)]
+fn open_archive(path: Option<String>, data: Option<Vec<u8>>) -> Result<Vec<ArchiveEntry>, String> {
+    let mut source = archive_source(path, data)?;
+    let archive_type = archive_type(source.as_mut())?;
+    info!("open_archive called - type: {}", archive_type);
+
+    let mut entries = Vec::new();
+    match archive_type {
+        "zip" => {
+            let mut archive =
+                zip::ZipArchive::new(source).map_err(|e| format!("Invalid zip archive: {}", e))?;
+            for i in 0..archive.len() {
+                let file = archive
+                    .by_index(i)
+                    .map_err(|e| format!("Failed to read zip entry: {}", e))?;
+                if file.is_file() {
+                    entries.push(ArchiveEntry {
+                        name: file.name().to_string(),
+                        size: file.size(),
+                        is_json: is_json_entry_name(file.name()),
+                    });
+                }
+            }
+        }
+        "tar" | "tar.gz" => {
+            let mut archive = tar_archive(source, archive_type);
+            for entry in archive
+                .entries()
+                .map_err(|e| format!("Invalid tar archive: {}", e))?
+            {
+                let entry = entry.map_err(|e| format!("Failed to read tar entry: {}", e))?;
+                if entry.header().entry_type().is_file() {
+                    let name = entry
+                        .path()
+                        .map_err(|e| format!("Invalid tar entry path: {}", e))?
+                        .to_string_lossy()
+                        .to_string();
+                    entries.push(ArchiveEntry {
+                        is_json: is_json_entry_name(&name),
+                        name,
+                        size: entry.size(),
+                    });
+                }
+            }
+        }
+        other => return Err(format!("Unsupported archive type: {}", other)),
+    }
+
+    Ok(entries)
+}
+
+/// Read one JSON or NDJSON entry out of an archive, decompressing only up to that entry
+#[tauri::command(async)]
+fn read_archive_entry(
+    path: Option<String>,
+    data: Option<Vec<u8>>,
+    entry_name: String,
+    output_options: Option<OutputOptions>,
+) -> Result<String, String> {
+    let mut source = archive_source(path, data)?;
+    let archive_type = archive_type(source.as_mut())?;
+    info!("read_archive_entry called - entry: '{}'", entry_name);
+
+    let content = match archive_type {
+        "zip" => {
+            let mut archive =
+                zip::ZipArchive::new(source).map_err(|e| format!("Invalid zip archive: {}", e))?;
+            let mut file = archive
+                .by_name(&entry_name)
+                .map_err(|e| format!("Entry '{}' not found: {}", entry_name, e))?;
+            read_entry_text(&mut file, &entry_name)?
+        }
+        "tar" | "tar.gz" => {
+            let mut archive = tar_archive(source, archive_type);
+            let mut content = None;
+            for entry in archive
+                .entries()
+                .map_err(|e| format!("Invalid tar archive: {}", e))?
+            {
+                let mut entry = entry.map_err(|e| format!("Failed to read tar entry: {}", e))?;
+                let matches = entry
+                    .path()
+                    .is_ok_and(|path| path.to_string_lossy() == entry_name.as_str());
+                if matches {
+                    content = Some(read_entry_text(&mut entry, &entry_name)?);
+                    break;
+                }
+            }
+            content.ok_or_else(|| format!("Entry '{}' not found", entry_name))?
+        }
+        other => return Err(format!("Unsupported archive type: {}", other)),
+    };
+
+    // Pretty-print single JSON documents; NDJSON and other text is returned as-is
+    check_parse_limits(&content)?;
+    match serde_json::from_str::<Value>(&content) {
+        Ok(parsed) => serialize_json(&parsed, true, &output_options.unwrap_or_default()),
+        Err(_) => Ok(content),
+    }
+}
+
+/// Read an archive entry as text. The read stops one byte past the input limit, since
+/// the size recorded in the archive can't be trusted to match the data.
+fn read_entry_text(entry: &mut dyn Read, entry_name: &str) -> Result<String, String> {
+    let max_bytes = parse_limits().max_input_mb * 1024 * 1024;
+    let mut data = Vec::new();
+    entry
+        .take(max_bytes + 1)
+        .read_to_end(&mut data)
+        .map_err(|e| format!("Failed to read entry '{}': {}", entry_name, e))?;
+    if data.len() as u64 > max_bytes {
+        return Err(format!(
+            "Entry '{}' is larger than the {} MB input limit",
+            entry_name,
+            parse_limits().max_input_mb
+        ));
+    }
+    check_job_memory("read_archive_entry", data.len())?;
+    decode_text(&data)
+        .map(|(text, _)| text)
+        .ok_or_else(|| format!("Entry '{}' is not text", entry_name))
+}
+
+/// Read tar entries one at a time, decompressing as they are reached
+fn tar_archive(source: Box<dyn ArchiveSource>, archive_type: &str) -> tar::Archive<Box<dyn Read>> {
+    let reader: Box<dyn Read> = if archive_type == "tar.gz" {
+        Box::new(flate2::read::GzDecoder::new(source))
+    } else {
+        Box::new(source)
+    };
+    tar::Archive::new(reader)
+}
+
+const SQLITE_READ_ONLY_PREFIXES: &[&str] = &["select", "with", "values", "explain", "pragma"];
+
+/// Only plain read statements are accepted. The database is also opened read-only and
+/// SQLite rejects writes from any statement that gets past this check.
+fn validate_read_only_query(query: &str) -> Result<(), String> {
+    let lower = query.trim_start().to_lowercase();
+    if SQLITE_READ_ONLY_PREFIXES
+        .iter()
+        .any(|prefix| lower.starts_with(prefix))
+    {
+        Ok(())
+    } else {
+        Err(
+            "Only read-only queries (SELECT, WITH, VALUES, EXPLAIN, PRAGMA) are allowed"
+                .to_string(),
+        )
+    }
+}
+
+/// Rows a SQLite query may return before the rest are left out
+const SQLITE_MAX_ROWS: usize = 100_000;
+
+/// Rows of a SQLite query as a JSON array
+#[derive(Debug, Clone, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct SqliteRows {
+    json: String,
+    rows: usize,
+    /// Whether rows past `SQLITE_MAX_ROWS` or the job memory limit were left out
+    truncated: bool,
+}
+
+/// Run one read-only statement and return its rows as objects keyed by column name, and
+/// whether reading stopped early at `SQLITE_MAX_ROWS` or the job memory limit. The
+/// bundled SQLite is opened read-only and keeps `load_extension` disabled; a query with a
+/// second statement after the first is refused.
+fn run_sqlite_json(path: &str, query: &str) -> Result<(Vec<Value>, bool), String> {
+    use rusqlite::types::ValueRef;
+
+    let db_path = Path::new(path.trim());
+    if !db_path.is_file() {
+        return Err(format!("SQLite file not found: {}", path.trim()));
+    }
+
+    let connection = rusqlite::Connection::open_with_flags(
+        db_path,
+        rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|e| format!("Failed to open SQLite file: {}", e))?;
+    let failed = |e: rusqlite::Error| format!("SQLite query failed: {}", e);
+    let mut batch = rusqlite::Batch::new(&connection, query);
+    let mut statement = batch.next().map_err(failed)?.ok_or("Query is empty")?;
+    if batch.next().map_err(failed)?.is_some() {
+        return Err("Only one statement can be run at a time".to_string());
+    }
+    if !statement.readonly() {
+        return Err("Only read-only queries are allowed".to_string());
+    }
+
+    let columns: Vec<String> = statement
+        .column_names()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let mut rows = statement.query([]).map_err(failed)?;
+    let mut output = Vec::new();
+    // Rough size of the rows as JSON text, checked against the job memory limit
+    let mut bytes = 0;
+    while let Some(row) = rows.next().map_err(failed)? {
+        if output.len() == SQLITE_MAX_ROWS || check_job_memory("sqlite_query", bytes).is_err() {
+            return Ok((output, true));
+        }
+        let mut object = serde_json::Map::new();
+        for (i, column) in columns.iter().enumerate() {
+            let value = match row.get_ref(i).map_err(failed)? {
+                ValueRef::Null => Value::Null,
+                ValueRef::Integer(n) => Value::from(n),
+                ValueRef::Real(n) => {
+                    serde_json::Number::from_f64(n).map_or(Value::Null, Value::Number)
+                }
+                ValueRef::Text(text) => Value::String(String::from_utf8_lossy(text).into_owned()),
+                // Blobs are returned as base64
+                ValueRef::Blob(bytes) => Value::String(BASE64.encode(bytes)),
+            };
+            bytes += column.len()
+                + match &value {
+                    Value::String(text) => text.len(),
+                    _ => 8,
+                }
+                + 6;
+            object.insert(column.clone(), value);
+        }
+        output.push(Value::Object(object));
+    }
+    Ok((output, false))
+}
+
+/// List the tables of a local SQLite database
+#[tauri::command(async)]
+fn sqlite_list_tables(path: String) -> Result<Vec<String>, String> {
+    info!("sqlite_list_tables called - path: '{}'", path);
+
+    let (rows, _) = run_sqlite_json(
+        &path,
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    )?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| row.get("name").and_then(Value::as_str))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Run a read-only query against a local SQLite database and return the rows as a JSON array
+#[tauri::command(async)]
+fn sqlite_query(
+    path: String,
+    query: String,
+    output_options: Option<OutputOptions>,
+) -> Result<SqliteRows, String> {
+    info!(
+        "sqlite_query called - path: '{}', query_len: {}",
+        path,
+        query.len()
+    );
+
+    validate_read_only_query(&query)?;
+    let (rows, truncated) = run_sqlite_json(&path, &query)?;
+    if truncated {
+        warn!("sqlite_query: stopped after {} rows", rows.len());
+    }
+    Ok(SqliteRows {
+        rows: rows.len(),
+        json: serialize_json(
+            &Value::Array(rows),
+            true,
+            &output_options.unwrap_or_default(),
+        )?,
+        truncated,
+    })
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+enum KeyValueStore {
+    Redis,
+    Etcd,
+}
+
+/// Connection profile for a local Redis or etcd instance
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct KeyValueProfile {
+    store: KeyValueStore,
+    #[serde(default)]
+    endpoint: String,
+    username: Option<String>,
+    password: Option<String>,
+    database: Option<u32>,
+}
+
+fn parse_key_value_endpoint(endpoint: &str, default_port: u16) -> Result<(String, u16), String> {
+    let endpoint = endpoint.trim();
+    let (host, port) = match endpoint.rsplit_once(':') {
+        Some((host, port)) => (
+            host,
+            port.parse::<u16>()
+                .map_err(|_| format!("Invalid port in endpoint: {}", endpoint))?,
+        ),
+        None => (endpoint, default_port),
+    };
+    let host = if host.is_empty() { "127.0.0.1" } else { host };
+    if host.starts_with('-') || host.chars().any(char::is_whitespace) {
+        return Err(format!("Invalid host: {}", host));
+    }
+    Ok((host.to_string(), port))
+}
+
+/// Seconds redis-cli and etcdctl may spend connecting and waiting for a reply
+const KEY_VALUE_TIMEOUT_SECS: u32 = 5;
+
+/// Only GET-style commands are ever built here, so profiles cannot modify the store.
+/// Credentials go through the CLIs' environment variables rather than the argument list.
+fn key_value_command(profile: &KeyValueProfile, key: &str) -> Result<Command, String> {
+    let timeout = KEY_VALUE_TIMEOUT_SECS.to_string();
+    let username = profile.username.as_deref().filter(|u| !u.is_empty());
+    let password = profile.password.as_deref().filter(|p| !p.is_empty());
+
+    let command = match profile.store {
+        KeyValueStore::Redis => {
+            let (host, port) = parse_key_value_endpoint(&profile.endpoint, 6379)?;
+            let mut command = Command::new("redis-cli");
+            command.args(["-h", &host, "-p", &port.to_string(), "-t", &timeout]);
+            if let Some(database) = profile.database {
+                command.args(["-n", &database.to_string()]);
+            }
+            if let Some(username) = username {
+                command.args(["--user", username]);
+            }
+            if let Some(password) = password {
+                command.env("REDISCLI_AUTH", password);
+            }
+            command.args(["--raw", "GET", key]);
+            command
+        }
+        KeyValueStore::Etcd => {
+            let (host, port) = parse_key_value_endpoint(&profile.endpoint, 2379)?;
+            let mut command = Command::new("etcdctl");
+            command
+                .env("ETCDCTL_API", "3")
+                .arg(format!("--endpoints={}:{}", host, port))
+                .arg(format!("--dial-timeout={}s", timeout))
+                .arg(format!("--command-timeout={}s", timeout));
+            if let Some(username) = username {
+                command.env("ETCDCTL_USER", username);
+            }
+            if let Some(password) = password {
+                command.env("ETCDCTL_PASSWORD", password);
+            }
+            command.args(["get", "--print-value-only", "--", key]);
+            command
+        }
+    };
+    Ok(command)
+}
+
+/// Fetch a key from a local Redis or etcd instance and format its JSON value
+#[tauri::command(async)]
+fn fetch_key_value(
+    profile: KeyValueProfile,
+    key: String,
+    output_options: Option<OutputOptions>,
+) -> Result<String, String> {
+    info!(
+        "fetch_key_value called - store: {:?}, endpoint: '{}', key: '{}'",
+        profile.store, profile.endpoint, key
+    );
+
+    if key.is_empty() {
+        return Err("Key is empty".to_string());
+    }
+
+    let program = match profile.store {
+        KeyValueStore::Redis => "redis-cli",
+        KeyValueStore::Etcd => "etcdctl",
+    };
+    let output = key_value_command(&profile, &key)?
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                format!("{} command not found on this system", program)
+            } else {
+                format!("Failed to run {}: {}", program, e)
+            }
+        })?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // redis-cli reports error replies such as WRONGTYPE on stdout
+    let redis_error = profile.store == KeyValueStore::Redis
+        && ["ERR", "WRONGTYPE", "NOAUTH", "WRONGPASS", "NOPERM"]
+            .iter()
+            .any(|prefix| stdout.starts_with(prefix));
+    if !output.status.success() || redis_error {
+        let message = if stderr.trim().is_empty() {
+            stdout.trim()
+        } else {
+            stderr.trim()
+        };
+        return Err(format!("{} failed: {}", program, message));
+    }
+
+    let raw = stdout.strip_suffix('\n').unwrap_or(&stdout);
+    if raw.trim().is_empty() {
+        return Err(format!("Key '{}' not found or empty", key));
+    }
+
+    check_parse_limits(raw)?;
+    let value: Value = serde_json::from_str(raw)
+        .map_err(|e| format!("Value of key '{}' is not valid JSON: {}", key, e))?;
+    serialize_json(&value, true, &output_options.unwrap_or_default())
+}
+
+/// Separates the response body from the status line curl appends with `--write-out`
+const FETCH_TRAILER: &str = "\n--json-formatter-fetch--\n";
+
+const FETCH_MAX_REDIRECTS: u32 = 10;
+
+/// An HTTP request run through curl by `fetch_json` and `replay_curl`
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct HttpRequest {
+    method: String,
+    url: String,
+    /// Name and value pairs, in order; a name may repeat
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+    /// Skip TLS certificate checks (`-k`)
+    insecure: bool,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct FetchedJson {
+    body: String,
+    status: u16,
+    content_type: String,
+    /// Address the body came from after following redirects
+    url: String,
+}
+
+/// curl arguments for `request`; only http(s) is allowed, including for redirects.
+/// The body, if any, is passed on stdin.
+fn http_request_args(request: &HttpRequest) -> Result<Vec<String>, String> {
+    let url = request.url.trim();
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err("URL must start with http:// or https://".to_string());
+    }
+
+    let max_bytes = parse_limits().max_input_mb * 1024 * 1024;
+    let mut args: Vec<String> = [
+        "-sS",
+        "-L",
+        "--proto",
+        "=http,https",
+        "--proto-redir",
+        "=http,https",
+        "--compressed",
+        "--connect-timeout",
+        "10",
+        "--max-time",
+        "60",
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    args.push("--max-redirs".to_string());
+    args.push(FETCH_MAX_REDIRECTS.to_string());
+    args.push("--max-filesize".to_string());
+    args.push(max_bytes.to_string());
+
+    match request.method.as_str() {
+        "GET" => {}
+        // -X HEAD would make curl wait for a body that never comes
+        "HEAD" => args.push("--head".to_string()),
+        method if !method.is_empty() && method.chars().all(|c| c.is_ascii_alphabetic()) => {
+            args.push("-X".to_string());
+            args.push(method.to_string());
+        }
+        method => return Err(format!("Invalid HTTP method '{}'", method)),
+    }
+    if request.insecure {
+        args.push("-k".to_string());
+    }
+
+    let has_accept = request
+        .headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("accept"));
+    if !has_accept {
+        args.push("-H".to_string());
+        args.push("Accept: application/json, */*;q=0.8".to_string());
+    }
+    for (name, value) in &request.headers {
+        let name = name.trim();
+        let valid_name = !name.is_empty() && name.chars().all(|c| c.is_ascii_graphic() && c != ':');
+        if !valid_name || value.contains(['\r', '\n']) {
+            return Err(format!("Invalid header '{}'", name));
+        }
+        args.push("-H".to_string());
+        args.push(format!("{}: {}", name, value.trim()));
+    }
+    if request.body.is_some() {
+        args.push("--data-binary".to_string());
+        args.push("@-".to_string());
+    }
+
+    args.push("--write-out".to_string());
+    args.push(format!(
+        "{}%{{http_code}}\n%{{content_type}}\n%{{url_effective}}",
+        FETCH_TRAILER
+    ));
+    args.push("--".to_string());
+    args.push(url.to_string());
+    Ok(args)
+}
+
+/// Split curl's output into the body and the `--write-out` status lines
+fn parse_fetch_output(stdout: &[u8]) -> Result<FetchedJson, String> {
+    let text = String::from_utf8_lossy(stdout);
+    let (body, trailer) = text
+        .rsplit_once(FETCH_TRAILER)
+        .ok_or_else(|| "curl returned no response".to_string())?;
+    let mut lines = trailer.lines();
+    let status = lines
+        .next()
+        .and_then(|code| code.trim().parse().ok())
+        .ok_or_else(|| "curl returned no status code".to_string())?;
+
+    Ok(FetchedJson {
+        body: body.to_string(),
+        status,
+        content_type: lines.next().unwrap_or_default().trim().to_string(),
+        url: lines.next().unwrap_or_default().trim().to_string(),
+    })
+}
+
+fn run_http_request(request: &HttpRequest) -> Result<FetchedJson, String> {
+    let args = http_request_args(request)?;
+    let mut child = Command::new("curl")
+        .args(&args)
+        .stdin(if request.body.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                "curl command not found on this system".to_string()
+            } else {
+                format!("Failed to run curl: {}", e)
+            }
+        })?;
+    // curl reads the whole body before sending, so writing it first can't deadlock
+    if let (Some(mut stdin), Some(body)) = (child.stdin.take(), &request.body) {
+        stdin
+            .write_all(body.as_bytes())
+            .map_err(|e| format!("Failed to send the request body: {}", e))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to run curl: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // Exit code 63: the body is larger than --max-filesize
+        if output.status.code() == Some(63) {
+            return Err(format!(
+                "Response is above the {} MB input limit",
+                parse_limits().max_input_mb
+            ));
+        }
+        return Err(format!(
+            "Request failed: {}",
+            stderr.trim().trim_start_matches("curl: ")
+        ));
+    }
+
+    let fetched = parse_fetch_output(&output.stdout)?;
+    info!(
+        "{} {}: {} {} - body_len: {}",
+        request.method,
+        request.url,
+        fetched.status,
+        fetched.content_type,
+        fetched.body.len()
+    );
+    Ok(fetched)
+}
+
+/// GET a URL with curl, following redirects, and return the body for the input pane.
+/// Error statuses still return their body, since APIs usually explain the error in JSON.
+#[tauri::command(async)]
+fn fetch_json(
+    url: String,
+    headers: Option<BTreeMap<String, String>>,
+) -> Result<FetchedJson, String> {
+    info!("fetch_json called - url: '{}'", url);
+    run_http_request(&HttpRequest {
+        method: "GET".to_string(),
+        url,
+        headers: headers.unwrap_or_default().into_iter().collect(),
+        ..HttpRequest::default()
+    })
+}
+
+/// Split a shell command line into words, honouring single and double quotes, `$'…'`
+/// strings and backslash-newline continuations as browsers' "Copy as cURL" writes them
+fn shell_words(command: &str) -> Result<Vec<String>, String> {
+    let unterminated = || "Command has an unterminated quote".to_string();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = command.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some('\r') => {
+                    chars.next_if_eq(&'\n');
+                }
+                Some(next) => {
+                    word.push(next);
+                    in_word = true;
+                }
+                None => return Err("Command ends with a backslash".to_string()),
+            },
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        '\'' => break,
+                        ch => word.push(ch),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        '"' => break,
+                        '\\' => match chars.next().ok_or_else(unterminated)? {
+                            ch @ ('"' | '\\' | '$' | '`') => word.push(ch),
+                            '\n' => {}
+                            ch => {
+                                word.push('\\');
+                                word.push(ch);
+                            }
+                        },
+                        ch => word.push(ch),
+                    }
+                }
+            }
+            '$' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                in_word = true;
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        '\'' => break,
+                        '\\' => match chars.next().ok_or_else(unterminated)? {
+                            'n' => word.push('\n'),
+                            't' => word.push('\t'),
+                            'r' => word.push('\r'),
+                            ch @ ('\\' | '\'' | '"') => word.push(ch),
+                            ch => {
+                                word.push('\\');
+                                word.push(ch);
+                            }
+                        },
+                        ch => word.push(ch),
+                    }
+                }
+            }
+            c => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+/// curl options that take a value and may be written with it attached, like `-XPOST`
+const CURL_SHORT_VALUE_OPTIONS: &[&str] = &["-X", "-H", "-d", "-u", "-A", "-b", "-e", "-m", "-o"];
+
+/// Options that don't change the request; the replay always follows redirects quietly
+const CURL_IGNORED_FLAGS: &[&str] = &[
+    "-s",
+    "--silent",
+    "-S",
+    "--show-error",
+    "-sS",
+    "-L",
+    "--location",
+    "-v",
+    "--verbose",
+    "-i",
+    "--include",
+    "--compressed",
+    "-g",
+    "--globoff",
+];
+const CURL_IGNORED_OPTIONS: &[&str] = &["-m", "--max-time", "--connect-timeout", "-o", "--output"];
+
+fn push_header(request: &mut HttpRequest, name: &str, value: String) {
+    request.headers.push((name.to_string(), value));
+}
+
+/// Extract the method, URL, headers and body from a pasted `curl …` command
+fn parse_curl_command(command: &str) -> Result<HttpRequest, String> {
+    let mut words = shell_words(command.trim())?.into_iter();
+    match words.next() {
+        Some(program)
+            if program == "curl" || program == "curl.exe" || program.ends_with("/curl") => {}
+        _ => return Err("Command must start with curl".to_string()),
+    }
+
+    let mut request = HttpRequest::default();
+    let mut method = None;
+    let mut url = None;
+    let mut data: Vec<String> = Vec::new();
+    let mut get = false;
+
+    while let Some(word) = words.next() {
+        let (option, attached) = if word.starts_with("--") {
+            match word.split_once('=') {
+                Some((option, value)) => (option.to_string(), Some(value.to_string())),
+                None => (word, None),
+            }
+        } else if word.len() > 2
+            && word.is_char_boundary(2)
+            && CURL_SHORT_VALUE_OPTIONS.contains(&&word[..2])
+        {
+            (word[..2].to_string(), Some(word[2..].to_string()))
+        } else {
+            (word, None)
+        };
+        let mut value = || {
+            attached
+                .clone()
+                .or_else(|| words.next())
+                .ok_or_else(|| format!("{} needs a value", option))
+        };
+
+        match option.as_str() {
+            "-X" | "--request" => method = Some(value()?.to_uppercase()),
+            "--url" => url = Some(value()?),
+            "-H" | "--header" => {
+                let line = value()?;
+                let (name, value) = line
+                    .split_once(':')
+                    .ok_or_else(|| format!("Header '{}' needs a colon", line))?;
+                push_header(&mut request, name.trim(), value.trim().to_string());
+            }
+            "-d" | "--data" | "--data-raw" | "--data-binary" | "--data-ascii" => {
+                let body = value()?;
+                if option != "--data-raw" && body.starts_with('@') {
+                    return Err("Reading the body from a file (@…) is not supported".to_string());
+                }
+                data.push(body);
+            }
+            "--json" => {
+                data.push(value()?);
+                for (name, value) in [
+                    ("Content-Type", "application/json"),
+                    ("Accept", "application/json"),
+                ] {
+                    if !request
+                        .headers
+                        .iter()
+                        .any(|(n, _)| n.eq_ignore_ascii_case(name))
+                    {
+                        push_header(&mut request, name, value.to_string());
+                    }
+                }
+            }
+            "-u" | "--user" => {
+                let credentials = BASE64.encode(value()?);
+                push_header(
+                    &mut request,
+                    "Authorization",
+                    format!("Basic {}", credentials),
+                );
+            }
+            "-A" | "--user-agent" => push_header(&mut request, "User-Agent", value()?),
+            "-e" | "--referer" => push_header(&mut request, "Referer", value()?),
+            "-b" | "--cookie" => {
+                let cookie = value()?;
+                if !cookie.contains('=') {
+                    return Err("Reading cookies from a file is not supported".to_string());
+                }
+                push_header(&mut request, "Cookie", cookie);
+            }
+            "-G" | "--get" => get = true,
+            "-I" | "--head" => method = Some("HEAD".to_string()),
+            "-k" | "--insecure" => request.insecure = true,
+            "-F" | "--form" => return Err("Multipart forms (-F) are not supported".to_string()),
+            option if CURL_IGNORED_FLAGS.contains(&option) => {}
+            option if CURL_IGNORED_OPTIONS.contains(&option) => {
+                value()?;
+            }
+            option if option.starts_with('-') && option.len() > 1 => {
+                return Err(format!("Unsupported curl option '{}'", option));
+            }
+            _ if url.is_some() => return Err("Command has more than one URL".to_string()),
+            _ => url = Some(option),
+        }
+    }
+
+    let mut url = url.ok_or_else(|| "Command has no URL".to_string())?;
+    let body = (!data.is_empty()).then(|| data.join("&"));
+    if get {
+        if let Some(query) = body {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(&query);
+        }
+    } else {
+        request.body = body;
+    }
+    request.method = method.unwrap_or_else(|| {
+        if request.body.is_some() {
+            "POST"
+        } else {
+            "GET"
+        }
+        .to_string()
+    });
+    request.url = url;
+    Ok(request)
+}
+
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+struct CurlReplay {
+    request: HttpRequest,
+    response: FetchedJson,
+}
+
+/// Parse a pasted curl command, run it and pretty-print a JSON response; other
+/// responses are returned as received
+#[tauri::command(async)]
+fn replay_curl(
+    command: String,
+    output_options: Option<OutputOptions>,
+) -> Result<CurlReplay, String> {
+    info!("replay_curl called - command_len: {}", command.len());
+    let request = parse_curl_command(&command)?;
+    let mut response = run_http_request(&request)?;
+    if let Ok(value) = serde_json::from_str::<Value>(&response.body) {
+        response.body = serialize_json(&value, true, &output_options.unwrap_or_default())?;
+    }
+    Ok(CurlReplay { request, response })
+}
+
+/// One request/response pair in a HAR log
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct HarEntry {
+    index: usize,
+    method: String,
+    url: String,
+    /// 0 when the request never got a response
+    status: i64,
+    mime_type: String,
+    /// Response body size in bytes, as recorded by the browser
+    size: i64,
+    has_request_body: bool,
+    has_response_body: bool,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum HarPart {
+    Request,
+    Response,
+}
+
+fn har_entries(text: &str) -> Result<Vec<Value>, String> {
+    let mut har: Value =
+        serde_json::from_str(text).map_err(|e| format!("Invalid HAR file: {}", e))?;
+    match har.pointer_mut("/log/entries").map(Value::take) {
+        Some(Value::Array(entries)) => Ok(entries),
+        _ => Err("Not a HAR file: missing log.entries".to_string()),
+    }
+}
+
+/// The body object of one side of an entry: `postData` or `content`
+fn har_body(entry: &Value, part: HarPart) -> Option<&Value> {
+    let pointer = match part {
+        HarPart::Request => "/request/postData",
+        HarPart::Response => "/response/content",
+    };
+    entry.pointer(pointer).filter(|body| {
+        body.get("text")
+            .and_then(Value::as_str)
+            .is_some_and(|t| !t.is_empty())
+    })
+}
+
+/// List the entries of a HAR (HTTP Archive) export
+#[tauri::command(async)]
+fn open_har(text: String) -> Result<Vec<HarEntry>, String> {
+    info!("open_har called - size: {}", text.len());
+    check_parse_limits(&text)?;
+    let entries = har_entries(&text)?;
+    let field = |entry: &Value, pointer: &str| {
+        entry
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+    Ok(entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| HarEntry {
+            index,
+            method: field(entry, "/request/method"),
+            url: field(entry, "/request/url"),
+            status: entry
+                .pointer("/response/status")
+                .and_then(Value::as_i64)
+                .unwrap_or(0),
+            mime_type: field(entry, "/response/content/mimeType"),
+            size: entry
+                .pointer("/response/content/size")
+                .and_then(Value::as_i64)
+                .unwrap_or(-1),
+            has_request_body: har_body(entry, HarPart::Request).is_some(),
+            has_response_body: har_body(entry, HarPart::Response).is_some(),
+        })
+        .collect())
+}
+
+/// Read the request or response body of one HAR entry; JSON bodies are pretty-printed
+#[tauri::command(async)]
+fn read_har_entry(
+    text: String,
+    index: usize,
+    part: HarPart,
+    output_options: Option<OutputOptions>,
+) -> Result<String, String> {
+    info!("read_har_entry called - index: {}, part: {:?}", index, part);
+    let entries = har_entries(&text)?;
+    let entry = entries
+        .get(index)
+        .ok_or_else(|| format!("HAR entry {} not found", index))?;
+    let body = har_body(entry, part).ok_or_else(|| {
+        format!(
+            "HAR entry {} has no {} body",
+            index,
+            if part == HarPart::Request {
+                "request"
+            } else {
+                "response"
+            }
+        )
+    })?;
+    let mut content = body["text"].as_str().unwrap_or_default().to_string();
+    if body.get("encoding").and_then(Value::as_str) == Some("base64") {
+        let bytes = BASE64
+            .decode(content.trim())
+            .map_err(|e| format!("Failed to decode base64 body: {}", e))?;
+        content = String::from_utf8(bytes).map_err(|_| "Body is not UTF-8 text".to_string())?;
+    }
+    if let Ok(value) = serde_json::from_str::<Value>(&content) {
+        content = serialize_json(&value, true, &output_options.unwrap_or_default())?;
+    }
+    Ok(content)
+}
+
+#[derive(serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct RenderedEnv {
+    output: String,
+    substituted: usize,
+    missing: Vec<String>,
+}
+
+fn is_env_var_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+/// Replace `${VAR}`, `${VAR:-default}` and `{{var}}` placeholders. Unresolved
+/// placeholders are left in place and reported once each in `missing`. With
+/// `escape_strings`, values landing inside a JSON string literal are escaped.
+fn substitute_placeholders(
+    input: &str,
+    variables: &HashMap<String, String>,
+    escape_strings: bool,
+) -> RenderedEnv {
+    let mut output = String::with_capacity(input.len());
+    let mut substituted = 0;
+    let mut missing: Vec<String> = Vec::new();
+    let mut rest = input;
+    let mut strings = JsonStringTracker::default();
+
+    while let Some(start) = rest.find(['$', '{']) {
+        output.push_str(&rest[..start]);
+        strings.advance(&rest[..start]);
+        let tail = &rest[start..];
+        let (open, close) = if tail.starts_with("${") {
+            ("${", "}")
+        } else if tail.starts_with("{{") {
+            ("{{", "}}")
+        } else {
+            output.push_str(&tail[..1]);
+            rest = &tail[1..];
+            continue;
+        };
+
+        let Some(end) = tail[open.len()..].find(close) else {
+            rest = tail;
+            break;
+        };
+        let placeholder = &tail[..open.len() + end + close.len()];
+        let inner = tail[open.len()..open.len() + end].trim();
+        let (name, default) = match inner.split_once(":-") {
+            Some((name, default)) if open == "${" => (name, Some(default)),
+            _ => (inner, None),
+        };
+
+        if !is_env_var_name(name) {
+            output.push_str(open);
+            rest = &tail[open.len()..];
+            continue;
+        }
+
+        match variables.get(name).map(String::as_str).or(default) {
+            Some(value) if escape_strings && strings.in_string => {
+                let quoted = Value::from(value).to_string();
+                output.push_str(&quoted[1..quoted.len() - 1]);
+                substituted += 1;
+            }
+            Some(value) => {
+                output.push_str(value);
+                substituted += 1;
+            }
+            None => {
+                output.push_str(placeholder);
+                if !missing.iter().any(|m| m == name) {
+                    missing.push(name.to_string());
+                }
+            }
+        }
+        rest = &tail[placeholder.len()..];
+    }
+    output.push_str(rest);
+
+    RenderedEnv {
+        output,
+        substituted,
+        missing,
+    }
+}
+
+/// Tracks whether a scan of JSON text is inside a string literal
+#[derive(Default)]
+struct JsonStringTracker {
+    in_string: bool,
+    escaped: bool,
+}
+
+impl JsonStringTracker {
+    fn advance(&mut self, text: &str) {
+        for c in text.chars() {
+            match c {
+                _ if self.escaped => self.escaped = false,
+                '\\' if self.in_string => self.escaped = true,
+                '"' => self.in_string = !self.in_string,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Substitute environment placeholders in a JSON or YAML config using the given
+/// variables, or the process environment when none are provided
+#[tauri::command]
+fn render_env(
+    input: String,
+    variables: Option<HashMap<String, String>>,
+    output_options: Option<OutputOptions>,
+) -> Result<RenderedEnv, String> {
+    info!(
+        "render_env called - input_len: {}, variables: {}",
+        input.len(),
+        variables
+            .as_ref()
+            .map_or("process environment".to_string(), |v| v.len().to_string())
+    );
+
+    if input.trim().is_empty() {
+        return Err("Input is empty".to_string());
+    }
+
+    let variables = variables.unwrap_or_else(|| std::env::vars().collect());
+    let is_json = input.trim_start().starts_with(['{', '[']);
+    let mut rendered = substitute_placeholders(&input, &variables, is_json);
+
+    // JSON configs are re-formatted; anything else (YAML) is returned as rendered
+    if is_json && rendered.missing.is_empty() {
+        let value = serde_json::from_str::<Value>(&rendered.output)
+            .map_err(|e| format!("Rendered config is not valid JSON: {}", e))?;
+        rendered.output = serialize_json(&value, true, &output_options.unwrap_or_default())?;
+    }
+
+    Ok(rendered)
+}
+
+/// One layer of a layered config, e.g. base, environment or local override
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ConfigLayer {
+    name: String,
+    content: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ConfigOrigin {
+    pointer: String,
+    layer: String,
+}
+
+#[derive(serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ResolvedConfig {
+    output: String,
+    origins: Vec<ConfigOrigin>,
+}
+
+/// Deep-merge `overlay` into `target`: objects merge key by key, while scalars and
+/// arrays replace the existing value. `origins` maps each leaf pointer to the layer
+/// that last set it.
+fn deep_merge(
+    target: &mut Value,
+    overlay: &Value,
+    pointer: &str,
+    layer: &str,
+    origins: &mut BTreeMap<String, String>,
+) {
+    match (target, overlay) {
+        (Value::Object(target_map), Value::Object(overlay_map)) if !overlay_map.is_empty() => {
+            for (key, value) in overlay_map {
+                let child = format!("{}/{}", pointer, escape_json_pointer_token(key));
+                let slot = target_map.entry(key.clone()).or_insert(Value::Null);
+                deep_merge(slot, value, &child, layer, origins);
+            }
+        }
+        (target, overlay) => {
+            let prefix = format!("{}/", pointer);
+            origins.retain(|existing, _| existing != pointer && !existing.starts_with(&prefix));
+            if let Value::Object(map) = overlay {
+                if !map.is_empty() {
+                    *target = Value::Object(serde_json::Map::new());
+                    deep_merge(target, overlay, pointer, layer, origins);
+                    return;
+                }
+            }
+            *target = overlay.clone();
+            origins.insert(pointer.to_string(), layer.to_string());
+        }
+    }
+}
+
+/// Merge config layers in order (later layers win) and report which layer each
+/// final value came from
+#[tauri::command]
+fn resolve_config(
+    layers: Vec<ConfigLayer>,
+    output_options: Option<OutputOptions>,
+) -> Result<ResolvedConfig, String> {
+    info!("resolve_config called - layers: {}", layers.len());
+
+    let mut merged = Value::Object(serde_json::Map::new());
+    let mut origins = BTreeMap::new();
+    let mut applied = 0;
+    for layer in &layers {
+        // Missing layers (e.g. no local override) are simply skipped
+        if layer.content.trim().is_empty() {
+            continue;
+        }
+        let value: Value = serde_json::from_str(&layer.content)
+            .map_err(|e| format!("Invalid JSON in layer '{}': {}", layer.name, e))?;
+        if !value.is_object() {
+            return Err(format!("Layer '{}' must be a JSON object", layer.name));
+        }
+        deep_merge(&mut merged, &value, "", &layer.name, &mut origins);
+        applied += 1;
+    }
+
+    if applied == 0 {
+        return Err("All config layers are empty".to_string());
+    }
+
+    Ok(ResolvedConfig {
+        output: serialize_json(&merged, true, &output_options.unwrap_or_default())?,
+        origins: origins
+            .into_iter()
+            .map(|(pointer, layer)| ConfigOrigin { pointer, layer })
+            .collect(),
+    })
+}
+
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct FeatureFlagSummary {
+    key: String,
+    source: &'static str,
+    enabled: bool,
+    variations: Vec<Value>,
+    rules: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct FlagEvaluation {
+    flag_key: String,
+    value: Value,
+    variation: Option<usize>,
+    reason: String,
+}
+
+/// Flags from a LaunchDarkly export (`flags` map) or an Unleash export (`features` array)
+fn feature_flags(export: &Value) -> Result<Vec<(&'static str, &Value)>, String> {
+    if let Some(flags) = export.get("flags").and_then(Value::as_object) {
+        return Ok(flags.values().map(|flag| ("launchDarkly", flag)).collect());
+    }
+    // GeoJSON also has a `features` array, so require Unleash's `enabled` switch
+    if let Some(features) = export.get("features").and_then(Value::as_array) {
+        if export.get("type").is_none() && features.iter().all(|f| f.get("enabled").is_some()) {
+            return Ok(features.iter().map(|flag| ("unleash", flag)).collect());
+        }
+    }
+    Err("Unrecognized flag export: expected a LaunchDarkly `flags` object or an Unleash `features` array".to_string())
+}
+
+fn feature_flag_key(source: &str, flag: &Value) -> String {
+    let field = if source == "unleash" { "name" } else { "key" };
+    flag.get(field)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn describe_flag_rules(source: &str, flag: &Value) -> Vec<String> {
+    let empty = Vec::new();
+    if source == "unleash" {
+        return flag
+            .get("strategies")
+            .and_then(Value::as_array)
+            .unwrap_or(&empty)
+            .iter()
+            .map(|strategy| {
+                let name = strategy.get("name").and_then(Value::as_str).unwrap_or("?");
+                let constraints = strategy
+                    .get("constraints")
+                    .and_then(Value::as_array)
+                    .map_or(0, Vec::len);
+                format!("{} ({} constraint(s))", name, constraints)
+            })
+            .collect();
+    }
+
+    flag.get("rules")
+        .and_then(Value::as_array)
+        .unwrap_or(&empty)
+        .iter()
+        .map(|rule| {
+            rule.get("clauses")
+                .and_then(Value::as_array)
+                .unwrap_or(&empty)
+                .iter()
+                .map(|clause| {
+                    format!(
+                        "{}{} {} {}",
+                        if clause.get("negate") == Some(&Value::Bool(true)) {
+                            "not "
+                        } else {
+                            ""
+                        },
+                        clause
+                            .get("attribute")
+                            .and_then(Value::as_str)
+                            .unwrap_or("?"),
+                        clause.get("op").and_then(Value::as_str).unwrap_or("?"),
+                        clause.get("values").unwrap_or(&Value::Null)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" and ")
+        })
+        .collect()
+}
+
+/// Look up a context attribute by name (also under LaunchDarkly's `custom`), or by
+/// JSON Pointer when it starts with `/`
+fn context_attribute<'a>(context: &'a Value, attribute: &str) -> Option<&'a Value> {
+    if attribute.starts_with('/') {
+        return context.pointer(attribute);
+    }
+    context.get(attribute).or_else(|| {
+        context
+            .get("custom")
+            .and_then(|custom| custom.get(attribute))
+    })
+}
+
+fn flag_operator_matches(op: &str, actual: &Value, expected: &Value) -> bool {
+    let text = |v: &Value| match v {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    match op {
+        "in" | "IN" | "NUM_EQ" => {
+            actual == expected
+                || text(actual) == text(expected)
+                || matches!((actual.as_f64(), expected.as_f64()), (Some(a), Some(b)) if a == b)
+        }
+        "startsWith" | "STR_STARTS_WITH" => text(actual).starts_with(&text(expected)),
+        "endsWith" | "STR_ENDS_WITH" => text(actual).ends_with(&text(expected)),
+        "contains" | "STR_CONTAINS" => text(actual).contains(&text(expected)),
+        "lessThan" | "NUM_LT" | "lessThanOrEqual" | "NUM_LTE" | "greaterThan" | "NUM_GT"
+        | "greaterThanOrEqual" | "NUM_GTE" => {
+            let parse = |v: &Value| v.as_f64().or_else(|| text(v).parse().ok());
+            let (Some(a), Some(b)) = (parse(actual), parse(expected)) else {
+                return false;
+            };
+            match op {
+                "lessThan" | "NUM_LT" => a < b,
+                "lessThanOrEqual" | "NUM_LTE" => a <= b,
+                "greaterThan" | "NUM_GT" => a > b,
+                _ => a >= b,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// A clause/constraint matches when any context value matches any listed value
+fn flag_condition_matches(context: &Value, attribute: &str, op: &str, values: &[Value]) -> bool {
+    let Some(actual) = context_attribute(context, attribute) else {
+        return false;
+    };
+    let actuals = match actual {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    };
+    actuals.iter().any(|actual| {
+        values
+            .iter()
+            .any(|expected| flag_operator_matches(op, actual, expected))
+    })
+}
+
+/// Rollouts are not bucketed; the heaviest variation stands in for the rollout
+fn launch_darkly_variation(serve: &Value) -> Option<(usize, bool)> {
+    if let Some(index) = serve.get("variation").and_then(Value::as_u64) {
+        return Some((index as usize, false));
+    }
+    serve
+        .pointer("/rollout/variations")
+        .and_then(Value::as_array)?
+        .iter()
+        .max_by_key(|v| v.get("weight").and_then(Value::as_u64).unwrap_or(0))
+        .and_then(|v| v.get("variation").and_then(Value::as_u64))
+        .map(|index| (index as usize, true))
+}
+
+fn evaluate_launch_darkly_flag(flag: &Value, context: &Value) -> (Option<usize>, String) {
+    let empty = Vec::new();
+    if flag.get("on") != Some(&Value::Bool(true)) {
+        let off = flag.get("offVariation").and_then(Value::as_u64);
+        return (off.map(|i| i as usize), "OFF".to_string());
+    }
+
+    let context_key = context.get("key").and_then(Value::as_str);
+    for target in flag
+        .get("targets")
+        .and_then(Value::as_array)
+        .unwrap_or(&empty)
+    {
+        let listed = target
+            .get("values")
+            .and_then(Value::as_array)
+            .unwrap_or(&empty)
+            .iter()
+            .any(|value| value.as_str().is_some() && value.as_str() == context_key);
+        if listed {
+            let index = target.get("variation").and_then(Value::as_u64);
+            return (index.map(|i| i as usize), "TARGET_MATCH".to_string());
+        }
+    }
+
+    let serve_reason = |serve: &Value, reason: String| match launch_darkly_variation(serve) {
+        Some((index, true)) => (
+            Some(index),
+            format!("{} (rollout, heaviest variation)", reason),
+        ),
+        Some((index, false)) => (Some(index), reason),
+        None => (None, format!("{} without a variation", reason)),
+    };
+
+    let rules = flag
+        .get("rules")
+        .and_then(Value::as_array)
+        .unwrap_or(&empty);
+    for (i, rule) in rules.iter().enumerate() {
+        let matched = rule
+            .get("clauses")
+            .and_then(Value::as_array)
+            .unwrap_or(&empty)
+            .iter()
+            .all(|clause| {
+                let attribute = clause
+                    .get("attribute")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                let op = clause.get("op").and_then(Value::as_str).unwrap_or("");
+                let values = clause
+                    .get("values")
+                    .and_then(Value::as_array)
+                    .unwrap_or(&empty);
+                let negate = clause.get("negate") == Some(&Value::Bool(true));
+                flag_condition_matches(context, attribute, op, values) != negate
+            });
+        if matched {
+            return serve_reason(rule, format!("RULE_MATCH (rule {})", i + 1));
+        }
+    }
+
+    serve_reason(
+        flag.get("fallthrough").unwrap_or(&Value::Null),
+        "FALLTHROUGH".to_string(),
+    )
+}
+
+fn evaluate_unleash_flag(flag: &Value, context: &Value) -> (bool, String) {
+    let empty = Vec::new();
+    if flag.get("enabled") != Some(&Value::Bool(true)) {
+        return (false, "DISABLED".to_string());
+    }
+
+    let strategies = flag
+        .get("strategies")
+        .and_then(Value::as_array)
+        .unwrap_or(&empty);
+    for (i, strategy) in strategies.iter().enumerate() {
+        let constraints_pass = strategy
+            .get("constraints")
+            .and_then(Value::as_array)
+            .unwrap_or(&empty)
+            .iter()
+            .all(|constraint| {
+                let name = constraint
+                    .get("contextName")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                let op = constraint
+                    .get("operator")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                let values = constraint
+                    .get("values")
+                    .and_then(Value::as_array)
+                    .unwrap_or(&empty);
+                let inverted = constraint.get("inverted") == Some(&Value::Bool(true));
+                let matched = if op == "NOT_IN" {
+                    !flag_condition_matches(context, name, "IN", values)
+                } else {
+                    flag_condition_matches(context, name, op, values)
+                };
+                matched != inverted
+            });
+        if !constraints_pass {
+            continue;
+        }
+
+        let name = strategy.get("name").and_then(Value::as_str).unwrap_or("");
+        let parameter = |key: &str| {
+            strategy
+                .pointer(&format!("/parameters/{}", key))
+                .map(|v| match v {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .unwrap_or_default()
+        };
+        let listed = |key: &str, attribute: &str| {
+            let actual = context_attribute(context, attribute).map(|v| match v {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            });
+            parameter(key)
+                .split(',')
+                .any(|item| Some(item.trim().to_string()) == actual)
+        };
+        let (enabled, note) = match name {
+            "default" => (true, ""),
+            "userWithId" => (listed("userIds", "userId"), ""),
+            "remoteAddress" => (listed("IPs", "remoteAddress"), ""),
+            "flexibleRollout"
+            | "gradualRolloutUserId"
+            | "gradualRolloutSessionId"
+            | "gradualRolloutRandom" => {
+                let rollout = parameter("rollout");
+                let rollout = if rollout.is_empty() {
+                    parameter("percentage")
+                } else {
+                    rollout
+                };
+                match rollout.parse::<u32>() {
+                    Ok(100) => (true, ""),
+                    Ok(0) => (false, ""),
+                    _ => (true, ", partial rollout not bucketed"),
+                }
+            }
+            _ => (false, ""),
+        };
+        if enabled {
+            return (
+                true,
+                format!("STRATEGY_MATCH ({} #{}{})", name, i + 1, note),
+            );
+        }
+    }
+
+    (false, "NO_STRATEGY_MATCHED".to_string())
+}
+
+/// List the flags in a LaunchDarkly or Unleash flag export
+#[tauri::command]
+fn list_feature_flags(input: String) -> Result<Vec<FeatureFlagSummary>, String> {
+    info!("list_feature_flags called - input_len: {}", input.len());
+
+    check_parse_limits(&input)?;
+    let export: Value = serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    Ok(feature_flags(&export)?
+        .into_iter()
+        .map(|(source, flag)| FeatureFlagSummary {
+            key: feature_flag_key(source, flag),
+            source,
+            enabled: flag.get(if source == "unleash" { "enabled" } else { "on" })
+                == Some(&Value::Bool(true)),
+            variations: flag
+                .get(if source == "unleash" {
+                    "variants"
+                } else {
+                    "variations"
+                })
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default(),
+            rules: describe_flag_rules(source, flag),
+        })
+        .collect())
+}
+
+/// Evaluate one flag of a flag export against a user context object, locally
+#[tauri::command]
+fn evaluate_feature_flag(
+    input: String,
+    flag_key: String,
+    context: String,
+) -> Result<FlagEvaluation, String> {
+    info!("evaluate_feature_flag called - flag_key: '{}'", flag_key);
+
+    check_parse_limits(&input)?;
+    let export: Value = serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let context: Value = if context.trim().is_empty() {
+        Value::Object(serde_json::Map::new())
+    } else {
+        serde_json::from_str(&context).map_err(|e| format!("Invalid context JSON: {}", e))?
+    };
+
+    let (source, flag) = feature_flags(&export)?
+        .into_iter()
+        .find(|(source, flag)| feature_flag_key(source, flag) == flag_key)
+        .ok_or_else(|| format!("Flag not found: {}", flag_key))?;
+
+    if source == "unleash" {
+        let (enabled, reason) = evaluate_unleash_flag(flag, &context);
+        return Ok(FlagEvaluation {
+            flag_key,
+            value: Value::Bool(enabled),
+            variation: None,
+            reason,
+        });
+    }
+
+    let (variation, reason) = evaluate_launch_darkly_flag(flag, &context);
+    let value = variation
+        .and_then(|index| flag.get("variations")?.get(index).cloned())
+        .unwrap_or(Value::Null);
+    Ok(FlagEvaluation {
+        flag_key,
+        value,
+        variation,
+        reason,
+    })
+}
+
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct DependencyEntry {
+    name: String,
+    dep_type: String,
+    range: String,
+    /// Workspace member declaring the dependency (Cargo metadata only)
+    declared_by: Option<String>,
+}
+
+#[derive(serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct DependencyReport {
+    manifest: &'static str,
+    counts: BTreeMap<String, usize>,
+    dependencies: Vec<DependencyEntry>,
+    duplicates: Vec<String>,
+    report: String,
+}
+
+const PACKAGE_JSON_DEP_TYPES: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+const COMPOSER_DEP_TYPES: &[&str] = &["require", "require-dev"];
+
+fn manifest_section_entries(manifest: &Value, sections: &[&str]) -> Vec<DependencyEntry> {
+    sections
+        .iter()
+        .filter_map(|section| Some((*section, manifest.get(*section)?.as_object()?)))
+        .flat_map(|(section, deps)| {
+            deps.iter().map(move |(name, range)| DependencyEntry {
+                name: name.clone(),
+                dep_type: section.to_string(),
+                range: range
+                    .as_str()
+                    .map_or_else(|| range.to_string(), str::to_string),
+                declared_by: None,
+            })
+        })
+        .collect()
+}
+
+/// `cargo metadata --format-version 1` output; only workspace members are reported
+fn cargo_metadata_entries(metadata: &Value) -> Vec<DependencyEntry> {
+    let members: Option<HashSet<&str>> = metadata
+        .get("workspace_members")
+        .and_then(Value::as_array)
+        .map(|ids| ids.iter().filter_map(Value::as_str).collect());
+    let empty = Vec::new();
+
+    metadata
+        .get("packages")
+        .and_then(Value::as_array)
+        .unwrap_or(&empty)
+        .iter()
+        .filter(|package| {
+            let id = package.get("id").and_then(Value::as_str).unwrap_or("");
+            members.as_ref().is_none_or(|members| members.contains(id))
+        })
+        .flat_map(|package| {
+            let package_name = package.get("name").and_then(Value::as_str).unwrap_or("?");
+            package
+                .get("dependencies")
+                .and_then(Value::as_array)
+                .unwrap_or(&empty)
+                .iter()
+                .map(move |dep| DependencyEntry {
+                    name: dep
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or("?")
+                        .to_string(),
+                    dep_type: dep
+                        .get("kind")
+                        .and_then(Value::as_str)
+                        .unwrap_or("normal")
+                        .to_string(),
+                    range: dep
+                        .get("req")
+                        .and_then(Value::as_str)
+                        .unwrap_or("*")
+                        .to_string(),
+                    declared_by: Some(package_name.to_string()),
+                })
+        })
+        .collect()
+}
+
+fn render_dependency_table(dependencies: &[DependencyEntry]) -> String {
+    let with_package = dependencies.iter().any(|dep| dep.declared_by.is_some());
+    let rows: Vec<Vec<&str>> = dependencies
+        .iter()
+        .map(|dep| {
+            let mut row = vec![dep.name.as_str(), dep.dep_type.as_str(), dep.range.as_str()];
+            if with_package {
+                row.push(dep.declared_by.as_deref().unwrap_or(""));
+            }
+            row
+        })
+        .collect();
+    let mut headers = vec!["Name", "Type", "Range"];
+    if with_package {
+        headers.push("Declared by");
+    }
+
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain(std::iter::once(headers[i].len()))
+                .max()
+                .unwrap_or(0)
+                .min(48)
+        })
+        .collect();
+    let separator = format!(
+        "+{}+",
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width + 2))
+            .collect::<Vec<_>>()
+            .join("+")
+    );
+
+    let mut lines = vec![separator.clone()];
+    lines.push(format_ascii_table_row(&headers, &widths));
+    lines.push(separator.clone());
+    for row in &rows {
+        lines.push(format_ascii_table_row(row, &widths));
+    }
+    lines.push(separator);
+    lines.join("\n")
+}
+
+/// Summarize the dependencies of a package.json, composer.json or `cargo metadata` document
+#[tauri::command(async)]
+fn dependency_report(input: String) -> Result<DependencyReport, String> {
+    info!("dependency_report called - input_len: {}", input.len());
+
+    check_parse_limits(&input)?;
+    let manifest_value: Value =
+        serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let (manifest, mut dependencies) =
+        if manifest_value.get("packages").is_some_and(Value::is_array) {
+            ("cargo metadata", cargo_metadata_entries(&manifest_value))
+        } else if COMPOSER_DEP_TYPES
+            .iter()
+            .any(|section| manifest_value.get(*section).is_some())
+        {
+            (
+                "composer.json",
+                manifest_section_entries(&manifest_value, COMPOSER_DEP_TYPES),
+            )
+        } else if PACKAGE_JSON_DEP_TYPES
+            .iter()
+            .any(|section| manifest_value.get(*section).is_some())
+        {
+            (
+                "package.json",
+                manifest_section_entries(&manifest_value, PACKAGE_JSON_DEP_TYPES),
+            )
+        } else {
+            return Err(
+            "Unrecognized manifest: expected package.json, composer.json or cargo metadata output"
+                .to_string(),
+        );
+        };
+
+    dependencies.sort_by(|a, b| {
+        (&a.name, &a.dep_type, &a.declared_by).cmp(&(&b.name, &b.dep_type, &b.declared_by))
+    });
+
+    let mut counts = BTreeMap::new();
+    for dep in &dependencies {
+        *counts.entry(dep.dep_type.clone()).or_insert(0) += 1;
+    }
+
+    let duplicates: Vec<String> = dependencies
+        .chunk_by(|a, b| a.name == b.name)
+        .filter(|group| group.len() > 1)
+        .map(|group| {
+            let declarations: Vec<String> = group
+                .iter()
+                .map(|dep| match &dep.declared_by {
+                    Some(package) => format!("{} {} {}", package, dep.dep_type, dep.range),
+                    None => format!("{} {}", dep.dep_type, dep.range),
+                })
+                .collect();
+            format!("{}: {}", group[0].name, declarations.join(", "))
+        })
+        .collect();
+
+    let mut report = format!("Manifest: {}\nTotal: {}", manifest, dependencies.len());
+    for (dep_type, count) in &counts {
+        report.push_str(&format!(", {}: {}", dep_type, count));
+    }
+    report.push_str("\n\n");
+    if !duplicates.is_empty() {
+        report.push_str("Declared more than once:\n");
+        for duplicate in &duplicates {
+            report.push_str(&format!("  {}\n", duplicate));
+        }
+        report.push('\n');
+    }
+    if !dependencies.is_empty() {
+        report.push_str(&render_dependency_table(&dependencies));
+        report.push('\n');
+    }
+
+    Ok(DependencyReport {
+        manifest,
+        counts,
+        dependencies,
+        duplicates,
+        report,
+    })
+}
+
+/// UI action an analyzer offers for a detected document. `id` names a frontend handler;
+/// `pointer` optionally targets a subtree for that handler.
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct AnalyzerAction {
+    id: &'static str,
+    label: &'static str,
+    pointer: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct DocumentAnalysis {
+    analyzer: &'static str,
+    label: &'static str,
+    summary: Vec<String>,
+    issues: Vec<String>,
+    actions: Vec<AnalyzerAction>,
+}
+
+/// A domain-aware analyzer. New document kinds slot in by adding an entry to
+/// `DOCUMENT_ANALYZERS`; `analyze` fills in the summary and issues.
+struct DocumentAnalyzer {
+    id: &'static str,
+    label: &'static str,
+    detect: fn(&Value) -> bool,
+    analyze: fn(&Value, &mut DocumentAnalysis),
+    actions: &'static [AnalyzerAction],
+}
+
+const DOCUMENT_ANALYZERS: &[DocumentAnalyzer] = &[
+    DocumentAnalyzer {
+        id: "geojson",
+        label: "GeoJSON",
+        detect: is_geojson,
+        analyze: analyze_geojson,
+        actions: &[],
+    },
+    DocumentAnalyzer {
+        id: "har",
+        label: "HTTP Archive (HAR)",
+        detect: is_har,
+        analyze: analyze_har,
+        actions: &[AnalyzerAction {
+            id: "showPointer",
+            label: "Show entries",
+            pointer: Some("/log/entries"),
+        }],
+    },
+    DocumentAnalyzer {
+        id: "openapi",
+        label: "OpenAPI",
+        detect: is_openapi,
+        analyze: analyze_openapi,
+        actions: &[AnalyzerAction {
+            id: "showPointer",
+            label: "Show paths",
+            pointer: Some("/paths"),
+        }],
+    },
+    DocumentAnalyzer {
+        id: "packageJson",
+        label: "package.json",
+        detect: is_package_json,
+        analyze: analyze_package_json,
+        actions: &[AnalyzerAction {
+            id: "dependencyReport",
+            label: "Dependency report",
+            pointer: None,
+        }],
+    },
+    DocumentAnalyzer {
+        id: "cloudEvents",
+        label: "CloudEvents",
+        detect: is_cloud_events,
+        analyze: analyze_cloud_events,
+        actions: &[],
+    },
+    DocumentAnalyzer {
+        id: "featureFlags",
+        label: "Feature flag export",
+        detect: |value| feature_flags(value).is_ok(),
+        analyze: |value, analysis| {
+            if let Ok(flags) = feature_flags(value) {
+                analysis.summary.push(format!("{} flag(s)", flags.len()));
+            }
+        },
+        actions: &[AnalyzerAction {
+            id: "listFlags",
+            label: "List flags",
+            pointer: None,
+        }],
+    },
+];
+
+const GEOJSON_TYPES: &[&str] = &[
+    "FeatureCollection",
+    "Feature",
+    "Point",
+    "MultiPoint",
+    "LineString",
+    "MultiLineString",
+    "Polygon",
+    "MultiPolygon",
+    "GeometryCollection",
+];
+
+fn is_geojson(value: &Value) -> bool {
+    value
+        .get("type")
+        .and_then(Value::as_str)
+        .is_some_and(|kind| GEOJSON_TYPES.contains(&kind))
+        && (value.get("features").is_some()
+            || value.get("geometry").is_some()
+            || value.get("coordinates").is_some()
+            || value.get("geometries").is_some())
+}
+
+/// Collect every `[lon, lat]` position below a `coordinates` value
+fn collect_positions<'a>(coordinates: &'a Value, positions: &mut Vec<&'a [Value]>) {
+    if let Some(items) = coordinates.as_array() {
+        if items.first().is_some_and(Value::is_number) {
+            positions.push(items);
+        } else {
+            for item in items {
+                collect_positions(item, positions);
+            }
+        }
+    }
+}
+
+fn analyze_geojson(value: &Value, analysis: &mut DocumentAnalysis) {
+    let mut geometries = Vec::new();
+    match value.get("type").and_then(Value::as_str) {
+        Some("FeatureCollection") => match value.get("features").and_then(Value::as_array) {
+            Some(features) => {
+                analysis
+                    .summary
+                    .push(format!("{} feature(s)", features.len()));
+                for (i, feature) in features.iter().enumerate() {
+                    match feature.get("geometry") {
+                        Some(geometry) => geometries.push((format!("/features/{}", i), geometry)),
+                        None => analysis
+                            .issues
+                            .push(format!("/features/{}: feature has no geometry", i)),
+                    }
+                }
+            }
+            None => analysis
+                .issues
+                .push("FeatureCollection has no features array".to_string()),
+        },
+        Some("Feature") => match value.get("geometry") {
+            Some(geometry) => geometries.push((String::new(), geometry)),
+            None => analysis.issues.push("Feature has no geometry".to_string()),
+        },
+        _ => geometries.push((String::new(), value)),
+    }
+
+    let mut type_counts: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut bounds: Option<(f64, f64, f64, f64)> = None;
+    for (pointer, geometry) in geometries {
+        if geometry.is_null() {
+            continue;
+        }
+        let kind = geometry.get("type").and_then(Value::as_str).unwrap_or("?");
+        *type_counts.entry(kind).or_insert(0) += 1;
+
+        let mut positions = Vec::new();
+        collect_positions(
+            geometry.get("coordinates").unwrap_or(&Value::Null),
+            &mut positions,
+        );
+        for position in positions {
+            let (Some(lon), Some(lat)) = (
+                position.first().and_then(Value::as_f64),
+                position.get(1).and_then(Value::as_f64),
+            ) else {
+                continue;
+            };
+            if !(-180.0..=180.0).contains(&lon) || !(-90.0..=90.0).contains(&lat) {
+                analysis.issues.push(format!(
+                    "{}: position [{}, {}] is out of range (longitude comes first)",
+                    pointer, lon, lat
+                ));
+            }
+            bounds = Some(match bounds {
+                None => (lon, lat, lon, lat),
+                Some((w, s, e, n)) => (w.min(lon), s.min(lat), e.max(lon), n.max(lat)),
+            });
+        }
+
+        if kind == "Polygon" {
+            let rings = geometry.get("coordinates").and_then(Value::as_array);
+            for ring in rings.into_iter().flatten().filter_map(Value::as_array) {
+                if ring.first() != ring.last() || ring.len() < 4 {
+                    analysis.issues.push(format!(
+                        "{}: polygon ring must be closed and have at least 4 positions",
+                        pointer
+                    ));
+                }
+            }
+        }
+    }
+
+    if !type_counts.is_empty() {
+        let types: Vec<String> = type_counts
+            .iter()
+            .map(|(kind, count)| format!("{} {}", count, kind))
+            .collect();
+        analysis
+            .summary
+            .push(format!("Geometries: {}", types.join(", ")));
+    }
+    if let Some((west, south, east, north)) = bounds {
+        analysis.summary.push(format!(
+            "Bounding box: [{}, {}, {}, {}]",
+            west, south, east, north
+        ));
+    }
+}
+
+fn is_har(value: &Value) -> bool {
+    value.pointer("/log/entries").is_some_and(Value::is_array)
+}
+
+fn analyze_har(value: &Value, analysis: &mut DocumentAnalysis) {
+    let empty = Vec::new();
+    let entries = value
+        .pointer("/log/entries")
+        .and_then(Value::as_array)
+        .unwrap_or(&empty);
+    analysis
+        .summary
+        .push(format!("{} request(s)", entries.len()));
+
+    let mut statuses: BTreeMap<u64, usize> = BTreeMap::new();
+    let mut total_bytes = 0i64;
+    let mut slowest: Option<(f64, &str)> = None;
+    for (i, entry) in entries.iter().enumerate() {
+        let url = entry
+            .pointer("/request/url")
+            .and_then(Value::as_str)
+            .unwrap_or("?");
+        if entry.get("request").is_none() || entry.get("response").is_none() {
+            analysis
+                .issues
+                .push(format!("/log/entries/{}: missing request or response", i));
+            continue;
+        }
+        let status = entry
+            .pointer("/response/status")
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+        *statuses.entry(status).or_insert(0) += 1;
+        if status == 0 || status >= 400 {
+            analysis
+                .issues
+                .push(format!("/log/entries/{}: {} returned {}", i, url, status));
+        }
+        total_bytes += entry
+            .pointer("/response/content/size")
+            .and_then(Value::as_i64)
+            .unwrap_or(0)
+            .max(0);
+        let time = entry.get("time").and_then(Value::as_f64).unwrap_or(0.0);
+        if slowest.is_none_or(|(slowest_time, _)| time > slowest_time) {
+            slowest = Some((time, url));
+        }
+    }
+
+    if !statuses.is_empty() {
+        let statuses: Vec<String> = statuses
+            .iter()
+            .map(|(status, count)| format!("{} x{}", status, count))
+            .collect();
+        analysis
+            .summary
+            .push(format!("Statuses: {}", statuses.join(", ")));
+    }
+    analysis
+        .summary
+        .push(format!("Response content: {} bytes", total_bytes));
+    if let Some((time, url)) = slowest {
+        analysis
+            .summary
+            .push(format!("Slowest: {} ({:.0} ms)", url, time));
+    }
+}
+
+fn is_openapi(value: &Value) -> bool {
+    value.get("openapi").is_some_and(Value::is_string)
+        || value.get("swagger").is_some_and(Value::is_string)
+}
+
+const OPENAPI_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Collect every local `$ref` target in the document
+fn collect_local_refs<'a>(value: &'a Value, refs: &mut BTreeSet<&'a str>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                match (key.as_str(), child) {
+                    ("$ref", Value::String(target)) if target.starts_with("#/") => {
+                        refs.insert(target);
+                    }
+                    _ => collect_local_refs(child, refs),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_local_refs(item, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn analyze_openapi(value: &Value, analysis: &mut DocumentAnalysis) {
+    let version = value
+        .get("openapi")
+        .or_else(|| value.get("swagger"))
+        .and_then(Value::as_str)
+        .unwrap_or("?");
+    let title = value.pointer("/info/title").and_then(Value::as_str);
+    analysis.summary.push(format!(
+        "{} (spec {})",
+        title.unwrap_or("Untitled API"),
+        version
+    ));
+    if title.is_none() || value.pointer("/info/version").is_none() {
+        analysis
+            .issues
+            .push("info.title and info.version are required".to_string());
+    }
+
+    let empty = serde_json::Map::new();
+    let paths = value
+        .get("paths")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let mut operations = 0;
+    let mut operation_ids: HashMap<&str, usize> = HashMap::new();
+    for (path, item) in paths {
+        for method in OPENAPI_METHODS {
+            let Some(operation) = item.get(*method) else {
+                continue;
+            };
+            operations += 1;
+            if operation.get("responses").is_none() {
+                analysis.issues.push(format!(
+                    "{} {}: operation has no responses",
+                    method.to_uppercase(),
+                    path
+                ));
+            }
+            if let Some(id) = operation.get("operationId").and_then(Value::as_str) {
+                *operation_ids.entry(id).or_insert(0) += 1;
+            }
+        }
+    }
+    let mut duplicated: Vec<&str> = operation_ids
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(id, _)| id)
+        .collect();
+    duplicated.sort_unstable();
+    for id in duplicated {
+        analysis
+            .issues
+            .push(format!("operationId '{}' is used more than once", id));
+    }
+
+    let schemas = value
+        .pointer("/components/schemas")
+        .or_else(|| value.get("definitions"))
+        .and_then(Value::as_object)
+        .map_or(0, |schemas| schemas.len());
+    analysis.summary.push(format!(
+        "{} path(s), {} operation(s), {} schema(s)",
+        paths.len(),
+        operations,
+        schemas
+    ));
+
+    let mut refs = BTreeSet::new();
+    collect_local_refs(value, &mut refs);
+    for target in refs {
+        if value.pointer(&target[1..]).is_none() {
+            analysis
+                .issues
+                .push(format!("$ref '{}' does not resolve", target));
+        }
+    }
+}
+
+fn is_package_json(value: &Value) -> bool {
+    value.get("name").is_some_and(Value::is_string)
+        && (value.get("scripts").is_some()
+            || PACKAGE_JSON_DEP_TYPES
+                .iter()
+                .any(|section| value.get(*section).is_some()))
+}
+
+fn analyze_package_json(value: &Value, analysis: &mut DocumentAnalysis) {
+    let name = value.get("name").and_then(Value::as_str).unwrap_or("");
+    let version = value.get("version").and_then(Value::as_str);
+    analysis
+        .summary
+        .push(format!("{}@{}", name, version.unwrap_or("(no version)")));
+
+    let counts: Vec<String> = PACKAGE_JSON_DEP_TYPES
+        .iter()
+        .filter_map(|section| {
+            let deps = value.get(*section)?.as_object()?;
+            Some(format!("{} {}", deps.len(), section))
+        })
+        .collect();
+    if !counts.is_empty() {
+        analysis.summary.push(counts.join(", "));
+    }
+    if let Some(scripts) = value.get("scripts").and_then(Value::as_object) {
+        let names: Vec<&str> = scripts.keys().map(String::as_str).collect();
+        analysis
+            .summary
+            .push(format!("Scripts: {}", names.join(", ")));
+    }
+
+    if name.len() > 214
+        || name.chars().any(|c| c.is_uppercase() || c.is_whitespace())
+        || name.starts_with(['.', '_'])
+    {
+        analysis.issues.push(format!(
+            "'{}' is not a valid npm package name (lowercase, no spaces, max 214 characters)",
+            name
+        ));
+    }
+    if version.is_none() && value.get("private") != Some(&Value::Bool(true)) {
+        analysis
+            .issues
+            .push("version is required unless the package is private".to_string());
+    }
+}
+
+const CLOUD_EVENT_REQUIRED: &[&str] = &["id", "source", "specversion", "type"];
+
+fn is_cloud_events(value: &Value) -> bool {
+    match value {
+        Value::Array(events) => events
+            .first()
+            .is_some_and(|event| event.get("specversion").is_some()),
+        _ => value.get("specversion").is_some(),
+    }
+}
+
+fn analyze_cloud_events(value: &Value, analysis: &mut DocumentAnalysis) {
+    let events: Vec<&Value> = match value {
+        Value::Array(events) => events.iter().collect(),
+        event => vec![event],
+    };
+    if events.len() > 1 {
+        analysis
+            .summary
+            .push(format!("Batch of {} events", events.len()));
+    }
+
+    let mut types: BTreeMap<&str, usize> = BTreeMap::new();
+    for (i, event) in events.iter().enumerate() {
+        let prefix = if value.is_array() {
+            format!("/{}: ", i)
+        } else {
+            String::new()
+        };
+        for attribute in CLOUD_EVENT_REQUIRED {
+            if event
+                .get(*attribute)
+                .and_then(Value::as_str)
+                .is_none_or(str::is_empty)
+            {
+                analysis.issues.push(format!(
+                    "{}required attribute '{}' is missing or empty",
+                    prefix, attribute
+                ));
+            }
+        }
+        if let Some(version) = event.get("specversion").and_then(Value::as_str) {
+            if version != "1.0" {
+                analysis
+                    .issues
+                    .push(format!("{}specversion '{}' is not 1.0", prefix, version));
+            }
+        }
+        if event.get("data").is_some() && event.get("data_base64").is_some() {
+            analysis.issues.push(format!(
+                "{}data and data_base64 are mutually exclusive",
+                prefix
+            ));
+        }
+        if let Some(kind) = event.get("type").and_then(Value::as_str) {
+            *types.entry(kind).or_insert(0) += 1;
+        }
+    }
+
+    if let [event] = events.as_slice() {
+        for attribute in ["type", "source", "subject", "datacontenttype"] {
+            if let Some(text) = event.get(attribute).and_then(Value::as_str) {
+                analysis.summary.push(format!("{}: {}", attribute, text));
+            }
+        }
+    } else if !types.is_empty() {
+        let types: Vec<String> = types
+            .iter()
+            .map(|(kind, count)| format!("{} x{}", kind, count))
+            .collect();
+        analysis
+            .summary
+            .push(format!("Types: {}", types.join(", ")));
+    }
+}
+
+/// Run every registered analyzer that recognizes the document
+#[tauri::command(async)]
+fn analyze_document(input: String) -> Result<Vec<DocumentAnalysis>, String> {
+    info!("analyze_document called - input_len: {}", input.len());
+
+    check_job_memory("analyze_document", input.len())?;
+    let value = parse_json(&input)?;
+
+    Ok(DOCUMENT_ANALYZERS
+        .iter()
+        .filter(|analyzer| (analyzer.detect)(&value))
+        .map(|analyzer| {
+            let mut analysis = DocumentAnalysis {
+                analyzer: analyzer.id,
+                label: analyzer.label,
+                actions: analyzer.actions.to_vec(),
+                ..Default::default()
+            };
+            (analyzer.analyze)(&value, &mut analysis);
+            analysis
+        })
+        .collect())
+}
+
+/// Containers nested deeper than this are reported by `lint_json`
+const LINT_MAX_DEPTH: usize = 8;
+/// Strings longer than this are reported by `lint_json`
+const LINT_MAX_STRING_BYTES: usize = 64 * 1024;
+/// `lint_json` stops collecting issues after this many
+const LINT_MAX_ISSUES: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum LintRule {
+    MixedArray,
+    NumericString,
+    KeyCasing,
+    DeepNesting,
+    LargeString,
+}
+
+/// A structural smell found by `lint_json`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct LintIssue {
+    rule: LintRule,
+    pointer: String,
+    message: String,
+    suggestion: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct LintReport {
+    issues: Vec<LintIssue>,
+    /// Whether issues past `LINT_MAX_ISSUES` were left out
+    truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum KeyCase {
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl KeyCase {
+    /// The convention a key follows, ignoring a leading `_`, `$` or `@`. Single lowercase
+    /// words fit several conventions and give `None`, as do keys with other characters.
+    fn of(key: &str) -> Option<KeyCase> {
+        let key = key.trim_start_matches(['_', '$', '@']);
+        if key.is_empty()
+            || !key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return None;
+        }
+        let lower = key.chars().any(|c| c.is_ascii_lowercase());
+        let upper = key.chars().any(|c| c.is_ascii_uppercase());
+        match (key.contains('_'), key.contains('-')) {
+            (true, false) if !upper => Some(KeyCase::Snake),
+            (true, false) if !lower => Some(KeyCase::ScreamingSnake),
+            (false, true) if !upper => Some(KeyCase::Kebab),
+            (false, false) if upper && lower => {
+                if key.starts_with(|c: char| c.is_ascii_uppercase()) {
+                    Some(KeyCase::Pascal)
+                } else {
+                    Some(KeyCase::Camel)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            KeyCase::Camel => "camelCase",
+            KeyCase::Pascal => "PascalCase",
+            KeyCase::Snake => "snake_case",
+            KeyCase::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            KeyCase::Kebab => "kebab-case",
+        }
+    }
+
+    /// `key` renamed to this convention, keeping its prefix
+    fn apply(self, key: &str) -> String {
+        let name = key.trim_start_matches(['_', '$', '@']);
+        let prefix = &key[..key.len() - name.len()];
+        // Word boundaries of an all-caps key are only its separators
+        let name = if name.chars().any(|c| c.is_ascii_lowercase()) {
+            name.to_string()
+        } else {
+            name.to_lowercase()
+        };
+        let renamed = match self {
+            KeyCase::Camel => to_camel_case(&name),
+            KeyCase::Pascal => to_pascal_case(&name),
+            KeyCase::Snake => to_snake_case(&name),
+            KeyCase::ScreamingSnake => to_snake_case(&name).to_uppercase(),
+            KeyCase::Kebab => to_snake_case(&name).replace('_', "-"),
+        };
+        format!("{}{}", prefix, renamed)
+    }
+}
+
+fn count_key_cases(value: &Value, counts: &mut BTreeMap<KeyCase, usize>) {
+    match value {
+        Value::Array(items) => items.iter().for_each(|item| count_key_cases(item, counts)),
+        Value::Object(map) => {
+            for (key, item) in map {
+                if let Some(case) = KeyCase::of(key) {
+                    *counts.entry(case).or_default() += 1;
+                }
+                count_key_cases(item, counts);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+struct JsonLinter {
+    /// The document's prevailing key convention, set when keys disagree
+    key_case: Option<KeyCase>,
+    /// Keys already reported, so a key repeated in every record is reported once
+    reported_keys: HashSet<String>,
+    report: LintReport,
+}
+
+impl JsonLinter {
+    fn push(&mut self, rule: LintRule, pointer: &str, message: String, suggestion: String) {
+        if self.report.issues.len() == LINT_MAX_ISSUES {
+            self.report.truncated = true;
+            return;
+        }
+        self.report.issues.push(LintIssue {
+            rule,
+            pointer: pointer.to_string(),
+            message,
+            suggestion,
+        });
+    }
+
+    /// Lint `value` at `pointer`; `depth` counts the containers down to it, starting at 1
+    fn lint(&mut self, value: &Value, pointer: &str, depth: usize) {
+        if value.is_array() || value.is_object() {
+            // Only the first container past the limit is reported for each subtree
+            if depth == LINT_MAX_DEPTH + 1 {
+                self.push(
+                    LintRule::DeepNesting,
+                    pointer,
+                    format!(
+                        "Nesting reaches depth {} (more than {})",
+                        depth - 1 + json_depth(value),
+                        LINT_MAX_DEPTH
+                    ),
+                    "Flatten the structure or move this subtree into a resource of its own"
+                        .to_string(),
+                );
+            }
+        }
+        match value {
+            Value::String(text) => self.lint_string(text, pointer),
+            Value::Array(items) => {
+                let types: BTreeSet<&str> = items
+                    .iter()
+                    .filter(|item| !item.is_null())
+                    .map(json_type_name)
+                    .collect();
+                if types.len() > 1 {
+                    self.push(
+                        LintRule::MixedArray,
+                        pointer,
+                        format!(
+                            "Array mixes {}",
+                            types.into_iter().collect::<Vec<_>>().join(", ")
+                        ),
+                        "Use one element type, or wrap the variants in objects with a type field"
+                            .to_string(),
+                    );
+                }
+                for (index, item) in items.iter().enumerate() {
+                    self.lint(item, &format!("{}/{}", pointer, index), depth + 1);
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map {
+                    let item_pointer = format!("{}/{}", pointer, escape_json_pointer_token(key));
+                    self.lint_key(key, &item_pointer);
+                    self.lint(item, &item_pointer, depth + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn lint_key(&mut self, key: &str, pointer: &str) {
+        let (Some(expected), Some(case)) = (self.key_case, KeyCase::of(key)) else {
+            return;
+        };
+        if case != expected && self.reported_keys.insert(key.to_string()) {
+            self.push(
+                LintRule::KeyCasing,
+                pointer,
+                format!(
+                    "Key \"{}\" is {} while most keys are {}",
+                    key,
+                    case.label(),
+                    expected.label()
+                ),
+                format!("Rename it to \"{}\"", expected.apply(key)),
+            );
+        }
+    }
+
+    fn lint_string(&mut self, text: &str, pointer: &str) {
+        if text.len() > LINT_MAX_STRING_BYTES {
+            self.push(
+                LintRule::LargeString,
+                pointer,
+                format!("String of {} KB", text.len() / 1024),
+                "Move large blobs out of the payload and reference them by URL or ID".to_string(),
+            );
+        // Longer digit runs are usually identifiers or need more precision than a double
+        } else if text.len() <= 15
+            && text.trim() == text
+            && serde_json::from_str::<serde_json::Number>(text).is_ok()
+        {
+            self.push(
+                LintRule::NumericString,
+                pointer,
+                format!("Number stored as the string \"{}\"", text),
+                format!(
+                    "Store it as the number {} unless it is an identifier or code",
+                    text
+                ),
+            );
+        }
+    }
+}
+
+/// Report structural smells worth fixing before an API payload is reviewed: arrays mixing
+/// element types, numbers stored as strings, keys breaking the document's naming
+/// convention, very deep nesting and huge embedded strings
+#[tauri::command(async)]
+fn lint_json(input: String) -> Result<LintReport, String> {
+    info!("lint_json called - input_len: {}", input.len());
+
+    check_job_memory("lint_json", input.len())?;
+    let value = parse_json(&input)?;
+
+    let mut counts = BTreeMap::new();
+    count_key_cases(&value, &mut counts);
+    let mut linter = JsonLinter {
+        key_case: counts
+            .iter()
+            .max_by_key(|(_, count)| **count)
+            .filter(|_| counts.len() > 1)
+            .map(|(case, _)| *case),
+        reported_keys: HashSet::new(),
+        report: LintReport::default(),
+    };
+    linter.lint(&value, "", 1);
+
+    info!("lint_json: {} issue(s)", linter.report.issues.len());
+    Ok(linter.report)
+}
+
+/// `validate_strict` lists at most this many findings; the per-check counts stay exact
+const CONFORMANCE_MAX_FINDINGS: usize = 500;
+/// Integers beyond this magnitude can't be represented exactly by IEEE 754 doubles
+const MAX_EXACT_INTEGER: u64 = (1 << 53) - 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum ConformanceRule {
+    Syntax,
+    ControlCharacter,
+    LoneSurrogate,
+    DuplicateKey,
+    PrecisionLoss,
+}
+
+impl ConformanceRule {
+    const ALL: [ConformanceRule; 5] = [
+        ConformanceRule::Syntax,
+        ConformanceRule::ControlCharacter,
+        ConformanceRule::LoneSurrogate,
+        ConformanceRule::DuplicateKey,
+        ConformanceRule::PrecisionLoss,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            ConformanceRule::Syntax => "RFC 8259 grammar",
+            ConformanceRule::ControlCharacter => "No unescaped control characters",
+            ConformanceRule::LoneSurrogate => "No lone surrogates",
+            ConformanceRule::DuplicateKey => "Unique object keys",
+            ConformanceRule::PrecisionLoss => "Numbers fit IEEE 754 doubles",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ConformanceFinding {
+    rule: ConformanceRule,
+    pointer: String,
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ConformanceCheck {
+    rule: ConformanceRule,
+    label: &'static str,
+    violations: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ConformanceReport {
+    /// Whether every check passed
+    conforming: bool,
+    checks: Vec<ConformanceCheck>,
+    findings: Vec<ConformanceFinding>,
+    /// Whether findings past `CONFORMANCE_MAX_FINDINGS` were left out
+    truncated: bool,
+}
+
+/// Significant digits and decimal exponent of a JSON number, so `1.50e1` and `15` compare
+/// equal. Zero has no digits.
+fn decimal_digits(number: &str) -> (String, i64) {
+    let number = number.trim_start_matches('-');
+    let (mantissa, exponent) = match number.find(['e', 'E']) {
+        Some(at) => (&number[..at], number[at + 1..].parse::<i64>().unwrap_or(0)),
+        None => (number, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", integer, fraction);
+    let significant = digits.trim_end_matches('0');
+    let exponent = exponent - fraction.len() as i64 + (digits.len() - significant.len()) as i64;
+    (significant.trim_start_matches('0').to_string(), exponent)
+}
+
+/// Why a number token can't be read exactly by parsers that use doubles, if it can't
+fn number_precision_loss(number: &str) -> Option<String> {
+    if !number.contains(['.', 'e', 'E']) {
+        let magnitude = number.trim_start_matches('-');
+        let exact = magnitude
+            .parse::<u64>()
+            .is_ok_and(|value| value <= MAX_EXACT_INTEGER);
+        return (!exact).then(|| {
+            format!(
+                "Integer {} is outside ±2^53-1 and is rounded by double-based parsers",
+                number
+            )
+        });
+    }
+    let value: f64 = number.parse().ok()?;
+    let (digits, exponent) = decimal_digits(number);
+    if value.is_infinite() {
+        Some(format!("Number {} overflows a double", number))
+    } else if value == 0.0 && !digits.is_empty() {
+        Some(format!("Number {} underflows to zero", number))
+    } else if decimal_digits(&format!("{:e}", value)) != (digits, exponent) {
+        Some(format!(
+            "Number {} is read as {} by double-based parsers",
+            number, value
+        ))
+    } else {
+        None
+    }
+}
+
+/// Checks a document against RFC 8259 without stopping at the first problem: control
+/// characters, lone surrogates, duplicate keys and inexact numbers are recorded and
+/// scanning goes on. Only a grammar error ends the scan.
+struct StrictJsonValidator<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+    column: usize,
+    max_depth: usize,
+    counts: [usize; 5],
+    findings: Vec<ConformanceFinding>,
+}
+
+impl StrictJsonValidator<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) {
+        if let Some(byte) = self.peek() {
+            if byte == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else if byte & 0xC0 != 0x80 {
+                self.column += 1;
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.advance();
+        }
+    }
+
+    fn record(
+        &mut self,
+        rule: ConformanceRule,
+        pointer: &str,
+        at: (usize, usize),
+        message: String,
+    ) {
+        self.counts[rule as usize] += 1;
+        if self.findings.len() < CONFORMANCE_MAX_FINDINGS {
+            self.findings.push(ConformanceFinding {
+                rule,
+                pointer: pointer.to_string(),
+                line: at.0,
+                column: at.1,
+                message,
+            });
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        match self.peek() {
+            Some(found) if found == byte => {
+                self.advance();
+                Ok(())
+            }
+            Some(found) => Err(format!(
+                "Expected '{}' but found '{}'",
+                byte as char,
+                char::from(found).escape_default()
+            )),
+            None => Err(format!("Expected '{}' but the input ended", byte as char)),
+        }
+    }
+
+    fn validate_value(&mut self, pointer: &str, depth: usize) -> Result<(), String> {
+        if depth > self.max_depth {
+            return Err(format!("Nesting exceeds the limit of {}", self.max_depth));
+        }
+        match self.peek() {
+            Some(b'{') => self.validate_object(pointer, depth),
+            Some(b'[') => self.validate_array(pointer, depth),
+            Some(b'"') => self.validate_string(pointer).map(|_| ()),
+            Some(b'-' | b'0'..=b'9') => self.validate_number(pointer),
+            Some(b't') => self.validate_literal("true"),
+            Some(b'f') => self.validate_literal("false"),
+            Some(b'n') => self.validate_literal("null"),
+            Some(found) => Err(format!(
+                "Unexpected '{}'",
+                char::from(found).escape_default()
+            )),
+            None => Err("Expected a value but the input ended".to_string()),
+        }
+    }
+
+    fn validate_object(&mut self, pointer: &str, depth: usize) -> Result<(), String> {
+        self.advance();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.advance();
+            return Ok(());
+        }
+        let mut keys = HashSet::new();
+        loop {
+            self.skip_whitespace();
+            let at = (self.line, self.column);
+            if self.peek() != Some(b'"') {
+                return Err("Expected a string key".to_string());
+            }
+            let key = self.validate_string(pointer)?;
+            let member = format!("{}/{}", pointer, escape_json_pointer_token(&key));
+            if !keys.insert(key) {
+                self.record(
+                    ConformanceRule::DuplicateKey,
+                    &member,
+                    at,
+                    "Duplicate key; parsers disagree on which value wins".to_string(),
+                );
+            }
+            self.skip_whitespace();
+            self.expect(b':')?;
+            self.skip_whitespace();
+            self.validate_value(&member, depth + 1)?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.advance(),
+                Some(b'}') => {
+                    self.advance();
+                    return Ok(());
+                }
+                _ => return Err("Expected ',' or '}' after an object member".to_string()),
+            }
+        }
+    }
+
+    fn validate_array(&mut self, pointer: &str, depth: usize) -> Result<(), String> {
+        self.advance();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.advance();
+            return Ok(());
+        }
+        for index in 0.. {
+            self.skip_whitespace();
+            self.validate_value(&format!("{}/{}", pointer, index), depth + 1)?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.advance(),
+                Some(b']') => break,
+                _ => return Err("Expected ',' or ']' after an array element".to_string()),
+            }
+        }
+        self.advance();
+        Ok(())
+    }
+
+    fn hex_escape(&mut self) -> Result<u32, String> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self
+                .peek()
+                .and_then(|byte| char::from(byte).to_digit(16))
+                .ok_or("Expected four hex digits after \\u")?;
+            code = code * 16 + digit;
+            self.advance();
+        }
+        Ok(code)
+    }
+
+    /// Consume a `\uDC00`-`\uDFFF` escape if one comes next
+    fn low_surrogate(&mut self) -> Option<u32> {
+        let escape = self.bytes.get(self.pos..self.pos + 6)?;
+        let hex = std::str::from_utf8(&escape[2..]).ok()?;
+        let code = u32::from_str_radix(hex, 16).ok()?;
+        if !escape.starts_with(b"\\u") || !(0xDC00..=0xDFFF).contains(&code) {
+            return None;
+        }
+        for _ in 0..escape.len() {
+            self.advance();
+        }
+        Some(code)
+    }
+
+    /// Validate a string literal and return its decoded text, with lone surrogates
+    /// replaced by U+FFFD
+    fn validate_string(&mut self, pointer: &str) -> Result<String, String> {
+        self.advance();
+        let mut decoded = Vec::new();
+        loop {
+            let at = (self.line, self.column);
+            let Some(byte) = self.peek() else {
+                return Err("Unterminated string".to_string());
+            };
+            self.advance();
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let Some(escape) = self.peek() else {
+                        return Err("Unterminated string".to_string());
+                    };
+                    self.advance();
+                    let character = match escape {
+                        b'"' | b'\\' | b'/' => char::from(escape),
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let code = self.hex_escape()?;
+                            let low = if (0xD800..0xDC00).contains(&code) {
+                                self.low_surrogate()
+                            } else {
+                                None
+                            };
+                            let code = match low {
+                                Some(low) => 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00),
+                                None => code,
+                            };
+                            char::from_u32(code).unwrap_or_else(|| {
+                                self.record(
+                                    ConformanceRule::LoneSurrogate,
+                                    pointer,
+                                    at,
+                                    format!("Lone surrogate \\u{:04X}", code),
+                                );
+                                '\u{FFFD}'
+                            })
+                        }
+                        other => {
+                            return Err(format!(
+                                "Invalid escape '\\{}'",
+                                char::from(other).escape_default()
+                            ))
+                        }
+                    };
+                    decoded.extend(character.to_string().bytes());
+                }
+                0x00..=0x1F => {
+                    self.record(
+                        ConformanceRule::ControlCharacter,
+                        pointer,
+                        at,
+                        format!("Unescaped control character U+{:04X}", byte),
+                    );
+                    decoded.push(byte);
+                }
+                _ => decoded.push(byte),
+            }
+        }
+        Ok(String::from_utf8_lossy(&decoded).into_owned())
+    }
+
+    fn validate_number(&mut self, pointer: &str) -> Result<(), String> {
+        let at = (self.line, self.column);
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.advance();
+        }
+        match self.peek() {
+            Some(b'0') => self.advance(),
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err("Expected a digit".to_string()),
+        }
+        if self.peek() == Some(b'.') {
+            self.advance();
+            if !self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+                return Err("Expected a digit after the decimal point".to_string());
+            }
+            self.skip_digits();
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.advance();
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.advance();
+            }
+            if !self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+                return Err("Expected a digit in the exponent".to_string());
+            }
+            self.skip_digits();
+        }
+        // The scanned bytes are ASCII digits and signs
+        let number = String::from_utf8_lossy(&self.bytes[start..self.pos]);
+        if let Some(message) = number_precision_loss(&number) {
+            self.record(ConformanceRule::PrecisionLoss, pointer, at, message);
+        }
+        Ok(())
+    }
+
+    fn skip_digits(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.advance();
+        }
+    }
+
+    fn validate_literal(&mut self, literal: &str) -> Result<(), String> {
+        if !self.bytes[self.pos..].starts_with(literal.as_bytes()) {
+            return Err(format!("Expected '{}'", literal));
+        }
+        for _ in 0..literal.len() {
+            self.advance();
+        }
+        Ok(())
+    }
+}
+
+/// Check `input` against RFC 8259 more strictly than the parser does: besides the
+/// grammar, flag unescaped control characters, lone surrogates, duplicate keys and numbers
+/// that doubles can't hold exactly, with a pass/fail result per check
+#[tauri::command(async)]
+fn validate_strict(input: String) -> Result<ConformanceReport, String> {
+    info!("validate_strict called - input_len: {}", input.len());
+
+    check_job_memory("validate_strict", input.len())?;
+    let mut validator = StrictJsonValidator {
+        bytes: input.as_bytes(),
+        pos: 0,
+        line: 1,
+        column: 1,
+        max_depth: parse_limits().max_depth,
+        counts: [0; 5],
+        findings: Vec::new(),
+    };
+    validator.skip_whitespace();
+    let scanned = validator.validate_value("", 1).and_then(|()| {
+        validator.skip_whitespace();
+        match validator.peek() {
+            None => Ok(()),
+            Some(_) => Err("Unexpected content after the document".to_string()),
+        }
+    });
+    if let Err(message) = scanned {
+        let at = (validator.line, validator.column);
+        validator.record(ConformanceRule::Syntax, "", at, message);
+    }
+
+    let checks: Vec<ConformanceCheck> = ConformanceRule::ALL
+        .into_iter()
+        .map(|rule| ConformanceCheck {
+            rule,
+            label: rule.label(),
+            violations: validator.counts[rule as usize],
+        })
+        .collect();
+    let total: usize = validator.counts.iter().sum();
+    info!("validate_strict: {} violation(s)", total);
+    Ok(ConformanceReport {
+        conforming: total == 0,
+        checks,
+        truncated: total > validator.findings.len(),
+        findings: validator.findings,
+    })
+}
+
+/// Languages accepted by `json_to_class`
+const CODEGEN_LANGUAGES: &[&str] = &[
+    "typescript",
+    "javascript",
+    "python",
+    "rust",
+    "java",
+    "csharp",
+    "go",
+    "kotlin",
+    "swift",
+    "dart",
+    "cpp",
+    "haskell",
+    "zod",
+];
+
+/// Decoders accepted by `decode_binary_input`
+const BINARY_DECODERS: &[&str] = &["gunzip", "protobuf", "base64"];
+
+/// Features backed by an external program, with the program they need
+const TOOL_FEATURES: &[(&str, &str)] = &[
+    ("opensslCert", "openssl"),
+    ("traceroute", "traceroute"),
+    ("redis", "redis-cli"),
+    ("etcd", "etcdctl"),
+    ("httpFetch", "curl"),
+];
+
+#[derive(serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct Capabilities {
+    version: String,
+    /// Tool-backed features and whether their program was found on this host
+    features: BTreeMap<String, bool>,
+    languages: Vec<String>,
+    decoders: Vec<String>,
+    analyzers: Vec<String>,
+    limits: ConcurrencySettings,
+}
+
+fn tool_available(program: &str) -> bool {
+    Command::new(program)
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok()
+}
+
+/// App version, available features, supported languages and decoders, and current limits
+#[tauri::command]
+fn capabilities() -> Capabilities {
+    info!("capabilities called");
+    let to_strings = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+    Capabilities {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        features: TOOL_FEATURES
+            .iter()
+            .map(|(feature, program)| (feature.to_string(), tool_available(program)))
+            .collect(),
+        languages: to_strings(CODEGEN_LANGUAGES),
+        decoders: to_strings(BINARY_DECODERS),
+        analyzers: DOCUMENT_ANALYZERS
+            .iter()
+            .map(|analyzer| analyzer.id.to_string())
+            .collect(),
+        limits: concurrency_settings(),
+    }
+}
+
+/// JSON Schemas for the request and response of every command
+#[tauri::command]
+fn generate_api_schema(output_options: Option<OutputOptions>) -> Result<String, String> {
+    info!("generate_api_schema called");
+    serialize_json(
+        &api::api_schema(),
+        true,
+        &output_options.unwrap_or_default(),
+    )
+}
+
+/// Convert JSON to Protocol Buffers (proto3) schema
+#[tauri::command(async)]
+fn json_to_proto(input: String, options: Option<CodegenOptions>) -> Result<Timed<String>, String> {
+    if input.trim().is_empty() {
+        return Err("Input is empty".to_string());
+    }
+
+    check_job_memory("json_to_proto", input.len())?;
+    let mut timer = OperationTimer::start("json_to_proto", input.len());
+    let samples = parse_json_samples(&input)?;
+    timer.parsed();
+
+    let mut proto = String::new();
+    let mut message_counter = 0;
+    let options = options.unwrap_or_default();
+    let mut hints = proto_hints(&options, &["Root"]);
+    let unified = unify_proto_samples(&samples, "Root", &options, &mut hints)?;
+    generate_proto_message(
+        &unified,
+        "Root",
+        &mut proto,
+        &mut message_counter,
+        &hints,
+        0,
+    )?;
+    proto.push_str(&enum_definitions("proto", &hints, &options));
+    let proto = proto_header(&proto, &options)? + &proto;
+    timer.transformed();
+    Ok(timer.finish(proto))
+}
+
+/// Field hints for proto generation, with `names` already taken by top-level messages
+fn proto_hints(options: &CodegenOptions, names: &[&str]) -> FieldHints {
+    FieldHints {
+        enum_threshold: options.enum_threshold,
+        nullability: options.nullability,
+        field_examples: options.field_examples,
+        detect_oneofs: !options.omit_oneofs,
+        types: names.iter().map(|name| name.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+/// Check that the samples describe a message and unify them into one value
+fn unify_proto_samples(
+    samples: &[Value],
+    message_name: &str,
+    options: &CodegenOptions,
+    hints: &mut FieldHints,
+) -> Result<Value, String> {
+    if samples.is_empty() {
+        return Err("Cannot generate proto schema from empty array".to_string());
+    }
+    if !samples.iter().all(Value::is_object) {
+        return Err(
+            "Input must be a JSON object, an array of objects, or NDJSON objects".to_string(),
+        );
+    }
+    check_codegen_depth(samples, options)?;
+    let mut unified = unify_samples(&samples.iter().collect::<Vec<_>>(), message_name, hints);
+    if !options.string_formats {
+        clear_string_values(&mut unified);
+    }
+    Ok(unified)
+}
+
+/// Build a proto3 file with a `service` holding one RPC, whose request and response
+/// messages are inferred from captured JSON. An empty request or response becomes
+/// `google.protobuf.Empty`.
+#[tauri::command(async)]
+fn json_to_grpc_service(
+    request: String,
+    response: String,
+    method: String,
+    service: Option<String>,
+    options: Option<CodegenOptions>,
+) -> Result<Timed<String>, String> {
+    info!(
+        "json_to_grpc_service called - method: '{}', request_len: {}, response_len: {}",
+        method,
+        request.len(),
+        response.len()
+    );
+
+    let method = to_pascal_case(&method);
+    if !method.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err("Method name must start with a letter".to_string());
+    }
+    let service = match service.as_deref().map(to_pascal_case) {
+        Some(service) if !service.is_empty() => service,
+        _ => format!("{}Service", method),
+    };
+
+    check_job_memory("json_to_grpc_service", request.len() + response.len())?;
+    let mut timer = OperationTimer::start("json_to_grpc_service", request.len() + response.len());
+    let options = options.unwrap_or_default();
+    let request_name = format!("{}Request", method);
+    let response_name = format!("{}Response", method);
+    let mut hints = proto_hints(&options, &[&request_name, &response_name]);
+
+    let mut unified = Vec::new();
+    for (input, name) in [(&request, &request_name), (&response, &response_name)] {
+        if input.trim().is_empty() {
+            continue;
+        }
+        let samples = parse_json_samples(input).map_err(|e| format!("{}: {}", name, e))?;
+        let value = unify_proto_samples(&samples, name, &options, &mut hints)
+            .map_err(|e| format!("{}: {}", name, e))?;
+        unified.push((name.clone(), value));
+    }
+    timer.parsed();
+
+    let message_type = |input: &str, name: &str| match input.trim() {
+        "" => "google.protobuf.Empty".to_string(),
+        _ => name.to_string(),
+    };
+    let mut proto = format!(
+        "service {} {{\n  rpc {}({}) returns ({});\n}}\n",
+        service,
+        method,
+        message_type(&request, &request_name),
+        message_type(&response, &response_name)
+    );
+    let mut message_counter = 0;
+    for (name, value) in &unified {
+        proto.push('\n');
+        generate_proto_message(value, name, &mut proto, &mut message_counter, &hints, 0)?;
+    }
+    proto.push_str(&enum_definitions("proto", &hints, &options));
+    let proto = proto_header(&proto, &options)? + &proto;
+    timer.transformed();
+    Ok(timer.finish(proto))
+}
+
+/// Files declaring the `google.protobuf` well-known types
+const PROTO_WELL_KNOWN_IMPORTS: &[(&str, &str)] = &[
+    ("Any", "google/protobuf/any.proto"),
+    ("Duration", "google/protobuf/duration.proto"),
+    ("Empty", "google/protobuf/empty.proto"),
+    ("FieldMask", "google/protobuf/field_mask.proto"),
+    ("ListValue", "google/protobuf/struct.proto"),
+    ("Struct", "google/protobuf/struct.proto"),
+    ("Timestamp", "google/protobuf/timestamp.proto"),
+    ("Value", "google/protobuf/struct.proto"),
+    ("BoolValue", "google/protobuf/wrappers.proto"),
+    ("BytesValue", "google/protobuf/wrappers.proto"),
+    ("DoubleValue", "google/protobuf/wrappers.proto"),
+    ("FloatValue", "google/protobuf/wrappers.proto"),
+    ("Int32Value", "google/protobuf/wrappers.proto"),
+    ("Int64Value", "google/protobuf/wrappers.proto"),
+    ("StringValue", "google/protobuf/wrappers.proto"),
+    ("UInt32Value", "google/protobuf/wrappers.proto"),
+    ("UInt64Value", "google/protobuf/wrappers.proto"),
+];
+
+/// `syntax`, `package`, the imports of the well-known types `body` uses, and the
+/// file options, in the order the style guide lists them
+fn proto_header(body: &str, options: &CodegenOptions) -> Result<String, String> {
+    let mut header = String::from("syntax = \"proto3\";\n\n");
+
+    let package = options.namespace.trim();
+    if !package.is_empty() {
+        let valid = package.split('.').all(|segment| {
+            segment.starts_with(|c: char| c.is_ascii_alphabetic())
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+        if !valid {
+            return Err(format!("Invalid proto package: {}", package));
+        }
+        header.push_str(&format!("package {};\n\n", package));
+    }
+
+    let imports: BTreeSet<&str> = PROTO_WELL_KNOWN_IMPORTS
+        .iter()
+        .filter(|(type_name, _)| {
+            mentions_identifier(body, &format!("google.protobuf.{}", type_name))
+        })
+        .map(|(_, file)| *file)
+        .collect();
+    if !imports.is_empty() {
+        for file in imports {
+            header.push_str(&format!("import \"{}\";\n", file));
+        }
+        header.push('\n');
+    }
+
+    let file_options: Vec<String> = [
+        ("java_package", &options.java_package),
+        ("go_package", &options.go_package),
+    ]
+    .iter()
+    .filter(|(_, value)| !value.trim().is_empty())
+    .map(|(name, value)| format!("option {} = {:?};\n", name, value.trim()))
+    .collect();
+    if !file_options.is_empty() {
+        header.push_str(&file_options.concat());
+        header.push('\n');
+    }
+    Ok(header)
+}
+
+/// Convert Protocol Buffers (proto3) schema to JSON sample
+#[tauri::command(async)]
+fn proto_to_json(
+    input: String,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    info!("proto_to_json called - input_len: {}", input.len());
+
+    if input.trim().is_empty() {
+        warn!("proto_to_json: Input is empty");
+        return Err("Input is empty".to_string());
+    }
+
+    check_job_memory("proto_to_json", input.len())?;
+    let mut timer = OperationTimer::start("proto_to_json", input.len());
+    let messages = parse_proto_messages(&input)?;
+
+    if messages.is_empty() {
+        return Err("No message definitions found in proto file".to_string());
+    }
+    timer.parsed();
+
+    // Find the root message (first non-nested message or one named "Root")
+    let root_message = messages
+        .iter()
+        .find(|m| m.name == "Root")
+        .or_else(|| messages.first())
+        .ok_or("No messages found")?;
+
+    let json_value = proto_message_to_json(root_message, &messages)?;
+    timer.transformed();
+    let formatted = serialize_json(&json_value, true, &output_options.unwrap_or_default())?;
+
+    info!("proto_to_json: Success - output_len: {}", formatted.len());
+    Ok(timer.finish(formatted))
+}
+
+#[derive(Debug, Clone)]
+struct ProtoMessage {
+    name: String,
+    fields: Vec<ProtoField>,
+}
+
+#[derive(Debug, Clone)]
+struct ProtoField {
+    field_type: String,
+    name: String,
+    #[allow(dead_code)]
+    number: i32,
+    is_repeated: bool,
+}
+
+fn parse_proto_messages(input: &str) -> Result<Vec<ProtoMessage>, String> {
+    let mut messages = Vec::new();
+    let lines: Vec<&str> = input.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        // Look for message definitions
+        if line.starts_with("message ") {
+            let message_name = line
+                .trim_start_matches("message ")
+                .trim_end_matches(" {")
+                .trim_end_matches('{')
+                .trim()
+                .to_string();
+
+            let mut fields = Vec::new();
+            i += 1;
+
+            // Parse fields until we hit the closing brace
+            while i < lines.len() {
+                let field_line = lines[i].trim();
+
+                if field_line == "}" {
+                    break;
+                }
+
+                if !field_line.is_empty()
+                    && !field_line.starts_with("//")
+                    && !field_line.starts_with("syntax")
+                {
+                    if let Some(field) = parse_proto_field(field_line) {
+                        fields.push(field);
+                    }
+                }
+
+                i += 1;
+            }
+
+            messages.push(ProtoMessage {
+                name: message_name,
+                fields,
+            });
+        }
+
+        i += 1;
+    }
+
+    Ok(messages)
+}
+
+fn parse_proto_field(line: &str) -> Option<ProtoField> {
+    // Format: [repeated] type name = number;
+    let parts: Vec<&str> = line.split_whitespace().collect();
+
+    if parts.len() < 4 {
+        return None;
+    }
+
+    let mut idx = 0;
+    let is_repeated = parts[idx] == "repeated";
+    if is_repeated {
+        idx += 1;
+    }
+
+    if parts.len() < idx + 3 {
+        return None;
+    }
+
+    let field_type = parts[idx].to_string();
+    let name = parts[idx + 1].to_string();
+
+    // Parse field number (format: "= number;")
+    let number_str = parts.get(idx + 3)?.trim_end_matches(';').trim();
+    let number = number_str.parse::<i32>().ok()?;
+
+    Some(ProtoField {
+        field_type,
+        name,
+        number,
+        is_repeated,
+    })
+}
+
+fn proto_message_to_json(
+    message: &ProtoMessage,
+    all_messages: &[ProtoMessage],
+) -> Result<Value, String> {
+    let mut map = serde_json::Map::new();
+
+    for field in &message.fields {
+        let value = proto_field_to_json_value(&field, all_messages)?;
+        map.insert(field.name.clone(), value);
+    }
+
+    Ok(Value::Object(map))
+}
+
+fn proto_field_to_json_value(
+    field: &ProtoField,
+    all_messages: &[ProtoMessage],
+) -> Result<Value, String> {
+    let base_value = match field.field_type.as_str() {
+        "string" => Value::String("".to_string()),
+        "int32" | "int64" | "uint32" | "uint64" | "sint32" | "sint64" | "fixed32" | "fixed64"
+        | "sfixed32" | "sfixed64" => Value::Number(serde_json::Number::from(0)),
+        "float" | "double" => Value::Number(serde_json::Number::from_f64(0.0).unwrap()),
+        "bool" => Value::Bool(false),
+        "bytes" => Value::String("".to_string()),
+        "google.protobuf.Timestamp" => Value::String("1970-01-01T00:00:00Z".to_string()),
+        "google.protobuf.Struct" => Value::Object(serde_json::Map::new()),
+        "google.protobuf.ListValue" => Value::Array(Vec::new()),
+        _ => {
+            // Check if it's a nested message type
+            if let Some(nested_msg) = all_messages.iter().find(|m| m.name == field.field_type) {
+                proto_message_to_json(nested_msg, all_messages)?
+            } else {
+                Value::Null
+            }
+        }
+    };
+
+    if field.is_repeated {
+        Ok(Value::Array(vec![base_value]))
+    } else {
+        Ok(base_value)
+    }
+}
+
+/// Decode a protobuf payload to JSON using the message types of a `.proto` schema.
+/// `format` is `base64`, `hex` or `text` (protobuf text format) and is detected when
+/// missing; `message_type` defaults to the first message of the schema. Binary payloads
+/// may keep their 5-byte gRPC frame header.
+#[tauri::command(async)]
+fn decode_protobuf(
+    schema: String,
+    payload: String,
+    message_type: Option<String>,
+    format: Option<String>,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    info!(
+        "decode_protobuf called - schema_len: {}, payload_len: {}, message_type: {:?}, format: {:?}",
+        schema.len(),
+        payload.len(),
+        message_type,
+        format
+    );
+
+    if payload.trim().is_empty() {
+        return Err("Payload is empty".to_string());
+    }
+
+    check_job_memory("decode_protobuf", schema.len() + payload.len())?;
+    let mut timer = OperationTimer::start("decode_protobuf", payload.len());
+    let schema = ProtoSchema::parse(&schema)?;
+    let message = schema.message_named(message_type.as_deref())?;
+    let format = match format.as_deref().map(str::trim) {
+        None | Some("") => detect_protobuf_payload_format(&payload).to_string(),
+        Some(format) => format.to_lowercase(),
+    };
+
+    let decoded = match format.as_str() {
+        "base64" | "hex" => {
+            let bytes = decode_protobuf_payload(&payload, &format)?;
+            timer.parsed();
+            schema.decode_message(strip_grpc_frame(&bytes), &message, 0)
+        }
+        "text" => {
+            let tokens = proto_tokens(&payload)?;
+            let entries = parse_proto_text(&tokens, &mut 0, None, 0)?;
+            timer.parsed();
+            schema.text_message(&entries, &message, 0)
+        }
+        other => Err(format!("Unsupported payload format: {}", other)),
+    }
+    .map_err(|e| {
+        warn!("decode_protobuf: {}", e);
+        e
+    })?;
+    timer.transformed();
+
+    let output = serialize_json(&decoded, true, &output_options.unwrap_or_default())?;
+    Ok(timer.finish(output))
+}
+
+/// Hex when the payload is only hex digits, base64 when it decodes as such, otherwise
+/// protobuf text format
+fn detect_protobuf_payload_format(payload: &str) -> &'static str {
+    let compact: String = payload.split_whitespace().collect();
+    let compact = compact.strip_prefix("0x").unwrap_or(&compact);
+    let is_hex = !compact.is_empty()
+        && compact
+            .as_bytes()
+            .chunks(2)
+            .all(|pair| pair.len() == 2 && pair.iter().all(u8::is_ascii_hexdigit));
+    if is_hex {
+        "hex"
+    } else if decode_protobuf_payload(payload, "base64").is_ok() {
+        "base64"
+    } else {
+        "text"
+    }
+}
+
+fn decode_protobuf_payload(payload: &str, format: &str) -> Result<Vec<u8>, String> {
+    let compact: String = payload.split_whitespace().collect();
+    if format == "hex" {
+        let hex = compact.strip_prefix("0x").unwrap_or(&compact);
+        return hex
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                let digits = String::from_utf8_lossy(pair);
+                match pair.len() {
+                    2 => u8::from_str_radix(&digits, 16)
+                        .map_err(|_| format!("Invalid hex digits '{}'", digits)),
+                    _ => Err("Hex payload has an odd number of digits".to_string()),
+                }
+            })
+            .collect();
+    }
+    BASE64
+        .decode(&compact)
+        .or_else(|_| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(compact.trim_end_matches('='))
+        })
+        .map_err(|e| format!("Invalid base64 payload: {}", e))
+}
+
+/// Drop the compression flag and length prefix of a captured gRPC message
+fn strip_grpc_frame(bytes: &[u8]) -> &[u8] {
+    match bytes {
+        [0, a, b, c, d, message @ ..]
+            if u32::from_be_bytes([*a, *b, *c, *d]) as usize == message.len() =>
+        {
+            message
+        }
+        _ => bytes,
+    }
+}
+
+const PROTO_SCALAR_TYPES: &[&str] = &[
+    "double", "float", "int32", "int64", "uint32", "uint64", "sint32", "sint64", "fixed32",
+    "fixed64", "sfixed32", "sfixed64", "bool", "string", "bytes",
+];
+
+/// The well-known types a schema may use without declaring them
+const PROTO_WELL_KNOWN_SCHEMA: &str = r#"
+package google.protobuf;
+message Timestamp { int64 seconds = 1; int32 nanos = 2; }
+message Duration { int64 seconds = 1; int32 nanos = 2; }
+message Empty {}
+message FieldMask { repeated string paths = 1; }
+message Any { string type_url = 1; bytes value = 2; }
+message Struct { map<string, Value> fields = 1; }
+message ListValue { repeated Value values = 1; }
+enum NullValue { NULL_VALUE = 0; }
+message Value {
+  oneof kind {
+    NullValue null_value = 1;
+    double number_value = 2;
+    string string_value = 3;
+    bool bool_value = 4;
+    Struct struct_value = 5;
+    ListValue list_value = 6;
+  }
+}
+message DoubleValue { double value = 1; }
+message FloatValue { float value = 1; }
+message Int64Value { int64 value = 1; }
+message UInt64Value { uint64 value = 1; }
+message Int32Value { int32 value = 1; }
+message UInt32Value { uint32 value = 1; }
+message BoolValue { bool value = 1; }
+message StringValue { string value = 1; }
+message BytesValue { bytes value = 1; }
+"#;
+
+#[derive(Debug, Clone)]
+struct ProtoSchemaField {
+    name: String,
+    json_name: String,
+    number: u64,
+    /// Scalar type, or a message or enum name as written in the schema
+    type_name: String,
+    /// Full name of the message declaring the field, for resolving `type_name`
+    scope: String,
+    repeated: bool,
+    /// Key and value types of a `map<K, V>` field
+    map: Option<(String, String)>,
+    /// Name of the `oneof` the field belongs to
+    oneof: Option<String>,
+}
+
+/// Messages and enums of a `.proto` file by full name (`package.Outer.Inner`)
+#[derive(Debug, Default)]
+struct ProtoSchema {
+    messages: HashMap<String, Vec<ProtoSchemaField>>,
+    enums: HashMap<String, Vec<(String, i64)>>,
+    /// Messages of the parsed schema in declaration order
+    declared: Vec<String>,
+}
+
+enum ProtoFieldKind {
+    Scalar(String),
+    Enum(String),
+    Message(String),
+}
+
+/// A field value in protobuf text format; string literals are kept as raw bytes
+enum ProtoText {
+    Scalar(String),
+    Bytes(Vec<u8>),
+    Message(Vec<(String, ProtoText)>),
+}
+
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl ProtoSchema {
+    fn parse(input: &str) -> Result<ProtoSchema, String> {
+        let mut schema = ProtoSchema::default();
+        let tokens = proto_tokens(PROTO_WELL_KNOWN_SCHEMA)?;
+        schema.parse_declarations(&tokens, &mut 0, "", None, 0)?;
+        schema.declared.clear();
+
+        let tokens = proto_tokens(input)?;
+        schema.parse_declarations(&tokens, &mut 0, "", None, 0)?;
+        if schema.declared.is_empty() {
+            return Err("No message definitions found in proto schema".to_string());
+        }
+        Ok(schema)
+    }
+
+    /// Parse declarations up to the closing brace of the enclosing block. `message` is
+    /// the full name of the message (or oneof's message) being parsed.
+    fn parse_declarations(
+        &mut self,
+        tokens: &[String],
+        pos: &mut usize,
+        prefix: &str,
+        message: Option<&str>,
+        depth: usize,
+    ) -> Result<(), String> {
+        if depth > PROTOBUF_MAX_DEPTH {
+            return Err(format!(
+                "Messages are nested more than {} levels deep",
+                PROTOBUF_MAX_DEPTH
+            ));
+        }
+        let mut prefix = prefix.to_string();
+        while let Some(token) = tokens.get(*pos) {
+            *pos += 1;
+            match token.as_str() {
+                "}" => return Ok(()),
+                ";" => {}
+                "syntax" | "edition" | "import" | "option" | "reserved" | "extensions" => {
+                    skip_proto_statement(tokens, pos)
+                }
+                "package" if message.is_none() => {
+                    prefix = format!("{}.", proto_token(tokens, pos)?);
+                    skip_proto_statement(tokens, pos);
+                }
+                "service" | "extend" => {
+                    skip_proto_statement(tokens, pos);
+                    skip_proto_block(tokens, pos);
+                }
+                "message" => {
+                    let full_name = format!("{}{}", prefix, proto_token(tokens, pos)?);
+                    expect_proto_token(tokens, pos, "{")?;
+                    self.messages.entry(full_name.clone()).or_default();
+                    self.declared.push(full_name.clone());
+                    let nested_prefix = format!("{}.", full_name);
+                    self.parse_declarations(
+                        tokens,
+                        pos,
+                        &nested_prefix,
+                        Some(&full_name),
+                        depth + 1,
+                    )?;
+                }
+                "enum" => {
+                    let full_name = format!("{}{}", prefix, proto_token(tokens, pos)?);
+                    expect_proto_token(tokens, pos, "{")?;
+                    let values = parse_proto_enum_values(tokens, pos)?;
+                    self.enums.insert(full_name, values);
+                }
+                "oneof" if message.is_some() => {
+                    let oneof = proto_token(tokens, pos)?;
+                    expect_proto_token(tokens, pos, "{")?;
+                    let owner = message.unwrap_or_default();
+                    let start = self.messages.get(owner).map_or(0, Vec::len);
+                    self.parse_declarations(tokens, pos, &prefix, message, depth + 1)?;
+                    if let Some(fields) = self.messages.get_mut(owner) {
+                        for field in &mut fields[start..] {
+                            field.oneof = Some(oneof.clone());
+                        }
+                    }
+                }
+                _ => match message {
+                    Some(message) => {
+                        *pos -= 1;
+                        let field = parse_proto_schema_field(tokens, pos, message)?;
+                        self.messages
+                            .entry(message.to_string())
+                            .or_default()
+                            .push(field);
+                    }
+                    None => return Err(format!("Unexpected '{}' in proto schema", token)),
+                },
+            }
+        }
+        match message {
+            Some(message) => Err(format!("Message {} is missing its closing brace", message)),
+            None => Ok(()),
+        }
+    }
+
+    /// The full name of a message given by full or short name, or the first message
+    fn message_named(&self, name: Option<&str>) -> Result<String, String> {
+        let name = name.map(|name| name.trim().trim_start_matches('.'));
+        let found = match name {
+            None | Some("") => self.declared.first(),
+            Some(name) => self.declared.iter().find(|full| *full == name).or_else(|| {
+                self.declared
+                    .iter()
+                    .find(|full| full.rsplit('.').next() == Some(name))
+            }),
+        };
+        found.cloned().ok_or_else(|| {
+            format!(
+                "Message {} not found; the schema declares {}",
+                name.unwrap_or_default(),
+                self.declared.join(", ")
+            )
+        })
+    }
+
+    /// Resolve a type name the way protoc does: innermost scope first, then outwards
+    fn resolve(&self, scope: &str, type_name: &str) -> Option<String> {
+        let known = |name: &str| self.messages.contains_key(name) || self.enums.contains_key(name);
+        if let Some(absolute) = type_name.strip_prefix('.') {
+            return known(absolute).then(|| absolute.to_string());
+        }
+        let mut scope = scope;
+        loop {
+            let candidate = if scope.is_empty() {
+                type_name.to_string()
+            } else {
+                format!("{}.{}", scope, type_name)
+            };
+            if known(&candidate) {
+                return Some(candidate);
+            }
+            if scope.is_empty() {
+                return None;
+            }
+            scope = scope.rsplit_once('.').map_or("", |(parent, _)| parent);
+        }
+    }
+
+    fn kind(&self, scope: &str, type_name: &str) -> Result<ProtoFieldKind, String> {
+        if PROTO_SCALAR_TYPES.contains(&type_name) {
+            return Ok(ProtoFieldKind::Scalar(type_name.to_string()));
+        }
+        match self.resolve(scope, type_name) {
+            Some(name) if self.enums.contains_key(&name) => Ok(ProtoFieldKind::Enum(name)),
+            Some(name) => Ok(ProtoFieldKind::Message(name)),
+            None => Err(format!("Unknown type {} in {}", type_name, scope)),
+        }
+    }
+
+    /// The synthetic `key`/`value` fields of a map entry message
+    fn map_entry_fields(field: &ProtoSchemaField) -> Option<[ProtoSchemaField; 2]> {
+        let (key_type, value_type) = field.map.as_ref()?;
+        let entry_field = |name: &str, number: u64, type_name: &str| ProtoSchemaField {
+            name: name.to_string(),
+            json_name: name.to_string(),
+            number,
+            type_name: type_name.to_string(),
+            scope: field.scope.clone(),
+            repeated: false,
+            map: None,
+            oneof: None,
+        };
+        Some([
+            entry_field("key", 1, key_type),
+            entry_field("value", 2, value_type),
+        ])
+    }
+
+    fn enum_json(&self, enum_name: &str, number: i64) -> Value {
+        self.enums
+            .get(enum_name)
+            .and_then(|values| values.iter().find(|(_, value)| *value == number))
+            .map_or_else(
+                || Value::from(number),
+                |(name, _)| Value::from(name.as_str()),
+            )
+    }
+
+    fn decode_message(&self, data: &[u8], message: &str, depth: usize) -> Result<Value, String> {
+        if depth > PROTOBUF_MAX_DEPTH {
+            return Err(format!(
+                "Messages are nested more than {} levels deep",
+                PROTOBUF_MAX_DEPTH
+            ));
+        }
+        let fields = self.decode_fields(data, &self.messages[message], depth)?;
+        Ok(well_known_proto_json(message, fields))
+    }
+
+    fn decode_fields(
+        &self,
+        data: &[u8],
+        fields: &[ProtoSchemaField],
+        depth: usize,
+    ) -> Result<serde_json::Map<String, Value>, String> {
+        let mut output = serde_json::Map::new();
+        let mut pos = 0;
+        while pos < data.len() {
+            let start = pos;
+            let truncated = || format!("Payload is truncated in the field at byte {}", start);
+            let key = read_protobuf_varint(data, &mut pos).ok_or_else(truncated)?;
+            if !matches!(key & 0x07, 0 | 1 | 2 | 5) {
+                return Err(format!(
+                    "Unsupported wire type {} in the field at byte {}",
+                    key & 0x07,
+                    start
+                ));
+            }
+            let wire = read_wire_value(data, &mut pos, key & 0x07).ok_or_else(truncated)?;
+            let number = key >> 3;
+            let Some(field) = fields.iter().find(|field| field.number == number) else {
+                // Fields missing from the schema are kept under their number
+                let value = match wire {
+                    WireValue::Varint(value) | WireValue::Fixed64(value) => Value::from(value),
+                    WireValue::Fixed32(value) => Value::from(value),
+                    WireValue::Bytes(bytes) => decode_protobuf_wire(bytes, depth + 1)
+                        .unwrap_or_else(|| Value::String(BASE64.encode(bytes))),
+                };
+                match output.get_mut(&number.to_string()) {
+                    Some(Value::Array(items)) => items.push(value),
+                    Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+                    None => {
+                        output.insert(number.to_string(), value);
+                    }
+                }
+                continue;
+            };
+
+            if let Some(entry_fields) = Self::map_entry_fields(field) {
+                let WireValue::Bytes(entry) = wire else {
+                    return Err(format!("Map field {} is not length-delimited", field.name));
+                };
+                let mut entry = self.decode_fields(entry, &entry_fields, depth + 1)?;
+                insert_proto_map_entry(&mut output, field, &mut entry);
+                continue;
+            }
+
+            let kind = self.kind(&field.scope, &field.type_name)?;
+            let values = match (&kind, wire) {
+                (ProtoFieldKind::Message(message), WireValue::Bytes(bytes)) => {
+                    vec![self.decode_message(bytes, message, depth + 1)?]
+                }
+                (ProtoFieldKind::Message(_), _) => {
+                    return Err(format!(
+                        "Message field {} is not length-delimited",
+                        field.name
+                    ))
+                }
+                // Packed repeated scalars and enums
+                (ProtoFieldKind::Scalar(type_name), WireValue::Bytes(bytes))
+                    if field.repeated && !matches!(type_name.as_str(), "string" | "bytes") =>
+                {
+                    read_packed_values(bytes, type_name)?
+                        .into_iter()
+                        .map(|value| proto_scalar_json(type_name, &value))
+                        .collect::<Result<_, _>>()?
+                }
+                (ProtoFieldKind::Enum(enum_name), WireValue::Bytes(bytes)) => {
+                    read_packed_values(bytes, "int32")?
+                        .into_iter()
+                        .map(|value| match value {
+                            WireValue::Varint(number) => {
+                                self.enum_json(enum_name, number as i32 as i64)
+                            }
+                            _ => Value::Null,
+                        })
+                        .collect()
+                }
+                (ProtoFieldKind::Enum(enum_name), WireValue::Varint(number)) => {
+                    vec![self.enum_json(enum_name, number as i32 as i64)]
+                }
+                (ProtoFieldKind::Enum(_), _) => {
+                    return Err(format!("Enum field {} is not a varint", field.name))
+                }
+                (ProtoFieldKind::Scalar(type_name), wire) => {
+                    vec![proto_scalar_json(type_name, &wire)
+                        .map_err(|e| format!("Field {}: {}", field.name, e))?]
+                }
+            };
+            clear_other_oneof_members(&mut output, fields, field);
+            for value in values {
+                insert_proto_value(&mut output, &field.json_name, field.repeated, value);
+            }
+        }
+        Ok(output)
+    }
+
+    fn text_message(
+        &self,
+        entries: &[(String, ProtoText)],
+        message: &str,
+        depth: usize,
+    ) -> Result<Value, String> {
+        if depth > PROTOBUF_MAX_DEPTH {
+            return Err(format!(
+                "Messages are nested more than {} levels deep",
+                PROTOBUF_MAX_DEPTH
+            ));
+        }
+        let fields = self
+            .text_fields(entries, &self.messages[message], depth)
+            .map_err(|e| format!("{}: {}", message, e))?;
+        Ok(well_known_proto_json(message, fields))
+    }
+
+    fn text_fields(
+        &self,
+        entries: &[(String, ProtoText)],
+        fields: &[ProtoSchemaField],
+        depth: usize,
+    ) -> Result<serde_json::Map<String, Value>, String> {
+        let mut output = serde_json::Map::new();
+        for (name, text) in entries {
+            let field = fields
+                .iter()
+                .find(|field| field.name == *name || field.json_name == *name)
+                .ok_or_else(|| format!("Unknown field '{}'", name))?;
+
+            if let Some(entry_fields) = Self::map_entry_fields(field) {
+                let ProtoText::Message(entry) = text else {
+                    return Err(format!(
+                        "Map field {} needs {{ key: ... value: ... }}",
+                        name
+                    ));
+                };
+                let mut entry = self.text_fields(entry, &entry_fields, depth + 1)?;
+                insert_proto_map_entry(&mut output, field, &mut entry);
+                continue;
+            }
+
+            let value = match (self.kind(&field.scope, &field.type_name)?, text) {
+                (ProtoFieldKind::Message(message), ProtoText::Message(entries)) => {
+                    self.text_message(entries, &message, depth + 1)?
+                }
+                (ProtoFieldKind::Message(_), _) => {
+                    return Err(format!("Field {} needs a message value", name))
+                }
+                (ProtoFieldKind::Enum(enum_name), ProtoText::Scalar(token)) => {
+                    match token.parse::<i64>() {
+                        Ok(number) => self.enum_json(&enum_name, number),
+                        Err(_)
+                            if self.enums[&enum_name]
+                                .iter()
+                                .any(|(value, _)| value == token) =>
+                        {
+                            Value::from(token.as_str())
+                        }
+                        Err(_) => return Err(format!("{} is not a value of {}", token, enum_name)),
+                    }
+                }
+                (ProtoFieldKind::Scalar(type_name), text) => proto_text_scalar(&type_name, text)
+                    .map_err(|e| format!("Field {}: {}", name, e))?,
+                (ProtoFieldKind::Enum(_), _) => {
+                    return Err(format!("Field {} needs an enum value", name))
+                }
+            };
+            clear_other_oneof_members(&mut output, fields, field);
+            insert_proto_value(&mut output, &field.json_name, field.repeated, value);
+        }
+        Ok(output)
+    }
+}
+
+/// Split a `.proto` schema or protobuf text format into tokens, dropping `//`, `/* */`
+/// and `#` comments. String literals keep their quotes and escapes.
+fn proto_tokens(input: &str) -> Result<Vec<String>, String> {
+    let is_word = |c: char| c.is_alphanumeric() || matches!(c, '_' | '.' | '-' | '+');
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '#' || (c == '/' && chars.get(i + 1) == Some(&'/')) {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            if i >= chars.len() {
+                return Err("Unterminated string literal".to_string());
+            }
+            i += 1;
+            tokens.push(chars[start..i].iter().collect());
+        } else if is_word(c) {
+            let start = i;
+            while i < chars.len() && is_word(chars[i]) {
+                i += 1;
+            }
+            tokens.push(chars[start..i].iter().collect());
+        } else {
+            tokens.push(c.to_string());
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+fn proto_token(tokens: &[String], pos: &mut usize) -> Result<String, String> {
+    let token = tokens
+        .get(*pos)
+        .cloned()
+        .ok_or("Unexpected end of proto schema")?;
+    *pos += 1;
+    Ok(token)
+}
+
+fn expect_proto_token(tokens: &[String], pos: &mut usize, expected: &str) -> Result<(), String> {
+    match proto_token(tokens, pos)? {
+        token if token == expected => Ok(()),
+        token => Err(format!("Expected '{}' but found '{}'", expected, token)),
+    }
+}
+
+/// Skip to the end of a statement: past its `;`, or up to the `{` opening its block
+fn skip_proto_statement(tokens: &[String], pos: &mut usize) {
+    while let Some(token) = tokens.get(*pos) {
+        if token == "{" {
+            return;
+        }
+        *pos += 1;
+        if token == ";" {
+            return;
+        }
+    }
+}
+
+fn skip_proto_block(tokens: &[String], pos: &mut usize) {
+    let mut depth = 0;
+    while let Some(token) = tokens.get(*pos) {
+        *pos += 1;
+        match token.as_str() {
+            "{" => depth += 1,
+            "}" if depth <= 1 => return,
+            "}" => depth -= 1,
+            _ => {}
+        }
+    }
+}
+
+fn parse_proto_enum_values(
+    tokens: &[String],
+    pos: &mut usize,
+) -> Result<Vec<(String, i64)>, String> {
+    let mut values = Vec::new();
+    loop {
+        let token = proto_token(tokens, pos)?;
+        match token.as_str() {
+            "}" => return Ok(values),
+            ";" => {}
+            "option" | "reserved" => skip_proto_statement(tokens, pos),
+            _ => {
+                expect_proto_token(tokens, pos, "=")?;
+                let number = proto_token(tokens, pos)?;
+                let number = parse_proto_integer(&number)
+                    .and_then(|number| i64::try_from(number).ok())
+                    .ok_or_else(|| format!("Invalid value for enum value {}", token))?;
+                values.push((token, number));
+                skip_proto_statement(tokens, pos);
+            }
+        }
+    }
+}
+
+/// `[repeated|optional|required] type name = number [options];`
+fn parse_proto_schema_field(
+    tokens: &[String],
+    pos: &mut usize,
+    scope: &str,
+) -> Result<ProtoSchemaField, String> {
+    let mut type_name = proto_token(tokens, pos)?;
+    let repeated = type_name == "repeated";
+    if matches!(type_name.as_str(), "repeated" | "optional" | "required") {
+        type_name = proto_token(tokens, pos)?;
+    }
+    let map = if type_name == "map" {
+        expect_proto_token(tokens, pos, "<")?;
+        let key_type = proto_token(tokens, pos)?;
+        expect_proto_token(tokens, pos, ",")?;
+        let value_type = proto_token(tokens, pos)?;
+        expect_proto_token(tokens, pos, ">")?;
+        Some((key_type, value_type))
+    } else {
+        None
+    };
+    if type_name == "group" {
+        return Err("Groups are not supported".to_string());
+    }
+
+    let name = proto_token(tokens, pos)?;
+    expect_proto_token(tokens, pos, "=")?;
+    let number = proto_token(tokens, pos)?;
+    let number = parse_proto_integer(&number)
+        .and_then(|number| u64::try_from(number).ok())
+        .ok_or_else(|| format!("Invalid number for field {}", name))?;
+
+    let mut json_name = to_camel_case(&name);
+    if tokens.get(*pos).map(String::as_str) == Some("[") {
+        while let Some(token) = tokens.get(*pos) {
+            *pos += 1;
+            if token == "]" {
+                break;
+            }
+            if token == "json_name" && tokens.get(*pos).map(String::as_str) == Some("=") {
+                let literal = tokens.get(*pos + 1).cloned().unwrap_or_default();
+                json_name = String::from_utf8_lossy(&unescape_proto_string(&literal)?).into();
+            }
+        }
+    }
+    expect_proto_token(tokens, pos, ";")?;
+
+    Ok(ProtoSchemaField {
+        name,
+        json_name,
+        number,
+        type_name,
+        scope: scope.to_string(),
+        repeated: repeated || map.is_some(),
+        map,
+        oneof: None,
+    })
+}
+
+/// Decimal, hex (`0x`) or octal (leading `0`) integer with an optional sign
+fn parse_proto_integer(token: &str) -> Option<i128> {
+    let (negative, digits) = match token.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+    let magnitude = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i128::from_str_radix(hex, 16).ok()?
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i128::from_str_radix(&digits[1..], 8).ok()?
+    } else {
+        digits.parse::<i128>().ok()?
+    };
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+/// Bytes of a quoted string literal, with C-style escapes resolved
+fn unescape_proto_string(literal: &str) -> Result<Vec<u8>, String> {
+    let inner = literal
+        .get(1..literal.len().saturating_sub(1))
+        .filter(|_| literal.len() >= 2)
+        .ok_or_else(|| format!("Expected a string literal, found {}", literal))?;
+    let mut bytes = Vec::with_capacity(inner.len());
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+        let escaped = chars.next().ok_or("String literal ends with a backslash")?;
+        let mut take_digits = |radix: u32, max: usize, first: Option<char>| {
+            let mut digits: String = first.into_iter().collect();
+            while digits.len() < max {
+                match chars.peek() {
+                    Some(d) if d.is_digit(radix) => digits.push(chars.next().unwrap_or_default()),
+                    _ => break,
+                }
+            }
+            u32::from_str_radix(&digits, radix)
+                .map_err(|_| format!("Invalid escape in {}", literal))
+        };
+        match escaped {
+            'n' => bytes.push(b'\n'),
+            'r' => bytes.push(b'\r'),
+            't' => bytes.push(b'\t'),
+            'a' => bytes.push(0x07),
+            'b' => bytes.push(0x08),
+            'f' => bytes.push(0x0c),
+            'v' => bytes.push(0x0b),
+            'x' | 'X' => bytes.push(take_digits(16, 2, None)? as u8),
+            'u' | 'U' => {
+                let code = take_digits(16, if escaped == 'u' { 4 } else { 8 }, None)?;
+                let c =
+                    char::from_u32(code).ok_or_else(|| format!("Invalid escape in {}", literal))?;
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+            '0'..='7' => bytes.push(take_digits(8, 3, Some(escaped))? as u8),
+            other => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(other.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+/// Fields of a text-format message up to `closing` (or the end of the input)
+fn parse_proto_text(
+    tokens: &[String],
+    pos: &mut usize,
+    closing: Option<&str>,
+    depth: usize,
+) -> Result<Vec<(String, ProtoText)>, String> {
+    if depth > PROTOBUF_MAX_DEPTH {
+        return Err(format!(
+            "Messages are nested more than {} levels deep",
+            PROTOBUF_MAX_DEPTH
+        ));
+    }
+    let mut entries = Vec::new();
+    loop {
+        let Some(token) = tokens.get(*pos) else {
+            return match closing {
+                Some(closing) => Err(format!("Missing '{}' in text format", closing)),
+                None => Ok(entries),
+            };
+        };
+        *pos += 1;
+        if Some(token.as_str()) == closing {
+            return Ok(entries);
+        }
+        if token == ";" || token == "," {
+            continue;
+        }
+        if !token.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            return Err(format!("Expected a field name, found '{}'", token));
+        }
+
+        let has_colon = tokens.get(*pos).map(String::as_str) == Some(":");
+        if has_colon {
+            *pos += 1;
+        }
+        if tokens.get(*pos).map(String::as_str) == Some("[") {
+            *pos += 1;
+            while tokens.get(*pos).map(String::as_str) != Some("]") {
+                if *pos >= tokens.len() {
+                    return Err(format!("Missing ']' after the values of {}", token));
+                }
+                entries.push((
+                    token.clone(),
+                    parse_proto_text_value(tokens, pos, true, depth)?,
+                ));
+                if tokens.get(*pos).map(String::as_str) == Some(",") {
+                    *pos += 1;
+                }
+            }
+            *pos += 1;
+        } else {
+            entries.push((
+                token.clone(),
+                parse_proto_text_value(tokens, pos, has_colon, depth)?,
+            ));
+        }
+    }
+}
+
+fn parse_proto_text_value(
+    tokens: &[String],
+    pos: &mut usize,
+    scalar_allowed: bool,
+    depth: usize,
+) -> Result<ProtoText, String> {
+    let token = proto_token(tokens, pos)?;
+    match token.as_str() {
+        "{" => Ok(ProtoText::Message(parse_proto_text(
+            tokens,
+            pos,
+            Some("}"),
+            depth + 1,
+        )?)),
+        "<" => Ok(ProtoText::Message(parse_proto_text(
+            tokens,
+            pos,
+            Some(">"),
+            depth + 1,
+        )?)),
+        _ if !scalar_allowed => Err(format!("Expected ':' before {}", token)),
+        // Adjacent string literals are concatenated
+        _ if token.starts_with(['"', '\'']) => {
+            let mut bytes = unescape_proto_string(&token)?;
+            while let Some(next) = tokens
+                .get(*pos)
+                .filter(|next| next.starts_with(['"', '\'']))
+            {
+                bytes.extend(unescape_proto_string(next)?);
+                *pos += 1;
+            }
+            Ok(ProtoText::Bytes(bytes))
+        }
+        _ => Ok(ProtoText::Scalar(token)),
+    }
+}
+
+fn read_wire_value<'a>(data: &'a [u8], pos: &mut usize, wire_type: u64) -> Option<WireValue<'a>> {
+    match wire_type {
+        0 => read_protobuf_varint(data, pos).map(WireValue::Varint),
+        1 => {
+            let bytes = data.get(*pos..*pos + 8)?;
+            *pos += 8;
+            Some(WireValue::Fixed64(u64::from_le_bytes(
+                bytes.try_into().ok()?,
+            )))
+        }
+        2 => {
+            let length = usize::try_from(read_protobuf_varint(data, pos)?).ok()?;
+            let bytes = data.get(*pos..pos.checked_add(length)?)?;
+            *pos += length;
+            Some(WireValue::Bytes(bytes))
+        }
+        5 => {
+            let bytes = data.get(*pos..*pos + 4)?;
+            *pos += 4;
+            Some(WireValue::Fixed32(u32::from_le_bytes(
+                bytes.try_into().ok()?,
+            )))
+        }
+        _ => None,
+    }
+}
+
+fn read_packed_values<'a>(bytes: &'a [u8], type_name: &str) -> Result<Vec<WireValue<'a>>, String> {
+    let wire_type = match type_name {
+        "double" | "fixed64" | "sfixed64" => 1,
+        "float" | "fixed32" | "sfixed32" => 5,
+        _ => 0,
+    };
+    let mut values = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        values.push(
+            read_wire_value(bytes, &mut pos, wire_type)
+                .ok_or_else(|| format!("Packed {} values are truncated", type_name))?,
+        );
+    }
+    Ok(values)
+}
+
+/// NaN and infinities are strings in the proto3 JSON mapping
+fn proto_float_json(value: f64) -> Value {
+    match serde_json::Number::from_f64(value) {
+        Some(number) => Value::Number(number),
+        None if value.is_nan() => Value::from("NaN"),
+        None if value > 0.0 => Value::from("Infinity"),
+        None => Value::from("-Infinity"),
+    }
+}
+
+/// A scalar in the proto3 JSON mapping: 64-bit integers as strings, bytes as base64
+fn proto_scalar_json(type_name: &str, wire: &WireValue) -> Result<Value, String> {
+    let zigzag = |value: u64| (value >> 1) as i64 ^ -((value & 1) as i64);
+    Ok(match (type_name, wire) {
+        ("double", WireValue::Fixed64(bits)) => proto_float_json(f64::from_bits(*bits)),
+        ("float", WireValue::Fixed32(bits)) => proto_float_json(f64::from(f32::from_bits(*bits))),
+        ("int32", WireValue::Varint(value)) => Value::from(*value as i32),
+        ("int64", WireValue::Varint(value)) => Value::from((*value as i64).to_string()),
+        ("uint32", WireValue::Varint(value)) => Value::from(*value as u32),
+        ("uint64", WireValue::Varint(value)) => Value::from(value.to_string()),
+        ("sint32", WireValue::Varint(value)) => Value::from(zigzag(*value) as i32),
+        ("sint64", WireValue::Varint(value)) => Value::from(zigzag(*value).to_string()),
+        ("bool", WireValue::Varint(value)) => Value::Bool(*value != 0),
+        ("fixed32", WireValue::Fixed32(value)) => Value::from(*value),
+        ("sfixed32", WireValue::Fixed32(value)) => Value::from(*value as i32),
+        ("fixed64", WireValue::Fixed64(value)) => Value::from(value.to_string()),
+        ("sfixed64", WireValue::Fixed64(value)) => Value::from((*value as i64).to_string()),
+        ("string", WireValue::Bytes(bytes)) => Value::from(
+            std::str::from_utf8(bytes).map_err(|_| "string is not valid UTF-8".to_string())?,
+        ),
+        ("bytes", WireValue::Bytes(bytes)) => Value::from(BASE64.encode(bytes)),
+        _ => return Err(format!("a {} can't be read from this wire type", type_name)),
+    })
+}
+
+fn proto_text_scalar(type_name: &str, text: &ProtoText) -> Result<Value, String> {
+    let token = match (type_name, text) {
+        ("string", ProtoText::Bytes(bytes)) => {
+            return String::from_utf8(bytes.clone())
+                .map(Value::String)
+                .map_err(|_| "string is not valid UTF-8".to_string())
+        }
+        ("bytes", ProtoText::Bytes(bytes)) => return Ok(Value::from(BASE64.encode(bytes))),
+        (_, ProtoText::Scalar(token)) => token.as_str(),
+        _ => return Err(format!("expected a {} value", type_name)),
+    };
+    let invalid = || format!("'{}' is not a valid {}", token, type_name);
+    match type_name {
+        "bool" => match token {
+            "true" | "True" | "t" | "1" => Ok(Value::Bool(true)),
+            "false" | "False" | "f" | "0" => Ok(Value::Bool(false)),
+            _ => Err(invalid()),
+        },
+        "double" | "float" => {
+            let lower = token.to_lowercase();
+            let value = match lower.trim_start_matches('-') {
+                "inf" | "infinity" => f64::INFINITY,
+                "nan" => f64::NAN,
+                number => number
+                    .trim_end_matches('f')
+                    .parse::<f64>()
+                    .map_err(|_| invalid())?,
+            };
+            Ok(proto_float_json(if lower.starts_with('-') {
+                -value
+            } else {
+                value
+            }))
+        }
+        _ => {
+            let number = parse_proto_integer(token).ok_or_else(invalid)?;
+            let (min, max, as_string) = match type_name {
+                "int32" | "sint32" | "sfixed32" => (i32::MIN as i128, i32::MAX as i128, false),
+                "uint32" | "fixed32" => (0, u32::MAX as i128, false),
+                "int64" | "sint64" | "sfixed64" => (i64::MIN as i128, i64::MAX as i128, true),
+                "uint64" | "fixed64" => (0, u64::MAX as i128, true),
+                _ => return Err(invalid()),
+            };
+            if number < min || number > max {
+                return Err(format!("{} is out of range for {}", token, type_name));
+            }
+            Ok(if as_string {
+                Value::from(number.to_string())
+            } else {
+                Value::from(number as i64)
+            })
+        }
+    }
+}
+
+/// Add a decoded value; repeated fields collect into an array
+fn insert_proto_value(
+    output: &mut serde_json::Map<String, Value>,
+    name: &str,
+    repeated: bool,
+    value: Value,
+) {
+    match output.get_mut(name) {
+        Some(Value::Array(items)) if repeated => items.push(value),
+        _ if repeated => {
+            output.insert(name.to_string(), Value::Array(vec![value]));
+        }
+        // The last occurrence of a singular field wins
+        _ => {
+            output.insert(name.to_string(), value);
+        }
+    }
+}
+
+/// Only one member of a `oneof` is set at a time; like protoc, the last one seen wins
+fn clear_other_oneof_members(
+    output: &mut serde_json::Map<String, Value>,
+    fields: &[ProtoSchemaField],
+    field: &ProtoSchemaField,
+) {
+    let Some(oneof) = &field.oneof else {
+        return;
+    };
+    for other in fields {
+        if other.oneof.as_ref() == Some(oneof) && other.number != field.number {
+            output.shift_remove(&other.json_name);
+        }
+    }
+}
+
+fn insert_proto_map_entry(
+    output: &mut serde_json::Map<String, Value>,
+    field: &ProtoSchemaField,
+    entry: &mut serde_json::Map<String, Value>,
+) {
+    let key = match entry.remove("key") {
+        Some(Value::String(key)) => key,
+        Some(key) => key.to_string(),
+        None => String::new(),
+    };
+    let value = entry.remove("value").unwrap_or(Value::Null);
+    if let Value::Object(map) = output
+        .entry(field.json_name.clone())
+        .or_insert_with(|| Value::Object(serde_json::Map::new()))
+    {
+        map.insert(key, value);
+    }
+}
+
+/// The special JSON forms of the well-known types
+fn well_known_proto_json(message: &str, mut fields: serde_json::Map<String, Value>) -> Value {
+    let integer = |value: Option<&Value>| match value {
+        Some(Value::String(s)) => s.parse::<i64>().unwrap_or(0),
+        Some(value) => value.as_i64().unwrap_or(0),
+        None => 0,
+    };
+    match message.strip_prefix("google.protobuf.") {
+        Some("Timestamp") => Value::from(format_rfc3339(
+            integer(fields.get("seconds")),
+            integer(fields.get("nanos")),
+        )),
+        Some("Duration") => {
+            let seconds = integer(fields.get("seconds"));
+            let nanos = integer(fields.get("nanos"));
+            let sign = if seconds < 0 || nanos < 0 { "-" } else { "" };
+            let fraction = format!(".{:09}", nanos.abs());
+            let fraction = fraction.trim_end_matches('0').trim_end_matches('.');
+            Value::from(format!("{}{}{}s", sign, seconds.abs(), fraction))
+        }
+        Some("Struct") => fields
+            .remove("fields")
+            .unwrap_or_else(|| Value::Object(serde_json::Map::new())),
+        Some("ListValue") => fields
+            .remove("values")
+            .unwrap_or_else(|| Value::Array(Vec::new())),
+        Some("Value") => fields
+            .into_iter()
+            .find(|(kind, _)| kind != "nullValue")
+            .map_or(Value::Null, |(_, value)| value),
+        Some("FieldMask") => match fields.remove("paths") {
+            Some(Value::Array(paths)) => Value::from(
+                paths
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(to_camel_case)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            _ => Value::from(""),
+        },
+        Some(wrapper) if wrapper.ends_with("Value") => {
+            fields.remove("value").unwrap_or_else(|| match wrapper {
+                "BoolValue" => Value::Bool(false),
+                "StringValue" | "BytesValue" => Value::from(""),
+                "Int64Value" | "UInt64Value" => Value::from("0"),
+                _ => Value::from(0),
+            })
+        }
+        _ => Value::Object(fields),
+    }
+}
+
+/// RFC 3339 UTC timestamp for seconds since the Unix epoch
+fn format_rfc3339(seconds: i64, nanos: i64) -> String {
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let days = seconds.div_euclid(86_400);
+    let time = seconds.rem_euclid(86_400);
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    let fraction = match nanos {
+        0 => String::new(),
+        n if n % 1_000_000 == 0 => format!(".{:03}", n / 1_000_000),
+        n if n % 1_000 == 0 => format!(".{:06}", n / 1_000),
+        n => format!(".{:09}", n),
+    };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60,
+        fraction
+    )
+}
+
+/// Generate a filled-in example JSON payload from a JSON sample or proto3 schema.
+/// Values are chosen from field names (emails, names, dates, ...) using a seeded
+/// generator so the same seed always yields the same payload.
+#[tauri::command(async)]
+fn generate_sample_json(
+    input: String,
+    seed: Option<u64>,
+    count: Option<usize>,
+    output_options: Option<OutputOptions>,
+) -> Result<Timed<String>, String> {
+    info!(
+        "generate_sample_json called - input_len: {}, seed: {:?}, count: {:?}",
+        input.len(),
+        seed,
+        count
+    );
+
+    if input.trim().is_empty() {
+        warn!("generate_sample_json: Input is empty");
+        return Err("Input is empty".to_string());
+    }
+
+    check_job_memory("generate_sample_json", input.len())?;
+    check_parse_limits(&input)?;
+    let mut timer = OperationTimer::start("generate_sample_json", input.len());
+    let mut rng = SampleRng::new(seed.unwrap_or(42));
+    let count = count.unwrap_or(1).clamp(1, 1000);
+
+    let mut samples = Vec::with_capacity(count);
+    if let Ok(template) = serde_json::from_str::<Value>(&input) {
+        for _ in 0..count {
+            samples.push(sample_from_json_template(&template, "", &mut rng));
+        }
+    } else {
+        let messages = parse_proto_messages(&input)?;
+        let root_message = messages
+            .iter()
+            .find(|m| m.name == "Root")
+            .or_else(|| messages.first())
+            .ok_or("Input must be valid JSON or a proto3 schema with message definitions")?;
+
+        for _ in 0..count {
+            samples.push(sample_from_proto_message(
+                root_message,
+                &messages,
+                &mut rng,
+                0,
+            ));
+        }
+    }
+
+    let result = if samples.len() == 1 {
+        samples.remove(0)
+    } else {
+        Value::Array(samples)
+    };
+    timer.transformed();
+
+    let formatted = serialize_json(&result, true, &output_options.unwrap_or_default())?;
+    info!(
+        "generate_sample_json: Success - output_len: {}",
+        formatted.len()
+    );
+    Ok(timer.finish(formatted))
+}
+
+/// Maximum message nesting followed when sampling self-referential proto messages
+const SAMPLE_MAX_DEPTH: usize = 8;
+
+const SAMPLE_FIRST_NAMES: &[&str] = &[
+    "John", "Jane", "Alex", "Maria", "Budi", "Siti", "Chen", "Aisha", "Liam", "Emma",
+];
+const SAMPLE_LAST_NAMES: &[&str] = &[
+    "Doe", "Smith", "Santoso", "Garcia", "Wang", "Rahman", "Muller", "Tanaka", "Brown", "Silva",
+];
+const SAMPLE_CITIES: &[&str] = &[
+    "New York",
+    "Jakarta",
+    "London",
+    "Tokyo",
+    "Berlin",
+    "Sydney",
+    "Toronto",
+    "Singapore",
+];
+const SAMPLE_COUNTRIES: &[&str] = &[
+    "United States",
+    "Indonesia",
+    "United Kingdom",
+    "Japan",
+    "Germany",
+    "Australia",
+    "Canada",
+];
+const SAMPLE_STREETS: &[&str] = &["Main St", "Oak Ave", "Sudirman", "High St", "Park Lane"];
+const SAMPLE_STATUSES: &[&str] = &["ACTIVE", "INACTIVE", "PENDING"];
+const SAMPLE_WORDS: &[&str] = &[
+    "alpha", "bravo", "delta", "echo", "lorem", "ipsum", "sample", "value", "nova", "orbit",
+];
+
+/// Small deterministic PRNG (SplitMix64) so sample payloads are reproducible per seed
+struct SampleRng(u64);
+
+impl SampleRng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random integer in the inclusive range `min..=max`
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        let span = (max - min) as u64 + 1;
+        min + (self.next_u64() % span) as i64
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.range(0, items.len() as i64 - 1) as usize]
+    }
+}
+
+fn sample_from_json_template(template: &Value, key: &str, rng: &mut SampleRng) -> Value {
+    match template {
+        Value::Null => Value::Null,
+        Value::Bool(_) => Value::Bool(rng.range(0, 1) == 1),
+        Value::Number(n) => {
+            if n.is_f64() {
+                sample_float_value(key, rng)
+            } else {
+                Value::from(sample_integer(key, rng))
+            }
+        }
+        Value::String(_) => Value::String(sample_string(key, rng)),
+        Value::Array(arr) => match arr.first() {
+            Some(first) => {
+                let len = rng.range(1, 3);
+                Value::Array(
+                    (0..len)
+                        .map(|_| sample_from_json_template(first, key, rng))
+                        .collect(),
+                )
+            }
+            None => Value::Array(Vec::new()),
+        },
+        Value::Object(map) => {
+            let mut result = serde_json::Map::new();
+            for (field, value) in map {
+                result.insert(field.clone(), sample_from_json_template(value, field, rng));
+            }
+            Value::Object(result)
+        }
+    }
+}
+
+fn sample_from_proto_message(
+    message: &ProtoMessage,
+    all_messages: &[ProtoMessage],
+    rng: &mut SampleRng,
+    depth: usize,
+) -> Value {
+    let mut map = serde_json::Map::new();
+
+    for field in &message.fields {
+        let value = if field.is_repeated {
+            let len = rng.range(1, 3);
+            Value::Array(
+                (0..len)
+                    .map(|_| sample_proto_field_value(field, all_messages, rng, depth))
+                    .collect(),
+            )
+        } else {
+            sample_proto_field_value(field, all_messages, rng, depth)
+        };
+        map.insert(field.name.clone(), value);
+    }
+
+    Value::Object(map)
+}
+
+fn sample_proto_field_value(
+    field: &ProtoField,
+    all_messages: &[ProtoMessage],
+    rng: &mut SampleRng,
+    depth: usize,
+) -> Value {
+    match field.field_type.as_str() {
+        "string" => Value::String(sample_string(&field.name, rng)),
+        "int32" | "int64" | "uint32" | "uint64" | "sint32" | "sint64" | "fixed32" | "fixed64"
+        | "sfixed32" | "sfixed64" => Value::from(sample_integer(&field.name, rng)),
+        "float" | "double" => sample_float_value(&field.name, rng),
+        "bool" => Value::Bool(rng.range(0, 1) == 1),
+        "bytes" => Value::String(BASE64.encode(sample_string(&field.name, rng))),
+        other => match all_messages.iter().find(|m| m.name == other) {
+            Some(nested) if depth < SAMPLE_MAX_DEPTH => {
+                sample_from_proto_message(nested, all_messages, rng, depth + 1)
+            }
+            _ => Value::Null,
+        },
+    }
+}
+
+fn sample_string(key: &str, rng: &mut SampleRng) -> String {
+    // Whole words, so `capacity`, `runtime` and `blink` don't read as a city, time or link
+    let words = sample_key_words(key);
+    let has_word = |names: &[&str]| words.iter().any(|word| names.contains(&word.as_str()));
+    let first = rng.pick(SAMPLE_FIRST_NAMES);
+    let last = rng.pick(SAMPLE_LAST_NAMES);
+
+    if has_word(&["email"]) {
+        format!(
+            "{}.{}@example.com",
+            first.to_lowercase(),
+            last.to_lowercase()
+        )
+    } else if has_word(&["uuid", "guid"]) || words.last().is_some_and(|word| word == "id") {
+        sample_uuid(rng)
+    } else if has_word(&["first", "firstname"]) {
+        first.to_string()
+    } else if has_word(&["last", "lastname", "surname"]) {
+        last.to_string()
+    } else if has_word(&["name", "fullname", "username", "author", "user"]) {
+        format!("{} {}", first, last)
+    } else if has_word(&["url", "uri", "website", "link", "href"]) {
+        format!("https://example.com/{}", rng.pick(SAMPLE_WORDS))
+    } else if has_word(&["phone", "mobile"]) {
+        format!("+1-555-{:04}", rng.range(0, 9999))
+    } else if has_word(&["city"]) {
+        rng.pick(SAMPLE_CITIES).to_string()
+    } else if has_word(&["country"]) {
+        rng.pick(SAMPLE_COUNTRIES).to_string()
+    } else if has_word(&["street", "address"]) {
+        format!("{} {}", rng.range(1, 999), rng.pick(SAMPLE_STREETS))
+    } else if has_word(&["zip", "zipcode", "postal", "postcode"]) {
+        format!("{:05}", rng.range(10000, 99999))
+    } else if has_word(&["date", "time", "timestamp", "datetime"])
+        || words.last().is_some_and(|word| word == "at")
+    {
+        sample_timestamp(rng)
+    } else if has_word(&["status", "state"]) {
+        rng.pick(SAMPLE_STATUSES).to_string()
+    } else {
+        format!("{} {}", rng.pick(SAMPLE_WORDS), rng.pick(SAMPLE_WORDS))
+    }
+}
+
+/// Lowercase words of a camelCase, snake_case or kebab-case key
+fn sample_key_words(key: &str) -> Vec<String> {
+    to_snake_case(key)
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn sample_integer(key: &str, rng: &mut SampleRng) -> i64 {
+    // Match whole words so `page`, `usage` and `valid` don't read as `age` or `id`
+    let words = sample_key_words(key);
+    let has_word = |names: &[&str]| words.iter().any(|word| names.contains(&word.as_str()));
+
+    if has_word(&["age"]) {
+        rng.range(18, 80)
+    } else if has_word(&["year", "years"]) {
+        rng.range(1990, 2025)
+    } else if has_word(&["zip", "zipcode", "postal", "postcode"]) {
+        rng.range(10000, 99999)
+    } else if has_word(&["count", "total", "quantity", "qty"]) {
+        rng.range(0, 100)
+    } else if words.last().is_some_and(|word| word == "id") {
+        rng.range(1, 99999)
+    } else {
+        rng.range(0, 1000)
+    }
+}
+
+fn sample_float_value(key: &str, rng: &mut SampleRng) -> Value {
+    let lower = key.to_lowercase();
+    let (min, max) = if lower.starts_with("lat") {
+        (-90.0, 90.0)
+    } else if lower.starts_with("lon") || lower.starts_with("lng") {
+        (-180.0, 180.0)
+    } else if lower.contains("price") || lower.contains("amount") {
+        (1.0, 500.0)
+    } else {
+        (0.0, 100.0)
+    };
+
+    let fraction = (rng.next_u64() % 1_000_000) as f64 / 1_000_000.0;
+    let value = ((min + (max - min) * fraction) * 100.0).round() / 100.0;
+    serde_json::Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn sample_timestamp(rng: &mut SampleRng) -> String {
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        rng.range(2020, 2025),
+        rng.range(1, 12),
+        rng.range(1, 28),
+        rng.range(0, 23),
+        rng.range(0, 59),
+        rng.range(0, 59)
+    )
+}
+
+fn sample_uuid(rng: &mut SampleRng) -> String {
+    let hex = format!("{:016x}{:016x}", rng.next_u64(), rng.next_u64());
+    format!(
+        "{}-{}-4{}-a{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[13..16],
+        &hex[17..20],
+        &hex[20..32]
+    )
+}
+
+/// Parse the samples for code generation: a single document, the elements of a top-level
+/// array, or every document of an NDJSON / concatenated batch
+fn parse_json_samples(input: &str) -> Result<Vec<Value>, String> {
+    // A single document goes through the parse cache; batches fail there and parse below
+    if let Ok(document) = parse_json(input) {
+        return Ok(match &*document {
+            Value::Array(items) => items.clone(),
+            value => vec![value.clone()],
+        });
+    }
+    check_parse_limits(input)?;
+
+    let mut documents = serde_json::Deserializer::from_str(input)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<Value>, _>>()
+        .map_err(|e| format!("Invalid JSON: {}", e))?;
+    if let [Value::Array(items)] = documents.as_mut_slice() {
+        return Ok(std::mem::take(items));
+    }
+    Ok(documents)
+}
+
+/// Well-known string formats that map to richer types in generated code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum StringFormat {
+    DateTime,
+    Date,
+    Uuid,
+    Uri,
+    Email,
+}
+
+fn is_iso_date(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() == 10
+        && b[4] == b'-'
+        && b[7] == b'-'
+        && [0, 1, 2, 3, 5, 6, 8, 9]
+            .iter()
+            .all(|&i| b[i].is_ascii_digit())
+}
+
+/// ISO-8601 / RFC 3339 timestamps with an explicit `Z` or `±HH:MM` offset
+fn is_iso_date_time(s: &str) -> bool {
+    let Some((date, time)) = s.split_once(['T', 't']) else {
+        return false;
+    };
+    let zone_start = time.rfind(['Z', 'z', '+', '-']).filter(|&i| i >= 8);
+    let Some(zone_start) = zone_start else {
+        return false;
+    };
+    let (clock, zone) = time.split_at(zone_start);
+    let b = clock.as_bytes();
+    let clock_ok = b.len() >= 8
+        && b[2] == b':'
+        && b[5] == b':'
+        && [0, 1, 3, 4, 6, 7].iter().all(|&i| b[i].is_ascii_digit())
+        && (b.len() == 8 || (b[8] == b'.' && b.len() > 9 && b[9..].iter().all(u8::is_ascii_digit)));
+    let zone_ok = zone.eq_ignore_ascii_case("z")
+        || (zone.len() == 6
+            && zone.as_bytes()[3] == b':'
+            && zone[1..3]
+                .bytes()
+                .chain(zone[4..].bytes())
+                .all(|c| c.is_ascii_digit()));
+    is_iso_date(date) && clock_ok && zone_ok
+}
+
+fn is_uuid(s: &str) -> bool {
+    let groups: Vec<&str> = s.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(group, len)| group.len() == len && group.bytes().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn detect_string_format(s: &str) -> Option<StringFormat> {
+    if is_iso_date_time(s) {
+        Some(StringFormat::DateTime)
+    } else if is_iso_date(s) {
+        Some(StringFormat::Date)
+    } else if is_uuid(s) {
+        Some(StringFormat::Uuid)
+    } else if (s.starts_with("http://") || s.starts_with("https://"))
+        && s.len() > "https://".len()
+        && !s.contains(char::is_whitespace)
+    {
+        Some(StringFormat::Uri)
+    } else {
+        let (local, domain) = s.split_once('@')?;
+        let valid = !local.is_empty()
+            && !domain.contains('@')
+            && domain.contains('.')
+            && !domain.starts_with('.')
+            && !domain.ends_with('.')
+            && !s.contains(char::is_whitespace);
+        valid.then_some(StringFormat::Email)
+    }
+}
+
+/// The format shared by every string sample, if any
+fn common_string_format(samples: &[&Value]) -> Option<StringFormat> {
+    let mut formats = samples
+        .iter()
+        .map(|v| v.as_str().and_then(detect_string_format));
+    let first = formats.next()??;
+    formats.all(|format| format == Some(first)).then_some(first)
+}
+
+/// Blank every string so format detection is skipped when it is turned off
+fn clear_string_values(value: &mut Value) {
+    match value {
+        Value::String(s) => s.clear(),
+        Value::Array(items) => items.iter_mut().for_each(clear_string_values),
+        Value::Object(map) => map.values_mut().for_each(clear_string_values),
+        _ => {}
+    }
+}
+
+fn collect_string_formats(value: &Value, formats: &mut BTreeSet<StringFormat>) {
+    match value {
+        Value::String(s) => formats.extend(detect_string_format(s)),
+        Value::Array(items) => items
+            .iter()
+            .for_each(|v| collect_string_formats(v, formats)),
+        Value::Object(map) => map
+            .values()
+            .for_each(|v| collect_string_formats(v, formats)),
+        _ => {}
+    }
+}
+
+/// Richer type for a formatted string value in the given language, if it has one
+fn string_format_type(value: &str, language: &str) -> Option<&'static str> {
+    let rich = match (language, detect_string_format(value)?) {
+        ("rust", StringFormat::DateTime) => "chrono::DateTime<chrono::Utc>",
+        ("rust", StringFormat::Date) => "chrono::NaiveDate",
+        ("rust", StringFormat::Uuid) => "uuid::Uuid",
+        ("rust", StringFormat::Uri) => "url::Url",
+        ("python", StringFormat::DateTime) => "datetime",
+        ("python", StringFormat::Date) => "date",
+        ("python", StringFormat::Uuid) => "UUID",
+        ("java", StringFormat::DateTime) => "Instant",
+        ("java", StringFormat::Date) => "LocalDate",
+        ("java", StringFormat::Uuid) => "UUID",
+        ("java", StringFormat::Uri) => "URI",
+        ("csharp", StringFormat::DateTime) => "DateTimeOffset",
+        ("csharp", StringFormat::Date) => "DateTime",
+        ("csharp", StringFormat::Uuid) => "Guid",
+        ("csharp", StringFormat::Uri) => "Uri",
+        ("go", StringFormat::DateTime) => "time.Time",
+        ("swift", StringFormat::DateTime) => "Date",
+        ("swift", StringFormat::Uuid) => "UUID",
+        ("swift", StringFormat::Uri) => "URL",
+        ("dart", StringFormat::DateTime | StringFormat::Date) => "DateTime",
+        ("dart", StringFormat::Uri) => "Uri",
+        _ => return None,
+    };
+    Some(rich)
+}
+
+/// What unification learned about individual fields, keyed by (class name, JSON key).
+/// Class names follow the generators' `to_pascal_case(field_name)` convention.
+#[derive(Default)]
+struct FieldHints {
+    /// Fields that were missing or null in some samples
+    optional: HashSet<(String, String)>,
+    /// Names given to nested object types, unique across the whole document
+    type_names: HashMap<(String, String), String>,
+    /// Type names already taken, including the root type
+    types: HashSet<String>,
+    /// Dynamic-key objects (IDs, UUIDs, dates as keys); their unified value is the value type
+    maps: HashSet<(String, String)>,
+    /// Low-cardinality string fields and their values in first-seen order
+    enums: BTreeMap<(String, String), Vec<String>>,
+    /// Maximum number of distinct values for a string field to become an enum (0 = off)
+    enum_threshold: usize,
+    /// Overrides the inferred `optional` set
+    nullability: Nullability,
+    /// Record an example value per field for doc comments
+    field_examples: bool,
+    /// First non-null scalar (or scalar array) of each field, as compact JSON
+    examples: HashMap<(String, String), String>,
+    /// Look for mutually exclusive fields (proto `oneof`)
+    detect_oneofs: bool,
+    /// Groups of fields per class of which every sample has exactly one
+    oneofs: HashMap<String, Vec<Vec<String>>>,
+}
+
+fn is_optional_field(hints: &FieldHints, class_name: &str, key: &str) -> bool {
+    match hints.nullability {
+        Nullability::Inferred => hints
+            .optional
+            .contains(&(class_name.to_string(), key.to_string())),
+        Nullability::AllOptional => true,
+        Nullability::AllRequired => false,
+    }
+}
+
+/// Compact JSON of a sample value for an example doc comment; objects have their own
+/// type and are skipped. `*/` is written as `*\/` so block comments stay closed.
+fn example_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null | Value::Object(_) => None,
+        Value::Array(items) if items.is_empty() || items.iter().any(contains_object) => None,
+        _ => {
+            let json = serde_json::to_string(value).ok()?.replace("*/", "*\\/");
+            Some(match json.char_indices().nth(60) {
+                Some((end, _)) => format!("{}…", &json[..end]),
+                None => json,
+            })
+        }
+    }
+}
+
+fn field_example<'a>(hints: &'a FieldHints, class_name: &str, key: &str) -> Option<&'a String> {
+    hints
+        .examples
+        .get(&(class_name.to_string(), key.to_string()))
+}
+
+/// Doc comment line for a field's example value, or nothing without one
+fn example_comment(
+    hints: &FieldHints,
+    class_name: &str,
+    key: &str,
+    open: &str,
+    close: &str,
+) -> String {
+    match field_example(hints, class_name, key) {
+        Some(example) => format!("{}e.g. {}{}\n", open, example, close),
+        None => String::new(),
+    }
+}
+
+/// Type name registered for a nested object field; falls back to the key, which the
+/// generators turn into a type name with `to_pascal_case`
+fn nested_type_name(hints: &FieldHints, class_name: &str, key: &str) -> String {
+    hints
+        .type_names
+        .get(&(class_name.to_string(), key.to_string()))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Name the nested type of `key` inside `class_name`: the key's own name when it is free,
+/// otherwise the parent-prefixed name, with a numeric suffix if even that is taken
+fn register_type_name(hints: &mut FieldHints, class_name: &str, key: &str) -> String {
+    let base = type_identifier(key);
+    let mut name = base.clone();
+    if hints.types.contains(&name) {
+        name = format!("{}{}", class_name, base);
+        let prefixed = name.clone();
+        let mut suffix = 2;
+        while hints.types.contains(&name) {
+            name = format!("{}{}", prefixed, suffix);
+            suffix += 1;
+        }
+    }
+    hints.types.insert(name.clone());
+    hints
+        .type_names
+        .insert((class_name.to_string(), key.to_string()), name.clone());
+    name
+}
+
+fn contains_object(value: &Value) -> bool {
+    match value {
+        Value::Object(_) => true,
+        Value::Array(items) => items.iter().any(contains_object),
+        _ => false,
+    }
+}
+
+fn is_map_field(hints: &FieldHints, class_name: &str, key: &str) -> bool {
+    hints
+        .maps
+        .contains(&(class_name.to_string(), key.to_string()))
+}
+
+/// Keys that are data rather than field names: numeric IDs, UUIDs and dates
+fn is_dynamic_key(key: &str) -> bool {
+    (!key.is_empty() && key.bytes().all(|b| b.is_ascii_digit())) || is_uuid(key) || is_iso_date(key)
+}
+
+/// Values of objects keyed by dynamic keys when they all share one non-array type, so the
+/// objects are better described as a map than a struct with a field per key
+fn dynamic_map_values<'a>(samples: &[&'a Value]) -> Option<Vec<&'a Value>> {
+    let non_null: Vec<&Value> = samples.iter().copied().filter(|v| !v.is_null()).collect();
+    let maps: Vec<&'a serde_json::Map<String, Value>> =
+        samples.iter().filter_map(|v| v.as_object()).collect();
+    if maps.is_empty() || maps.len() != non_null.len() {
+        return None;
+    }
+    if !maps
+        .iter()
+        .flat_map(|map| map.keys())
+        .all(|key| is_dynamic_key(key))
+    {
+        return None;
+    }
+
+    let values: Vec<&Value> = maps.iter().flat_map(|map| map.values()).collect();
+    let first = values.iter().find(|v| !v.is_null())?;
+    let homogeneous = values
+        .iter()
+        .filter(|v| !v.is_null())
+        .all(|v| std::mem::discriminant(*v) == std::mem::discriminant(*first) && !v.is_array());
+    homogeneous.then_some(values)
+}
+
+/// Enum type name for a field detected as low-cardinality, if any
+fn enum_field_type(hints: &FieldHints, class_name: &str, key: &str) -> Option<String> {
+    hints
+        .enums
+        .contains_key(&(class_name.to_string(), key.to_string()))
+        .then(|| type_identifier(key))
+}
+
+/// Lowercase words of an enum value, split on punctuation and camelCase boundaries
+fn enum_variant_words(value: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+    for c in value.chars() {
+        if !c.is_alphanumeric() {
+            words.push(std::mem::take(&mut current));
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.extend(c.to_lowercase());
+    }
+    words.push(current);
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+fn enum_variant_pascal(value: &str) -> String {
+    to_pascal_case(&enum_variant_words(value).join(" "))
+}
+
+fn enum_variant_screaming(value: &str) -> String {
+    enum_variant_words(value).join("_").to_uppercase()
+}
+
+/// Distinct values of a string field when there are few enough of them (and they repeat)
+/// to be worth an enum, and every value can become a variant name
+fn enum_candidate(samples: &[&Value], threshold: usize) -> Option<Vec<String>> {
+    if threshold == 0 {
+        return None;
+    }
+    let strings: Vec<&str> = samples.iter().filter_map(|v| v.as_str()).collect();
+    if strings.is_empty() || strings.len() != samples.iter().filter(|v| !v.is_null()).count() {
+        return None;
+    }
+
+    let mut values: Vec<String> = Vec::new();
+    for s in &strings {
+        if !values.iter().any(|v| v == s) {
+            values.push(s.to_string());
+        }
+    }
+    if values.len() < 2 || values.len() > threshold || values.len() >= strings.len() {
+        return None;
+    }
+
+    let mut variants = HashSet::new();
+    let nameable = values.iter().all(|v| {
+        v.chars().next().is_some_and(char::is_alphabetic)
+            && detect_string_format(v).is_none()
+            && variants.insert(enum_variant_pascal(v))
+    });
+    nameable.then_some(values)
+}
+
+/// Enums grouped by type name; fields sharing a name share one enum with the union of
+/// their values
+fn collected_enums(hints: &FieldHints) -> BTreeMap<String, Vec<String>> {
+    let mut enums: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for ((_, key), values) in &hints.enums {
+        let merged = enums.entry(type_identifier(key)).or_default();
+        for value in values {
+            if !merged.contains(value) {
+                merged.push(value.clone());
+            }
+        }
+    }
+    enums
+}
+
+/// Render one enum definition for the target language
+fn enum_definition(
+    language: &str,
+    name: &str,
+    values: &[String],
+    options: &CodegenOptions,
+) -> String {
+    let mut output = String::new();
+    match language {
+        "typescript" => {
+            let members: Vec<String> = values.iter().map(|v| format!("{:?}", v)).collect();
+            output.push_str(&format!("type {} = {};\n", name, members.join(" | ")));
+        }
+        "python" => {
+            output.push_str(&format!("class {}(str, Enum):\n", name));
+            for value in values {
+                output.push_str(&format!(
+                    "    {} = {:?}\n",
+                    enum_variant_screaming(value),
+                    value
+                ));
+            }
+        }
+        "rust" => {
+            output.push_str("#[derive(Debug, Serialize, Deserialize)]\n");
+            output.push_str(&format!("pub enum {} {{\n", name));
+            for value in values {
+                output.push_str(&format!("    #[serde(rename = {:?})]\n", value));
+                output.push_str(&format!("    {},\n", enum_variant_pascal(value)));
+            }
+            output.push_str("}\n");
+        }
+        "java" => {
+            let annotation = match options.java_annotations {
+                JavaAnnotations::Jackson => "JsonProperty",
+                JavaAnnotations::Gson => "SerializedName",
+            };
+            output.push_str(&format!("public enum {} {{\n", name));
+            for value in values {
+                output.push_str(&format!("    @{}({:?})\n", annotation, value));
+                output.push_str(&format!("    {},\n", enum_variant_screaming(value)));
+            }
+            output.push_str("}\n");
+        }
+        "csharp" => {
+            let (converter, member) = match options.csharp_serializer {
+                CSharpSerializer::Newtonsoft => ("StringEnumConverter", "EnumMember(Value = "),
+                CSharpSerializer::SystemTextJson => {
+                    ("JsonStringEnumConverter", "JsonStringEnumMemberName(")
+                }
+            };
+            output.push_str(&format!("[JsonConverter(typeof({}))]\n", converter));
+            output.push_str(&format!("public enum {}\n{{\n", name));
+            for value in values {
+                output.push_str(&format!("    [{}{:?})]\n", member, value));
+                output.push_str(&format!("    {},\n", enum_variant_pascal(value)));
+            }
+            output.push_str("}\n");
+        }
+        "go" => {
+            output.push_str(&format!("type {} string\n\nconst (\n", name));
+            for value in values {
+                output.push_str(&format!(
+                    "    {}{} {} = {:?}\n",
+                    name,
+                    enum_variant_pascal(value),
+                    name,
+                    value
+                ));
+            }
+            output.push_str(")\n");
+        }
+        "kotlin" => {
+            let annotation = match options.kotlin_serialization {
+                KotlinSerialization::Gson => "SerializedName",
+                KotlinSerialization::Kotlinx => {
+                    output.push_str("@Serializable\n");
+                    "SerialName"
+                }
+            };
+            output.push_str(&format!("enum class {} {{\n", name));
+            for value in values {
+                output.push_str(&format!("    @{}({:?})\n", annotation, value));
+                output.push_str(&format!("    {},\n", enum_variant_screaming(value)));
+            }
+            output.push_str("}\n");
+        }
+        "swift" => {
+            output.push_str(&format!("enum {}: String, Codable {{\n", name));
+            for value in values {
+                output.push_str(&format!(
+                    "    case {} = {:?}\n",
+                    to_camel_case(&enum_variant_pascal(value)),
+                    value
+                ));
+            }
+            output.push_str("}\n");
+        }
+        "dart" => {
+            output.push_str(&format!("enum {} {{\n", name));
+            for value in values {
+                output.push_str(&format!("  @JsonValue('{}')\n", value.replace('\'', "\\'")));
+                output.push_str(&format!(
+                    "  {},\n",
+                    to_camel_case(&enum_variant_pascal(value))
+                ));
+            }
+            output.push_str("}\n");
+        }
+        "proto" => {
+            let prefix = to_snake_case(name).to_uppercase();
+            output.push_str(&format!("enum {} {{\n", name));
+            output.push_str(&format!("  {}_UNSPECIFIED = 0;\n", prefix));
+            for (number, value) in (1..).zip(values) {
+                output.push_str(&format!(
+                    "  {}_{} = {};\n",
+                    prefix,
+                    enum_variant_screaming(value),
+                    number
+                ));
+            }
+            output.push_str("}\n");
+        }
+        _ => {}
+    }
+    output
+}
+
+/// All detected enums rendered for the target language, each preceded by a blank line
+fn enum_definitions(language: &str, hints: &FieldHints, options: &CodegenOptions) -> String {
+    collected_enums(hints)
+        .iter()
+        .map(|(name, values)| enum_definition(language, name, values, options))
+        .filter(|definition| !definition.is_empty())
+        .map(|definition| format!("\n{}", definition))
+        .collect()
+}
+
+/// Merge samples into one representative value for code generation. Arrays collapse to
+/// one element unified across all of their elements, objects carry the union of their
+/// keys, integers widen to floats, and conflicting types become `null` (any). Keys that
+/// are missing or null in some objects, and low-cardinality string fields, are recorded
+/// in `hints`.
+fn unify_samples(samples: &[&Value], class_name: &str, hints: &mut FieldHints) -> Value {
+    let non_null: Vec<&Value> = samples.iter().copied().filter(|v| !v.is_null()).collect();
+    let Some(first) = non_null.first() else {
+        return Value::Null;
+    };
+    if non_null
+        .iter()
+        .any(|v| std::mem::discriminant(*v) != std::mem::discriminant(*first))
+    {
+        return Value::Null;
+    }
+
+    match first {
+        Value::Number(_) => match non_null.iter().find(|v| v.is_f64()) {
+            Some(float) => (*float).clone(),
+            // Keep the widest integer so range-based types (int32/int64) still fit
+            None => non_null
+                .iter()
+                .max_by_key(|v| v.as_i64().map_or(u64::MAX, i64::unsigned_abs))
+                .map_or(Value::Null, |v| (*v).clone()),
+        },
+        // Only keep a representative string when every sample shares its format
+        Value::String(_) => match common_string_format(&non_null) {
+            Some(_) => (*first).clone(),
+            None => Value::String(String::new()),
+        },
+        Value::Array(_) => {
+            let items: Vec<&Value> = non_null
+                .iter()
+                .filter_map(|v| v.as_array())
+                .flatten()
+                .collect();
+            if items.is_empty() {
+                return Value::Array(Vec::new());
+            }
+            // A trailing null keeps track of elements that were sometimes null
+            let mut collapsed = vec![unify_samples(&items, class_name, hints)];
+            if items.iter().any(|v| v.is_null()) && !collapsed[0].is_null() {
+                collapsed.push(Value::Null);
+            }
+            Value::Array(collapsed)
+        }
+        Value::Object(_) => {
+            let objects: Vec<&serde_json::Map<String, Value>> =
+                non_null.iter().filter_map(|v| v.as_object()).collect();
+            let keys: BTreeSet<&String> = objects.iter().flat_map(|map| map.keys()).collect();
+
+            let mut merged = serde_json::Map::new();
+            for key in keys {
+                let present: Vec<&Value> = objects.iter().filter_map(|map| map.get(key)).collect();
+                let children = match dynamic_map_values(&present) {
+                    Some(values) => {
+                        hints.maps.insert((class_name.to_string(), key.clone()));
+                        values
+                    }
+                    None => present.clone(),
+                };
+                let value = if children.iter().any(|v| contains_object(v)) {
+                    let name = register_type_name(hints, class_name, key);
+                    unify_samples(&children, &name, hints)
+                } else {
+                    unify_samples(&children, &type_identifier(key), hints)
+                };
+                let sometimes_absent =
+                    present.len() < objects.len() || present.iter().any(|v| v.is_null());
+                if sometimes_absent && !value.is_null() {
+                    hints.optional.insert((class_name.to_string(), key.clone()));
+                }
+                if hints.field_examples {
+                    if let Some(example) = present.iter().find_map(|v| example_value(v)) {
+                        hints
+                            .examples
+                            .insert((class_name.to_string(), key.clone()), example);
+                    }
+                }
+                if let Some(values) = enum_candidate(&present, hints.enum_threshold) {
+                    hints
+                        .enums
+                        .insert((class_name.to_string(), key.clone()), values);
+                }
+                merged.insert(key.clone(), value);
+            }
+            if hints.detect_oneofs {
+                let groups = oneof_groups(&objects, &merged, class_name, hints);
+                if !groups.is_empty() {
+                    hints.oneofs.insert(class_name.to_string(), groups);
+                }
+            }
+            Value::Object(merged)
+        }
+        _ => (*first).clone(),
+    }
+}
+
+/// Fields that never appear together, grouped so that every sample sets exactly one
+/// field of each group. Arrays and maps can't be part of a proto `oneof`.
+fn oneof_groups(
+    objects: &[&serde_json::Map<String, Value>],
+    merged: &serde_json::Map<String, Value>,
+    class_name: &str,
+    hints: &FieldHints,
+) -> Vec<Vec<String>> {
+    let is_set = |map: &serde_json::Map<String, Value>, key: &str| {
+        map.get(key).is_some_and(|value| !value.is_null())
+    };
+    let candidates = merged.iter().filter(|(key, value)| {
+        !value.is_array()
+            && !is_map_field(hints, class_name, key)
+            && objects.iter().any(|map| !is_set(map, key))
+    });
+
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    for (key, _) in candidates {
+        let exclusive = |group: &&mut Vec<String>| {
+            group.iter().all(|member| {
+                !objects
+                    .iter()
+                    .any(|map| is_set(map, key) && is_set(map, member))
+            })
+        };
+        match groups.iter_mut().find(exclusive) {
+            Some(group) => group.push(key.clone()),
+            None => groups.push(vec![key.clone()]),
+        }
+    }
+    groups.retain(|group| {
+        group.len() > 1
+            && objects
+                .iter()
+                .all(|map| group.iter().any(|key| is_set(map, key)))
+    });
+    groups
+}
+
+/// Generate a type and every type nested in it from an explicit work stack rather than
+/// by recursion, returning each type's code under its name. `append` writes one type
+/// and returns the nested types it refers to; with `children_first` those come before
+/// the type using them.
+fn collect_types<Root: ?Sized, Nested: Borrow<Root>>(
+    root: &Root,
+    root_name: &str,
+    children_first: bool,
+    mut append: impl FnMut(&Root, &str, &mut String) -> Result<Vec<(String, Nested)>, String>,
+) -> Result<Vec<(String, String)>, String> {
+    let mut code = String::new();
+    let mut pending = append(root, root_name, &mut code)?;
+    let mut types = vec![(root_name.to_string(), code)];
+    // The stack pops from the end, so reversing visits the nested types in order;
+    // children-first output reverses the whole visit order at the end instead
+    if !children_first {
+        pending.reverse();
+    }
+    while let Some((name, value)) = pending.pop() {
+        let mut code = String::new();
+        let mut nested = append(value.borrow(), &name, &mut code)?;
+        if !children_first {
+            nested.reverse();
+        }
+        pending.extend(nested);
+        types.push((name, code));
+    }
+    if children_first {
+        types.reverse();
+    }
+    Ok(types)
+}
+
+/// Generated source before it is joined into a single file
+struct GeneratedCode {
+    /// Imports and declarations that precede the types
+    preamble: String,
+    /// Each type's code under the type's name, in output order
+    types: Vec<(String, String)>,
+    separator: &'static str,
+    /// Closes what the preamble opened (C++ namespaces)
+    epilogue: String,
+}
+
+impl GeneratedCode {
+    fn new(preamble: String, types: Vec<(String, String)>, separator: &'static str) -> Self {
+        GeneratedCode {
+            preamble,
+            types,
+            separator,
+            epilogue: String::new(),
+        }
+    }
+
+    fn render(&self) -> String {
+        let types: Vec<&str> = self.types.iter().map(|(_, code)| code.as_str()).collect();
+        format!(
+            "{}{}{}",
+            self.preamble,
+            types.join(self.separator),
+            self.epilogue
+        )
+    }
+}
+
+/// Nesting depth of objects and arrays, measured without recursion
+fn json_depth(value: &Value) -> usize {
+    let mut deepest = 0;
+    let mut pending = vec![(value, 1)];
+    while let Some((value, depth)) = pending.pop() {
+        match value {
+            Value::Array(items) => pending.extend(items.iter().map(|item| (item, depth + 1))),
+            Value::Object(map) => pending.extend(map.values().map(|item| (item, depth + 1))),
+            _ => continue,
+        }
+        deepest = deepest.max(depth);
+    }
+    deepest
+}
+
+/// Reject documents nested deeper than the code generators will walk
+fn check_codegen_depth(samples: &[Value], options: &CodegenOptions) -> Result<(), String> {
+    let limit = match options.max_depth {
+        0 => CODEGEN_MAX_DEPTH,
+        limit => limit,
+    };
+    match samples.iter().map(json_depth).max() {
+        Some(depth) if depth > limit => Err(format!(
+            "Input is nested {} levels deep, beyond the code generation limit of {}",
+            depth, limit
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn generate_proto_message(
+    value: &Value,
+    message_name: &str,
+    output: &mut String,
+    counter: &mut i32,
+    hints: &FieldHints,
+    indent: usize,
+) -> Result<(), String> {
+    let messages = collect_types(value, message_name, false, |value, name, output| {
+        Ok(append_proto_message(
+            value, name, output, counter, hints, indent,
+        ))
+    })?;
+    let messages: Vec<String> = messages.into_iter().map(|(_, code)| code).collect();
+    output.push_str(&messages.join("\n"));
+    Ok(())
+}
+
+fn append_proto_message(
+    value: &Value,
+    message_name: &str,
+    output: &mut String,
+    counter: &mut i32,
+    hints: &FieldHints,
+    indent: usize,
+) -> Vec<(String, Value)> {
+    let indent_str = "  ".repeat(indent);
+    let mut nested_messages = Vec::new();
+
+    if let Value::Object(map) = value {
+        output.push_str(&format!("{}message {} {{\n", indent_str, message_name));
+
+        let mut field_number = 1;
+        let oneofs = hints.oneofs.get(message_name).cloned().unwrap_or_default();
+        let mut names = FieldNames::default();
+        let mut field_line = |key: &str, val: &Value, field_indent: &str, in_oneof: bool| {
+            let field_name = names.claim("proto", &to_snake_case(key));
+            let (mut field_type, nested_msg) =
+                infer_proto_type(val, &nested_type_name(hints, message_name, key), counter);
+            if let Some(enum_type) = enum_field_type(hints, message_name, key) {
+                field_type = enum_type;
+            }
+            // Map fields and members of a oneof are never `optional` in proto3
+            if is_map_field(hints, message_name, key) {
+                field_type = format!("map<string, {}>", field_type);
+            } else if !in_oneof
+                && is_optional_field(hints, message_name, key)
+                && !field_type.starts_with("repeated ")
+            {
+                field_type = format!("optional {}", field_type);
+            }
+
+            // The JSON mapping accepts the field name and its lowerCamelCase form
+            let json_name = if *key == field_name || *key == to_camel_case(&field_name) {
+                String::new()
+            } else {
+                format!(" [json_name = {:?}]", key)
+            };
+            let mut line = example_comment(
+                hints,
+                message_name,
+                key,
+                &format!("{}// ", field_indent),
+                "",
+            );
+            line.push_str(&format!(
+                "{}{} {} = {}{};\n",
+                field_indent, field_type, field_name, field_number, json_name
+            ));
+
+            // Arrays of objects describe their message with the first element
+            match (nested_msg, val) {
+                (Some(msg), Value::Array(items)) => {
+                    if let Some(first) = items.first().filter(|first| first.is_object()) {
+                        nested_messages.push((msg, first.clone()));
+                    }
+                }
+                (Some(msg), _) => nested_messages.push((msg, val.clone())),
+                (None, _) => {}
+            }
+
+            field_number += 1;
+            line
+        };
+
+        let field_indent = format!("{}  ", indent_str);
+        for (key, val) in map {
+            match oneofs.iter().find(|group| group.contains(key)) {
+                // The whole group is written where its first field would be
+                Some(group) if group[0] == *key => {
+                    let oneof_name = group
+                        .iter()
+                        .map(|member| to_snake_case(member))
+                        .collect::<Vec<_>>()
+                        .join("_or_");
+                    output.push_str(&format!(
+                        "{}oneof {} {{\n",
+                        field_indent,
+                        escape_identifier("proto", &oneof_name)
+                    ));
+                    for member in group {
+                        let member_indent = format!("{}  ", field_indent);
+                        output.push_str(&field_line(member, &map[member], &member_indent, true));
+                    }
+                    output.push_str(&format!("{}}}\n", field_indent));
+                }
+                Some(_) => {}
+                None => output.push_str(&field_line(key, val, &field_indent, false)),
+            }
+        }
+
+        output.push_str(&format!("{}}}\n", indent_str));
+    }
+    nested_messages
+}
+
+fn infer_proto_type(
+    value: &Value,
+    field_name: &str,
+    counter: &mut i32,
+) -> (String, Option<String>) {
+    match value {
+        // Null-only and mixed-type fields can hold any JSON value
+        Value::Null => ("google.protobuf.Value".to_string(), None),
+        Value::Bool(_) => ("bool".to_string(), None),
+        Value::Number(n) => {
+            if n.is_f64() {
+                ("double".to_string(), None)
+            } else if n.is_i64() {
+                let num = n.as_i64().unwrap();
+                if num >= i32::MIN as i64 && num <= i32::MAX as i64 {
+                    ("int32".to_string(), None)
+                } else {
+                    ("int64".to_string(), None)
+                }
+            } else {
+                ("uint64".to_string(), None)
+            }
+        }
+        Value::String(s) if is_iso_date_time(s) => ("google.protobuf.Timestamp".to_string(), None),
+        Value::String(_) => ("string".to_string(), None),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                ("repeated string".to_string(), None)
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    *counter += 1;
+                    let nested_name = to_pascal_case(field_name);
+                    (format!("repeated {}", nested_name), Some(nested_name))
+                } else {
+                    let (inner_type, _) = infer_proto_type(first, field_name, counter);
+                    let base_type = inner_type.replace("repeated ", "");
+                    (format!("repeated {}", base_type), None)
+                }
+            }
+        }
+        Value::Object(_) => {
+            *counter += 1;
+            let nested_name = to_pascal_case(field_name);
+            (nested_name.clone(), Some(nested_name))
+        }
+    }
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    let mut prev_is_upper = false;
+
+    for (i, c) in s.chars().enumerate() {
+        if !c.is_alphanumeric() {
+            // Dashes, spaces and dots separate words like underscores do
+            if !result.is_empty() && !result.ends_with('_') {
+                result.push('_');
+            }
+            prev_is_upper = false;
+        } else if c.is_uppercase() {
+            if i > 0 && !prev_is_upper && !result.ends_with('_') {
+                result.push('_');
+            }
+            result.push(c.to_lowercase().next().unwrap());
+            prev_is_upper = true;
+        } else {
+            result.push(c);
+            prev_is_upper = false;
+        }
+    }
+
+    result
+}
+
+fn to_pascal_case(s: &str) -> String {
+    let s = s.replace(|c: char| !c.is_alphanumeric(), " ");
+    s.split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            }
+        })
+        .collect()
+}
+
+fn to_camel_case(s: &str) -> String {
+    let pascal = to_pascal_case(s);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+    }
+}
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield",
+];
+
+const JAVA_KEYWORDS: &[&str] = &[
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+const KOTLIN_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+const SWIFT_KEYWORDS: &[&str] = &[
+    "Any",
+    "Self",
+    "as",
+    "associatedtype",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "open",
+    "operator",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+
+const DART_KEYWORDS: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+    "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+    "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "while",
+    "with",
+];
+
+const CPP_KEYWORDS: &[&str] = &[
+    "alignas",
+    "alignof",
+    "and",
+    "and_eq",
+    "asm",
+    "auto",
+    "bitand",
+    "bitor",
+    "bool",
+    "break",
+    "case",
+    "catch",
+    "char",
+    "char16_t",
+    "char32_t",
+    "class",
+    "compl",
+    "const",
+    "const_cast",
+    "constexpr",
+    "continue",
+    "decltype",
+    "default",
+    "delete",
+    "do",
+    "double",
+    "dynamic_cast",
+    "else",
+    "enum",
+    "explicit",
+    "export",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "friend",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "mutable",
+    "namespace",
+    "new",
+    "noexcept",
+    "not",
+    "not_eq",
+    "nullptr",
+    "operator",
+    "or",
+    "or_eq",
+    "private",
+    "protected",
+    "public",
+    "register",
+    "reinterpret_cast",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "static_assert",
+    "static_cast",
+    "struct",
+    "switch",
+    "template",
+    "this",
+    "thread_local",
+    "throw",
+    "true",
+    "try",
+    "typedef",
+    "typeid",
+    "typename",
+    "union",
+    "unsigned",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "wchar_t",
+    "while",
+    "xor",
+    "xor_eq",
+];
+
+/// Turn a field name into a valid identifier for `language`: other characters become `_`,
+/// a leading digit gets a `field` prefix, and reserved words are escaped the way the
+/// language allows (`r#type`, `` `class` ``, `@class`) or suffixed with `_`
+fn escape_identifier(language: &str, name: &str) -> String {
+    let mut name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name = match language {
+            "go" | "csharp" => format!("Field{}", name),
+            "python" | "rust" | "cpp" | "proto" if !name.is_empty() => format!("field_{}", name),
+            _ => format!("field{}", name),
+        };
+    }
+    // Haskell fields start lowercase; uppercase names are constructors
+    if language == "haskell" && name.starts_with(char::is_uppercase) {
+        name = to_camel_case(&name);
+    }
+
+    let keywords = match language {
+        "python" => PYTHON_KEYWORDS,
+        "rust" => RUST_KEYWORDS,
+        "java" => JAVA_KEYWORDS,
+        "csharp" => CSHARP_KEYWORDS,
+        "go" => GO_KEYWORDS,
+        "kotlin" => KOTLIN_KEYWORDS,
+        "swift" => SWIFT_KEYWORDS,
+        "dart" => DART_KEYWORDS,
+        "cpp" => CPP_KEYWORDS,
+        "haskell" => HASKELL_KEYWORDS,
+        _ => &[],
+    };
+    if !keywords.contains(&name.as_str()) {
+        return name;
+    }
+    match language {
+        "rust" if !matches!(name.as_str(), "self" | "Self" | "super" | "crate") => {
+            format!("r#{}", name)
+        }
+        "kotlin" | "swift" => format!("`{}`", name),
+        "csharp" => format!("@{}", name),
+        _ => format!("{}_", name),
+    }
+}
+
+/// Name of a generated type; names that would start with a digit get a `Type` prefix
+fn type_identifier(key: &str) -> String {
+    let name = to_pascal_case(key);
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Type{}", name)
+    } else {
+        name
+    }
+}
+
+/// JavaScript keeps keys that are already identifiers and camelCases the rest
+fn js_property_name(key: &str) -> String {
+    if is_js_identifier(key) {
+        key.to_string()
+    } else {
+        to_camel_case(key)
+    }
+}
+
+/// Whether `key` can be written unquoted as a JavaScript property name
+fn is_js_identifier(key: &str) -> bool {
+    key.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Class style emitted by the Python generator
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum PythonStyle {
+    #[default]
+    Dataclass,
+    /// Pydantic v2 `BaseModel` with `Field(alias=...)` for renamed keys
+    Pydantic,
+    /// `TypedDict` with every key required
+    TypedDict,
+    /// `TypedDict` declared with `total=False`
+    TypedDictPartial,
+    /// `attrs.define` classes with aliases for renamed keys
+    Attrs,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum CSharpSerializer {
+    /// Newtonsoft.Json `[JsonProperty]` attributes
+    #[default]
+    Newtonsoft,
+    /// System.Text.Json `[JsonPropertyName]` attributes
+    SystemTextJson,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum KotlinSerialization {
+    /// Gson `@SerializedName` annotations
+    #[default]
+    Gson,
+    /// kotlinx.serialization `@Serializable` data classes
+    Kotlinx,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum JavaStyle {
+    /// Class with private fields plus getters and setters
+    #[default]
+    Pojo,
+    /// Java 17 `record`
+    Record,
+    /// Lombok `@Data` class
+    Lombok,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum JavaAnnotations {
+    #[default]
+    Jackson,
+    Gson,
+}
+
+/// Default nesting limit for code generation; serde_json stops parsing at 128 levels
+const CODEGEN_MAX_DEPTH: usize = 64;
+
+/// Language-specific code generation options
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+struct CodegenOptions {
+    /// Prefix record fields with the type name (Haskell) so records can share field names
+    field_prefix: bool,
+    python_style: PythonStyle,
+    csharp_serializer: CSharpSerializer,
+    /// Emit C# `record` types with `init` accessors instead of mutable classes
+    csharp_records: bool,
+    kotlin_serialization: KotlinSerialization,
+    java_style: JavaStyle,
+    java_annotations: JavaAnnotations,
+    /// Map ISO-8601 timestamps, UUIDs, URLs and emails to richer types where supported
+    string_formats: bool,
+    /// Turn string fields with at most this many distinct, repeating values into enums (0 = off)
+    enum_threshold: usize,
+    naming_convention: NamingConvention,
+    nullability: Nullability,
+    /// Read-only fields where the language defaults to mutable ones (TypeScript,
+    /// JavaScript, Python classes, C#)
+    immutable: bool,
+    /// Leave out per-field serialization annotations (serde renames, Jackson/Gson, C#
+    /// attributes, Go tags, Kotlin and Dart names)
+    omit_annotations: bool,
+    /// Name of the list type emitted for a top-level array input (default `{Root}List`)
+    root_array_name: String,
+    /// Package, namespace or module for Java, C#, Go, Kotlin, Rust, C++, Haskell and proto
+    /// output
+    namespace: String,
+    /// `option java_package` of proto output
+    java_package: String,
+    /// `option go_package` of proto output, e.g. `example.com/api/v1;apiv1`
+    go_package: String,
+    /// Keep mutually exclusive proto fields as separate fields instead of a `oneof`
+    omit_oneofs: bool,
+    /// Document each field with an example value taken from the input
+    field_examples: bool,
+    /// Deepest object/array nesting accepted (0 = the default of 64)
+    max_depth: usize,
+}
+
+/// Field naming in languages where field names may differ from the JSON keys. Go and C#
+/// always use PascalCase; TypeScript, Zod and TypedDict keep the keys.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum NamingConvention {
+    /// Each language's usual convention (snake_case for Python, Rust and C++,
+    /// camelCase elsewhere)
+    #[default]
+    Idiomatic,
+    /// Use the JSON keys as they are
+    Preserve,
+    CamelCase,
+    SnakeCase,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+enum Nullability {
+    /// Optional only when missing or null in some samples
+    #[default]
+    Inferred,
+    AllOptional,
+    AllRequired,
+}
+
+/// Field name for `key` under the configured naming convention, escaped for `language`
+/// and unique among the fields already named in `names`
+fn field_identifier(
+    language: &str,
+    key: &str,
+    options: &CodegenOptions,
+    idiomatic: fn(&str) -> String,
+    names: &mut FieldNames,
+) -> String {
+    let name = match options.naming_convention {
+        NamingConvention::Idiomatic => idiomatic(key),
+        NamingConvention::Preserve => key.to_string(),
+        NamingConvention::CamelCase => to_camel_case(key),
+        NamingConvention::SnakeCase => to_snake_case(key),
+    };
+    names.claim(language, &name)
+}
+
+/// The field identifiers already used in one generated type. Keys such as `user_id` and
+/// `userId` can map to the same identifier, so later ones get a numeric suffix.
+#[derive(Default)]
+struct FieldNames(HashSet<String>);
+
+impl FieldNames {
+    /// Escape `name` for `language`, suffixing it (`user_id_2`, `userId2`) if it is taken
+    fn claim(&mut self, language: &str, name: &str) -> String {
+        let mut identifier = escape_identifier(language, name);
+        // Suffix the bare name so the result is escaped again (`r#type`, `` `class` ``)
+        let base = identifier
+            .trim_start_matches("r#")
+            .trim_start_matches('@')
+            .trim_matches('`')
+            .to_string();
+        let snake_case = base.contains('_')
+            || matches!(language, "python" | "rust" | "cpp" | "proto")
+                && !base.contains(char::is_uppercase);
+        let separator = if snake_case { "_" } else { "" };
+        let mut suffix = 2;
+        while !self.0.insert(identifier.clone()) {
+            identifier = escape_identifier(language, &format!("{}{}{}", base, separator, suffix));
+            suffix += 1;
+        }
+        identifier
+    }
+}
+
+/// Wrap Rust code in `pub mod` blocks, one per segment of a dotted or `::` path
+fn rust_module(code: &str, namespace: &str) -> String {
+    let modules: Vec<String> = namespace
+        .split(['.', ':'])
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| escape_identifier("rust", &to_snake_case(segment)))
+        .collect();
+    let mut output = String::new();
+    for (depth, module) in modules.iter().enumerate() {
+        output.push_str(&format!("{}pub mod {} {{\n", "    ".repeat(depth), module));
+    }
+    let indent = "    ".repeat(modules.len());
+    for line in code.lines() {
+        if line.is_empty() {
+            output.push('\n');
+        } else {
+            output.push_str(&format!("{}{}\n", indent, line));
+        }
+    }
+    for depth in (0..modules.len()).rev() {
+        output.push_str(&format!("{}}}\n", "    ".repeat(depth)));
+    }
+    output
+}
+
+/// Named list type for a top-level array input whose elements became `element`; C++
+/// aliases follow the closing namespace, so `namespace` qualifies the element there
+fn root_array_alias(language: &str, alias: &str, element: &str, namespace: &str) -> String {
+    let doc = format!("The top-level JSON array of {}", element);
+    match language {
+        "typescript" => format!("\n/** {} */\ntype {} = {}[];\n", doc, alias, element),
+        "javascript" => format!(
+            "\n/**\n * {}\n * @typedef {{{}[]}} {}\n */\n",
+            doc, element, alias
+        ),
+        "python" => format!("\n# {}\n{} = List[{}]\n", doc, alias, element),
+        "rust" => format!("\n/// {}\npub type {} = Vec<{}>;\n", doc, alias, element),
+        "java" => format!(
+            "\n/** {} */\npublic class {} extends java.util.ArrayList<{}> {{}}\n",
+            doc, alias, element
+        ),
+        "csharp" => format!(
+            "\n/// <summary>{}</summary>\npublic class {} : List<{}> {{}}\n",
+            doc, alias, element
+        ),
+        "go" => format!(
+            "\n// {} is the top-level JSON array of {}\ntype {} []{}\n",
+            alias, element, alias, element
+        ),
+        "kotlin" => format!("\n/** {} */\ntypealias {} = List<{}>\n", doc, alias, element),
+        "swift" => format!("\n/// {}\ntypealias {} = [{}]\n", doc, alias, element),
+        "dart" => format!("\n/// {}\ntypedef {} = List<{}>;\n", doc, alias, element),
+        "cpp" if namespace.is_empty() => format!(
+            "\n/// {}\nusing {} = std::vector<{}>;\n",
+            doc, alias, element
+        ),
+        "cpp" => format!(
+            "\n/// {}\nusing {} = std::vector<{}::{}>;\n",
+            doc, alias, namespace, element
+        ),
+        "haskell" => format!("\n-- | {}\ntype {} = [{}]\n", doc, alias, element),
+        "zod" => format!(
+            "\n/** {} */\nexport const {}Schema = z.array({}Schema);\n\nexport type {} = z.infer<typeof {}Schema>;\n",
+            doc, alias, element, alias, alias
+        ),
+        _ => String::new(),
+    }
+}
+
+/// Classes need objects at the top level, either directly or as the elements of an array
+fn check_class_root(samples: &[Value], root_is_array: bool) -> Result<(), String> {
+    let kind = |value: &Value| match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    };
+    if root_is_array && samples.is_empty() {
+        return Err(
+            "The top-level array is empty; add at least one object to generate classes from"
+                .to_string(),
+        );
+    }
+    match samples.iter().find(|value| !value.is_object()) {
+        None => Ok(()),
+        Some(value) if root_is_array => Err(format!(
+            "The top-level array contains {}; classes can only be generated from an array of objects",
+            kind(value)
+        )),
+        Some(value) => Err(format!(
+            "The top-level value is {}, which has no fields to generate a class from; wrap it in an object such as {{\"value\": ...}}",
+            kind(value)
+        )),
+    }
+}
+
+/// Everything the class generator produced for one input, before it is written out as
+/// one file or as a file per type
+struct ClassCode {
+    /// Lowercase language, with `c#` and `c++` as `csharp` and `cpp`
+    language: String,
+    name: String,
+    code: GeneratedCode,
+    /// Enum definitions by name; Python keeps its enums among the types
+    enums: Vec<(String, String)>,
+    /// Name and code of the list type for a top-level array
+    root_alias: Option<(String, String)>,
+}
+
+/// Parse, check and unify the input, then run the generator for `language`
+fn generate_class_code(
+    command: &str,
+    input: &str,
+    language: &str,
+    name: String,
+    options: &CodegenOptions,
+    timer: &mut OperationTimer,
+) -> Result<ClassCode, String> {
+    let mut samples = parse_json_samples(input).map_err(|e| {
+        error!("{}: Failed to parse JSON - {}", command, e);
+        e
+    })?;
+    timer.parsed();
+    let root_is_array = input.trim_start().starts_with('[');
+    check_class_root(&samples, root_is_array)
+        .and_then(|()| check_codegen_depth(&samples, options))
+        .map_err(|e| {
+            warn!("{}: {}", command, e);
+            e
+        })?;
+
+    let final_class_name = if name.is_empty() {
+        "Root".to_string()
+    } else {
+        name
+    };
+
+    info!(
+        "{}: Converting to {} with class name '{}'",
+        command, language, final_class_name
+    );
+
+    let mut hints = FieldHints {
+        enum_threshold: options.enum_threshold,
+        nullability: options.nullability,
+        field_examples: options.field_examples,
+        types: HashSet::from([final_class_name.clone()]),
+        ..Default::default()
+    };
+    let mut unified = unify_samples(
+        &samples.iter().collect::<Vec<_>>(),
+        &final_class_name,
+        &mut hints,
+    );
+    if !options.string_formats {
+        clear_string_values(&mut unified);
+        samples.iter_mut().for_each(clear_string_values);
+    }
+    let name = &final_class_name;
+    let language_id = match language.to_lowercase().as_str() {
+        "c#" => "csharp".to_string(),
+        "c++" => "cpp".to_string(),
+        other => other.to_string(),
+    };
+    let code = match language_id.as_str() {
+        "typescript" => generate_typescript_class(&unified, name, options, &hints),
+        "javascript" => generate_javascript_class(&unified, name, options, &hints),
+        "python" => generate_python_models(&unified, name, options, &hints),
+        "rust" => generate_rust_struct(&unified, name, options, &hints),
+        "java" => generate_java_class(&unified, name, options, &hints),
+        "csharp" => generate_csharp_class(&unified, name, options, &hints),
+        "go" => generate_go_struct(&unified, name, options, &hints),
+        "kotlin" => match options.kotlin_serialization {
+            KotlinSerialization::Gson => generate_kotlin_class(&unified, name, options, &hints),
+            KotlinSerialization::Kotlinx => {
+                generate_kotlin_serializable(&unified, name, options, &hints)
+            }
+        },
+        "swift" => generate_swift_struct(&unified, name, options, &hints),
+        "dart" => generate_dart_class(&unified, name, options, &hints),
+        "cpp" => generate_cpp_struct(&unified, name, options, &hints),
+        "haskell" => generate_haskell_data(&unified, name, options, &hints),
+        "zod" => generate_zod_schema(&samples, name, &hints),
+        _ => {
+            error!("{}: Unsupported language: {}", command, language);
+            Err(format!("Unsupported language: {}", language))
+        }
+    }?;
+
+    // Python places its enums itself since they must precede the models using them
+    let enums = match language_id.as_str() {
+        "python" => Vec::new(),
+        other => collected_enums(&hints)
+            .iter()
+            .map(|(enum_name, values)| {
+                let definition = enum_definition(other, enum_name, values, options);
+                (enum_name.clone(), definition)
+            })
+            .filter(|(_, definition)| !definition.is_empty())
+            .collect(),
+    };
+    // API list endpoints return arrays, so they get a named list type by default
+    let root_alias = root_is_array.then(|| {
+        let alias = match options.root_array_name.trim() {
+            "" => format!("{}List", name),
+            alias => alias.to_string(),
+        };
+        let code = root_array_alias(&language_id, &alias, name, &cpp_namespace(options));
+        (alias, code)
+    });
+
+    Ok(ClassCode {
+        language: language_id,
+        name: final_class_name,
+        code,
+        enums,
+        root_alias,
+    })
+}
+
+/// Convert JSON to class definition in various programming languages
+#[tauri::command(async)]
+fn json_to_class(
+    input: String,
+    language: String,
+    name: String,
+    options: Option<CodegenOptions>,
+) -> Result<Timed<String>, String> {
+    info!(
+        "json_to_class called - language: {}, class_name: '{}', input_len: {}",
+        language,
+        name,
+        input.len()
+    );
+
+    if input.trim().is_empty() {
+        warn!("json_to_class: Input is empty");
+        return Err("Input is empty".to_string());
+    }
+
+    check_job_memory("json_to_class", input.len())?;
+    let mut timer = OperationTimer::start("json_to_class", input.len());
+    let options = options.unwrap_or_default();
+    let result = generate_class_code(
+        "json_to_class",
+        &input,
+        &language,
+        name,
+        &options,
+        &mut timer,
+    )
+    .map(|class| {
+        let mut output = class.code.render();
+        for (_, definition) in &class.enums {
+            output.push('\n');
+            output.push_str(definition);
+        }
+        if let Some((_, alias)) = &class.root_alias {
+            output.push_str(alias);
+        }
+        // Rust modules wrap the whole output, enums and list type included
+        match options.namespace.trim() {
+            namespace if class.language == "rust" && !namespace.is_empty() => {
+                rust_module(&output, namespace)
+            }
+            _ => output,
+        }
+    });
+
+    match result {
+        Ok(output) => {
+            timer.transformed();
+            info!(
+                "json_to_class: Successfully generated {} code ({} chars)",
+                language,
+                output.len()
+            );
+            debug!("Generated code:\n{}", output);
+            Ok(timer.finish(output))
+        }
+        Err(e) => {
+            error!(
+                "json_to_class: Failed to generate {} code - {}",
+                language, e
+            );
+            Err(e)
+        }
+    }
+}
+
+/// Generate classes like `json_to_class`, but as one file per type and enum with the
+/// imports between them, keyed by relative path
+#[tauri::command(async)]
+fn json_to_class_files(
+    input: String,
+    language: String,
+    name: String,
+    options: Option<CodegenOptions>,
+) -> Result<Timed<BTreeMap<String, String>>, String> {
+    info!(
+        "json_to_class_files called - language: {}, class_name: '{}', input_len: {}",
+        language,
+        name,
+        input.len()
+    );
+
+    if input.trim().is_empty() {
+        return Err("Input is empty".to_string());
+    }
+
+    check_job_memory("json_to_class_files", input.len())?;
+    let mut timer = OperationTimer::start("json_to_class_files", input.len());
+    let options = options.unwrap_or_default();
+    let class = generate_class_code(
+        "json_to_class_files",
+        &input,
+        &language,
+        name,
+        &options,
+        &mut timer,
+    )?;
+    let files = split_class_files(&class, &options);
+    timer.transformed();
+    info!(
+        "json_to_class_files: Generated {} files for {}",
+        files.len(),
+        class.name
+    );
+    Ok(timer.finish(files))
+}
+
+/// Module (file stem) of a type in languages that import other files by module
+fn class_module(language: &str, type_name: &str) -> String {
+    match language {
+        "python" => escape_identifier("python", &to_snake_case(type_name)),
+        _ => to_snake_case(type_name),
+    }
+}
+
+fn class_file_name(language: &str, type_name: &str, options: &CodegenOptions) -> String {
+    let module = class_module(language, type_name);
+    match language {
+        "typescript" | "zod" => format!("{}.ts", type_name),
+        "javascript" => format!("{}.js", type_name),
+        "python" => format!("{}.py", module),
+        "rust" => format!("{}.rs", module),
+        "go" => format!("{}.go", module),
+        "dart" => format!("{}.dart", module),
+        "cpp" => format!("{}.hpp", module),
+        "java" => format!("{}.java", type_name),
+        "csharp" => format!("{}.cs", type_name),
+        "kotlin" => format!("{}.kt", type_name),
+        "swift" => format!("{}.swift", type_name),
+        "haskell" => format!(
+            "{}.hs",
+            haskell_module(options, type_name).replace('.', "/")
+        ),
+        _ => type_name.to_string(),
+    }
+}
+
+/// Import of another generated file; empty where types share a package or namespace
+fn class_file_import(language: &str, type_name: &str, options: &CodegenOptions) -> String {
+    let module = class_module(language, type_name);
+    match language {
+        "typescript" => format!("import {{ {} }} from \"./{}\";", type_name, type_name),
+        "zod" => format!("import {{ {}Schema }} from \"./{}\";", type_name, type_name),
+        "python" => format!("from .{} import {}", module, type_name),
+        "rust" => format!(
+            "use super::{}::{};",
+            escape_identifier("rust", &module),
+            type_name
+        ),
+        "dart" => format!("import '{}.dart';", module),
+        "cpp" => format!("#include \"{}.hpp\"", module),
+        "haskell" => format!(
+            "import {} ({}(..))",
+            haskell_module(options, type_name),
+            type_name
+        ),
+        _ => String::new(),
+    }
+}
+
+/// Whether code refers to the identifier `name` outside comment lines
+fn mentions_identifier(code: &str, name: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    code.lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            !["//", "/*", "*", "#", "--"]
+                .iter()
+                .any(|comment| line.starts_with(comment))
+        })
+        .any(|line| {
+            line.match_indices(name).any(|(start, _)| {
+                !line[..start].ends_with(is_word)
+                    && !line[start + name.len()..].starts_with(is_word)
+            })
+        })
+}
+
+/// One file for a single type: the language's preamble, imports of the other types it
+/// uses, and the type itself
+fn class_file(
+    language: &str,
+    code: &GeneratedCode,
+    type_name: &str,
+    body: &str,
+    imports: &[String],
+    options: &CodegenOptions,
+) -> String {
+    let imports: String = imports.iter().map(|line| format!("{}\n", line)).collect();
+    let preamble = code.preamble.as_str();
+    match language {
+        // Types in separate modules have to be exported
+        "typescript" | "javascript" => {
+            let body: String = body
+                .lines()
+                .map(|line| {
+                    if ["interface ", "type ", "class "]
+                        .iter()
+                        .any(|keyword| line.starts_with(keyword))
+                    {
+                        format!("export {}\n", line)
+                    } else {
+                        format!("{}\n", line)
+                    }
+                })
+                .collect();
+            if imports.is_empty() {
+                body
+            } else {
+                format!("{}\n{}", imports, body)
+            }
+        }
+        "zod" => format!("{}{}\n{}", preamble, imports, body),
+        "python" if imports.is_empty() => format!("{}\n\n{}", preamble, body),
+        "python" => format!("{}\n{}\n\n{}", preamble, imports, body),
+        // A list type alone would leave the serde import unused
+        "rust" if !body.contains("Serialize") => format!("{}\n{}", imports, body)
+            .trim_start_matches('\n')
+            .to_string(),
+        "rust" => format!("{}\n{}\n{}", preamble.trim_end(), imports, body),
+        // Only the package line is shared, since an unused import does not compile
+        "go" => {
+            let package = preamble.lines().next().unwrap_or_default();
+            if body.contains("time.") {
+                format!("{}\n\nimport \"time\"\n\n{}", package, body)
+            } else {
+                format!("{}\n\n{}", package, body)
+            }
+        }
+        "dart" => {
+            let part = if body.contains("@JsonSerializable") {
+                format!("part '{}.g.dart';\n\n", class_module(language, type_name))
+            } else {
+                String::new()
+            };
+            let annotations = if body.contains("@Json") {
+                "import 'package:json_annotation/json_annotation.dart';\n"
+            } else {
+                ""
+            };
+            format!("{}{}\n{}{}", annotations, imports, part, body)
+                .trim_start_matches('\n')
+                .to_string()
+        }
+        "cpp" => {
+            let namespace = cpp_namespace(options);
+            let opening = if namespace.is_empty() {
+                String::new()
+            } else {
+                format!("\nnamespace {} {{\n", namespace)
+            };
+            let includes = preamble.strip_suffix(&opening).unwrap_or(preamble);
+            format!(
+                "{}{}{}\n{}{}",
+                includes, imports, opening, body, code.epilogue
+            )
+        }
+        "haskell" => {
+            let library_imports = preamble
+                .split_once(" where\n\n")
+                .map_or("", |(_, rest)| rest);
+            format!(
+                "{{-# LANGUAGE DeriveGeneric #-}}\n\nmodule {} where\n\n{}{}\n{}",
+                haskell_module(options, type_name),
+                library_imports,
+                imports,
+                body
+            )
+        }
+        // Java, C#, Kotlin and Swift types share a package or module
+        _ => format!("{}{}", preamble, body),
+    }
+}
+
+/// Lay generated classes out as one file per type, enum and list type, plus the
+/// `__init__.py` or `mod.rs` that Python and Rust need to use them as a package
+fn split_class_files(class: &ClassCode, options: &CodegenOptions) -> BTreeMap<String, String> {
+    let language = class.language.as_str();
+    let units: Vec<(&str, &str)> = class
+        .code
+        .types
+        .iter()
+        .chain(&class.enums)
+        .chain(&class.root_alias)
+        .map(|(name, code)| (name.as_str(), code.trim_start_matches('\n')))
+        .collect();
+
+    let mut files = BTreeMap::new();
+    for (name, body) in &units {
+        let imports: Vec<String> = units
+            .iter()
+            .filter(|(other, _)| other != name)
+            .filter(|(other, _)| match language {
+                "zod" => mentions_identifier(body, &format!("{}Schema", other)),
+                _ => mentions_identifier(body, other),
+            })
+            .map(|(other, _)| class_file_import(language, other, options))
+            .filter(|line| !line.is_empty())
+            .collect();
+        files.insert(
+            class_file_name(language, name, options),
+            class_file(language, &class.code, name, body, &imports, options),
+        );
+    }
+
+    let modules: Vec<(String, &str)> = units
+        .iter()
+        .map(|(name, _)| (class_module(language, name), *name))
+        .collect();
+    match language {
+        "python" => {
+            let exports: String = modules
+                .iter()
+                .map(|(module, name)| format!("from .{} import {}\n", module, name))
+                .collect();
+            files.insert("__init__.py".to_string(), exports);
+        }
+        "rust" => {
+            let declarations: String = modules
+                .iter()
+                .map(|(module, _)| format!("pub mod {};\n", escape_identifier("rust", module)))
+                .collect();
+            let exports: String = modules
+                .iter()
+                .map(|(module, name)| {
+                    format!("pub use {}::{};\n", escape_identifier("rust", module), name)
+                })
+                .collect();
+            files.insert(
+                "mod.rs".to_string(),
+                format!("{}\n{}", declarations, exports),
+            );
+        }
+        _ => {}
+    }
+    files
+}
+
+/// Write generated files below `directory`, creating folders as needed. File names must
+/// be relative paths that stay inside the directory.
+#[tauri::command]
+fn save_generated_files(
+    directory: String,
+    files: BTreeMap<String, String>,
+) -> Result<Vec<String>, String> {
+    info!(
+        "save_generated_files called - directory: '{}', files: {}",
+        directory,
+        files.len()
+    );
+    let directory = directory.trim();
+    if directory.is_empty() {
+        return Err("Choose a directory to save the files to".to_string());
+    }
+    if let Some(name) = files.keys().find(|name| {
+        name.is_empty()
+            || !Path::new(name)
+                .components()
+                .all(|component| matches!(component, std::path::Component::Normal(_)))
+    }) {
+        return Err(format!(
+            "Refusing to write '{}' outside the chosen directory",
+            name
+        ));
+    }
+
+    let mut written = Vec::new();
+    for (name, content) in &files {
+        let path = Path::new(directory).join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        written.push(path.display().to_string());
+    }
+    info!("save_generated_files: Wrote {} files", written.len());
+    Ok(written)
+}
+
+/// Read a file chosen in the Open dialog; the bytes go through the same binary
+/// detection as dropped or pasted input
+#[tauri::command(async)]
+fn read_input_file(path: String) -> Result<Vec<u8>, String> {
+    info!("read_input_file called - path: '{}'", path);
+    read_input_path(&path)
+}
+
+/// Read an input file within the input size limit and add it to the recent files
+fn read_input_path(path: &str) -> Result<Vec<u8>, String> {
+    let size = fs::metadata(path)
+        .map_err(|e| format!("Failed to open '{}': {}", path, e))?
+        .len();
+    let limit_mb = parse_limits().max_input_mb;
+    if size > limit_mb * 1024 * 1024 {
+        return Err(format!(
+            "File is {:.1} MB, above the {} MB input limit; use Large file to format it on disk",
+            size as f64 / (1024.0 * 1024.0),
+            limit_mb
+        ));
+    }
+    let bytes = fs::read(path).map_err(|e| format!("Failed to read '{}': {}", path, e))?;
+    record_recent_file(path);
+    Ok(bytes)
+}
+
+/// Write the output pane to a file chosen in the Save dialog
+#[tauri::command(async)]
+fn save_output_file(path: String, contents: String) -> Result<(), String> {
+    info!(
+        "save_output_file called - path: '{}', len: {}",
+        path,
+        contents.len()
+    );
+    fs::write(&path, contents).map_err(|e| format!("Failed to write '{}': {}", path, e))?;
+    record_recent_file(&path);
+    Ok(())
+}
+
+/// Styles and the expand/collapse script embedded in exported HTML so the file works offline
+const HTML_EXPORT_HEAD: &str = r#"<style>
+:root { color-scheme: light dark; --bg: #f7f9fc; --text: #2d3748; --muted: #718096; --key: #2b6cb0; --string: #2f855a; --number: #c05621; --literal: #805ad5; }
+@media (prefers-color-scheme: dark) { :root { --bg: #1a1d23; --text: #e2e8f0; --muted: #a0aec0; --key: #63b3ed; --string: #68d391; --number: #f6ad55; --literal: #b794f4; } }
+body { margin: 0; padding: 24px; background: var(--bg); color: var(--text); font: 13px/1.6 "JetBrains Mono", Menlo, Consolas, monospace; }
+h1 { font-size: 16px; margin: 0 0 4px; }
+.meta { color: var(--muted); margin-bottom: 12px; }
+button { font: inherit; margin-right: 6px; cursor: pointer; }
+.json { margin-top: 12px; }
+details > div { margin-left: 2ch; }
+summary { cursor: pointer; list-style: none; }
+summary::-webkit-details-marker { display: none; }
+summary::before { content: "▾ "; color: var(--muted); }
+details:not([open]) > summary::before { content: "▸ "; }
+details:not([open]) > summary .open::after { content: " … " attr(data-close); color: var(--muted); }
+details[open] > summary .count { display: none; }
+.count { color: var(--muted); font-style: italic; margin-left: 1ch; }
+.key { color: var(--key); }
+.string { color: var(--string); }
+.number { color: var(--number); }
+.literal { color: var(--literal); }
+</style>
+<script>
+function setAll(open) { document.querySelectorAll("details").forEach((node) => (node.open = open)); }
+</script>"#;
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Append one value as HTML; objects and arrays become `<details>` that start closed
+/// when their JSON pointer is in `collapsed`
+fn append_json_html(
+    key: Option<&str>,
+    value: &Value,
+    pointer: &str,
+    collapsed: &HashSet<String>,
+    comma: bool,
+    output: &mut String,
+) {
+    let label = match key {
+        Some(key) => format!(
+            "<span class=\"key\">{}</span>: ",
+            escape_html(&Value::from(key).to_string())
+        ),
+        None => String::new(),
+    };
+    let comma = if comma { "," } else { "" };
+    let (open, close, count, noun) = match value {
+        Value::Object(map) => ("{", "}", map.len(), "key"),
+        Value::Array(items) => ("[", "]", items.len(), "item"),
+        _ => {
+            let class = match value {
+                Value::String(_) => "string",
+                Value::Number(_) => "number",
+                _ => "literal",
+            };
+            output.push_str(&format!(
+                "<div class=\"line\">{}<span class=\"{}\">{}</span>{}</div>\n",
+                label,
+                class,
+                escape_html(&value.to_string()),
+                comma
+            ));
+            return;
+        }
+    };
+    if count == 0 {
+        output.push_str(&format!(
+            "<div class=\"line\">{}{}{}{}</div>\n",
+            label, open, close, comma
+        ));
+        return;
+    }
+
+    let state = if collapsed.contains(pointer) {
+        ""
+    } else {
+        " open"
+    };
+    output.push_str(&format!(
+        "<details{}><summary>{}<span class=\"open\" data-close=\"{}{}\">{}</span><span class=\"count\">{} {}{}</span></summary><div>\n",
+        state,
+        label,
+        close,
+        comma,
+        open,
+        count,
+        noun,
+        if count == 1 { "" } else { "s" }
+    ));
+    let children: Box<dyn Iterator<Item = (String, &Value)>> = match value {
+        Value::Object(map) => Box::new(map.iter().map(|(k, v)| (k.clone(), v))),
+        Value::Array(items) => Box::new(items.iter().enumerate().map(|(i, v)| (i.to_string(), v))),
+        _ => unreachable!(),
+    };
+    for (i, (child_key, child)) in children.enumerate() {
+        let child_pointer = format!("{}/{}", pointer, escape_json_pointer_token(&child_key));
+        let child_label = value.is_object().then_some(child_key.as_str());
+        append_json_html(
+            child_label,
+            child,
+            &child_pointer,
+            collapsed,
+            i + 1 < count,
+            output,
+        );
+    }
+    output.push_str(&format!("</div>{}{}</details>\n", close, comma));
+}
+
+/// Render JSON as a self-contained HTML page with collapsible, highlighted nodes
+fn render_json_html(value: &Value, title: &str, collapsed: &HashSet<String>) -> String {
+    let mut body = String::new();
+    append_json_html(None, value, "", collapsed, false, &mut body);
+    let title = escape_html(title);
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n{HTML_EXPORT_HEAD}\n</head>\n<body>\n<h1>{title}</h1>\n<div class=\"meta\">Exported from Palugada</div>\n<button onclick=\"setAll(true)\">Expand all</button><button onclick=\"setAll(false)\">Collapse all</button>\n<div class=\"json\">\n{body}</div>\n</body>\n</html>\n"
+    )
+}
+
+/// Export JSON as a standalone HTML page; `collapsed` lists JSON pointers of nodes to
+/// start closed, e.g. the ones closed in the tree view
+#[tauri::command(async)]
+fn export_html(
+    input: String,
+    path: String,
+    title: Option<String>,
+    collapsed: Option<Vec<String>>,
+) -> Result<(), String> {
+    info!(
+        "export_html called - path: '{}', len: {}",
+        path,
+        input.len()
+    );
+    check_parse_limits(&input)?;
+    let value: Value = serde_json::from_str(&input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let collapsed: HashSet<String> = collapsed.unwrap_or_default().into_iter().collect();
+    let title = title.unwrap_or_else(|| "JSON export".to_string());
+    let html = render_json_html(&value, &title, &collapsed);
+    write_file_atomically(Path::new(&path), html.as_bytes())
+        .map_err(|e| format!("Failed to write '{}': {}", path, e))
+}
+
+/// Unpinned entries kept in the recent files list
+const RECENT_FILES_LIMIT: usize = 12;
+
+/// Recently opened or saved files, most recent first; loaded from `recent-files.json` in
+/// the app data directory on first use
+static RECENT_FILES: Mutex<Option<Vec<RecentFile>>> = Mutex::new(None);
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct RecentFile {
+    path: String,
+    pinned: bool,
+    /// Seconds since the Unix epoch
+    last_used: u64,
+}
+
+/// `name` in the app data directory, once the app is running
+fn app_data_file(name: &str) -> Option<PathBuf> {
+    let dir = APP_HANDLE.get()?.path().app_data_dir().ok()?;
+    Some(dir.join(name))
+}
+
+/// Apply `update` to the recent files list and save it when `update` reports a change
+fn update_recent_files(update: impl FnOnce(&mut Vec<RecentFile>) -> bool) -> Vec<RecentFile> {
+    let mut recent = RECENT_FILES.lock().unwrap_or_else(|e| e.into_inner());
+    let files = recent.get_or_insert_with(|| {
+        app_data_file("recent-files.json")
+            .and_then(|store| fs::read_to_string(store).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    });
+
+    if update(files) {
+        if let Some(store) = app_data_file("recent-files.json") {
+            let saved = store
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| fs::write(&store, serde_json::to_vec_pretty(files)?));
+            if let Err(e) = saved {
+                warn!("Failed to save recent files to {}: {}", store.display(), e);
+            }
+        }
+    }
+    files.clone()
+}
+
+/// Move `path` to the top of the list, keeping every pinned entry but only the newest
+/// `RECENT_FILES_LIMIT` unpinned ones
+fn touch_recent_file(files: &mut Vec<RecentFile>, path: &str, now: u64) {
+    let pinned = files.iter().any(|file| file.path == path && file.pinned);
+    files.retain(|file| file.path != path);
+    files.insert(
+        0,
+        RecentFile {
+            path: path.to_string(),
+            pinned,
+            last_used: now,
+        },
+    );
+
+    let mut unpinned = 0;
+    files.retain(|file| {
+        unpinned += usize::from(!file.pinned);
+        file.pinned || unpinned <= RECENT_FILES_LIMIT
+    });
+}
+
+fn record_recent_file(path: &str) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    update_recent_files(|files| {
+        touch_recent_file(files, path, now);
+        true
+    });
+}
+
+#[tauri::command]
+fn recent_files() -> Vec<RecentFile> {
+    update_recent_files(|_| false)
+}
+
+/// Pin or unpin a recent file; pinned files are never dropped from the list
+#[tauri::command]
+fn pin_recent_file(path: String, pinned: bool) -> Vec<RecentFile> {
+    info!(
+        "pin_recent_file called - path: '{}', pinned: {}",
+        path, pinned
+    );
+    update_recent_files(|files| {
+        let Some(file) = files.iter_mut().find(|file| file.path == path) else {
+            return false;
+        };
+        file.pinned = pinned;
+        true
+    })
+}
+
+#[tauri::command]
+fn remove_recent_file(path: String) -> Vec<RecentFile> {
+    info!("remove_recent_file called - path: '{}'", path);
+    update_recent_files(|files| {
+        let before = files.len();
+        files.retain(|file| file.path != path);
+        files.len() != before
+    })
+}
+
+const INPUT_HISTORY_LIMIT: usize = 50;
+
+/// Inputs larger than this aren't kept, so the history file stays small
+const INPUT_HISTORY_MAX_BYTES: usize = 1024 * 1024;
+
+const INPUT_HISTORY_PREVIEW_CHARS: usize = 160;
+
+/// Inputs that operations ran on, newest first; loaded from `input-history.json` in the
+/// app data directory on first use
+static INPUT_HISTORY: Mutex<Option<Vec<InputHistoryEntry>>> = Mutex::new(None);
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InputHistoryEntry {
+    id: u64,
+    input: String,
+    operation: String,
+    /// Seconds since the Unix epoch
+    timestamp: u64,
+}
+
+/// A history entry as listed in the panel; the input itself is fetched on demand
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct InputHistorySummary {
+    id: u64,
+    operation: String,
+    timestamp: u64,
+    bytes: usize,
+    preview: String,
+}
+
+impl From<&InputHistoryEntry> for InputHistorySummary {
+    fn from(entry: &InputHistoryEntry) -> Self {
+        let mut preview: String = entry
+            .input
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .take(INPUT_HISTORY_PREVIEW_CHARS + 1)
+            .collect();
+        if preview.chars().count() > INPUT_HISTORY_PREVIEW_CHARS {
+            preview = preview.chars().take(INPUT_HISTORY_PREVIEW_CHARS).collect();
+            preview.push('…');
+        }
+        InputHistorySummary {
+            id: entry.id,
+            operation: entry.operation.clone(),
+            timestamp: entry.timestamp,
+            bytes: entry.input.len(),
+            preview,
+        }
+    }
+}
+
+/// Apply `update` to the input history, saving it when `update` reports a change, and
+/// return the summaries
+fn update_input_history(
+    update: impl FnOnce(&mut Vec<InputHistoryEntry>) -> bool,
+) -> Vec<InputHistorySummary> {
+    let mut history = INPUT_HISTORY.lock().unwrap_or_else(|e| e.into_inner());
+    let entries = history.get_or_insert_with(|| {
+        app_data_file("input-history.json")
+            .and_then(|store| fs::read_to_string(store).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    });
+
+    if update(entries) {
+        if let Some(store) = app_data_file("input-history.json") {
+            let saved = serde_json::to_vec(entries)
+                .map_err(std::io::Error::from)
+                .and_then(|bytes| write_file_atomically(&store, &bytes));
+            if let Err(e) = saved {
+                warn!("Failed to save input history to {}: {}", store.display(), e);
+            }
+        }
+    }
+    entries.iter().map(InputHistorySummary::from).collect()
+}
+
+/// Put `input` at the top of the history. Running another operation on the same input
+/// updates the newest entry instead of adding a duplicate.
+fn push_input_history(
+    entries: &mut Vec<InputHistoryEntry>,
+    input: &str,
+    operation: &str,
+    now: u64,
+) {
+    if let Some(newest) = entries.first_mut().filter(|entry| entry.input == input) {
+        newest.operation = operation.to_string();
+        newest.timestamp = now;
+        return;
+    }
+    let id = entries
+        .iter()
+        .map(|entry| entry.id)
+        .max()
+        .map_or(1, |id| id + 1);
+    entries.insert(
+        0,
+        InputHistoryEntry {
+            id,
+            input: input.to_string(),
+            operation: operation.to_string(),
+            timestamp: now,
+        },
+    );
+    entries.truncate(INPUT_HISTORY_LIMIT);
+}
+
+/// Remember the input an operation ran on; blank and oversized inputs are skipped
+#[tauri::command(async)]
+fn record_input_history(input: String, operation: String) -> Vec<InputHistorySummary> {
+    let keep = !input.trim().is_empty() && input.len() <= INPUT_HISTORY_MAX_BYTES;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    update_input_history(|entries| {
+        if keep {
+            push_input_history(entries, &input, &operation, now);
+        }
+        keep
+    })
+}
+
+#[tauri::command(async)]
+fn input_history() -> Vec<InputHistorySummary> {
+    update_input_history(|_| false)
+}
+
+/// The full input of a history entry
+#[tauri::command(async)]
+fn input_history_entry(id: u64) -> Result<String, String> {
+    let mut input = None;
+    update_input_history(|entries| {
+        input = entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.input.clone());
+        false
+    });
+    input.ok_or_else(|| "History entry no longer exists".to_string())
+}
+
+#[tauri::command(async)]
+fn remove_input_history(id: u64) -> Vec<InputHistorySummary> {
+    info!("remove_input_history called - id: {}", id);
+    update_input_history(|entries| {
+        let before = entries.len();
+        entries.retain(|entry| entry.id != id);
+        entries.len() != before
+    })
+}
+
+#[tauri::command(async)]
+fn clear_input_history() -> Vec<InputHistorySummary> {
+    info!("clear_input_history called");
+    update_input_history(|entries| {
+        let changed = !entries.is_empty();
+        entries.clear();
+        changed
+    })
+}
+
+/// How long a window close waits for the frontend to save the session
+const SESSION_CLOSE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Set once the session is saved on close, letting the next close request through
+static CLOSE_APPROVED: AtomicBool = AtomicBool::new(false);
+
+/// Converter documents autosaved by the frontend to `session.json` and restored at startup
+#[derive(
+    Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+struct Session {
+    documents: Vec<SessionDocument>,
+    active_document: usize,
+    /// Id of the converter button used last, repeated by Ctrl+Enter
+    last_operation: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct SessionDocument {
+    name: String,
+    input: String,
+    output: String,
+    tree_view: bool,
+    /// The file or snippet the input was loaded from, which keys its bookmarks
+    #[serde(default)]
+    source: Option<String>,
+}
+
+/// Write through a synced temporary file renamed over `path`, so a crash mid-write keeps
+/// the previous contents
+fn write_file_atomically(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // A hidden sibling named after the whole file, so it can't be another file the user owns
+    static SEQUENCE: AtomicUsize = AtomicUsize::new(0);
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "No file name"))?;
+    let temporary = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        SEQUENCE.fetch_add(1, Ordering::Relaxed)
+    ));
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temporary)?;
+    let result = file
+        .write_all(contents)
+        .and_then(|_| file.sync_all())
+        .and_then(|_| fs::rename(&temporary, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temporary);
+    }
+    result
+}
+
+#[tauri::command(async)]
+fn save_session(session: Session) -> Result<(), String> {
+    let store = app_data_file("session.json").ok_or("No app data directory for the session")?;
+    let bytes =
+        serde_json::to_vec(&session).map_err(|e| format!("Failed to serialize session: {}", e))?;
+    write_file_atomically(&store, &bytes)
+        .map_err(|e| format!("Failed to save session to {}: {}", store.display(), e))
+}
+
+/// The session saved by the previous run, if any
+#[tauri::command(async)]
+fn load_session() -> Option<Session> {
+    let store = app_data_file("session.json")?;
+    let text = fs::read_to_string(&store).ok()?;
+    serde_json::from_str(&text)
+        .map_err(|e| warn!("Ignoring unreadable session {}: {}", store.display(), e))
+        .ok()
+}
+
+/// Close the window once the frontend has saved the session
+#[tauri::command]
+fn close_window() {
+    info!("close_window called");
+    CLOSE_APPROVED.store(true, Ordering::SeqCst);
+    if let Some(window) = APP_HANDLE
+        .get()
+        .and_then(|app| app.get_webview_window("main"))
+    {
+        let _ = window.close();
+    }
+}
+
+/// Hold a close request and ask the frontend to save the session first; the window closes
+/// when it calls `close_window`, or after `SESSION_CLOSE_TIMEOUT` if it doesn't answer.
+/// Compare windows have no session and close right away.
+fn handle_window_event(window: &tauri::Window, event: &tauri::WindowEvent) {
+    let tauri::WindowEvent::CloseRequested { api, .. } = event else {
+        return;
+    };
+    if window.label() != "main" || CLOSE_APPROVED.load(Ordering::SeqCst) {
+        return;
+    }
+    api.prevent_close();
+    if let Err(e) = window.emit("save-session", ()) {
+        warn!("Failed to emit save-session - {}", e);
+    }
+    let window = window.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(SESSION_CLOSE_TIMEOUT);
+        CLOSE_APPROVED.store(true, Ordering::SeqCst);
+        let _ = window.close();
+    });
+}
+
+/// Documents for compare windows that haven't picked them up yet, keyed by window label
+static PENDING_COMPARES: Mutex<BTreeMap<String, CompareInputs>> = Mutex::new(BTreeMap::new());
+
+static NEXT_COMPARE_WINDOW: AtomicUsize = AtomicUsize::new(1);
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+struct CompareInputs {
+    left: String,
+    right: String,
+}
+
+/// Open a compare window with `left` and `right` filled in and return its label.
+/// Async because building a window from a synchronous command deadlocks on Windows.
+#[tauri::command(async)]
+fn open_compare_window(left: String, right: String) -> Result<String, String> {
+    info!(
+        "open_compare_window called - left_len: {}, right_len: {}",
+        left.len(),
+        right.len()
+    );
+    let app = APP_HANDLE
+        .get()
+        .ok_or_else(|| "The app is still starting".to_string())?;
+
+    let label = format!(
+        "compare-{}",
+        NEXT_COMPARE_WINDOW.fetch_add(1, Ordering::SeqCst)
+    );
+    // The new window asks for its inputs while loading, so the lock must not be held
+    // across the build
+    PENDING_COMPARES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(label.clone(), CompareInputs { left, right });
+    let url = format!("index.html?compareWindow={}", label);
+    let built = tauri::WebviewWindowBuilder::new(app, &label, tauri::WebviewUrl::App(url.into()))
+        .title("Palugada – Compare")
+        .inner_size(1100.0, 750.0)
+        .min_inner_size(600.0, 400.0)
+        .build();
+    if let Err(e) = built {
+        PENDING_COMPARES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&label);
+        return Err(format!("Failed to open compare window: {}", e));
+    }
+    Ok(label)
+}
+
+/// Documents handed to a compare window; `None` once taken, so a reload starts empty
+#[tauri::command]
+fn take_compare_inputs(window: String) -> Option<CompareInputs> {
+    PENDING_COMPARES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&window)
+}
+
+/// Quiet period after the last change before the watched file is re-read, so an editor
+/// writing in several steps triggers a single run
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Watcher behind `watch_file`; replacing or dropping it stops the previous watch
+static FILE_WATCHER: Mutex<Option<notify::RecommendedWatcher>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum WatchOperation {
+    Format,
+    Minify,
+    Validate,
+}
+
+/// Result of running the watched operation, emitted as the `file-watch` event; `output` is
+/// the file itself for `validate`
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct FileWatchUpdate {
+    path: String,
+    operation: WatchOperation,
+    output: Option<String>,
+    error: Option<String>,
+    /// Timing of a successful format or minify
+    timing: Option<OperationTiming>,
+}
+
+fn run_watch_operation(
+    path: &Path,
+    operation: WatchOperation,
+    options: &OutputOptions,
+) -> FileWatchUpdate {
+    let result = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))
+        .and_then(|input| match operation {
+            WatchOperation::Format => format_json(input, Some(options.clone()))
+                .map(|timed| (timed.result, Some(timed.timing))),
+            WatchOperation::Minify => minify_json(input, Some(options.clone()))
+                .map(|timed| (timed.result, Some(timed.timing))),
+            WatchOperation::Validate => parse_json(&input).map(|_| (input, None)),
+        });
+    let (output, error, timing) = match result {
+        Ok((output, timing)) => (Some(output), None, timing),
+        Err(e) => (None, Some(e), None),
+    };
+    FileWatchUpdate {
+        path: path.display().to_string(),
+        operation,
+        output,
+        error,
+        timing,
+    }
+}
+
+/// Call `on_change` after `path` is created or modified. The parent directory is watched
+/// so editors that save by replacing the file keep triggering; the callback thread exits
+/// once the returned watcher is dropped.
+fn watch_path(
+    path: &Path,
+    on_change: impl Fn() + Send + 'static,
+) -> Result<notify::RecommendedWatcher, String> {
+    use notify::{EventKind, RecursiveMode, Watcher};
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("'{}' is not a file", path.display()))?
+        .to_os_string();
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else { return };
+        let changed = matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+            && event
+                .paths
+                .iter()
+                .any(|changed| changed.file_name() == Some(file_name.as_os_str()));
+        if changed {
+            let _ = sender.send(());
+        }
+    })
+    .map_err(|e| format!("Failed to start file watcher: {}", e))?;
+    watcher
+        .watch(directory, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch '{}': {}", directory.display(), e))?;
+
+    std::thread::spawn(move || {
+        while receiver.recv().is_ok() {
+            while receiver.recv_timeout(WATCH_DEBOUNCE).is_ok() {}
+            on_change();
+        }
+    });
+    Ok(watcher)
+}
+
+/// Run `operation` on the file now and again whenever it changes, emitting each later
+/// result as a `file-watch` event; replaces any file already being watched
+#[tauri::command(async)]
+fn watch_file(
+    path: String,
+    operation: WatchOperation,
+    output_options: Option<OutputOptions>,
+) -> Result<FileWatchUpdate, String> {
+    info!("watch_file called - {:?}: '{}'", operation, path);
+
+    let path = fs::canonicalize(&path).map_err(|e| format!("Failed to open '{}': {}", path, e))?;
+    let options = output_options.unwrap_or_default();
+    let first = run_watch_operation(&path, operation, &options);
+
+    let watched = path.clone();
+    let watcher = watch_path(&path, move || {
+        let update = run_watch_operation(&watched, operation, &options);
+        if let Some(app) = APP_HANDLE.get() {
+            if let Err(e) = app.emit("file-watch", update) {
+                warn!("watch_file: failed to emit update - {}", e);
+            }
+        }
+    })?;
+    *FILE_WATCHER.lock().unwrap_or_else(|e| e.into_inner()) = Some(watcher);
+    Ok(first)
+}
+
+#[tauri::command]
+fn unwatch_file() {
+    info!("unwatch_file called");
+    FILE_WATCHER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+}
+
+/// File paths handed to the app before the window could receive them
+struct OpenFileRequests {
+    /// Set once the frontend has asked for `pending`; later files are emitted directly
+    ready: bool,
+    pending: Option<String>,
+}
+
+static OPEN_FILE_REQUESTS: Mutex<OpenFileRequests> = Mutex::new(OpenFileRequests {
+    ready: false,
+    pending: None,
+});
+
+/// The file to open from a launch command line, e.g. `json-formatter big.json` or an
+/// "Open with…" file association; flags are skipped and relative paths resolved against `cwd`
+fn file_argument(args: &[String], cwd: &Path) -> Option<String> {
+    let arg = args.iter().skip(1).find(|arg| !arg.starts_with('-'))?;
+    Some(cwd.join(arg).display().to_string())
+}
+
+/// Load `path` into the input pane: emitted as `open-file` once the frontend is running,
+/// otherwise kept for `take_launch_file`
+fn request_open_file(path: String) {
+    info!("Opening '{}' from the command line", path);
+    let mut requests = OPEN_FILE_REQUESTS.lock().unwrap_or_else(|e| e.into_inner());
+    if !requests.ready {
+        requests.pending = Some(path);
+        return;
+    }
+    let Some(app) = APP_HANDLE.get() else { return };
+    if let Err(e) = app.emit("open-file", path) {
+        warn!("Failed to emit open-file - {}", e);
+    }
+    show_main_window(app);
+}
+
+fn show_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// The file the app was launched with, if any; called once the frontend has loaded
+#[tauri::command]
+fn take_launch_file() -> Option<String> {
+    let mut requests = OPEN_FILE_REQUESTS.lock().unwrap_or_else(|e| e.into_inner());
+    requests.ready = true;
+    requests.pending.take()
+}
+
+fn generate_typescript_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let types = collect_types(value, class_name, false, |value, name, output| {
+        append_typescript_interface(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(String::new(), types, "\n"))
+}
+
+fn append_typescript_interface(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    if let Value::Object(map) = value {
+        output.push_str(&format!("interface {} {{\n", class_name));
+        let mut nested_interfaces = Vec::new();
+
+        for (key, val) in map {
+            let mut ts_type = enum_field_type(hints, class_name, key).unwrap_or_else(|| {
+                infer_typescript_type(
+                    val,
+                    &nested_type_name(hints, class_name, key),
+                    &mut nested_interfaces,
+                )
+            });
+            if is_map_field(hints, class_name, key) {
+                ts_type = format!("Record<string, {}>", ts_type);
+            }
+            let marker = if is_optional_field(hints, class_name, key) {
+                "?"
+            } else {
+                ""
+            };
+            let readonly = if options.immutable { "readonly " } else { "" };
+            output.push_str(&example_comment(hints, class_name, key, "  /** ", " */"));
+            let property = if is_js_identifier(key) {
+                key.clone()
+            } else {
+                format!("{:?}", key)
+            };
+            output.push_str(&format!(
+                "  {}{}{}: {};\n",
+                readonly, property, marker, ts_type
+            ));
+        }
+
+        output.push_str("}\n");
+
+        Ok(nested_interfaces)
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_typescript_type(
+    value: &Value,
+    field_name: &str,
+    nested: &mut Vec<(String, Value)>,
+) -> String {
+    match value {
+        Value::Null => "any".to_string(),
+        Value::Bool(_) => "boolean".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "number".to_string()
+            } else {
+                "number".to_string()
+            }
+        }
+        Value::String(_) => "string".to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "any[]".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("{}[]", nested_name)
+                } else {
+                    format!("{}[]", infer_typescript_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn generate_javascript_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    if let Value::Object(map) = value {
+        let mut output = format!("class {} {{\n", class_name);
+        output.push_str("  constructor(data) {\n");
+
+        let mut names = FieldNames::default();
+        for (key, _) in map {
+            let accessor = if is_js_identifier(key) {
+                format!(".{}", key)
+            } else {
+                format!("[{:?}]", key)
+            };
+            output.push_str(&example_comment(hints, class_name, key, "    // ", ""));
+            output.push_str(&format!(
+                "    this.{} = data{};\n",
+                field_identifier("javascript", key, options, js_property_name, &mut names),
+                accessor
+            ));
+        }
+        if options.immutable {
+            output.push_str("    Object.freeze(this);\n");
+        }
+
+        output.push_str("  }\n");
+        output.push_str("}\n");
+
+        Ok(GeneratedCode::new(
+            String::new(),
+            vec![(class_name.to_string(), output)],
+            "",
+        ))
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_python_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "Optional[Any]".to_string(),
+        Value::Bool(_) => "bool".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "float".to_string()
+            } else {
+                "int".to_string()
+            }
+        }
+        Value::String(s) => string_format_type(s, "python").unwrap_or("str").to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "List[Any]".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("List[{}]", nested_name)
+                } else {
+                    format!("List[{}]", infer_python_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn optional_python_type(py_type: String) -> String {
+    if py_type.starts_with("Optional[") {
+        py_type
+    } else {
+        format!("Optional[{}]", py_type)
+    }
+}
+
+fn generate_python_models(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let mut formats = BTreeSet::new();
+    collect_string_formats(value, &mut formats);
+    let mut output = String::new();
+    let datetime_names: Vec<&str> = [
+        (StringFormat::Date, "date"),
+        (StringFormat::DateTime, "datetime"),
+    ]
+    .into_iter()
+    .filter(|(format, _)| formats.contains(format))
+    .map(|(_, name)| name)
+    .collect();
+    if !datetime_names.is_empty() {
+        output.push_str(&format!(
+            "from datetime import {}\n",
+            datetime_names.join(", ")
+        ));
+    }
+    if formats.contains(&StringFormat::Uuid) {
+        output.push_str("from uuid import UUID\n");
+    }
+    // Enum classes have to be defined before the models that annotate with them
+    let enums: Vec<(String, String)> = collected_enums(hints)
+        .iter()
+        .map(|(name, values)| {
+            let definition = enum_definition("python", name, values, options);
+            (name.clone(), format!("\n\n{}", definition))
+        })
+        .collect();
+    if !enums.is_empty() {
+        output.push_str("from enum import Enum\n");
+    }
+
+    let import = match options.python_style {
+        PythonStyle::Dataclass => "from dataclasses import dataclass\n",
+        PythonStyle::Pydantic => "from pydantic import BaseModel, ConfigDict, Field\n",
+        PythonStyle::TypedDict | PythonStyle::TypedDictPartial => "from typing import TypedDict\n",
+        PythonStyle::Attrs => "import attrs\n",
+    };
+
+    if hints.maps.is_empty() {
+        output.push_str("from typing import Any, List, Optional\n\n");
+    } else {
+        output.push_str("from typing import Any, Dict, List, Optional\n\n");
+    }
+    output.push_str(import);
+    let types = collect_types(value, class_name, true, |value, name, output| {
+        append_python_model(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(
+        output,
+        enums.into_iter().chain(types).collect(),
+        "",
+    ))
+}
+
+fn is_python_identifier(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !PYTHON_KEYWORDS.contains(&name)
+}
+
+/// Nested models come first so every annotation refers to an already-defined class
+fn append_python_model(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    let map = value.as_object().ok_or("Input must be a JSON object")?;
+    let style = options.python_style;
+
+    let mut nested_classes = Vec::new();
+    let mut names = FieldNames::default();
+    let fields: Vec<(&String, String, String)> = map
+        .iter()
+        .map(|(key, val)| {
+            let mut py_type = enum_field_type(hints, class_name, key).unwrap_or_else(|| {
+                infer_python_type(
+                    val,
+                    &nested_type_name(hints, class_name, key),
+                    &mut nested_classes,
+                )
+            });
+            if is_map_field(hints, class_name, key) {
+                py_type = format!("Dict[str, {}]", py_type);
+            }
+            if is_optional_field(hints, class_name, key) {
+                py_type = optional_python_type(py_type);
+            }
+            (
+                key,
+                field_identifier("python", key, options, to_snake_case, &mut names),
+                py_type,
+            )
+        })
+        .collect();
+
+    // TypedDicts describe the JSON dict itself, so they keep the original keys
+    if matches!(
+        style,
+        PythonStyle::TypedDict | PythonStyle::TypedDictPartial
+    ) {
+        let total = if style == PythonStyle::TypedDictPartial {
+            ", total=False"
+        } else {
+            ""
+        };
+        if fields.iter().all(|(key, _, _)| is_python_identifier(key)) {
+            output.push_str(&format!("\n\nclass {}(TypedDict{}):\n", class_name, total));
+            if fields.is_empty() {
+                output.push_str("    pass\n");
+            }
+            for (key, _, py_type) in &fields {
+                output.push_str(&example_comment(hints, class_name, key, "    #: ", ""));
+                output.push_str(&format!("    {}: {}\n", key, py_type));
+            }
+        } else {
+            // Keys that are not identifiers need the functional syntax
+            output.push_str(&format!(
+                "\n\n{} = TypedDict(\n    {:?},\n    {{\n",
+                class_name, class_name
+            ));
+            for (key, _, py_type) in &fields {
+                output.push_str(&example_comment(hints, class_name, key, "        # ", ""));
+                output.push_str(&format!("        {:?}: {},\n", key, py_type));
+            }
+            output.push_str(&format!("    }}{},\n)\n", total));
+        }
+        return Ok(nested_classes);
+    }
+
+    match style {
+        PythonStyle::Pydantic => {
+            output.push_str(&format!("\n\nclass {}(BaseModel):\n", class_name));
+            let mut config = Vec::new();
+            if options.immutable {
+                config.push("frozen=True");
+            }
+            if fields.iter().any(|(key, field_name, _)| *key != field_name) {
+                config.push("populate_by_name=True");
+            }
+            if !config.is_empty() {
+                output.push_str(&format!(
+                    "    model_config = ConfigDict({})\n\n",
+                    config.join(", ")
+                ));
+            }
+        }
+        PythonStyle::Attrs => {
+            let decorator = if options.immutable {
+                "frozen"
+            } else {
+                "define"
+            };
+            output.push_str(&format!(
+                "\n\n@attrs.{}(kw_only=True)\nclass {}:\n",
+                decorator, class_name
+            ));
+        }
+        _ if options.immutable => output.push_str(&format!(
+            "\n\n@dataclass(frozen=True)\nclass {}:\n",
+            class_name
+        )),
+        _ => output.push_str(&format!("\n\n@dataclass\nclass {}:\n", class_name)),
+    }
+    if fields.is_empty() {
+        output.push_str("    pass\n");
+    }
+
+    for (key, field_name, py_type) in &fields {
+        let optional = py_type.starts_with("Optional[");
+        let renamed = *key != field_name;
+        let field = match (style, renamed) {
+            (PythonStyle::Pydantic, true) if optional => {
+                format!(" = Field(None, alias={:?})", key)
+            }
+            (PythonStyle::Pydantic, true) => format!(" = Field(alias={:?})", key),
+            // attrs aliases rename the __init__ argument, so `Model(**payload)` works
+            (PythonStyle::Attrs, true) if optional => {
+                format!(" = attrs.field(default=None, alias={:?})", key)
+            }
+            (PythonStyle::Attrs, true) => format!(" = attrs.field(alias={:?})", key),
+            // A dataclass default would have to follow every required field
+            (PythonStyle::Pydantic | PythonStyle::Attrs, false) if optional => {
+                " = None".to_string()
+            }
+            _ => String::new(),
+        };
+        output.push_str(&example_comment(hints, class_name, key, "    #: ", ""));
+        output.push_str(&format!("    {}: {}{}\n", field_name, py_type, field));
+    }
+
+    Ok(nested_classes)
+}
+
+fn generate_rust_struct(
+    value: &Value,
+    struct_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let types = collect_types(value, struct_name, false, |value, name, output| {
+        append_rust_struct(value, name, options, hints, output)
+    })?;
+    let body: String = types.iter().map(|(_, code)| code.as_str()).collect();
+
+    // Crates the generated types need beyond serde itself
+    let crates: Vec<&str> = [
+        ("serde_json::", "serde_json"),
+        ("chrono::", "chrono (feature \"serde\")"),
+        ("uuid::", "uuid (feature \"serde\")"),
+        ("url::", "url (feature \"serde\")"),
+    ]
+    .into_iter()
+    .filter(|(path, _)| body.contains(path))
+    .map(|(_, dependency)| dependency)
+    .collect();
+    let mut output = String::new();
+    if !crates.is_empty() {
+        output.push_str(&format!(
+            "// Requires serde (feature \"derive\"), {}\n",
+            crates.join(", ")
+        ));
+    }
+    output.push_str("use serde::{Deserialize, Serialize};\n");
+    if !hints.maps.is_empty() {
+        output.push_str("use std::collections::HashMap;\n");
+    }
+    output.push('\n');
+    Ok(GeneratedCode::new(output, types, "\n"))
+}
+
+/// Fields are snake_case; `rename_all = "camelCase"` is used when it covers more keys
+/// than per-field renames would
+fn append_rust_struct(
+    value: &Value,
+    struct_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    if let Value::Object(map) = value {
+        let mut nested_structs = Vec::new();
+        let mut names = FieldNames::default();
+
+        let fields: Vec<(&String, String, String)> = map
+            .iter()
+            .map(|(key, val)| {
+                let mut rust_type = enum_field_type(hints, struct_name, key).unwrap_or_else(|| {
+                    infer_rust_type(
+                        val,
+                        &nested_type_name(hints, struct_name, key),
+                        &mut nested_structs,
+                    )
+                });
+                if is_map_field(hints, struct_name, key) {
+                    rust_type = format!("HashMap<String, {}>", rust_type);
+                }
+                if is_optional_field(hints, struct_name, key) && !rust_type.starts_with("Option<") {
+                    rust_type = format!("Option<{}>", rust_type);
+                }
+                let field_name = field_identifier("rust", key, options, to_snake_case, &mut names);
+                (key, field_name, rust_type)
+            })
+            .collect();
+
+        // serde strips the `r#` of raw identifiers itself
+        let serde_name = |field_name: &str, camel_case: bool| {
+            let name = field_name.trim_start_matches("r#");
+            if camel_case {
+                to_camel_case(name)
+            } else {
+                name.to_string()
+            }
+        };
+        let renames = |camel_case: bool| {
+            fields
+                .iter()
+                .filter(|(key, field_name, _)| serde_name(field_name, camel_case) != **key)
+                .count()
+        };
+        let camel_case = !options.omit_annotations && renames(true) < renames(false);
+
+        output.push_str("#[derive(Debug, Serialize, Deserialize)]\n");
+        if camel_case {
+            output.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        }
+        output.push_str(&format!("pub struct {} {{\n", struct_name));
+
+        for (key, field_name, rust_type) in &fields {
+            output.push_str(&example_comment(hints, struct_name, key, "    /// ", ""));
+            let mut attributes = Vec::new();
+            if serde_name(field_name, camel_case) != **key && !options.omit_annotations {
+                attributes.push(format!("rename = {:?}", key));
+            }
+            // Missing keys deserialize to `None` as well as explicit nulls
+            if rust_type.starts_with("Option<") {
+                attributes.push("default".to_string());
+            }
+            if !attributes.is_empty() {
+                output.push_str(&format!("    #[serde({})]\n", attributes.join(", ")));
+            }
+            output.push_str(&format!("    pub {}: {},\n", field_name, rust_type));
+        }
+
+        output.push_str("}\n");
+
+        Ok(nested_structs)
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_rust_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "Option<String>".to_string(),
+        Value::Bool(_) => "bool".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "f64".to_string()
+            } else {
+                "i64".to_string()
+            }
+        }
+        Value::String(s) => string_format_type(s, "rust")
+            .unwrap_or("String")
+            .to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "Vec<serde_json::Value>".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("Vec<{}>", nested_name)
+                } else {
+                    format!("Vec<{}>", infer_rust_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn generate_java_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let mut output = match options.namespace.trim() {
+        "" => String::new(),
+        namespace => format!("package {};\n\n", namespace),
+    };
+    // Enum constants keep their annotations, so the import stays when there are enums
+    match options.java_annotations {
+        _ if options.omit_annotations && hints.enums.is_empty() => {}
+        JavaAnnotations::Jackson => {
+            output.push_str("import com.fasterxml.jackson.annotation.JsonProperty;\n")
+        }
+        JavaAnnotations::Gson => {
+            output.push_str("import com.google.gson.annotations.SerializedName;\n")
+        }
+    }
+    let mut formats = BTreeSet::new();
+    collect_string_formats(value, &mut formats);
+    let mut java_imports = vec!["java.util.List"];
+    if !hints.maps.is_empty() {
+        java_imports.push("java.util.Map");
+    }
+    for format in formats {
+        java_imports.extend(match format {
+            StringFormat::DateTime => Some("java.time.Instant"),
+            StringFormat::Date => Some("java.time.LocalDate"),
+            StringFormat::Uuid => Some("java.util.UUID"),
+            StringFormat::Uri => Some("java.net.URI"),
+            StringFormat::Email => None,
+        });
+    }
+    java_imports.sort_unstable();
+    for import in java_imports {
+        output.push_str(&format!("import {};\n", import));
+    }
+    if options.java_style == JavaStyle::Lombok {
+        output.push_str("import lombok.Data;\nimport lombok.NoArgsConstructor;\n");
+    }
+    output.push('\n');
+
+    let types = collect_types(value, class_name, false, |value, name, output| {
+        append_java_class(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(output, types, "\n"))
+}
+
+fn append_java_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    let map = value.as_object().ok_or("Input must be a JSON object")?;
+    let mut nested_classes = Vec::new();
+
+    let annotation = |key: &str| match options.java_annotations {
+        _ if options.omit_annotations => String::new(),
+        JavaAnnotations::Jackson => format!("@JsonProperty(\"{}\") ", key),
+        JavaAnnotations::Gson => format!("@SerializedName(\"{}\") ", key),
+    };
+    let mut names = FieldNames::default();
+    let fields: Vec<(&String, String, String)> = map
+        .iter()
+        .map(|(key, val)| {
+            let mut java_type = enum_field_type(hints, class_name, key).unwrap_or_else(|| {
+                infer_java_type(
+                    val,
+                    &nested_type_name(hints, class_name, key),
+                    &mut nested_classes,
+                )
+            });
+            if is_map_field(hints, class_name, key) {
+                java_type = format!("Map<String, {}>", java_type);
+            }
+            (
+                key,
+                java_type,
+                field_identifier("java", key, options, to_camel_case, &mut names),
+            )
+        })
+        .collect();
+
+    match options.java_style {
+        JavaStyle::Record => {
+            let components: Vec<String> = fields
+                .iter()
+                .map(|(key, java_type, field_name)| {
+                    format!(
+                        "{}    {}{} {}",
+                        example_comment(hints, class_name, key, "    /** ", " */"),
+                        annotation(key),
+                        java_type,
+                        field_name
+                    )
+                })
+                .collect();
+            output.push_str(&format!(
+                "public record {}(\n{}\n) {{}}\n",
+                class_name,
+                components.join(",\n")
+            ));
+        }
+        JavaStyle::Pojo | JavaStyle::Lombok => {
+            if options.java_style == JavaStyle::Lombok {
+                output.push_str("@Data\n@NoArgsConstructor\n");
+            }
+            output.push_str(&format!("public class {} {{\n", class_name));
+
+            for (key, java_type, field_name) in &fields {
+                output.push_str(&example_comment(hints, class_name, key, "    /** ", " */"));
+                let annotation = annotation(key);
+                if !annotation.is_empty() {
+                    output.push_str(&format!("    {}\n", annotation.trim_end()));
+                }
+                output.push_str(&format!("    private {} {};\n\n", java_type, field_name));
+            }
+
+            // Generate getters and setters
+            if options.java_style == JavaStyle::Pojo {
+                for (_, java_type, field_name) in &fields {
+                    // From the escaped field name, so a `class` key gets `getClass_`
+                    // instead of clashing with `Object.getClass()`
+                    let mut chars = field_name.chars();
+                    let accessor: String = chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default();
+                    let getter_name = format!("get{}", accessor);
+                    let setter_name = format!("set{}", accessor);
+
+                    output.push_str(&format!("    public {} {}() {{\n", java_type, getter_name));
+                    output.push_str(&format!("        return {};\n", field_name));
+                    output.push_str("    }\n\n");
+
+                    output.push_str(&format!(
+                        "    public void {}({} {}) {{\n",
+                        setter_name, java_type, field_name
+                    ));
+                    output.push_str(&format!("        this.{} = {};\n", field_name, field_name));
+                    output.push_str("    }\n\n");
+                }
+            }
+
+            output.push_str("}\n");
+        }
+    }
+
+    Ok(nested_classes)
+}
+
+fn infer_java_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "Object".to_string(),
+        Value::Bool(_) => "Boolean".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "Double".to_string()
+            } else {
+                "Integer".to_string()
+            }
+        }
+        Value::String(s) => string_format_type(s, "java")
+            .unwrap_or("String")
+            .to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "List<Object>".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("List<{}>", nested_name)
+                } else {
+                    format!("List<{}>", infer_java_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn generate_csharp_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let serializer_using = match options.csharp_serializer {
+        CSharpSerializer::Newtonsoft => "using Newtonsoft.Json;",
+        CSharpSerializer::SystemTextJson => "using System.Text.Json.Serialization;",
+    };
+    let mut formats = BTreeSet::new();
+    collect_string_formats(value, &mut formats);
+    let mut output = String::new();
+    if formats.iter().any(|f| *f != StringFormat::Email) {
+        output.push_str("using System;\n");
+    }
+    output.push_str("using System.Collections.Generic;\n");
+    let has_enums = !hints.enums.is_empty();
+    if has_enums && options.csharp_serializer == CSharpSerializer::Newtonsoft {
+        output.push_str("using System.Runtime.Serialization;\n");
+        output.push_str(&format!(
+            "{}\nusing Newtonsoft.Json.Converters;\n\n",
+            serializer_using
+        ));
+    } else if has_enums || !options.omit_annotations {
+        output.push_str(&format!("{}\n\n", serializer_using));
+    } else {
+        output.push('\n');
+    }
+    let namespace = options.namespace.trim();
+    if !namespace.is_empty() {
+        output.push_str(&format!("namespace {};\n\n", namespace));
+    }
+    let types = collect_types(value, class_name, false, |value, name, output| {
+        append_csharp_class(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(output, types, "\n"))
+}
+
+fn append_csharp_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    let map = value.as_object().ok_or("Input must be a JSON object")?;
+    let mut nested_classes = Vec::new();
+
+    let kind = if options.csharp_records {
+        "record"
+    } else {
+        "class"
+    };
+    let setter = if options.csharp_records || options.immutable {
+        "init"
+    } else {
+        "set"
+    };
+    let attribute = match options.csharp_serializer {
+        CSharpSerializer::Newtonsoft => "JsonProperty",
+        CSharpSerializer::SystemTextJson => "JsonPropertyName",
+    };
+
+    output.push_str(&format!("public {} {}\n{{\n", kind, class_name));
+
+    let mut names = FieldNames::default();
+    for (key, val) in map {
+        let mut cs_type = enum_field_type(hints, class_name, key).unwrap_or_else(|| {
+            infer_csharp_type(
+                val,
+                &nested_type_name(hints, class_name, key),
+                &mut nested_classes,
+            )
+        });
+        if is_map_field(hints, class_name, key) {
+            cs_type = format!("Dictionary<string, {}>", cs_type);
+        }
+        // Reference types are already nullable; value types need `?`
+        if is_optional_field(hints, class_name, key)
+            && (matches!(
+                cs_type.as_str(),
+                "bool" | "int" | "double" | "DateTimeOffset" | "DateTime" | "Guid"
+            ) || enum_field_type(hints, class_name, key).is_some())
+        {
+            cs_type.push('?');
+        }
+        if let Some(example) = field_example(hints, class_name, key) {
+            let example = example
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            output.push_str(&format!("    /// <summary>e.g. {}</summary>\n", example));
+        }
+        if !options.omit_annotations {
+            output.push_str(&format!("    [{}(\"{}\")]\n", attribute, key));
+        }
+        output.push_str(&format!(
+            "    public {} {} {{ get; {}; }}\n\n",
+            cs_type,
+            names.claim("csharp", &to_pascal_case(key)),
+            setter
+        ));
+    }
+
+    output.push_str("}\n");
+
+    Ok(nested_classes)
+}
+
+fn infer_csharp_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "object".to_string(),
+        Value::Bool(_) => "bool".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "double".to_string()
+            } else {
+                "int".to_string()
+            }
+        }
+        Value::String(s) => string_format_type(s, "csharp")
+            .unwrap_or("string")
+            .to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "List<object>".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("List<{}>", nested_name)
+                } else {
+                    format!("List<{}>", infer_csharp_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn generate_go_struct(
+    value: &Value,
+    struct_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    // Go package names are a single segment, so `com.acme.models` becomes `models`
+    let package = match options.namespace.trim() {
+        "" => "main",
+        namespace => namespace.rsplit(['.', '/']).next().unwrap_or(namespace),
+    };
+    let mut output = format!("package {}\n\n", package);
+    let mut formats = BTreeSet::new();
+    collect_string_formats(value, &mut formats);
+    if formats.contains(&StringFormat::DateTime) {
+        output.push_str("import \"time\"\n\n");
+    }
+    let types = collect_types(value, struct_name, false, |value, name, output| {
+        append_go_struct(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(output, types, "\n"))
+}
+
+fn append_go_struct(
+    value: &Value,
+    struct_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    if let Value::Object(map) = value {
+        let mut nested_structs = Vec::new();
+
+        output.push_str(&format!("type {} struct {{\n", struct_name));
+
+        let mut names = FieldNames::default();
+        for (key, val) in map {
+            let mut go_type = enum_field_type(hints, struct_name, key).unwrap_or_else(|| {
+                infer_go_type(
+                    val,
+                    &nested_type_name(hints, struct_name, key),
+                    &mut nested_structs,
+                )
+            });
+            if is_map_field(hints, struct_name, key) {
+                go_type = format!("map[string]{}", go_type);
+            }
+            let mut tag = key.clone();
+            // Optional scalars and structs become pointers so absent differs from zero
+            if is_optional_field(hints, struct_name, key) {
+                if !go_type.starts_with("[]")
+                    && !go_type.starts_with("map[")
+                    && go_type != "interface{}"
+                {
+                    go_type = format!("*{}", go_type);
+                }
+                tag.push_str(",omitempty");
+            }
+            let field_name = names.claim("go", &to_pascal_case(key));
+            output.push_str(&example_comment(hints, struct_name, key, "    // ", ""));
+            if options.omit_annotations {
+                output.push_str(&format!("    {} {}\n", field_name, go_type));
+            } else {
+                output.push_str(&format!(
+                    "    {} {} `json:\"{}\"`\n",
+                    field_name, go_type, tag
+                ));
+            }
+        }
+
+        output.push_str("}\n");
+
+        Ok(nested_structs)
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_go_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "interface{}".to_string(),
+        Value::Bool(_) => "bool".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "float64".to_string()
+            } else {
+                "int".to_string()
+            }
+        }
+        Value::String(s) => string_format_type(s, "go").unwrap_or("string").to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "[]interface{}".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("[]{}", nested_name)
+                } else {
+                    format!("[]{}", infer_go_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn kotlin_package(options: &CodegenOptions) -> String {
+    match options.namespace.trim() {
+        "" => String::new(),
+        namespace => format!("package {}\n\n", namespace),
+    }
+}
+
+fn generate_kotlin_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let mut output = kotlin_package(options);
+    if !options.omit_annotations || !hints.enums.is_empty() {
+        output.push_str("import com.google.gson.annotations.SerializedName\n\n");
+    }
+    let types = collect_types(value, class_name, false, |value, name, output| {
+        append_kotlin_class(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(output, types, "\n"))
+}
+
+fn append_kotlin_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    if let Value::Object(map) = value {
+        let mut nested_classes = Vec::new();
+
+        output.push_str(&format!("data class {}(\n", class_name));
+
+        let entries: Vec<_> = map.iter().collect();
+        let mut names = FieldNames::default();
+        for (i, (key, val)) in entries.iter().enumerate() {
+            let mut kt_type = enum_field_type(hints, class_name, key).unwrap_or_else(|| {
+                infer_kotlin_type(
+                    val,
+                    &nested_type_name(hints, class_name, key),
+                    &mut nested_classes,
+                )
+            });
+            if is_map_field(hints, class_name, key) {
+                kt_type = format!("Map<String, {}>", kt_type);
+            }
+            output.push_str(&example_comment(hints, class_name, key, "    /** ", " */"));
+            if !options.omit_annotations {
+                output.push_str(&format!("    @SerializedName(\"{}\")\n", key));
+            }
+            let field_name = field_identifier("kotlin", key, options, to_camel_case, &mut names);
+            if is_optional_field(hints, class_name, key) {
+                output.push_str(&format!(
+                    "    val {}: {}? = null",
+                    field_name,
+                    kt_type.trim_end_matches('?')
+                ));
+            } else {
+                output.push_str(&format!("    val {}: {}", field_name, kt_type));
+            }
+            if i < entries.len() - 1 {
+                output.push(',');
+            }
+            output.push('\n');
+        }
+
+        output.push_str(")\n");
+
+        Ok(nested_classes)
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_kotlin_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "Any?".to_string(),
+        Value::Bool(_) => "Boolean".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "Double".to_string()
+            } else {
+                "Int".to_string()
+            }
+        }
+        Value::String(_) => "String".to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "List<Any>".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("List<{}>", nested_name)
+                } else {
+                    format!("List<{}>", infer_kotlin_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn generate_kotlin_serializable(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let types = collect_types(value, class_name, false, |value, name, output| {
+        append_kotlin_serializable(value, name, options, hints, output)
+    })?;
+    let body: String = types.iter().map(|(_, code)| code.as_str()).collect();
+
+    let mut output = kotlin_package(options);
+    if !options.omit_annotations || !hints.enums.is_empty() {
+        output.push_str("import kotlinx.serialization.SerialName\n");
+    }
+    output.push_str("import kotlinx.serialization.Serializable\n");
+    if body.contains("JsonElement") {
+        output.push_str("import kotlinx.serialization.json.JsonElement\n");
+    }
+    output.push('\n');
+    Ok(GeneratedCode::new(output, types, "\n"))
+}
+
+fn append_kotlin_serializable(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    let map = value.as_object().ok_or("Input must be a JSON object")?;
+    let mut nested_classes = Vec::new();
+
+    output.push_str(&format!("@Serializable\ndata class {}(\n", class_name));
+
+    let entries: Vec<_> = map.iter().collect();
+    let mut names = FieldNames::default();
+    for (i, (key, val)) in entries.iter().enumerate() {
+        let mut kt_type = enum_field_type(hints, class_name, key).unwrap_or_else(|| {
+            infer_kotlinx_type(
+                val,
+                &nested_type_name(hints, class_name, key),
+                &mut nested_classes,
+            )
+        });
+        if is_map_field(hints, class_name, key) {
+            kt_type = format!("Map<String, {}>", kt_type);
+        }
+        if is_optional_field(hints, class_name, key) && !kt_type.ends_with('?') {
+            kt_type.push('?');
+        }
+        let default = if kt_type.ends_with('?') {
+            " = null"
+        } else {
+            ""
+        };
+        output.push_str(&example_comment(hints, class_name, key, "    /** ", " */"));
+        if !options.omit_annotations {
+            output.push_str(&format!("    @SerialName(\"{}\")\n", key));
+        }
+        output.push_str(&format!(
+            "    val {}: {}{}",
+            field_identifier("kotlin", key, options, to_camel_case, &mut names),
+            kt_type,
+            default
+        ));
+        if i < entries.len() - 1 {
+            output.push(',');
+        }
+        output.push('\n');
+    }
+
+    output.push_str(")\n");
+
+    Ok(nested_classes)
+}
+
+/// kotlinx.serialization cannot handle `Any`, so unknown values become `JsonElement`
+/// and nulls (in fields or array elements) make the type nullable
+fn infer_kotlinx_type(
+    value: &Value,
+    field_name: &str,
+    nested: &mut Vec<(String, Value)>,
+) -> String {
+    match value {
+        Value::Null => "JsonElement?".to_string(),
+        Value::Array(arr) => {
+            let Some(first) = arr.iter().find(|item| !item.is_null()) else {
+                return "List<JsonElement?>".to_string();
+            };
+            let element = if first.is_object() {
+                let nested_name = to_pascal_case(field_name);
+                nested.push((nested_name.clone(), first.clone()));
+                nested_name
+            } else {
+                infer_kotlinx_type(first, field_name, nested)
+            };
+            if arr.iter().any(Value::is_null) {
+                format!("List<{}?>", element)
+            } else {
+                format!("List<{}>", element)
+            }
+        }
+        _ => infer_kotlin_type(value, field_name, nested),
+    }
+}
+
+fn generate_swift_struct(
+    value: &Value,
+    struct_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let output = String::from("import Foundation\n\n");
+    let types = collect_types(value, struct_name, false, |value, name, output| {
+        append_swift_struct(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(output, types, "\n"))
+}
+
+fn append_swift_struct(
+    value: &Value,
+    struct_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    if let Value::Object(map) = value {
+        let mut nested_structs = Vec::new();
+
+        output.push_str(&format!("struct {}: Codable {{\n", struct_name));
+        let mut coding_keys = Vec::new();
+        let mut names = FieldNames::default();
+
+        for (key, val) in map {
+            let mut swift_type = enum_field_type(hints, struct_name, key).unwrap_or_else(|| {
+                infer_swift_type(
+                    val,
+                    &nested_type_name(hints, struct_name, key),
+                    &mut nested_structs,
+                )
+            });
+            if is_map_field(hints, struct_name, key) {
+                swift_type = format!("[String: {}]", swift_type);
+            }
+            if is_optional_field(hints, struct_name, key) && !swift_type.ends_with('?') {
+                swift_type.push('?');
+            }
+            let field_name = field_identifier("swift", key, options, to_camel_case, &mut names);
+            output.push_str(&example_comment(hints, struct_name, key, "    /// ", ""));
+            output.push_str(&format!("    let {}: {}\n", field_name, swift_type));
+            coding_keys.push((field_name, key));
+        }
+
+        // Codable only maps keys by name, so renamed fields need explicit coding keys
+        if coding_keys
+            .iter()
+            .any(|(field_name, key)| field_name.trim_matches('`') != *key)
+        {
+            output.push_str("\n    enum CodingKeys: String, CodingKey {\n");
+            for (field_name, key) in &coding_keys {
+                if field_name.trim_matches('`') == *key {
+                    output.push_str(&format!("        case {}\n", field_name));
+                } else {
+                    output.push_str(&format!("        case {} = {:?}\n", field_name, key));
+                }
+            }
+            output.push_str("    }\n");
+        }
+
+        output.push_str("}\n");
+
+        Ok(nested_structs)
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_swift_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "Any?".to_string(),
+        Value::Bool(_) => "Bool".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "Double".to_string()
+            } else {
+                "Int".to_string()
+            }
+        }
+        Value::String(s) => string_format_type(s, "swift")
+            .unwrap_or("String")
+            .to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "[Any]".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("[{}]", nested_name)
+                } else {
+                    format!("[{}]", infer_swift_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn generate_dart_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let mut output = String::from("import 'package:json_annotation/json_annotation.dart';\n\n");
+    output.push_str(&format!("part '{}.g.dart';\n\n", to_snake_case(class_name)));
+    let types = collect_types(value, class_name, false, |value, name, output| {
+        append_dart_class(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(output, types, "\n"))
+}
+
+fn append_dart_class(
+    value: &Value,
+    class_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    if let Value::Object(map) = value {
+        let mut nested_classes = Vec::new();
+
+        output.push_str("@JsonSerializable()\n");
+        output.push_str(&format!("class {} {{\n", class_name));
+
+        let mut field_names = Vec::new();
+        let mut names = FieldNames::default();
+        for (key, val) in map {
+            let mut dart_type = enum_field_type(hints, class_name, key).unwrap_or_else(|| {
+                infer_dart_type(
+                    val,
+                    &nested_type_name(hints, class_name, key),
+                    &mut nested_classes,
+                )
+            });
+            if is_map_field(hints, class_name, key) {
+                dart_type = format!("Map<String, {}>", dart_type);
+            }
+            let is_optional = is_optional_field(hints, class_name, key);
+            if is_optional && dart_type != "dynamic" && !dart_type.ends_with('?') {
+                dart_type.push('?');
+            }
+            let field_name = field_identifier("dart", key, options, to_camel_case, &mut names);
+            output.push_str(&example_comment(hints, class_name, key, "  /// ", ""));
+            if field_name != *key && !options.omit_annotations {
+                output.push_str(&format!("  @JsonKey(name: '{}')\n", key));
+            }
+            output.push_str(&format!("  final {} {};\n\n", dart_type, field_name));
+            field_names.push((field_name, is_optional));
+        }
+
+        output.push_str(&format!("  {}({{\n", class_name));
+        for (field_name, is_optional) in &field_names {
+            if *is_optional {
+                output.push_str(&format!("    this.{},\n", field_name));
+            } else {
+                output.push_str(&format!("    required this.{},\n", field_name));
+            }
+        }
+        output.push_str("  });\n\n");
+
+        output.push_str(&format!(
+            "  factory {}.fromJson(Map<String, dynamic> json) =>\n      _${}FromJson(json);\n\n",
+            class_name, class_name
+        ));
+        output.push_str(&format!(
+            "  Map<String, dynamic> toJson() => _${}ToJson(this);\n",
+            class_name
+        ));
+        output.push_str("}\n");
+
+        Ok(nested_classes)
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_dart_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "dynamic".to_string(),
+        Value::Bool(_) => "bool".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "double".to_string()
+            } else {
+                "int".to_string()
+            }
+        }
+        Value::String(s) => string_format_type(s, "dart")
+            .unwrap_or("String")
+            .to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "List<dynamic>".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("List<{}>", nested_name)
+                } else {
+                    format!("List<{}>", infer_dart_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn generate_cpp_struct(
+    value: &Value,
+    struct_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let mut output = String::from("#pragma once\n\n");
+    output.push_str("#include <cstdint>\n");
+    if !hints.maps.is_empty() {
+        output.push_str("#include <map>\n");
+    }
+    output.push_str("#include <optional>\n#include <string>\n#include <vector>\n\n");
+    output.push_str("#include <nlohmann/json.hpp>\n");
+    let namespace = cpp_namespace(options);
+    if !namespace.is_empty() {
+        output.push_str(&format!("\nnamespace {} {{\n", namespace));
+    }
+    let types = collect_types(value, struct_name, true, |value, name, output| {
+        append_cpp_struct(value, name, options, hints, output)
+    })?;
+    let mut code = GeneratedCode::new(output, types, "");
+    if !namespace.is_empty() {
+        code.epilogue = format!("\n}}  // namespace {}\n", namespace);
+    }
+    Ok(code)
+}
+
+/// Dotted package names become nested C++ namespaces
+fn cpp_namespace(options: &CodegenOptions) -> String {
+    options.namespace.trim().replace('.', "::")
+}
+
+/// C++ needs a type declared before use, so nested structs are emitted first
+fn append_cpp_struct(
+    value: &Value,
+    struct_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    if let Value::Object(map) = value {
+        let mut nested_structs = Vec::new();
+        let mut names = FieldNames::default();
+        let fields: Vec<(&String, String, String)> = map
+            .iter()
+            .map(|(key, val)| {
+                let mut cpp_type = infer_cpp_type(
+                    val,
+                    &nested_type_name(hints, struct_name, key),
+                    &mut nested_structs,
+                );
+                if is_map_field(hints, struct_name, key) {
+                    cpp_type = format!("std::map<std::string, {}>", cpp_type);
+                }
+                if is_optional_field(hints, struct_name, key)
+                    && !cpp_type.starts_with("std::optional<")
+                {
+                    cpp_type = format!("std::optional<{}>", cpp_type);
+                }
+                (
+                    key,
+                    field_identifier("cpp", key, options, to_snake_case, &mut names),
+                    cpp_type,
+                )
+            })
+            .collect();
+
+        output.push_str(&format!("\nstruct {} {{\n", struct_name));
+        for (key, field_name, cpp_type) in &fields {
+            output.push_str(&example_comment(hints, struct_name, key, "    /// ", ""));
+            output.push_str(&format!("    {} {};\n", cpp_type, field_name));
+        }
+        output.push_str("};\n\n");
+
+        output.push_str(&format!(
+            "inline void to_json(nlohmann::json& j, const {}& value) {{\n    j = nlohmann::json::object();\n",
+            struct_name
+        ));
+        for (key, field_name, cpp_type) in &fields {
+            if cpp_type.starts_with("std::optional<") {
+                output.push_str(&format!(
+                    "    j[\"{}\"] = value.{} ? nlohmann::json(*value.{}) : nlohmann::json(nullptr);\n",
+                    key, field_name, field_name
+                ));
+            } else {
+                output.push_str(&format!("    j[\"{}\"] = value.{};\n", key, field_name));
+            }
+        }
+        output.push_str("}\n\n");
+
+        output.push_str(&format!(
+            "inline void from_json(const nlohmann::json& j, {}& value) {{\n",
+            struct_name
+        ));
+        for (key, field_name, cpp_type) in &fields {
+            if let Some(inner) = cpp_type
+                .strip_prefix("std::optional<")
+                .and_then(|rest| rest.strip_suffix('>'))
+            {
+                output.push_str(&format!(
+                    "    if (j.contains(\"{}\") && !j.at(\"{}\").is_null()) {{\n        value.{} = j.at(\"{}\").get<{}>();\n    }} else {{\n        value.{} = std::nullopt;\n    }}\n",
+                    key, key, field_name, key, inner, field_name
+                ));
+            } else {
+                output.push_str(&format!(
+                    "    j.at(\"{}\").get_to(value.{});\n",
+                    key, field_name
+                ));
+            }
+        }
+        output.push_str("}\n");
+
+        Ok(nested_structs)
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_cpp_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
+    match value {
+        Value::Null => "std::optional<nlohmann::json>".to_string(),
+        Value::Bool(_) => "bool".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "double".to_string()
+            } else {
+                "std::int64_t".to_string()
+            }
+        }
+        Value::String(_) => "std::string".to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "std::vector<nlohmann::json>".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("std::vector<{}>", nested_name)
+                } else {
+                    format!("std::vector<{}>", infer_cpp_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+const HASKELL_KEYWORDS: &[&str] = &[
+    "case", "class", "data", "default", "deriving", "do", "else", "foreign", "if", "import", "in",
+    "infix", "infixl", "infixr", "instance", "let", "module", "newtype", "of", "then", "type",
+    "where",
+];
+
+fn generate_haskell_data(
+    value: &Value,
+    type_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    let mut output = String::from("{-# LANGUAGE DeriveGeneric #-}\n\n");
+    output.push_str(&format!(
+        "module {} where\n\n",
+        haskell_module(options, type_name)
+    ));
+    output.push_str("import Data.Aeson\n");
+    if !hints.maps.is_empty() {
+        output.push_str("import Data.Map (Map)\n");
+    }
+    output.push_str("import Data.Text (Text)\nimport GHC.Generics (Generic)\n");
+    let types = collect_types(value, type_name, false, |value, name, output| {
+        append_haskell_data(value, name, options, hints, output)
+    })?;
+    Ok(GeneratedCode::new(output, types, ""))
+}
+
+/// Module of a Haskell type, below the namespace when one is set
+fn haskell_module(options: &CodegenOptions, type_name: &str) -> String {
+    match options.namespace.trim() {
+        "" => type_name.to_string(),
+        namespace => {
+            let segments: Vec<String> = namespace.split('.').map(to_pascal_case).collect();
+            format!("{}.{}", segments.join("."), type_name)
+        }
+    }
+}
+
+fn append_haskell_data(
+    value: &Value,
+    type_name: &str,
+    options: &CodegenOptions,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Result<Vec<(String, Value)>, String> {
+    if let Value::Object(map) = value {
+        let mut nested_types = Vec::new();
+        let prefix = to_camel_case(type_name);
+
+        output.push_str(&format!("\ndata {} = {}\n", type_name, type_name));
+        let mut renamed_fields = Vec::new();
+        let mut names = FieldNames::default();
+        for (i, (key, val)) in map.iter().enumerate() {
+            let mut haskell_type = infer_haskell_type(
+                val,
+                &nested_type_name(hints, type_name, key),
+                &mut nested_types,
+            );
+            if is_map_field(hints, type_name, key) {
+                haskell_type = if haskell_type.contains(' ') {
+                    format!("Map Text ({})", haskell_type)
+                } else {
+                    format!("Map Text {}", haskell_type)
+                };
+            }
+            if is_optional_field(hints, type_name, key) && !haskell_type.starts_with("Maybe ") {
+                haskell_type = format!("Maybe {}", haskell_type);
+            }
+            let field_name = if options.field_prefix {
+                names.claim("haskell", &format!("{}{}", prefix, to_pascal_case(key)))
+            } else {
+                field_identifier("haskell", key, options, to_camel_case, &mut names)
+            };
+            let separator = if i == 0 { '{' } else { ',' };
+            output.push_str(&format!(
+                "  {} {} :: {}\n",
+                separator, field_name, haskell_type
+            ));
+            output.push_str(&example_comment(hints, type_name, key, "    -- ^ ", ""));
+            if field_name != *key {
+                renamed_fields.push((field_name, key));
+            }
+        }
+        if map.is_empty() {
+            output.push_str("  {\n");
+        }
+        output.push_str("  } deriving (Show, Eq, Generic)\n\n");
+
+        if renamed_fields.is_empty() {
+            output.push_str(&format!("instance FromJSON {}\n\n", type_name));
+            output.push_str(&format!("instance ToJSON {}\n", type_name));
+        } else {
+            let options_name = format!("{}JsonOptions", prefix);
+            output.push_str(&format!(
+                "instance FromJSON {} where\n  parseJSON = genericParseJSON {}\n\n",
+                type_name, options_name
+            ));
+            output.push_str(&format!(
+                "instance ToJSON {} where\n  toJSON = genericToJSON {}\n\n",
+                type_name, options_name
+            ));
+            output.push_str(&format!("{} :: Options\n", options_name));
+            output.push_str(&format!(
+                "{} = defaultOptions {{ fieldLabelModifier = rename }}\n  where\n",
+                options_name
+            ));
+            for (field_name, key) in &renamed_fields {
+                output.push_str(&format!("    rename {:?} = {:?}\n", field_name, key));
+            }
+            output.push_str("    rename other = other\n");
+        }
+
+        Ok(nested_types)
+    } else {
+        Err("Input must be a JSON object".to_string())
+    }
+}
+
+fn infer_haskell_type(
+    value: &Value,
+    field_name: &str,
+    nested: &mut Vec<(String, Value)>,
+) -> String {
+    match value {
+        Value::Null => "Maybe Value".to_string(),
+        Value::Bool(_) => "Bool".to_string(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                "Double".to_string()
+            } else {
+                "Int".to_string()
+            }
+        }
+        Value::String(_) => "Text".to_string(),
+        Value::Array(arr) => {
+            if arr.is_empty() {
+                "[Value]".to_string()
+            } else {
+                let first = &arr[0];
+                if first.is_object() {
+                    let nested_name = to_pascal_case(field_name);
+                    nested.push((nested_name.clone(), first.clone()));
+                    format!("[{}]", nested_name)
+                } else {
+                    format!("[{}]", infer_haskell_type(first, field_name, nested))
+                }
+            }
+        }
+        Value::Object(_) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((nested_name.clone(), value.clone()));
+            nested_name
+        }
+    }
+}
+
+fn generate_zod_schema(
+    samples: &[Value],
+    schema_name: &str,
+    hints: &FieldHints,
+) -> Result<GeneratedCode, String> {
+    if !samples.iter().all(Value::is_object) {
+        return Err("Input must be a JSON object".to_string());
+    }
+
+    let output = String::from("import { z } from \"zod\";\n");
+    let types = collect_types(samples, schema_name, true, |samples, name, output| {
+        Ok(append_zod_schema(samples, name, hints, output))
+    })?;
+    Ok(GeneratedCode::new(output, types, ""))
+}
+
+/// Emit one `z.object` schema merged from every sample of that object, so keys missing
+/// from some samples become `.optional()` and keys that are sometimes null `.nullable()`.
+/// Nested schemas are emitted first because a `const` must be declared before use.
+fn append_zod_schema(
+    samples: &[Value],
+    schema_name: &str,
+    hints: &FieldHints,
+    output: &mut String,
+) -> Vec<(String, Vec<Value>)> {
+    let keys: BTreeSet<&String> = samples
+        .iter()
+        .filter_map(Value::as_object)
+        .flat_map(|map| map.keys())
+        .collect();
+
+    let mut nested_schemas = Vec::new();
+    let mut fields = Vec::new();
+    for key in keys {
+        let present: Vec<&Value> = samples.iter().filter_map(|s| s.get(key)).collect();
+        let non_null: Vec<&Value> = present.iter().copied().filter(|v| !v.is_null()).collect();
+
+        let mut zod_type = match hints.enums.get(&(schema_name.to_string(), key.clone())) {
+            Some(values) => format!("z.enum({:?})", values),
+            None if is_map_field(hints, schema_name, key) => {
+                let values: Vec<&Value> = non_null
+                    .iter()
+                    .filter_map(|v| v.as_object())
+                    .flat_map(|map| map.values())
+                    .filter(|v| !v.is_null())
+                    .collect();
+                format!(
+                    "z.record(z.string(), {})",
+                    infer_zod_type(
+                        &values,
+                        &nested_type_name(hints, schema_name, key),
+                        &mut nested_schemas
+                    )
+                )
+            }
+            None => infer_zod_type(
+                &non_null,
+                &nested_type_name(hints, schema_name, key),
+                &mut nested_schemas,
+            ),
+        };
+        if non_null.len() < present.len() {
+            zod_type.push_str(".nullable()");
+        }
+        let optional = match hints.nullability {
+            Nullability::Inferred => present.len() < samples.len(),
+            Nullability::AllOptional => true,
+            Nullability::AllRequired => false,
+        };
+        if optional {
+            zod_type.push_str(".optional()");
+        }
+        fields.push((key, zod_type));
+    }
+
+    output.push_str(&format!(
+        "\nexport const {}Schema = z.object({{\n",
+        schema_name
+    ));
+    for (key, zod_type) in fields {
+        output.push_str(&example_comment(hints, schema_name, key, "  /** ", " */"));
+        if is_js_identifier(key) {
+            output.push_str(&format!("  {}: {},\n", key, zod_type));
+        } else {
+            output.push_str(&format!("  {:?}: {},\n", key, zod_type));
+        }
+    }
+    output.push_str("});\n\n");
+    output.push_str(&format!(
+        "export type {} = z.infer<typeof {}Schema>;\n",
+        schema_name, schema_name
+    ));
+    nested_schemas
+}
+
+fn infer_zod_type(
+    values: &[&Value],
+    field_name: &str,
+    nested: &mut Vec<(String, Vec<Value>)>,
+) -> String {
+    match values.first() {
+        None => "z.unknown()".to_string(),
+        Some(Value::Bool(_)) => "z.boolean()".to_string(),
+        Some(Value::Number(_)) => {
+            if values.iter().all(|v| v.is_i64() || v.is_u64()) {
+                "z.number().int()".to_string()
+            } else {
+                "z.number()".to_string()
+            }
+        }
+        Some(Value::String(_)) => match common_string_format(values) {
+            Some(StringFormat::DateTime) => "z.string().datetime({ offset: true })".to_string(),
+            Some(StringFormat::Date) => "z.string().date()".to_string(),
+            Some(StringFormat::Uuid) => "z.string().uuid()".to_string(),
+            Some(StringFormat::Uri) => "z.string().url()".to_string(),
+            Some(StringFormat::Email) => "z.string().email()".to_string(),
+            None => "z.string()".to_string(),
+        },
+        Some(Value::Array(_)) => {
+            let items: Vec<&Value> = values
+                .iter()
+                .filter_map(|v| v.as_array())
+                .flatten()
+                .collect();
+            let non_null: Vec<&Value> = items.iter().copied().filter(|v| !v.is_null()).collect();
+            let mut item_type = infer_zod_type(&non_null, field_name, nested);
+            if !non_null.is_empty() && non_null.len() < items.len() {
+                item_type.push_str(".nullable()");
+            }
+            format!("z.array({})", item_type)
+        }
+        Some(Value::Object(_)) => {
+            let nested_name = to_pascal_case(field_name);
+            nested.push((
+                nested_name.clone(),
+                values.iter().map(|v| (*v).clone()).collect(),
+            ));
+            format!("{}Schema", nested_name)
+        }
+        Some(Value::Null) => "z.null()".to_string(),
+    }
+}
+
+/// Native menu bar. Items with a predefined role (clipboard, quit, about) are handled by the
+/// OS; every other item is emitted as a `menu-action` event carrying its id, which the
+/// frontend runs like the matching button or shortcut.
+fn app_menu<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<Menu<R>> {
+    let item = |id: &str, text: &str, accelerator: &str| {
+        MenuItemBuilder::with_id(id, text)
+            .accelerator(accelerator)
+            .build(app)
+    };
+
+    #[cfg(target_os = "macos")]
+    let app_submenu = SubmenuBuilder::new(app, "Palugada")
+        .about(None)
+        .separator()
+        .services()
+        .separator()
+        .hide()
+        .hide_others()
+        .show_all()
+        .separator()
+        .quit()
+        .build()?;
+
+    let file = SubmenuBuilder::new(app, "File")
+        .item(&item("new-document", "New Tab", "CmdOrCtrl+T")?)
+        .item(&item("open", "Open…", "CmdOrCtrl+O")?)
+        .item(&item("save", "Save Output As…", "CmdOrCtrl+S")?)
+        .text("export-html", "Export as HTML…")
+        .separator()
+        .item(&item("settings", "Settings…", "CmdOrCtrl+,")?)
+        .separator()
+        .item(&item("close-document", "Close Tab", "CmdOrCtrl+W")?);
+    #[cfg(not(target_os = "macos"))]
+    let file = file.separator().quit();
+    let file = file.build()?;
+
+    let edit = SubmenuBuilder::new(app, "Edit")
+        .item(&item("undo", "Undo", "CmdOrCtrl+Z")?)
+        .item(&item("redo", "Redo", "CmdOrCtrl+Shift+Z")?)
+        .separator()
+        .cut()
+        .copy()
+        .paste()
+        .item(&item(
+            "paste-format",
+            "Paste & Format",
+            "CmdOrCtrl+Shift+V",
+        )?)
+        .select_all()
+        .separator()
+        .item(&item("find", "Find…", "CmdOrCtrl+F")?)
+        .text("clear", "Clear All")
+        .build()?;
+
+    let transform = SubmenuBuilder::new(app, "Transform")
+        .item(&item("format", "Format JSON", "CmdOrCtrl+Shift+F")?)
+        .item(&item("minify", "Minify JSON", "CmdOrCtrl+M")?)
+        .separator()
+        .text("json-to-string", "JSON → String")
+        .text("json-to-markdown", "JSON → Markdown")
+        .text("string-to-json", "String → JSON")
+        .text("json-to-proto", "JSON → Proto")
+        .text("proto-to-json", "Proto → JSON")
+        .text("json-to-class", "Generate Class")
+        .separator()
+        .text("dependency-report", "Dependency Report")
+        .text("analyze", "Analyze")
+        .text("lint", "Lint")
+        .text("validate-strict", "Strict Validation")
+        .separator()
+        .item(&item("compare-documents", "Compare…", "CmdOrCtrl+Shift+D")?)
+        .build()?;
+
+    let view = SubmenuBuilder::new(app, "View")
+        .text("tab-converter", "JSON Converter")
+        .text("tab-compare", "JSON Compare")
+        .text("tab-mermaid", "Mermaid Editor")
+        .text("tab-imageResizer", "Image Resizer")
+        .text("tab-openssl", "OpenSSL Cert")
+        .text("tab-traceroute", "Traceroute")
+        .text("tab-jsonHtml", "JSON to HTML")
+        .text("tab-stream", "Stream Viewer")
+        .separator()
+        .text("tree-view", "Tree View")
+        .text("table-view", "Table View")
+        .text("split-view", "Split View")
+        .text("pane-layout", "Stack / Side by Side Panes")
+        .build()?;
+
+    // The About item lives in the application menu on macOS
+    let help = SubmenuBuilder::new(app, "Help");
+    #[cfg(not(target_os = "macos"))]
+    let help = help.about(None);
+    let help = help.build()?;
+
+    MenuBuilder::new(app)
+        .items(&[
+            #[cfg(target_os = "macos")]
+            &app_submenu,
+            &file,
+            &edit,
+            &transform,
+            &view,
+            &help,
+        ])
+        .build()
+}
+
+/// Tray icon menu; its item ids are handled in `handle_menu_event`
+fn create_tray_icon(app: &tauri::AppHandle) -> tauri::Result<()> {
+    let menu = MenuBuilder::new(app)
+        .text("tray-format-clipboard", "Format Clipboard")
+        .separator()
+        .text("tray-show", "Show Palugada")
+        .quit()
+        .build()?;
+    let mut tray = TrayIconBuilder::with_id("main")
+        .tooltip("Palugada")
+        .menu(&menu);
+    if let Some(icon) = app.default_window_icon() {
+        tray = tray.icon(icon.clone());
+    }
+    tray.build(app)?;
+    Ok(())
+}
+
+/// Format the clipboard text in place and report the outcome as a notification, without
+/// touching the window
+fn format_clipboard(app: &tauri::AppHandle) {
+    let result = app
+        .clipboard()
+        .read_text()
+        .map_err(|e| format!("The clipboard has no text: {}", e))
+        .and_then(|text| format_json(text, None))
+        .map(|timed| timed.result)
+        .and_then(|formatted| {
+            let lines = formatted.lines().count();
+            app.clipboard()
+                .write_text(formatted)
+                .map(|()| lines)
+                .map_err(|e| format!("Failed to write the clipboard: {}", e))
+        });
+    let (title, body) = match result {
+        Ok(lines) => (
+            "Clipboard formatted",
+            format!("{} lines of JSON copied back to the clipboard", lines),
+        ),
+        Err(e) => {
+            warn!("format_clipboard: {}", e);
+            ("Clipboard not formatted", e)
+        }
+    };
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        warn!("format_clipboard: failed to show notification - {}", e);
+    }
+}
+
+fn handle_menu_event(app: &tauri::AppHandle, event: MenuEvent) {
+    let id: &str = event.id().as_ref();
+    match id {
+        "tray-format-clipboard" => {
+            let app = app.clone();
+            std::thread::spawn(move || format_clipboard(&app));
+        }
+        "tray-show" => show_main_window(app),
+        _ => {
+            if let Err(e) = app.emit("menu-action", id) {
+                warn!("Failed to emit menu action '{}' - {}", id, e);
+            }
+        }
+    }
+}
+
+/// Registered at startup until the frontend applies the saved choice
+const DEFAULT_GLOBAL_SHORTCUT: &str = "CmdOrCtrl+Shift+J";
+
+/// The registered global shortcut and the text it was set from; `None` when turned off
+static GLOBAL_SHORTCUT: Mutex<Option<(String, Shortcut)>> = Mutex::new(None);
+
+/// Parse a shortcut such as `CmdOrCtrl+Shift+J`; an empty string turns the shortcut off
+fn parse_global_shortcut(shortcut: &str) -> Result<Option<Shortcut>, String> {
+    let shortcut = shortcut.trim();
+    if shortcut.is_empty() {
+        return Ok(None);
+    }
+    shortcut
+        .parse()
+        .map(Some)
+        .map_err(|e| format!("Invalid shortcut '{}': {}", shortcut, e))
+}
+
+/// Global shortcut: bring the window to front and load the clipboard into the input pane
+fn summon_with_clipboard(app: &tauri::AppHandle) {
+    show_main_window(app);
+    match app.clipboard().read_text() {
+        Ok(text) => {
+            if let Err(e) = app.emit("load-clipboard", text) {
+                warn!("Failed to emit load-clipboard - {}", e);
+            }
+        }
+        Err(e) => warn!("Global shortcut: the clipboard has no text - {}", e),
+    }
+}
+
+#[tauri::command]
+fn get_global_shortcut() -> String {
+    let current = GLOBAL_SHORTCUT.lock().unwrap_or_else(|e| e.into_inner());
+    current
+        .as_ref()
+        .map(|(text, _)| text.clone())
+        .unwrap_or_default()
+}
+
+/// Replace the global shortcut; the previous one stays registered if the new one can't be
+#[tauri::command]
+fn set_global_shortcut(shortcut: String) -> Result<String, String> {
+    info!("set_global_shortcut called - shortcut: '{}'", shortcut);
+
+    let next = parse_global_shortcut(&shortcut)?;
+    let app = APP_HANDLE.get().ok_or("The app is not running")?;
+    let mut current = GLOBAL_SHORTCUT.lock().unwrap_or_else(|e| e.into_inner());
+    if current.as_ref().map(|(_, registered)| *registered) == next {
+        return Ok(shortcut.trim().to_string());
+    }
+
+    if let Some(next) = next {
+        app.global_shortcut()
+            .register(next)
+            .map_err(|e| format!("Failed to register '{}': {}", shortcut.trim(), e))?;
+    }
+    if let Some((text, previous)) = current.take() {
+        if let Err(e) = app.global_shortcut().unregister(previous) {
+            warn!("Failed to unregister '{}': {}", text, e);
+        }
+    }
+    *current = next.map(|next| (shortcut.trim().to_string(), next));
+    Ok(shortcut.trim().to_string())
+}
+
+fn main() {
+    // `json-formatter generate-api-schema` prints the command contracts without the UI
+    if std::env::args().nth(1).as_deref() == Some("generate-api-schema") {
+        match serde_json::to_string_pretty(&api::api_schema()) {
+            Ok(schema) => println!("{}", schema),
+            Err(e) => eprintln!("Failed to serialize API schema: {}", e),
+        }
+        return;
+    }
+    // `json-formatter batch ...` converts a directory of files without the UI
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("batch") {
+        std::process::exit(run_batch_cli(&args[2..]));
+    }
+
+    if let Some(path) = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| file_argument(&args, &cwd))
+    {
+        request_open_file(path);
+    }
+
+    tauri::Builder::default()
+        // A second launch forwards its file to the running window and exits
+        .plugin(tauri_plugin_single_instance::init(|_app, args, cwd| {
+            if let Some(path) = file_argument(&args, Path::new(&cwd)) {
+                request_open_file(path);
+            }
+        }))
+        .plugin(
+            tauri_plugin_log::Builder::default()
+                .level(log::LevelFilter::Info)
+                .build(),
+        )
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_window_state::Builder::default().build())
+        .plugin(tauri_plugin_store::Builder::new().build())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, _shortcut, event| {
+                    if event.state() == ShortcutState::Pressed {
+                        summon_with_clipboard(app);
+                    }
+                })
+                .build(),
+        )
+        .menu(app_menu)
+        .on_menu_event(handle_menu_event)
+        .on_window_event(handle_window_event)
+        .setup(|app| {
+            let _ = APP_HANDLE.set(app.handle().clone());
+            if let Err(e) = settings::load_settings(app.handle()) {
+                warn!("{}", e);
+            }
+            create_tray_icon(app.handle())?;
+            if let Err(e) = set_global_shortcut(DEFAULT_GLOBAL_SHORTCUT.to_string()) {
+                warn!("{}", e);
+            }
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            minify_json,
+            format_json,
+            format_stream_messages,
+            json_to_string,
+            json_to_csv,
+            copy_output_as,
+            json_to_markdown,
+            string_to_json,
+            compare_transform,
+            pointer_set,
+            pointer_get,
+            pointer_delete,
+            pointer_duplicate,
+            pointer_to_json_path,
+            locate_node,
+            document_fingerprint,
+            annotate_with_schema,
+            slowest_operations,
+            document_metrics,
+            detect_format,
+            convert_to_json,
+            record_input_history,
+            input_history,
+            input_history_entry,
+            remove_input_history,
+            clear_input_history,
+            get_concurrency_settings,
+            set_concurrency_settings,
+            get_parse_limits,
+            set_parse_limits,
+            get_theme,
+            set_theme,
+            settings::get_settings,
+            settings::set_settings,
+            settings::export_settings,
+            settings::import_settings,
+            snippets::save_snippet,
+            snippets::list_snippets,
+            snippets::load_snippet,
+            snippets::delete_snippet,
+            cancel_operation,
+            format_json_file,
+            minify_json_file,
+            batch_process,
+            inspect_input_bytes,
+            sanitize_input,
+            decode_binary_input,
+            open_archive,
+            read_archive_entry,
+            sqlite_list_tables,
+            sqlite_query,
+            fetch_key_value,
+            fetch_json,
+            replay_curl,
+            open_har,
+            read_har_entry,
+            render_env,
+            resolve_config,
+            list_feature_flags,
+            evaluate_feature_flag,
+            dependency_report,
+            analyze_document,
+            lint_json,
+            validate_strict,
+            generate_api_schema,
+            capabilities,
+            json_to_proto,
+            json_to_grpc_service,
+            decode_protobuf,
+            proto_to_json,
+            generate_sample_json,
+            json_to_class,
+            json_to_class_files,
+            save_generated_files,
+            read_input_file,
+            save_output_file,
+            export_html,
+            recent_files,
+            pin_recent_file,
+            remove_recent_file,
+            watch_file,
+            unwatch_file,
+            take_launch_file,
+            get_global_shortcut,
+            set_global_shortcut,
+            save_session,
+            open_compare_window,
+            take_compare_inputs,
+            load_session,
+            close_window,
+            remove_background,
+            openssl_cert_detail,
+            openssl_cert_detail_from_url,
+            run_traceroute
+        ])
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, _event| {
+            // macOS delivers "Open with…" files as an event instead of arguments
+            #[cfg(target_os = "macos")]
+            if let tauri::RunEvent::Opened { urls } = _event {
+                for url in urls {
+                    if let Ok(path) = url.to_file_path() {
+                        request_open_file(path.display().to_string());
+                    }
+                }
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minify_json() {
+        let input = r#"{
+  "name": "John",
+  "age": 30
+}"#
+        .to_string();
+        let result = minify_json(input, None).unwrap().result;
+        assert!(result.contains("\"name\":\"John\""));
+        assert!(result.contains("\"age\":30"));
+        assert!(!result.contains("\n"));
+    }
+
+    #[test]
+    fn test_format_json() {
+        let input = r#"{"name":"John","age":30}"#.to_string();
+        let result = format_json(input, None).unwrap().result;
+        assert!(result.contains("  \"name\""));
+        assert!(result.contains("  \"age\""));
+    }
+
+    #[test]
+    fn test_format_json_output_options() {
+        let input = r#"{"name":"John"}"#.to_string();
+        let options = OutputOptions {
+            line_ending: LineEnding::Crlf,
+            final_newline: true,
+            bom: true,
+            ..OutputOptions::default()
+        };
+        let result = format_json(input, Some(options)).unwrap().result;
+        assert!(result.starts_with('\u{feff}'));
+        assert!(result.contains("{\r\n  \"name\""));
+        assert!(result.ends_with("}\r\n"));
+        assert!(!result.replace("\r\n", "").contains('\n'));
+    }
+
+    #[test]
+    fn test_format_json_indent_and_sort_keys() {
+        let input = r#"{"b":{"d":1,"c":[true]},"a":null}"#;
+        // Keys are sorted unless input order is asked for
+        let result = format_json(input.to_string(), None).unwrap().result;
+        assert!(result.starts_with("{\n  \"a\": null,\n  \"b\": {\n    \"c\""));
+        let input_order = OutputOptions {
+            sort_keys: false,
+            ..OutputOptions::default()
+        };
+        let result = format_json(input.to_string(), Some(input_order.clone()))
+            .unwrap()
+            .result;
+        assert!(result.starts_with("{\n  \"b\": {\n    \"d\""));
+        let minified = minify_json(input.to_string(), Some(input_order))
+            .unwrap()
+            .result;
+        assert_eq!(minified, input);
+        assert_eq!(
+            minify_json(input.to_string(), None).unwrap().result,
+            r#"{"a":null,"b":{"c":[true],"d":1}}"#
+        );
+
+        let options = OutputOptions {
+            indent: Indent::Tab,
+            ..OutputOptions::default()
+        };
+        let result = format_json(input.to_string(), Some(options.clone()))
+            .unwrap()
+            .result;
+        assert_eq!(
+            result,
+            "{\n\t\"a\": null,\n\t\"b\": {\n\t\t\"c\": [\n\t\t\ttrue\n\t\t],\n\t\t\"d\": 1\n\t}\n}"
+        );
+        let minified = minify_json(input.to_string(), Some(options))
+            .unwrap()
+            .result;
+        assert_eq!(minified, r#"{"a":null,"b":{"c":[true],"d":1}}"#);
+    }
+
+    #[test]
+    fn test_minify_json_final_newline() {
+        let options = OutputOptions {
+            final_newline: true,
+            ..OutputOptions::default()
+        };
+        let result = minify_json(r#"{ "a": 1 }"#.to_string(), Some(options))
+            .unwrap()
+            .result;
+        assert_eq!(result, "{\"a\":1}\n");
+    }
+
+    #[test]
+    fn test_json_to_string() {
+        let input = r#"{"name":"John"}"#.to_string();
+        let result = json_to_string(input).unwrap().result;
+        assert_eq!(result, r#""{\"name\":\"John\"}""#);
+    }
+
+    #[test]
+    fn test_json_to_csv() {
+        let input =
+            r#"[{"id":1,"name":"Ann, Jr.","tags":["a"]},{"id":2,"note":"say \"hi\"","name":null}]"#;
+        let csv = json_to_csv(input.to_string(), None, None).unwrap().result;
+        assert_eq!(
+            csv,
+            "id,name,tags,note\n1,\"Ann, Jr.\",\"[\"\"a\"\"]\",\n2,,,\"say \"\"hi\"\"\""
+        );
+
+        let columns = Some(vec!["name".to_string(), "id".to_string()]);
+        let options = OutputOptions {
+            line_ending: LineEnding::Crlf,
+            ..OutputOptions::default()
+        };
+        let csv = json_to_csv(input.to_string(), columns, Some(options))
+            .unwrap()
+            .result;
+        assert_eq!(csv, "name,id\r\n\"Ann, Jr.\",1\r\n,2");
+
+        assert!(json_to_csv(r#"{"id":1}"#.to_string(), None, None).is_err());
+        assert!(json_to_csv("[1, 2]".to_string(), None, None).is_err());
+    }
+
+    #[test]
+    fn test_string_to_json() {
+        let input = r#""{\"name\":\"John\"}""#.to_string();
+        let result = string_to_json(input, None).unwrap().result;
+        assert!(result.contains("\"name\""));
+        assert!(result.contains("\"John\""));
+    }
+
+    #[test]
+    fn test_string_to_json_escaped_without_wrapper_quotes() {
+        let input = r#"{\"name\":\"John\",\"age\":30}"#.to_string();
+        let result = string_to_json(input, None).unwrap().result;
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["name"], "John");
+        assert_eq!(parsed["age"], 30);
+    }
+
+    #[test]
+    fn test_string_to_json_with_one_sided_quote() {
+        let input = r#"{\"name\":\"John\"}""#.to_string();
+        let result = string_to_json(input, None).unwrap().result;
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["name"], "John");
+    }
+
+    #[test]
+    fn test_scan_json_positions() {
+        let input = "{\n  \"a/b\": [1, \"x\"],\n  \"c\": {\"d\": null}\n}";
+        let nodes = scan_json_positions(input).unwrap();
+        let find = |pointer: &str| nodes.iter().find(|n| n.pointer == pointer).unwrap();
+        assert_eq!(find("").line, 1);
+        assert_eq!(find("/a~1b").line, 2);
+        assert_eq!(find("/a~1b/1").value, Some(Value::from("x")));
+        assert_eq!(find("/c/d").line, 3);
+        assert_eq!(find("/c/d").value, Some(Value::Null));
+    }
+
+    #[test]
+    fn test_compare_transform_reports_changed_values() {
+        let original = r#"{"a": 1, "b": "two", "c": true}"#.to_string();
+        let transformed = "{\n  \"a\": 1,\n  \"b\": \"2\"\n}".to_string();
+        let result = compare_transform(original, transformed).unwrap();
+        assert_eq!(result.changed_pointers, vec!["/b", "/c"]);
+        assert_eq!(result.transformed_changed_lines, vec![3]);
+        assert!(result.line_map.contains(&(1, 2)));
+    }
+
+    #[test]
+    fn test_pointer_set() {
+        let input = r#"{"user": {"name": "John"}, "tags": ["a"]}"#.to_string();
+        let result = pointer_set(
+            input.clone(),
+            "/user/name".to_string(),
+            "\"Jane\"".to_string(),
+            None,
+        )
+        .unwrap();
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["user"]["name"], "Jane");
+
+        let result =
+            pointer_set(input.clone(), "/tags/-".to_string(), "1".to_string(), None).unwrap();
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["tags"], serde_json::json!(["a", 1]));
+
+        assert!(pointer_set(input, "/missing/key".to_string(), "1".to_string(), None).is_err());
+    }
+
+    #[test]
+    fn test_pointer_node_operations() {
+        let input = r#"{"items": [{"id": 1}, {"id": 2}], "meta": {"a b": true}}"#.to_string();
+
+        let subtree = pointer_get(input.clone(), "/items/1".to_string()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&subtree).unwrap(),
+            serde_json::json!({"id": 2})
+        );
+
+        let result = pointer_duplicate(input.clone(), "/items/0".to_string(), None).unwrap();
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(
+            parsed["items"],
+            serde_json::json!([{"id": 1}, {"id": 1}, {"id": 2}])
+        );
+
+        let result = pointer_delete(input.clone(), "/meta/a b".to_string(), None).unwrap();
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["meta"], serde_json::json!({}));
+
+        assert!(pointer_duplicate(input.clone(), "/meta/a b".to_string(), None).is_err());
+        assert!(pointer_delete(input, "".to_string(), None).is_err());
+    }
+
+    #[test]
+    fn test_pointer_to_json_path() {
+        assert_eq!(pointer_to_json_path("".to_string()).unwrap(), "$");
+        assert_eq!(
+            pointer_to_json_path("/items/0/first~1last/a b".to_string()).unwrap(),
+            "$.items[0]['first/last']['a b']"
+        );
+    }
+
+    #[test]
+    fn test_document_fingerprint_ignores_values() {
+        let a = document_fingerprint(r#"{"a": 1, "b": [{"c": true}]}"#.to_string()).unwrap();
+        let b = document_fingerprint(r#"{"a": 2, "b": [{"c": false}, {"c": null}]}"#.to_string())
+            .unwrap();
+        let c = document_fingerprint(r#"{"a": 1, "d": []}"#.to_string()).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_annotate_with_schema() {
+        let schema = r##"{
+  "type": "object",
+  "required": ["name", "email"],
+  "properties": {
+    "name": { "type": "string", "title": "Full name" },
+    "age": { "$ref": "#/$defs/age" },
+    "tags": { "type": "array", "items": { "type": "string" } }
+  },
+  "$defs": { "age": { "type": "integer", "minimum": 0, "description": "Age in years" } }
+}"##
+        .to_string();
+        let input = r#"{"name": "John", "age": -1, "tags": ["a", 2]}"#.to_string();
+
+        let annotations = annotate_with_schema(input, schema).unwrap();
+        let find = |pointer: &str| annotations.iter().find(|a| a.pointer == pointer).unwrap();
+
+        assert!(!find("").valid);
+        assert!(find("")
+            .errors
+            .contains(&"Missing required property 'email'".to_string()));
+        assert!(find("/name").valid);
+        assert_eq!(find("/name").title.as_deref(), Some("Full name"));
+        assert!(!find("/age").valid);
+        assert_eq!(find("/age").description.as_deref(), Some("Age in years"));
+        assert!(!find("/tags").valid);
+        assert!(find("/tags/0").valid);
+        assert_eq!(
+            find("/tags/1").errors,
+            vec!["Expected type string".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_annotate_with_recursive_schema() {
+        let schema = r##"{"anyOf":[{"$ref":"#"},{"type":"string"}]}"##.to_string();
+        let err = annotate_with_schema("1".to_string(), schema).err().unwrap();
+        assert!(err.contains("recursive $ref"));
+
+        let tree = r##"{
+  "type": "object",
+  "properties": { "children": { "type": "array", "items": { "$ref": "#" } } }
+}"##
+        .to_string();
+        let input = r#"{"children": [{"children": [{"children": 1}]}]}"#.to_string();
+        let annotations = annotate_with_schema(input, tree).unwrap();
+        assert!(!annotations[0].valid);
+        assert!(annotations
+            .iter()
+            .any(|a| a.pointer == "/children/0/children/0/children" && !a.valid));
+    }
+
+    #[test]
+    fn test_operation_timings_are_recorded() {
+        let input = r#"{"timing_probe": [1, 2, 3, 4, 5, 6, 7]}"#.to_string();
+        let timed = format_json(input.clone(), None).unwrap();
+        assert_eq!(timed.timing.operation, "format_json");
+        assert_eq!(timed.timing.input_bytes, input.len());
+        assert!(!timed.timing.failed);
+        assert!(timed.timing.total_ms >= timed.timing.parse_ms + timed.timing.serialize_ms);
+
+        // Failed calls only show up in the history; find them by their input size
+        let broken = r#"{"timing_probe": [1, 2, 3, 4, 5, 6, 7,]}"#.to_string();
+        assert!(minify_json(broken.clone(), None).is_err());
+        let slowest = slowest_operations(Some(OPERATION_TIMING_HISTORY));
+        assert!(slowest
+            .iter()
+            .any(|t| t.operation == "format_json" && t.input_bytes == input.len() && !t.failed));
+        assert!(slowest
+            .iter()
+            .any(|t| t.operation == "minify_json" && t.input_bytes == broken.len() && t.failed));
+        assert!(slowest.windows(2).all(|w| w[0].total_ms >= w[1].total_ms));
+    }
+
+    #[test]
+    fn test_concurrency_settings_validation() {
+        let defaults = ConcurrencySettings::default();
+        assert!(defaults.worker_threads >= 1);
+
+        let no_threads = ConcurrencySettings {
+            worker_threads: 0,
+            ..defaults
+        };
+        assert!(set_concurrency_settings(no_threads).is_err());
+        let too_little_memory = ConcurrencySettings {
+            job_memory_limit_mb: 1,
+            ..defaults
+        };
+        assert!(set_concurrency_settings(too_little_memory).is_err());
+
+        assert!(check_job_memory("test", 1024).is_ok());
+        assert!(check_job_memory("test", 1 << 40).is_err());
+    }
+
+    #[test]
+    fn test_progress_tracker_cancel() {
+        let data = vec![b' '; PROGRESS_INTERVAL_BYTES * 2];
+        let mut tracker = ProgressTracker::new(&data[..], "test", "parsing", data.len());
+        let mut sink = Vec::new();
+        assert_eq!(tracker.read_to_end(&mut sink).unwrap(), data.len());
+        assert_eq!(tracker.processed, data.len());
+
+        cancel_operation();
+        let mut tracker = ProgressTracker::new(&data[..], "test", "parsing", data.len());
+        let error = tracker.read_to_end(&mut sink).unwrap_err();
+        assert_eq!(error.to_string(), "Operation cancelled");
+        CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+
+        let error = parse_json_tracked("test", "{\n  \"a\": }").unwrap_err();
+        assert_eq!(error, "Invalid JSON: expected value at line 2 column 8");
+
+        // A stale cancel doesn't fail the next operation on a cached document
+        let large = format!("[{}1]", "1,".repeat(PROGRESS_MIN_BYTES / 2));
+        assert!(format_json(large.clone(), None).is_ok());
+        cancel_operation();
+        assert!(minify_json(large, None).is_ok());
+    }
+
+    fn stream(input: &str, pretty: bool) -> Result<String, String> {
+        let options = OutputOptions::default();
+        let output = JsonStreamFormatter::new(input.as_bytes(), Vec::new(), pretty, &options)
+            .run(&options)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_stream_json_formatter() {
+        let input = r#" { "name": "caf\u00e9 ☕", "list": [1, -2.5e3, true, null, {}, []],
+            "nested": { "empty": "", "deep": [[{"a": false}]] } } "#;
+        // Strings spanning a buffer refill
+        let long = format!("[\"{}\", \"é{}\"]", "a".repeat(70_000), "b".repeat(65_534));
+        assert_eq!(stream(&long, false).unwrap(), long.replace(", ", ","));
+
+        let value: Value = serde_json::from_str(input).unwrap();
+        // serde_json sorts keys and decodes escapes, so compare values and layout separately
+        let expected = serde_json::to_string_pretty(&value).unwrap();
+        let pretty = stream(input, true).unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&pretty).unwrap(), value);
+        assert!(pretty.starts_with("{\n  \"name\": \"caf\\u00e9 ☕\",\n  \"list\": [\n    1,"));
+        assert!(pretty.contains("\"empty\": \"\""));
+        assert!(pretty.contains("{},\n    []\n  ]"));
+        assert_eq!(pretty.lines().count(), expected.lines().count());
+        assert_eq!(
+            stream(input, false).unwrap(),
+            r#"{"name":"caf\u00e9 ☕","list":[1,-2.5e3,true,null,{},[]],"nested":{"empty":"","deep":[[{"a":false}]]}}"#
+        );
+
+        for (input, error) in [
+            ("[1, 2,]", "trailing comma at line 1 column 7"),
+            ("{\"a\" 1}", "expected `:` at line 1 column 6"),
+            ("{1: 2}", "key must be a string at line 1 column 2"),
+            ("[01]", "invalid number at line 1 column 3"),
+            ("[tru]", "expected ident at line 1 column 5"),
+            ("{\"a\": [1 2]}", "expected `,` or `]` at line 1 column 10"),
+            ("\"abc", "EOF while parsing a string at line 1 column 4"),
+            ("{} x", "trailing characters at line 1 column 4"),
+            ("", "EOF while parsing a value at line 1 column 0"),
+        ] {
+            assert_eq!(
+                stream(input, true).unwrap_err(),
+                format!("Invalid JSON: {}", error),
+                "{}",
+                input
+            );
+        }
+
+        let options = OutputOptions::default();
+        let stream_bytes = |bytes: &[u8]| {
+            let input = [b"\"", bytes, b"\""].concat();
+            JsonStreamFormatter::new(input.as_slice(), Vec::new(), false, &options).run(&options)
+        };
+        // Overlong forms, surrogates and code points past U+10FFFF
+        for bytes in [
+            &b"\xE0\x80\x80"[..],
+            b"\xED\xA0\x80",
+            b"\xF0\x80\x80\x80",
+            b"\xF4\x90\x80\x80",
+        ] {
+            assert!(stream_bytes(bytes).is_err(), "{:?}", bytes);
+        }
+        for bytes in [
+            &b"\xE0\xA0\x80"[..],
+            b"\xED\x9F\xBF",
+            b"\xF0\x90\x80\x80",
+            b"\xF4\x8F\xBF\xBF",
+        ] {
+            assert_eq!(stream_bytes(bytes).unwrap(), [b"\"", bytes, b"\""].concat());
+        }
+
+        let directory =
+            std::env::temp_dir().join(format!("json-formatter-stream-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let source = directory.join("input.json");
+        let target = directory.join("output.json");
+        fs::write(&source, "\u{feff}[1,{\"a\":2}]").unwrap();
+        let options = OutputOptions {
+            line_ending: LineEnding::Crlf,
+            final_newline: true,
+            ..OutputOptions::default()
+        };
+        let source_path = source.to_str().unwrap();
+        let target_path = target.to_str().unwrap();
+        let written = stream_json_file("test", source_path, target_path, true, &options)
+            .unwrap()
+            .result;
+        let output = fs::read_to_string(&target).unwrap();
+        assert_eq!(output, "[\r\n  1,\r\n  {\r\n    \"a\": 2\r\n  }\r\n]\r\n");
+        assert_eq!(written, output.len() as u64);
+        assert!(stream_json_file("test", source_path, source_path, true, &options).is_err());
+
+        fs::write(&source, "[1,").unwrap();
+        assert!(stream_json_file("test", source_path, target_path, false, &options).is_err());
+        assert!(!target.exists());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_input_and_output_files() {
+        let path =
+            std::env::temp_dir().join(format!("json-formatter-io-{}.json", std::process::id()));
+        let path = path.to_str().unwrap().to_string();
+        save_output_file(path.clone(), "{\"a\": 1}".to_string()).unwrap();
+        assert_eq!(read_input_file(path.clone()).unwrap(), b"{\"a\": 1}");
+        let inspection = inspect_input_bytes(Some(path.clone()), None, None).unwrap();
+        assert_eq!(inspection.text.as_deref(), Some("{\"a\": 1}"));
+        fs::remove_file(&path).unwrap();
+        assert!(read_input_file(path)
+            .unwrap_err()
+            .starts_with("Failed to open"));
+    }
+
+    #[test]
+    fn test_touch_recent_file() {
+        let mut files = Vec::new();
+        for i in 0..RECENT_FILES_LIMIT + 3 {
+            touch_recent_file(&mut files, &format!("/data/{}.json", i), i as u64);
+        }
+        assert_eq!(files.len(), RECENT_FILES_LIMIT);
+        assert_eq!(
+            files[0].path,
+            format!("/data/{}.json", RECENT_FILES_LIMIT + 2)
+        );
+        assert!(!files.iter().any(|file| file.path == "/data/2.json"));
+
+        // Pinned entries survive any number of newer files and stay pinned when reopened
+        let oldest = files.last().unwrap().path.clone();
+        files.last_mut().unwrap().pinned = true;
+        for i in 100..100 + RECENT_FILES_LIMIT {
+            touch_recent_file(&mut files, &format!("/data/{}.json", i), i as u64);
+        }
+        assert_eq!(files.len(), RECENT_FILES_LIMIT + 1);
+        touch_recent_file(&mut files, &oldest, 500);
+        assert_eq!(files[0].path, oldest);
+        assert!(files[0].pinned);
+        assert_eq!(files[0].last_used, 500);
+        assert_eq!(files.iter().filter(|file| file.path == oldest).count(), 1);
+    }
+
+    #[test]
+    fn test_write_file_atomically() {
+        let directory =
+            std::env::temp_dir().join(format!("json-formatter-session-{}", std::process::id()));
+        let store = directory.join("nested").join("session.json");
+        let session = Session {
+            documents: vec![SessionDocument {
+                name: "Untitled 1".to_string(),
+                input: "{\"a\": 1}".to_string(),
+                output: String::new(),
+                tree_view: false,
+                source: Some("file:/tmp/a.json".to_string()),
+            }],
+            active_document: 0,
+            last_operation: Some("formatBtn".to_string()),
+        };
+        write_file_atomically(&store, &serde_json::to_vec(&session).unwrap()).unwrap();
+        write_file_atomically(&store, &serde_json::to_vec(&session).unwrap()).unwrap();
+
+        let text = fs::read_to_string(&store).unwrap();
+        assert_eq!(serde_json::from_str::<Session>(&text).unwrap(), session);
+        assert!(text.contains("\"lastOperation\":\"formatBtn\""));
+        // A file named like the old temporary is left alone, and no temporary remains
+        let neighbour = store.with_extension("tmp");
+        fs::write(&neighbour, "keep").unwrap();
+        write_file_atomically(&store, b"{}").unwrap();
+        assert_eq!(fs::read_to_string(&neighbour).unwrap(), "keep");
+        assert_eq!(fs::read_dir(store.parent().unwrap()).unwrap().count(), 2);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_watch_path() {
+        let directory =
+            std::env::temp_dir().join(format!("json-formatter-watch-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("watched.json");
+        fs::write(&path, "{\"a\": 1}").unwrap();
+
+        let options = OutputOptions::default();
+        let update = run_watch_operation(&path, WatchOperation::Minify, &options);
+        assert_eq!(update.output.as_deref(), Some("{\"a\":1}"));
+        let update = run_watch_operation(&path, WatchOperation::Validate, &options);
+        assert_eq!(update.output.as_deref(), Some("{\"a\": 1}"));
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let watcher = watch_path(&path, move || sender.send(()).unwrap()).unwrap();
+        fs::write(directory.join("other.json"), "[]").unwrap();
+        fs::write(&path, "{\"a\": 2,}").unwrap();
+        receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        let update = run_watch_operation(&path, WatchOperation::Validate, &options);
+        assert!(update.error.unwrap().contains("trailing comma"));
+
+        drop(watcher);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_file_argument() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        let cwd = Path::new("/home/user");
+        assert_eq!(file_argument(&args(&["json-formatter"]), cwd), None);
+        assert_eq!(
+            file_argument(&args(&["json-formatter", "big.json"]), cwd).as_deref(),
+            Some("/home/user/big.json")
+        );
+        assert_eq!(
+            file_argument(&args(&["json-formatter", "-psn_0_42", "/data/a.json"]), cwd).as_deref(),
+            Some("/data/a.json")
+        );
+    }
+
+    #[test]
+    fn test_parse_global_shortcut() {
+        assert_eq!(parse_global_shortcut("  "), Ok(None));
+        assert!(parse_global_shortcut(DEFAULT_GLOBAL_SHORTCUT)
+            .unwrap()
+            .is_some());
+        assert!(parse_global_shortcut("Ctrl+Shift+")
+            .unwrap_err()
+            .starts_with("Invalid shortcut 'Ctrl+Shift+'"));
+        assert!(parse_global_shortcut("Ctrl+Banana").is_err());
+    }
+
+    #[test]
+    fn test_run_batch() {
+        let directory =
+            std::env::temp_dir().join(format!("json-formatter-batch-{}", std::process::id()));
+        let output = directory.join("out");
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("a.json"), r#"[{"id": 1, "name": "x"}]"#).unwrap();
+        fs::write(directory.join("b.json"), "{").unwrap();
+        fs::write(directory.join("notes.txt"), "skipped").unwrap();
+
+        let report = run_batch(
+            &directory,
+            &output,
+            BatchOperation::Minify,
+            &OutputOptions::default(),
+        )
+        .unwrap();
+        assert_eq!((report.succeeded, report.failed), (1, 1));
+        assert!(report.files[0].input.ends_with("a.json"));
+        assert_eq!(
+            fs::read_to_string(output.join("a.json")).unwrap(),
+            r#"[{"id":1,"name":"x"}]"#
+        );
+        assert!(report.files[1]
+            .error
+            .as_ref()
+            .unwrap()
+            .starts_with("Invalid JSON"));
+        assert!(report.files[1].output.is_none());
+
+        run_batch(
+            &directory,
+            &output,
+            BatchOperation::Csv,
+            &OutputOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(output.join("a.csv")).unwrap(),
+            "id,name\n1,x"
+        );
+
+        let report = run_batch(
+            &directory,
+            &directory,
+            BatchOperation::Format,
+            &OutputOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(report.failed, 2);
+        assert_eq!(
+            report.files[0].error.as_deref(),
+            Some("Output file would overwrite the input")
+        );
+        assert_eq!(BatchOperation::parse("csv"), Some(BatchOperation::Csv));
+        assert_eq!(run_batch_cli(&["xml".to_string()]), 2);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        let limits = ParseLimits {
+            max_input_mb: 2,
+            max_depth: 3,
+            max_string_mb: 1,
+        };
+        assert!(limits
+            .check(r#"{"a": [[1]], "b": "[[[[", "c": "\\"[["}"#)
+            .is_ok());
+        assert_eq!(
+            limits.check("{\n  \"a\": [[[1]]]\n}").unwrap_err(),
+            "Nesting deeper than 3 levels at line 2 column 10"
+        );
+        let long = format!("[\"{}\"]", "x".repeat(1024 * 1024 + 1));
+        assert_eq!(
+            limits.check(&long).unwrap_err(),
+            "String longer than 1 MB at line 1 column 2"
+        );
+        let huge = " ".repeat(3 * 1024 * 1024);
+        assert_eq!(
+            limits.check(&huge).unwrap_err(),
+            "Input is 3.0 MB, above the 2 MB input limit"
+        );
+
+        // The default depth limit stays within what serde_json itself accepts
+        let deepest = ParseLimits::default().max_depth;
+        let nested = format!("{}{}", "[".repeat(deepest), "]".repeat(deepest));
+        assert!(parse_json_uncached(&nested).is_ok());
+        let deeper = format!("[{}]", nested);
+        assert!(parse_json_uncached(&deeper)
+            .unwrap_err()
+            .starts_with("Nesting deeper than 127 levels"));
+        assert!(set_parse_limits(ParseLimits {
+            max_depth: 128,
+            ..ParseLimits::default()
+        })
+        .is_err());
+    }
+
+    fn large_document(records: usize) -> String {
+        let rows: Vec<Value> = (0..records)
+            .map(|i| {
+                serde_json::json!({
+                    "id": i,
+                    "name": format!("user-{}", i),
+                    "score": i as f64 * 1.25,
+                    "active": i % 2 == 0,
+                    "tags": ["a", "b", null],
+                })
+            })
+            .collect();
+        serde_json::to_string(&Value::Array(rows)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_json_backends() {
+        let input = large_document(2_000);
+        assert!(input.len() > 64 * 1024);
+        assert_eq!(
+            parse_json_uncached(&input).unwrap(),
+            serde_json::from_str::<Value>(&input).unwrap()
+        );
+
+        // Large invalid input still reports a line and column
+        let invalid = format!("[{},\n  1,]", &input[1..input.len() - 1]);
+        let error = parse_json_uncached(&invalid).unwrap_err();
+        assert!(error.starts_with("Invalid JSON:"), "{}", error);
+        assert!(error.ends_with("at line 2 column 5"), "{}", error);
+    }
+
+    #[test]
+    fn test_parse_cache() {
+        let cache = ParseCache::new();
+        let input = r#"{"cache": [1, 2, 3]}"#;
+        let first = cache.get_or_parse(input, parse_json_uncached).unwrap();
+        let again = cache
+            .get_or_parse(input, |_| panic!("cached input parsed again"))
+            .unwrap();
+        assert!(Arc::ptr_eq(&first, &again));
+
+        let changed = cache
+            .get_or_parse(&input.replace('3', "4"), parse_json_uncached)
+            .unwrap();
+        assert_eq!(changed["cache"][2], 4);
+        assert!(cache.get_or_parse("{", parse_json_uncached).is_err());
+        let reparsed = cache.get_or_parse(input, parse_json_uncached).unwrap();
+        assert!(!Arc::ptr_eq(&first, &reparsed));
+        assert_eq!(first, reparsed);
+
+        // Only the same text is a hit, and clearing forces a fresh parse
+        let spaced = input.replace(", ", ",");
+        let other = cache.get_or_parse(&spaced, parse_json_uncached).unwrap();
+        assert!(!Arc::ptr_eq(&reparsed, &other));
+        cache.clear();
+        assert!(cache
+            .get_or_parse(&spaced, |_| Err("parsed again".to_string()))
+            .is_err());
+
+        assert_eq!(
+            parse_json_samples("[{\"a\": 1}, {\"a\": 2}]").unwrap(),
+            vec![serde_json::json!({"a": 1}), serde_json::json!({"a": 2})]
+        );
+        // NDJSON batches bypass the cache and still yield one sample per line
+        assert_eq!(
+            parse_json_samples("{\"a\": 1}\n{\"a\": 2}").unwrap().len(),
+            2
+        );
+    }
+
+    /// Compare the parse backends on a ~20 MB document:
+    /// `cargo test --release bench_parse_backends -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_parse_backends() {
+        let input = large_document(200_000);
+        let runs = 5;
+
+        let start = Instant::now();
+        for _ in 0..runs {
+            serde_json::from_str::<Value>(&input).unwrap();
+        }
+        let serde_elapsed = start.elapsed() / runs;
+        println!("serde_json: {:?} for {} bytes", serde_elapsed, input.len());
+
+        #[cfg(all(feature = "simd", not(target_arch = "wasm32")))]
+        {
+            let start = Instant::now();
+            for _ in 0..runs {
+                let mut bytes = input.as_bytes().to_vec();
+                simd_json::serde::from_slice::<Value>(&mut bytes).unwrap();
+            }
+            let simd_elapsed = start.elapsed() / runs;
+            println!(
+                "simd-json: {:?} ({:.1}x)",
+                simd_elapsed,
+                serde_elapsed.as_secs_f64() / simd_elapsed.as_secs_f64()
+            );
+        }
+    }
+
+    #[test]
+    fn test_settings_profile() {
+        let directory =
+            std::env::temp_dir().join(format!("json-formatter-settings-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("settings.json");
+        let path_str = path.to_str().unwrap().to_string();
+
+        settings::export_settings(path_str.clone()).unwrap();
+        let exported: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(exported["output"]["indent"], "twoSpaces");
+        assert_eq!(exported["codegen"]["namingConvention"], "idiomatic");
+        assert_eq!(exported["language"], "");
+
+        // Sections left out of an imported profile take their defaults
+        let partial: settings::Settings =
+            serde_json::from_str(r#"{"output": {"sortKeys": true}}"#).unwrap();
+        assert!(partial.output.sort_keys);
+        assert_eq!(partial.parse_limits, ParseLimits::default());
+
+        let invalid = settings::Settings {
+            concurrency: concurrency_settings(),
+            parse_limits: ParseLimits {
+                max_depth: 0,
+                ..ParseLimits::default()
+            },
+            ..settings::Settings::default()
+        };
+        assert!(settings::set_settings(invalid).is_err());
+        assert_eq!(parse_limits(), ParseLimits::default());
+
+        fs::write(&path, "[1, 2]").unwrap();
+        assert!(settings::import_settings(path_str)
+            .unwrap_err()
+            .starts_with("Not a settings file"));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_take_compare_inputs() {
+        let inputs = CompareInputs {
+            left: r#"{"env": "staging"}"#.to_string(),
+            right: r#"{"env": "production"}"#.to_string(),
+        };
+        PENDING_COMPARES
+            .lock()
+            .unwrap()
+            .insert("compare-test".to_string(), inputs.clone());
+        assert_eq!(
+            take_compare_inputs("compare-test".to_string()),
+            Some(inputs)
+        );
+        assert_eq!(take_compare_inputs("compare-test".to_string()), None);
+
+        assert!(open_compare_window(String::new(), String::new()).is_err());
+    }
+
+    #[test]
+    fn test_http_request_args() {
+        let mut request = HttpRequest {
+            method: "GET".to_string(),
+            url: " https://api.example.com/items ".to_string(),
+            headers: vec![("Authorization".to_string(), "Bearer abc".to_string())],
+            ..HttpRequest::default()
+        };
+        let args = http_request_args(&request).unwrap();
+        assert!(args.contains(&"-L".to_string()));
+        assert!(args.contains(&"Authorization: Bearer abc".to_string()));
+        assert!(args.contains(&"Accept: application/json, */*;q=0.8".to_string()));
+        assert!(!args.contains(&"-X".to_string()));
+        assert_eq!(args.last().unwrap(), "https://api.example.com/items");
+
+        request.method = "POST".to_string();
+        request.body = Some("{}".to_string());
+        let args = http_request_args(&request).unwrap();
+        assert!(args.windows(2).any(|w| w == ["-X", "POST"]));
+        assert!(args.windows(2).any(|w| w == ["--data-binary", "@-"]));
+
+        request.url = "file:///etc/passwd".to_string();
+        assert!(http_request_args(&request).is_err());
+        request.url = "https://example.com".to_string();
+        request
+            .headers
+            .push(("X-Evil".to_string(), "a\r\nHost: other".to_string()));
+        assert!(http_request_args(&request).is_err());
+
+        let stdout = format!(
+            "{{\"ok\": true}}{}404\napplication/json; charset=utf-8\nhttps://example.com/v2",
+            FETCH_TRAILER
+        );
+        assert_eq!(
+            parse_fetch_output(stdout.as_bytes()).unwrap(),
+            FetchedJson {
+                body: "{\"ok\": true}".to_string(),
+                status: 404,
+                content_type: "application/json; charset=utf-8".to_string(),
+                url: "https://example.com/v2".to_string(),
+            }
+        );
+        assert!(parse_fetch_output(b"partial").is_err());
+    }
+
+    #[test]
+    fn test_parse_curl_command() {
+        // As written by a browser's "Copy as cURL"
+        let command = "curl 'https://api.example.com/orders?page=1' \\\n  -H 'accept: application/json' \\\n  -H \"Authorization: Bearer t0k\\\"en\" \\\n  --data-raw $'{\"note\":\"it\\'s\"}' \\\n  --compressed";
+        let request = parse_curl_command(command).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "https://api.example.com/orders?page=1");
+        assert_eq!(
+            request.headers,
+            vec![
+                ("accept".to_string(), "application/json".to_string()),
+                ("Authorization".to_string(), "Bearer t0k\"en".to_string()),
+            ]
+        );
+        assert_eq!(request.body.as_deref(), Some(r#"{"note":"it's"}"#));
+
+        let request =
+            parse_curl_command("curl -G -d a=1 -d b=2 -XDELETE -u me:pw -k https://x.test/items")
+                .unwrap();
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.url, "https://x.test/items?a=1&b=2");
+        assert_eq!(request.body, None);
+        assert!(request.insecure);
+        assert_eq!(
+            request.headers[0].1,
+            format!("Basic {}", BASE64.encode("me:pw"))
+        );
+
+        assert!(parse_curl_command("wget https://x.test").is_err());
+        assert!(parse_curl_command("curl -F file=@a.json https://x.test").is_err());
+        assert_eq!(
+            parse_curl_command("curl --proxy p:1 https://x.test").unwrap_err(),
+            "Unsupported curl option '--proxy'"
+        );
+        assert!(parse_curl_command("curl 'https://x.test").is_err());
+    }
+
+    #[test]
+    fn test_har_entries() {
+        let har = serde_json::json!({"log": {"entries": [
+            {
+                "request": {
+                    "method": "POST",
+                    "url": "https://api.example.com/items",
+                    "postData": {"mimeType": "application/json", "text": "{\"name\":\"a\"}"}
+                },
+                "response": {
+                    "status": 201,
+                    "content": {
+                        "size": 8,
+                        "mimeType": "application/json",
+                        "encoding": "base64",
+                        "text": BASE64.encode("{\"id\":1}")
+                    }
+                }
+            },
+            {
+                "request": {"method": "GET", "url": "https://example.com/"},
+                "response": {"status": 0, "content": {"size": 0, "mimeType": "", "text": ""}}
+            }
+        ]}})
+        .to_string();
+
+        let entries = open_har(har.clone()).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].method, "POST");
+        assert_eq!(entries[0].status, 201);
+        assert!(entries[0].has_request_body && entries[0].has_response_body);
+        assert!(!entries[1].has_request_body && !entries[1].has_response_body);
+
+        let request = read_har_entry(har.clone(), 0, HarPart::Request, None).unwrap();
+        assert_eq!(request, "{\n  \"name\": \"a\"\n}");
+        let response = read_har_entry(har.clone(), 0, HarPart::Response, None).unwrap();
+        assert_eq!(response, "{\n  \"id\": 1\n}");
+        assert!(read_har_entry(har.clone(), 1, HarPart::Response, None)
+            .unwrap_err()
+            .contains("no response body"));
+        assert!(read_har_entry(har, 5, HarPart::Request, None).is_err());
+        assert!(open_har("{\"log\": {}}".to_string())
+            .unwrap_err()
+            .contains("Not a HAR file"));
+    }
+
+    #[test]
+    fn test_ingest_requests() {
+        let token = "0123456789abcdef";
+        let send = |raw: &str| {
+            let mut reader = Cursor::new(raw.as_bytes().to_vec());
+            match ingest::read_request(&mut reader, token) {
+                Ok(request) => ingest::respond(&request),
+                Err(error) => error,
+            }
+        };
+        let post = |path: &str, auth: &str, body: &str| {
+            send(&format!(
+                "POST {} HTTP/1.1\r\nHost: 127.0.0.1\r\nAuthorization: {}\r\nContent-Length: {}\r\n\r\n{}",
+                path,
+                auth,
+                body.len(),
+                body
+            ))
+        };
+        let bearer = format!("Bearer {}", token);
+
+        let (status, body) = post("/format", &bearer, r#"{"a":[1,2]}"#);
+        assert_eq!(status, 200);
+        assert_eq!(body, "{\n  \"a\": [\n    1,\n    2\n  ]\n}");
+
+        assert_eq!(post("/format", "Bearer wrong-token-value", "{}").0, 401);
+        assert_eq!(post("/format", "", "{}").0, 401);
+        assert_eq!(post("/nope", &bearer, "{}").0, 404);
+        assert_eq!(post("/format", &bearer, "{oops").0, 400);
+        // Without a window there is nowhere to open the document
+        assert_eq!(post("/open", &bearer, "{}").0, 503);
+        let get = format!("GET /format HTTP/1.1\r\nAuthorization: {}\r\n\r\n", bearer);
+        assert_eq!(send(&get).0, 405);
+        let request = |headers: &str| {
+            send(&format!(
+                "POST /format HTTP/1.1\r\nAuthorization: {}\r\n{}\r\n",
+                bearer, headers
+            ))
+        };
+        assert_eq!(request("Content-Length: 5\r\n").0, 400);
+        assert_eq!(request("Transfer-Encoding: chunked\r\n").0, 411);
+        // Unauthorized requests are refused before the body is read
+        assert_eq!(
+            send("POST /format HTTP/1.1\r\nContent-Length: 5\r\n\r\n{}").0,
+            401
+        );
+
+        let enabled = ingest::IngestSettings {
+            enabled: true,
+            token: "short".to_string(),
+            ..Default::default()
+        };
+        assert!(enabled.validate().is_err());
+        assert!(ingest::IngestSettings {
+            token: token.to_string(),
+            ..enabled
+        }
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_format_stream_messages() {
+        let options = OutputOptions {
+            final_newline: true,
+            sort_keys: true,
+            ..OutputOptions::default()
+        };
+        let formatted = format_stream_messages(
+            vec![
+                r#"{"type":"tick","a":1}"#.to_string(),
+                "ping".to_string(),
+                "[1,".to_string(),
+            ],
+            Some(options),
+        );
+        assert_eq!(
+            formatted,
+            vec![
+                Some("{\n  \"a\": 1,\n  \"type\": \"tick\"\n}".to_string()),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_json_html() {
+        let value = serde_json::json!({
+            "name": "<b>",
+            "tags": ["a"],
+            "meta": {"ok": true, "n": 1.5},
+            "empty": []
+        });
+        let collapsed = HashSet::from(["/meta".to_string()]);
+        let html = render_json_html(&value, "Ticket & notes", &collapsed);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Ticket &amp; notes</title>"));
+        assert!(html.contains("<span class=\"string\">&quot;&lt;b&gt;&quot;</span>,"));
+        assert!(
+            html.contains("<details open><summary><span class=\"key\">&quot;tags&quot;</span>: ")
+        );
+        assert!(html.contains("<details><summary><span class=\"key\">&quot;meta&quot;</span>: "));
+        assert!(html.contains("<span class=\"literal\">true</span>,"));
+        assert!(html.contains("<span class=\"number\">1.5</span></div>"));
+        assert!(html.contains("<span class=\"key\">&quot;empty&quot;</span>: []</div>"));
+        // No external resources, so the file works as an attachment
+        assert!(!html.contains("src=") && !html.contains("href="));
+    }
+
+    #[test]
+    fn test_json_to_markdown() {
+        let block = json_to_markdown(r#"{"a":"x```y"}"#.to_string(), None, None)
+            .unwrap()
+            .result;
+        assert_eq!(block, "````json\n{\n  \"a\": \"x```y\"\n}\n````");
+
+        let table = json_to_markdown(
+            r#"[{"id":1,"name":"a|b"},{"id":20,"name":"line\nbreak","ok":true},{"id":null}]"#
+                .to_string(),
+            Some(MarkdownMode::Table),
+            None,
+        )
+        .unwrap()
+        .result;
+        assert_eq!(
+            table,
+            "| id | name | ok |\n| ---: | --- | --- |\n| 1 | a\\|b |  |\n| 20 | line<br>break | true |\n|  |  |  |"
+        );
+
+        for input in [r#"[{"a":{"b":1}}]"#, r#"{"a":1}"#, "[]", "[1, 2]"] {
+            assert!(
+                json_to_markdown(input.to_string(), Some(MarkdownMode::Table), None)
+                    .unwrap_err()
+                    .contains("flat objects")
+            );
+        }
+    }
+
+    #[test]
+    fn test_locate_node() {
+        let input = "{\n  \"items\": [\n    {\"a b\": 1}\n  ],\n  \"n\": null\n}".to_string();
+        let locate = |line, column| {
+            locate_node(input.clone(), line, column)
+                .unwrap()
+                .map(|node| node.json_path)
+        };
+        assert_eq!(locate(1, 1).as_deref(), Some("$"));
+        // A click on the key picks the value it names
+        assert_eq!(locate(2, 3).as_deref(), Some("$.items"));
+        assert_eq!(locate(3, 5).as_deref(), Some("$.items[0]"));
+        assert_eq!(locate(3, 15).as_deref(), Some("$.items[0]['a b']"));
+        assert_eq!(locate(4, 3), None);
+        assert_eq!(
+            locate_node(input.clone(), 5, 1).unwrap(),
+            Some(NodeLocation {
+                pointer: "/n".to_string(),
+                json_path: "$.n".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_document_metrics() {
+        let metrics =
+            document_metrics(r#"{"a": [1, 2, {"b": null}], "c": "x"}"#.to_string()).unwrap();
+        assert_eq!(metrics.bytes, 36);
+        assert_eq!(metrics.nodes, 7);
+        assert_eq!(metrics.max_depth, 3);
+        assert!(metrics.parse_ms >= 0.0);
+
+        let scalar = document_metrics("42".to_string()).unwrap();
+        assert_eq!((scalar.nodes, scalar.max_depth), (1, 0));
+        assert!(document_metrics("  ".to_string()).is_err());
+        assert!(document_metrics("{".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_input_history() {
+        let mut entries = Vec::new();
+        push_input_history(&mut entries, r#"{"a": 1}"#, "Format JSON", 10);
+        push_input_history(&mut entries, r#"{"b": 2}"#, "Minify JSON", 20);
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].id, entries[1].id), (2, 1));
+
+        // Another operation on the newest input updates it in place
+        push_input_history(&mut entries, r#"{"b": 2}"#, "Analyze", 30);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].operation, "Analyze");
+        assert_eq!(entries[0].timestamp, 30);
+
+        for i in 0..INPUT_HISTORY_LIMIT {
+            push_input_history(&mut entries, &i.to_string(), "Format JSON", 40);
+        }
+        assert_eq!(entries.len(), INPUT_HISTORY_LIMIT);
+        assert_eq!(entries[0].input, (INPUT_HISTORY_LIMIT - 1).to_string());
+        assert!(entries.iter().all(|entry| entry.id > 2));
+
+        let summary = InputHistorySummary::from(&InputHistoryEntry {
+            id: 1,
+            input: format!("{{\n  \"key\": \"{}\"\n}}", "x".repeat(200)),
+            operation: "Format JSON".to_string(),
+            timestamp: 0,
+        });
+        assert!(summary.preview.starts_with(r#"{ "key": "xx"#));
+        assert!(summary.preview.ends_with('…'));
+        assert_eq!(
+            summary.preview.chars().count(),
+            INPUT_HISTORY_PREVIEW_CHARS + 1
+        );
+        assert_eq!(summary.bytes, 215);
+    }
+
+    #[test]
+    fn test_snippet_library() {
+        let tags = |tags: &[&str]| tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            snippets::normalize_tags(&tags(&[" #Orders", "orders", "", "smoke "])),
+            tags(&["Orders", "smoke"])
+        );
+
+        let snippet = snippets::Snippet {
+            name: "Order with discount".to_string(),
+            tags: tags(&["orders", "regression"]),
+            content: "{}".to_string(),
+            saved_at: 0,
+        };
+        assert!(snippets::snippet_matches(&snippet, ""));
+        assert!(snippets::snippet_matches(&snippet, "DISCOUNT regr"));
+        assert!(snippets::snippet_matches(&snippet, "#orders"));
+        assert!(!snippets::snippet_matches(&snippet, "#order"));
+        assert!(!snippets::snippet_matches(&snippet, "discount smoke"));
+
+        assert!(snippets::save_snippet(" ".to_string(), vec![], "{}".to_string()).is_err());
+        assert!(snippets::save_snippet("Empty".to_string(), vec![], " ".to_string()).is_err());
+        snippets::save_snippet("zeta".to_string(), tags(&["smoke"]), "1".to_string()).unwrap();
+        snippets::save_snippet("Alpha".to_string(), vec![], "[1]".to_string()).unwrap();
+        let listed =
+            snippets::save_snippet("zeta".to_string(), vec![], r#"{"a":1}"#.to_string()).unwrap();
+        let names: Vec<_> = listed.iter().map(|summary| summary.name.as_str()).collect();
+        assert_eq!(names, ["Alpha", "zeta"]);
+        assert_eq!((listed[1].bytes, listed[1].tags.len()), (7, 0));
+
+        assert_eq!(snippets::list_snippets(Some("alp".to_string())).len(), 1);
+        assert_eq!(
+            snippets::load_snippet("zeta".to_string()).unwrap(),
+            r#"{"a":1}"#
+        );
+        assert!(snippets::load_snippet("missing".to_string()).is_err());
+        assert_eq!(snippets::delete_snippet("zeta".to_string()).len(), 1);
+        assert!(snippets::list_snippets(Some("zeta".to_string())).is_empty());
+    }
+
+    #[test]
+    fn test_detect_input_format() {
+        let cases = [
+            (r#"{"a": 1}"#, InputFormat::Json),
+            ("\u{feff}  [1, 2]\n", InputFormat::Json),
+            (r#""{\"a\": 1}""#, InputFormat::EscapedJson),
+            (r#"{\"a\": 1}"#, InputFormat::EscapedJson),
+            (
+                "{\n  // comment\n  a: 'x',\n  b: [0x10, .5,],\n}",
+                InputFormat::Json5,
+            ),
+            ("{\"a\": 1}\n{\"a\": 2}\n", InputFormat::Ndjson),
+            ("name: demo\nitems:\n  - one\n  - two\n", InputFormat::Yaml),
+            ("<?xml version=\"1.0\"?><a>1</a>", InputFormat::Xml),
+            ("<root><item/></root>", InputFormat::Xml),
+            ("eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxIn0.sig", InputFormat::Jwt),
+            ("a=1&b=two%20words", InputFormat::UrlEncoded),
+            ("%7B%22a%22%3A1%7D", InputFormat::UrlEncoded),
+            ("just some words", InputFormat::Unknown),
+            ("", InputFormat::Unknown),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(detect_input_format(input), expected, "{:?}", input);
+        }
+        assert!(!detect_format("a: 1\nb: 2".to_string()).convertible);
+
+        let convert = |input: &str, format| {
+            let output = convert_to_json(input.to_string(), format, None)
+                .unwrap()
+                .result;
+            serde_json::from_str::<Value>(&output).unwrap()
+        };
+        assert_eq!(
+            convert(
+                "/* cfg */ {a: 'it\\'s', \"b\": +1., c: -0x1F, d: [1,], e: '\\u00e9',}",
+                InputFormat::Json5
+            ),
+            serde_json::json!({ "a": "it's", "b": 1, "c": -31, "d": [1], "e": "é" })
+        );
+        assert_eq!(
+            convert("{\"a\": 1}\n\n[2]", InputFormat::Ndjson),
+            serde_json::json!([{ "a": 1 }, [2]])
+        );
+        assert_eq!(
+            convert("eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxIn0.sig", InputFormat::Jwt),
+            serde_json::json!({
+                "header": { "alg": "HS256" },
+                "payload": { "sub": "1" },
+                "signature": "sig"
+            })
+        );
+        assert_eq!(
+            convert(
+                "https://x.test/p?tag=a&tag=b&q=two+words%21",
+                InputFormat::UrlEncoded
+            ),
+            serde_json::json!({ "tag": ["a", "b"], "q": "two words!" })
+        );
+        assert_eq!(
+            convert("%7B%22a%22%3A1%7D", InputFormat::UrlEncoded),
+            serde_json::json!({ "a": 1 })
+        );
+
+        assert!(parse_json5("{a: Infinity}").is_err());
+        assert!(parse_json5("{a: 1} x").is_err());
+        assert!(parse_json5("{'a: 1}").is_err());
+        assert!(parse_json5(&"[".repeat(200)).is_err());
+        assert!(convert_to_json("a: 1".to_string(), InputFormat::Yaml, None).is_err());
+    }
+
+    #[test]
+    fn test_copy_output_as() {
+        let input = "{\n  \"msg\": \"hi \\\"you\\\"\"\n}";
+        let copy = |form| copy_output_as(input.to_string(), form, None).unwrap();
+        assert_eq!(copy(CopyForm::Formatted), input);
+        assert_eq!(copy(CopyForm::Minified), r#"{"msg":"hi \"you\""}"#);
+        assert_eq!(copy(CopyForm::Escaped), r#""{\"msg\":\"hi \\\"you\\\"\"}""#);
+        assert_eq!(
+            BASE64.decode(copy(CopyForm::Base64)).unwrap(),
+            copy(CopyForm::Minified).as_bytes()
+        );
+        assert_eq!(
+            copy(CopyForm::DataUri),
+            format!("data:application/json;base64,{}", copy(CopyForm::Base64))
+        );
+        let options = OutputOptions {
+            line_ending: LineEnding::Crlf,
+            final_newline: true,
+            bom: true,
+            ..OutputOptions::default()
+        };
+        let minified =
+            copy_output_as(input.to_string(), CopyForm::Minified, Some(options)).unwrap();
+        assert_eq!(minified, r#"{"msg":"hi \"you\""}"#);
+        assert!(copy_output_as("  ".to_string(), CopyForm::Minified, None).is_err());
+        assert!(copy_output_as("{".to_string(), CopyForm::Base64, None).is_err());
+    }
+
+    #[test]
+    fn test_lint_json() {
+        let input = serde_json::json!({
+            "userId": "42",
+            "firstName": "Ada",
+            "last_name": "Lovelace",
+            "tags": ["a", 1, null],
+            "zip": "02139",
+            "phone": "4155550100123456",
+            "a": {"b": {"c": {"d": {"e": {"f": {"g": {"h": {"i": 1}}}}}}}},
+            "blob": "x".repeat(LINT_MAX_STRING_BYTES + 1),
+            "items": [{"createdAt": 1}, {"createdAt": 2}],
+        });
+        let report = lint_json(input.to_string()).unwrap();
+        let found: Vec<(LintRule, &str)> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.rule, issue.pointer.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (LintRule::NumericString, "/userId"),
+                (LintRule::KeyCasing, "/last_name"),
+                (LintRule::MixedArray, "/tags"),
+                (LintRule::DeepNesting, "/a/b/c/d/e/f/g/h"),
+                (LintRule::LargeString, "/blob"),
+            ]
+        );
+        assert!(!report.truncated);
+        assert_eq!(report.issues[1].suggestion, "Rename it to \"lastName\"");
+        assert_eq!(report.issues[2].message, "Array mixes number, string");
+        assert_eq!(
+            report.issues[3].message,
+            "Nesting reaches depth 9 (more than 8)"
+        );
+
+        assert_eq!(KeyCase::of("_id"), None);
+        assert_eq!(KeyCase::of("$schemaVersion"), Some(KeyCase::Camel));
+        assert_eq!(KeyCase::ScreamingSnake.apply("userId"), "USER_ID");
+        assert_eq!(KeyCase::Camel.apply("_USER_ID"), "_userId");
+        assert!(lint_json(r#"{"user_id": 1, "name": "2.5e3"}"#.to_string())
+            .unwrap()
+            .issues
+            .iter()
+            .all(|issue| issue.rule == LintRule::NumericString));
+    }
+
+    #[test]
+    fn test_validate_strict() {
+        let input = "{\"id\": 9007199254740993, \"id\": 1,\n \"name\": \"a\tb\", \"emoji\": \"\\ud83d\\ude00\",\n \"broken\": \"\\ud83d!\", \"price\": 0.1, \"big\": 1e400, \"exact\": 9007199254740991}";
+        let report = validate_strict(input.to_string()).unwrap();
+        assert!(!report.conforming);
+        let found: Vec<(ConformanceRule, &str, usize, usize)> = report
+            .findings
+            .iter()
+            .map(|finding| {
+                (
+                    finding.rule,
+                    finding.pointer.as_str(),
+                    finding.line,
+                    finding.column,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (ConformanceRule::PrecisionLoss, "/id", 1, 8),
+                (ConformanceRule::DuplicateKey, "/id", 1, 26),
+                (ConformanceRule::ControlCharacter, "/name", 2, 12),
+                (ConformanceRule::LoneSurrogate, "/broken", 3, 13),
+                (ConformanceRule::PrecisionLoss, "/big", 3, 44),
+            ]
+        );
+        let violations: Vec<usize> = report.checks.iter().map(|check| check.violations).collect();
+        assert_eq!(violations, vec![0, 1, 1, 1, 2]);
+
+        let report =
+            validate_strict("[1, 2.5e-3, \"\\u00e9\", {\"a\": null}] ".to_string()).unwrap();
+        assert!(report.conforming && report.findings.is_empty());
+
+        let report = validate_strict("{\"a\": 01}".to_string()).unwrap();
+        assert_eq!(report.findings[0].rule, ConformanceRule::Syntax);
+        assert_eq!(
+            report.findings[0].message,
+            "Expected ',' or '}' after an object member"
+        );
+        assert_eq!(report.findings[0].column, 8);
+
+        assert_eq!(number_precision_loss("1.50e1"), None);
+        assert_eq!(number_precision_loss("-9007199254740991"), None);
+        assert!(number_precision_loss("0.1000000000000000055511151231257827").is_some());
+        assert!(number_precision_loss("1e-400").is_some());
+    }
+
+    #[test]
+    fn test_decode_text_encodings() {
+        let json = "{\"name\": \"José\"}";
+        let utf16le: Vec<u8> = json.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let utf16be: Vec<u8> = json.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        let with_bom = |bom: &[u8], data: &[u8]| [bom, data].concat();
+
+        for (bytes, encoding) in [
+            (json.as_bytes().to_vec(), TextEncoding::Utf8),
+            (
+                with_bom(&[0xEF, 0xBB, 0xBF], json.as_bytes()),
+                TextEncoding::Utf8Bom,
+            ),
+            (with_bom(&[0xFF, 0xFE], &utf16le), TextEncoding::Utf16Le),
+            (with_bom(&[0xFE, 0xFF], &utf16be), TextEncoding::Utf16Be),
+            (utf16le.clone(), TextEncoding::Utf16Le),
+            (utf16be, TextEncoding::Utf16Be),
+            (b"{\"name\": \"Jos\xe9\"}".to_vec(), TextEncoding::Latin1),
+        ] {
+            assert_eq!(
+                decode_text(&bytes),
+                Some((json.to_string(), encoding)),
+                "{:?}",
+                encoding
+            );
+        }
+        assert_eq!(
+            decode_text(b"\x93quoted\x94").map(|(text, _)| text),
+            Some("“quoted”".to_string())
+        );
+        // An odd byte count isn't UTF-16, and NULs aren't text
+        assert_eq!(decode_text(&utf16le[1..]), None);
+        assert_eq!(decode_text(b"{\"a\": 1}\0"), None);
+
+        let inspection = inspect_bytes(&with_bom(&[0xFF, 0xFE], &utf16le), false);
+        assert_eq!(inspection.description, "UTF-16LE text");
+        assert_eq!(inspection.text.as_deref(), Some(json));
+    }
+
+    #[test]
+    fn test_lossy_input() {
+        let broken = b"{\"msg\": \"caf\xc3 \xff\xfe ok\", \"id\": \"\\ud800x\"}".to_vec();
+        let strict = inspect_bytes(&broken, false);
+        assert_eq!(strict.encoding, Some(TextEncoding::Latin1));
+        assert_eq!(strict.replacements, 0);
+
+        let lossy = inspect_bytes(&broken, true);
+        assert_eq!(lossy.encoding, Some(TextEncoding::Utf8));
+        assert_eq!(lossy.replacements, 4);
+        let text = lossy.text.unwrap();
+        assert_eq!(
+            text,
+            "{\"msg\": \"caf\u{FFFD} \u{FFFD}\u{FFFD} ok\", \"id\": \"\\ufffdx\"}"
+        );
+        assert_eq!(
+            parse_json(&text).unwrap()["msg"],
+            "caf\u{FFFD} \u{FFFD}\u{FFFD} ok"
+        );
+
+        // A BOM is dropped and reported rather than read as U+FEFF
+        let with_bom = inspect_bytes(b"\xEF\xBB\xBF{\"a\": \"\xff\"}", true);
+        assert_eq!(with_bom.encoding, Some(TextEncoding::Utf8Bom));
+        assert_eq!(with_bom.text.as_deref(), Some("{\"a\": \"\u{FFFD}\"}"));
+        assert_eq!(with_bom.replacements, 1);
+
+        // Binary formats stay binary in lossy mode
+        let png = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0];
+        assert!(inspect_bytes(&png, true).binary);
+
+        let sanitized = sanitize_input(
+            r#"["\udc00", "\ud83d\ude00", "\\ud800", "\ud83d\u0041", "é\ud83d"]"#.to_string(),
+        );
+        assert_eq!(sanitized.replacements, 3);
+        assert_eq!(
+            sanitized.text,
+            r#"["\ufffd", "\ud83d\ude00", "\\ud800", "\ufffd\u0041", "é\ufffd"]"#
+        );
+        assert!(parse_json(&sanitized.text).is_ok());
+    }
+
+    #[test]
+    fn test_theme_preference() {
+        assert_eq!(Theme::default(), Theme::System);
+        let dark: Theme = serde_json::from_str("\"dark\"").unwrap();
+        assert_eq!(set_theme(dark), Theme::Dark);
+        assert_eq!(get_theme(), Theme::Dark);
+        assert_eq!(
+            serde_json::to_string(&set_theme(Theme::System)).unwrap(),
+            "\"system\""
+        );
+        assert!(serde_json::from_str::<Theme>("\"sepia\"").is_err());
+    }
+
+    #[test]
+    fn test_inspect_input_bytes_detects_binary() {
+        let text = inspect_bytes("\u{feff}{\"a\": 1}".as_bytes(), false);
+        assert!(!text.binary);
+        assert_eq!(text.text.as_deref(), Some("{\"a\": 1}"));
+        assert_eq!(text.encoding, Some(TextEncoding::Utf8Bom));
+
+        let png = inspect_bytes(&[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0], false);
+        assert!(png.binary);
+        assert_eq!(png.detected_type, "image/png");
+        assert_eq!(png.decoders, vec!["image", "base64"]);
+
+        // field 1 = varint 150, field 2 = varint 0
+        let proto = inspect_bytes(&[0x08, 0x96, 0x01, 0x10, 0x00], false);
+        assert_eq!(proto.detected_type, "protobuf");
+        assert_eq!(
+            inspect_bytes(&[0xff, 0xff, 0x00], false).detected_type,
+            "binary"
+        );
+    }
+
+    #[test]
+    fn test_decode_binary_input() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(br#"{"a":[1,2]}"#).unwrap();
+        let gzip = encoder.finish().unwrap();
+        assert_eq!(
+            inspect_bytes(&gzip, false).decoders,
+            vec!["gunzip", "base64"]
+        );
+        let result = decode_binary_input(gzip, "gunzip".to_string(), None).unwrap();
+        assert_eq!(result, "{\n  \"a\": [\n    1,\n    2\n  ]\n}");
+
+        // Decompressed text goes through the same parse limits as typed input
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder
+            .write_all(format!("{}{}", "[".repeat(200), "]".repeat(200)).as_bytes())
+            .unwrap();
+        let deep = encoder.finish().unwrap();
+        assert!(decode_binary_input(deep, "gunzip".to_string(), None)
+            .unwrap_err()
+            .starts_with("Nesting deeper than"));
+
+        let proto = vec![0x08, 0x96, 0x01, 0x12, 0x02, b'h', b'i', 0x08, 0x01];
+        let result = decode_binary_input(proto, "protobuf".to_string(), None).unwrap();
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed, serde_json::json!({"1": [150, 1], "2": "hi"}));
+    }
+
+    #[test]
+    fn test_open_archive_zip_and_tar_gz() {
+        let mut zip_bytes = Cursor::new(Vec::new());
+        {
+            let mut writer = zip::ZipWriter::new(&mut zip_bytes);
+            let options = zip::write::SimpleFileOptions::default();
+            writer.start_file("data/users.json", options).unwrap();
+            writer.write_all(br#"{"users":[1]}"#).unwrap();
+            writer.start_file("README.txt", options).unwrap();
+            writer.write_all(b"hello").unwrap();
+            writer.start_file("utf16.json", options).unwrap();
+            writer.write_all(b"\xFF\xFE[\x001\x00]\x00").unwrap();
+            writer.finish().unwrap();
+        }
+        let zip_bytes = zip_bytes.into_inner();
+
+        let entries = open_archive(None, Some(zip_bytes.clone())).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert!(entries
+            .iter()
+            .any(|e| e.name == "data/users.json" && e.is_json));
+        let content = read_archive_entry(
+            None,
+            Some(zip_bytes.clone()),
+            "data/users.json".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(content, "{\n  \"users\": [\n    1\n  ]\n}");
+        // Entries are decoded like loaded files, not only as UTF-8
+        let content =
+            read_archive_entry(None, Some(zip_bytes), "utf16.json".to_string(), None).unwrap();
+        assert_eq!(content, "[\n  1\n]");
+
+        let ndjson = b"{\"a\":1}\n{\"a\":2}\n";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(ndjson.len() as u64);
+        header.set_mode(0o644);
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        builder
+            .append_data(&mut header, "events.ndjson", &ndjson[..])
+            .unwrap();
+        let tar_gz = builder.into_inner().unwrap().finish().unwrap();
+
+        assert_eq!(inspect_bytes(&tar_gz, false).detected_type, "tar.gz");
+        let entries = open_archive(None, Some(tar_gz.clone())).unwrap();
+        assert_eq!(entries[0].name, "events.ndjson");
+        let content = read_archive_entry(
+            None,
+            Some(tar_gz.clone()),
+            "events.ndjson".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(content.as_bytes(), ndjson);
+
+        // Archives opened from disk are streamed from the file
+        let path = std::env::temp_dir().join(format!(
+            "json-formatter-archive-{}.tar.gz",
+            std::process::id()
+        ));
+        fs::write(&path, &tar_gz).unwrap();
+        let path_string = path.to_string_lossy().to_string();
+        let entries = open_archive(Some(path_string.clone()), None).unwrap();
+        assert_eq!(entries[0].name, "events.ndjson");
+        let content =
+            read_archive_entry(Some(path_string), None, "events.ndjson".to_string(), None).unwrap();
+        assert_eq!(content.as_bytes(), ndjson);
+        assert!(read_archive_entry(
+            Some(path.to_string_lossy().to_string()),
+            None,
+            "missing.json".to_string(),
+            None
+        )
+        .is_err());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_validate_read_only_query() {
+        assert!(validate_read_only_query("  select * from users").is_ok());
+        assert!(validate_read_only_query("WITH t AS (SELECT 1) SELECT * FROM t").is_ok());
+        assert!(validate_read_only_query("DELETE FROM users").is_err());
+        assert!(validate_read_only_query(".shell ls").is_err());
+        assert!(sqlite_query("/nonexistent.db".to_string(), "SELECT 1".to_string(), None).is_err());
+    }
+
+    #[test]
+    fn test_sqlite_query() {
+        let path = std::env::temp_dir().join(format!("json-formatter-{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let connection = rusqlite::Connection::open(&path).unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE users (id INTEGER, name TEXT, score REAL, avatar BLOB);
+                 INSERT INTO users VALUES (1, 'Ann', 9.5, x'0102'), (2, NULL, NULL, NULL);",
+            )
+            .unwrap();
+        drop(connection);
+        let db = path.to_string_lossy().to_string();
+        let query =
+            |sql: &str| sqlite_query(db.clone(), sql.to_string(), None).map(|rows| rows.json);
+
+        let rows: Value =
+            serde_json::from_str(&query("SELECT * FROM users ORDER BY id").unwrap()).unwrap();
+        assert_eq!(
+            rows,
+            serde_json::json!([
+                {"id": 1, "name": "Ann", "score": 9.5, "avatar": "AQI="},
+                {"id": 2, "name": null, "score": null, "avatar": null},
+            ])
+        );
+        assert_eq!(sqlite_list_tables(db.clone()).unwrap(), vec!["users"]);
+        assert_eq!(query("SELECT 1 WHERE 0").unwrap(), "[]");
+
+        // Several statements, extension loading and writes are all refused
+        let error = query("SELECT 1; SELECT 2").unwrap_err();
+        assert_eq!(error, "Only one statement can be run at a time");
+        assert!(query("SELECT load_extension('/tmp/evil')").is_err());
+        assert!(query("WITH t AS (SELECT 1) DELETE FROM users").is_err());
+        assert!(query("PRAGMA user_version = 5").is_err());
+        let count = query("SELECT count(*) AS n FROM users").unwrap();
+        assert!(count.contains("\"n\": 2"));
+
+        let many = sqlite_query(
+            db.clone(),
+            format!(
+                "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n LIMIT {}) SELECT i FROM n",
+                SQLITE_MAX_ROWS + 1
+            ),
+            None,
+        )
+        .unwrap();
+        assert!(many.truncated);
+        assert_eq!(many.rows, SQLITE_MAX_ROWS);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_key_value_command_is_read_only() {
+        let profile = KeyValueProfile {
+            store: KeyValueStore::Redis,
+            endpoint: "localhost:6380".to_string(),
+            username: None,
+            password: Some("secret".to_string()),
+            database: Some(2),
+        };
+        let command = key_value_command(&profile, "cache:user").unwrap();
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(
+            args,
+            [
+                "-h",
+                "localhost",
+                "-p",
+                "6380",
+                "-t",
+                "5",
+                "-n",
+                "2",
+                "--raw",
+                "GET",
+                "cache:user"
+            ]
+        );
+        assert!(!args.contains(&std::ffi::OsStr::new("secret")));
+
+        let profile = KeyValueProfile {
+            store: KeyValueStore::Etcd,
+            endpoint: String::new(),
+            ..profile
+        };
+        let command = key_value_command(&profile, "-config").unwrap();
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(
+            args,
+            [
+                "--endpoints=127.0.0.1:2379",
+                "--dial-timeout=5s",
+                "--command-timeout=5s",
+                "get",
+                "--print-value-only",
+                "--",
+                "-config"
+            ]
+        );
+
+        assert!(parse_key_value_endpoint("-oProxy:6379", 6379).is_err());
+    }
+
+    #[test]
+    fn test_render_env() {
+        let variables = HashMap::from([
+            ("HOST".to_string(), "db.local".to_string()),
+            ("port".to_string(), "5432".to_string()),
+        ]);
+        let input = r#"{"url": "postgres://${HOST}:{{ port }}", "user": "${DB_USER:-admin}", "pass": "${DB_PASS}", "tpl": "{ literal }"}"#;
+        let rendered = substitute_placeholders(input, &variables, true);
+        assert_eq!(
+            rendered.output,
+            r#"{"url": "postgres://db.local:5432", "user": "admin", "pass": "${DB_PASS}", "tpl": "{ literal }"}"#
+        );
+        assert_eq!(rendered.substituted, 3);
+        assert_eq!(rendered.missing, vec!["DB_PASS"]);
+
+        let rendered =
+            render_env("port: ${port}\n".to_string(), Some(variables.clone()), None).unwrap();
+        assert_eq!(rendered.output, "port: 5432\n");
+        assert!(rendered.missing.is_empty());
+
+        // Values inside JSON strings are escaped; bare values are spliced as-is
+        let variables = HashMap::from([
+            ("PATH".to_string(), r#"C:\Tools;"quoted" dir"#.to_string()),
+            ("port".to_string(), "5432".to_string()),
+        ]);
+        let input = r#"{"path": "${PATH}", "port": ${port}, "note": "a \"${port}\" b"}"#;
+        let rendered = render_env(input.to_string(), Some(variables.clone()), None).unwrap();
+        let value: Value = serde_json::from_str(&rendered.output).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"path": r#"C:\Tools;"quoted" dir"#, "port": 5432, "note": "a \"5432\" b"})
+        );
+
+        let rendered =
+            substitute_placeholders(r#"{"a": "${port}", "b": "${port"#, &variables, true);
+        assert_eq!(rendered.output, r#"{"a": "5432", "b": "${port"#);
+
+        let error = render_env(r#"{"port": ${PATH}}"#.to_string(), Some(variables), None)
+            .err()
+            .unwrap();
+        assert!(
+            error.starts_with("Rendered config is not valid JSON"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_resolve_config_reports_origins() {
+        let layer = |name: &str, content: &str| ConfigLayer {
+            name: name.to_string(),
+            content: content.to_string(),
+        };
+        let resolved = resolve_config(
+            vec![
+                layer("base", r#"{"db": {"host": "localhost", "port": 5432}, "features": ["a"], "log": "info"}"#),
+                layer("production", r#"{"db": {"host": "db.prod"}, "features": ["b", "c"], "log": {"level": "warn"}}"#),
+                layer("local", ""),
+            ],
+            None,
+        )
+        .unwrap();
+
+        let merged: Value = serde_json::from_str(&resolved.output).unwrap();
+        assert_eq!(
+            merged,
+            serde_json::json!({"db": {"host": "db.prod", "port": 5432}, "features": ["b", "c"], "log": {"level": "warn"}})
+        );
+        let origins: Vec<(&str, &str)> = resolved
+            .origins
+            .iter()
+            .map(|o| (o.pointer.as_str(), o.layer.as_str()))
+            .collect();
+        assert_eq!(
+            origins,
+            [
+                ("/db/host", "production"),
+                ("/db/port", "base"),
+                ("/features", "production"),
+                ("/log/level", "production"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_feature_flags() {
+        let launch_darkly = r#"{"flags": {"new-checkout": {
+            "key": "new-checkout", "on": true, "variations": [true, false],
+            "offVariation": 1, "fallthrough": {"variation": 1},
+            "targets": [{"values": ["qa-user"], "variation": 0}],
+            "rules": [{"variation": 0, "clauses": [
+                {"attribute": "country", "op": "in", "values": ["ID", "SG"]},
+                {"attribute": "/account/plan", "op": "in", "values": ["free"], "negate": true}
+            ]}]
+        }}}"#;
+        let evaluate = |context: &str| {
+            evaluate_feature_flag(
+                launch_darkly.to_string(),
+                "new-checkout".to_string(),
+                context.to_string(),
+            )
+            .unwrap()
+        };
+
+        let result = evaluate(r#"{"key": "u1", "country": "ID", "account": {"plan": "pro"}}"#);
+        assert_eq!(
+            (result.value, result.reason.as_str()),
+            (Value::Bool(true), "RULE_MATCH (rule 1)")
+        );
+        let result = evaluate(r#"{"key": "u1", "country": "ID", "account": {"plan": "free"}}"#);
+        assert_eq!(
+            (result.value, result.reason.as_str()),
+            (Value::Bool(false), "FALLTHROUGH")
+        );
+        assert_eq!(evaluate(r#"{"key": "qa-user"}"#).reason, "TARGET_MATCH");
+
+        let unleash = r#"{"features": [{"name": "beta", "enabled": true, "strategies": [
+            {"name": "userWithId", "parameters": {"userIds": "a, b"}},
+            {"name": "default", "constraints": [{"contextName": "appName", "operator": "IN", "values": ["web"]}]}
+        ]}]}"#;
+        let flags = list_feature_flags(unleash.to_string()).unwrap();
+        assert_eq!(flags[0].key, "beta");
+        assert_eq!(
+            flags[0].rules,
+            ["userWithId (0 constraint(s))", "default (1 constraint(s))"]
+        );
+
+        let evaluate = |context: &str| {
+            evaluate_feature_flag(unleash.to_string(), "beta".to_string(), context.to_string())
+                .unwrap()
+        };
+        assert_eq!(evaluate(r#"{"userId": "b"}"#).value, Value::Bool(true));
+        assert_eq!(
+            evaluate(r#"{"appName": "web"}"#).reason,
+            "STRATEGY_MATCH (default #2)"
+        );
+        assert_eq!(evaluate(r#"{"appName": "ios"}"#).value, Value::Bool(false));
+    }
+
+    #[test]
+    fn test_dependency_report() {
+        let package_json = r#"{
+            "name": "app",
+            "dependencies": {"react": "^18.2.0", "lodash": "^4.17.21"},
+            "devDependencies": {"lodash": "^4.0.0", "vitest": "^1.0.0"}
+        }"#;
+        let report = dependency_report(package_json.to_string()).unwrap();
+        assert_eq!(report.manifest, "package.json");
+        assert_eq!(report.counts["dependencies"], 2);
+        assert_eq!(report.counts["devDependencies"], 2);
+        assert_eq!(
+            report.duplicates,
+            ["lodash: dependencies ^4.17.21, devDependencies ^4.0.0"]
+        );
+        assert_eq!(report.dependencies[0].name, "lodash");
+        assert!(report
+            .report
+            .contains("| react  | dependencies    | ^18.2.0  |"));
+
+        let composer =
+            r#"{"require": {"php": ">=8.1"}, "require-dev": {"phpunit/phpunit": "^10"}}"#;
+        assert_eq!(
+            dependency_report(composer.to_string()).unwrap().manifest,
+            "composer.json"
+        );
+        assert!(dependency_report(r#"{"name": "x"}"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_analyze_document_registry() {
+        let analyze = |input: &str| analyze_document(input.to_string()).unwrap();
+
+        let geojson = analyze(
+            r#"{"type": "FeatureCollection", "features": [
+                {"type": "Feature", "geometry": {"type": "Point", "coordinates": [106.8, -6.2]}},
+                {"type": "Feature", "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 95]]]}}
+            ]}"#,
+        );
+        assert_eq!(geojson.len(), 1);
+        assert_eq!(geojson[0].analyzer, "geojson");
+        assert!(geojson[0]
+            .summary
+            .contains(&"Geometries: 1 Point, 1 Polygon".to_string()));
+        assert_eq!(geojson[0].issues.len(), 2);
+
+        let openapi = analyze(
+            r##"{"openapi": "3.0.0", "info": {"title": "Pets", "version": "1"},
+                "paths": {"/pets": {"get": {"responses": {"200": {"$ref": "#/components/responses/Pets"}}}}}}"##,
+        );
+        assert_eq!(
+            openapi[0].summary[1],
+            "1 path(s), 1 operation(s), 0 schema(s)"
+        );
+        assert_eq!(
+            openapi[0].issues,
+            ["$ref '#/components/responses/Pets' does not resolve"]
+        );
+        assert_eq!(openapi[0].actions[0].pointer, Some("/paths"));
+
+        let event =
+            analyze(r#"{"specversion": "1.0", "type": "order.created", "source": "/shop"}"#);
+        assert_eq!(event[0].analyzer, "cloudEvents");
+        assert_eq!(
+            event[0].issues,
+            ["required attribute 'id' is missing or empty"]
+        );
+
+        let package =
+            analyze(r#"{"name": "My App", "private": true, "scripts": {"test": "vitest"}}"#);
+        assert_eq!(package[0].analyzer, "packageJson");
+        assert_eq!(package[0].issues.len(), 1);
+
+        assert!(analyze(r#"{"plain": true}"#).is_empty());
+    }
+
+    #[test]
+    fn test_json_to_class_ndjson_samples() {
+        let input =
+            "{\"id\": 1, \"name\": \"a\", \"email\": \"a@x.io\"}\n{\"id\": 2, \"name\": null}\n"
+                .to_string();
+        let rust = json_to_class(input.clone(), "rust".to_string(), "User".to_string(), None)
+            .unwrap()
+            .result;
+        assert!(rust.contains("pub email: Option<String>"));
+        assert!(rust.contains("pub name: Option<String>"));
+        assert!(rust.contains("pub id: i64"));
+        let python = json_to_class(
+            input.clone(),
+            "python".to_string(),
+            "User".to_string(),
+            None,
+        )
+        .unwrap()
+        .result;
+        assert!(python.contains("email: Optional[str]"));
+        let proto = json_to_proto(input, None).unwrap().result;
+        assert!(proto.contains("optional string email"));
+    }
+
+    #[test]
+    fn test_json_to_class_string_formats() {
+        let input = r#"[
+  {"id": "5f1c6a2e-8d4b-4f0a-9a57-3c2b1e0d9f8a", "createdAt": "2024-05-01T10:20:30Z", "site": "https://example.com", "note": "hi"},
+  {"id": "0b7e4c1d-2a3f-4e5d-8c9b-1a2b3c4d5e6f", "createdAt": "2024-05-02T08:00:00.123+07:00", "site": "plain text", "note": "yo"}
+]"#
+        .to_string();
+        let options = CodegenOptions {
+            string_formats: true,
+            ..Default::default()
+        };
+        let rust = json_to_class(
+            input.clone(),
+            "rust".to_string(),
+            "Row".to_string(),
+            Some(options.clone()),
+        )
+        .unwrap()
+        .result;
+        assert!(rust.contains("pub id: uuid::Uuid"));
+        assert!(rust.contains("pub created_at: chrono::DateTime<chrono::Utc>"));
+        assert!(rust.contains("pub site: String"));
+        let java = json_to_class(
+            input.clone(),
+            "java".to_string(),
+            "Row".to_string(),
+            Some(options),
+        )
+        .unwrap()
+        .result;
+        assert!(java.contains(
+            "import java.time.Instant;\nimport java.util.List;\nimport java.util.UUID;\n"
+        ));
+
+        let plain = json_to_class(input, "rust".to_string(), "Row".to_string(), None)
+            .unwrap()
+            .result;
+        assert!(plain.contains("pub id: String"));
+    }
+
+    #[test]
+    fn test_json_to_class_enum_detection() {
+        let input = r#"[
+  {"id": 1, "status": "ACTIVE", "name": "a"},
+  {"id": 2, "status": "INACTIVE", "name": "b"},
+  {"id": 3, "status": "ACTIVE", "name": "c"}
+]"#
+        .to_string();
+        let options = CodegenOptions {
+            enum_threshold: 3,
+            ..Default::default()
+        };
+        let rust = json_to_class(
+            input.clone(),
+            "rust".to_string(),
+            "User".to_string(),
+            Some(options.clone()),
+        )
+        .unwrap()
+        .result;
+        assert!(rust.contains("pub status: Status,"));
+        assert!(rust.contains("pub name: String,"));
+        assert!(rust.contains("pub enum Status {\n    #[serde(rename = \"ACTIVE\")]\n    Active,\n    #[serde(rename = \"INACTIVE\")]\n    Inactive,\n}"));
+
+        let python = json_to_class(
+            input.clone(),
+            "python".to_string(),
+            "User".to_string(),
+            Some(options.clone()),
+        )
+        .unwrap()
+        .result;
+        assert!(
+            python.find("class Status(str, Enum):").unwrap() < python.find("class User:").unwrap()
+        );
+        assert!(python.contains("    INACTIVE = \"INACTIVE\"\n"));
+
+        let proto = json_to_proto(input.clone(), Some(options)).unwrap().result;
+        assert!(proto.contains("Status status = "));
+        assert!(proto
+            .contains("  STATUS_UNSPECIFIED = 0;\n  STATUS_ACTIVE = 1;\n  STATUS_INACTIVE = 2;\n"));
+
+        let plain = json_to_class(input, "rust".to_string(), "User".to_string(), None)
+            .unwrap()
+            .result;
+        assert!(plain.contains("pub status: String,"));
+    }
+
+    #[test]
+    fn test_json_to_class_dynamic_key_maps() {
+        let input = r#"{
+  "name": "inventory",
+  "items": {
+    "1001": {"sku": "A-1", "qty": 3},
+    "1002": {"sku": "B-7", "qty": 0}
+  },
+  "scores": {"5f1c6a2e-8d4b-4f0a-9a57-3c2b1e0d9f8a": 1.5}
+}"#
+        .to_string();
+        let rust = json_to_class(input.clone(), "rust".to_string(), "Root".to_string(), None)
+            .unwrap()
+            .result;
+        assert!(rust.contains("use std::collections::HashMap;\n"));
+        assert!(rust.contains("pub items: HashMap<String, Items>,"));
+        assert!(rust.contains("pub scores: HashMap<String, f64>,"));
+        assert!(rust.contains("pub struct Items {\n    pub qty: i64,\n    pub sku: String,\n}"));
+
+        let typescript = json_to_class(
+            input.clone(),
+            "typescript".to_string(),
+            "Root".to_string(),
+            None,
+        )
+        .unwrap()
+        .result;
+        assert!(typescript.contains("  items: Record<string, Items>;\n"));
+
+        let proto = json_to_proto(input, None).unwrap().result;
+        assert!(proto.contains("map<string, Items> items = "));
+    }
+
+    #[test]
+    fn test_json_to_class_nested_name_collisions() {
+        let input = r#"{
+  "data": {"id": 1},
+  "user": {"data": {"email": "a@b.c"}, "items": [{"data": {"sku": "x"}}]}
+}"#
+        .to_string();
+        let rust = json_to_class(input.clone(), "rust".to_string(), "Root".to_string(), None)
+            .unwrap()
+            .result;
+        assert_eq!(rust.matches("pub struct Data {").count(), 1);
+        assert!(rust.contains("pub data: UserData,"));
+        assert!(rust.contains("pub struct UserData {\n    pub email: String,\n}"));
+        assert!(rust.contains("pub struct ItemsData {\n    pub sku: String,\n}"));
+
+        let proto = json_to_proto(input, None).unwrap().result;
+        assert_eq!(proto.matches("message Data {").count(), 1);
+        assert!(proto.contains("message UserData {"));
+    }
+
+    #[test]
+    fn test_api_schema_covers_every_command() {
+        let schema = api::api_schema();
+        let commands = schema["commands"].as_object().unwrap();
+        // Every command registered with the invoke handler, sync or async, needs a contract
+        let handlers = include_str!("main.rs")
+            .split("generate_handler![")
+            .nth(1)
+            .and_then(|rest| rest.split(']').next())
+            .unwrap();
+        let names: Vec<&str> = handlers
+            .split(',')
+            .filter_map(|path| path.trim().rsplit("::").next())
+            .filter(|name| !name.is_empty())
+            .collect();
+        for name in &names {
+            assert!(commands.contains_key(*name), "{} has no API contract", name);
+        }
+        assert_eq!(names.len(), commands.len());
+        assert_eq!(
+            schema["definitions"]["CodegenOptions"]["properties"]["enumThreshold"]["type"],
+            "integer"
+        );
+        let json_to_class = &commands["json_to_class"]["request"];
+        assert_eq!(json_to_class["$ref"], "#/definitions/JsonToClassRequest");
+    }
+
+    #[test]
+    fn test_capabilities_languages_are_supported() {
+        let caps = capabilities();
+        assert_eq!(caps.version, env!("CARGO_PKG_VERSION"));
+        assert!(caps.analyzers.contains(&"geojson".to_string()));
+        assert_eq!(caps.features.len(), TOOL_FEATURES.len());
+        for language in &caps.languages {
+            let result = json_to_class(
+                r#"{"id": 1}"#.to_string(),
+                language.clone(),
+                "Item".to_string(),
+                None,
+            );
+            assert!(result.is_ok(), "{} should be supported", language);
+        }
+        for decoder in &caps.decoders {
+            let result = decode_binary_input(b"{}".to_vec(), decoder.clone(), None);
+            assert!(!matches!(result, Err(e) if e.starts_with("Unsupported decoder")));
+        }
+    }
+
+    #[test]
+    fn test_json_to_class_codegen_options() {
+        let options = CodegenOptions {
+            naming_convention: NamingConvention::Preserve,
+            nullability: Nullability::AllOptional,
+            immutable: true,
+            omit_annotations: true,
+            root_array_name: "Users".to_string(),
+            namespace: "com.acme.models".to_string(),
+            ..Default::default()
+        };
+        let input = r#"[{"userId": 1, "profile": {"displayName": "Ann"}}]"#;
+        let generate = |language: &str| {
+            json_to_class(
+                input.to_string(),
+                language.to_string(),
+                "User".to_string(),
+                Some(options.clone()),
+            )
+            .unwrap()
+            .result
+        };
+
+        let go = generate("go");
+        assert!(go.starts_with("package models\n"));
+        assert_eq!(go.matches("package ").count(), 1);
+        assert!(go.contains("    UserId *int\n"));
+        assert!(go.contains("type Users []User\n"));
+
+        let rust = generate("rust");
+        assert_eq!(rust.matches("use serde").count(), 1);
+        assert!(rust.contains("    pub userId: Option<i64>,\n"));
+
+        let typescript = generate("typescript");
+        assert!(typescript.contains("  readonly displayName?: string;\n"));
+        assert!(typescript.contains("type Users = User[];\n"));
+
+        let java = generate("java");
+        assert!(java.starts_with("package com.acme.models;\n"));
+        assert!(!java.contains("@JsonProperty"));
+
+        let csharp = generate("csharp");
+        assert!(csharp.contains("namespace com.acme.models;\n"));
+        assert!(csharp.contains("public int? UserId { get; init; }"));
+        assert!(generate("cpp").contains("namespace com::acme::models {"));
+    }
+
+    #[test]
+    fn test_json_to_class_escapes_keywords_and_invalid_keys() {
+        let input = r#"{"type": "a", "class": 1, "1st-item": true, "first name": "x"}"#;
+        let generate = |language: &str| {
+            json_to_class(
+                input.to_string(),
+                language.to_string(),
+                "Root".to_string(),
+                None,
+            )
+            .unwrap()
+            .result
+        };
+
+        let rust = generate("rust");
+        assert!(rust.contains("    pub r#type: String,\n"));
+        assert!(
+            rust.contains("    #[serde(rename = \"1st-item\")]\n    pub field_1st_item: bool,\n")
+        );
+        assert!(
+            rust.contains("    #[serde(rename = \"first name\")]\n    pub first_name: String,\n")
+        );
+
+        assert!(generate("python").contains("    class_: int\n"));
+        assert!(generate("kotlin").contains("    val `class`: Int,\n"));
+        assert!(generate("typescript").contains("  \"1st-item\": boolean;\n"));
+        assert!(generate("javascript").contains("    this.field1stItem = data[\"1st-item\"];\n"));
+        assert!(generate("go").contains("    Field1stItem bool `json:\"1st-item\"`\n"));
+
+        let java = generate("java");
+        assert!(java.contains("    private Integer class_;\n"));
+        assert!(java.contains("public Integer getClass_()"));
+
+        let swift = generate("swift");
+        assert!(swift.contains("    let `class`: Int\n"));
+        assert!(swift.contains("        case field1stItem = \"1st-item\"\n"));
+
+        let proto = json_to_proto(input.to_string(), None).unwrap().result;
+        assert!(proto.contains("bool field_1st_item = 1 [json_name = \"1st-item\"];"));
+    }
+
+    #[test]
+    fn test_json_to_class_suffixes_colliding_field_names() {
+        // Keys in sorted order, so the first of each pair keeps the plain name either way
+        let input = r#"{"": 1, "a b": 2, "a_b": 3, "field": 4, "userId": 5, "user_id": 6}"#;
+        let generate = |language: &str| {
+            json_to_class(
+                input.to_string(),
+                language.to_string(),
+                "Root".to_string(),
+                None,
+            )
+            .unwrap()
+            .result
+        };
+
+        let rust = generate("rust");
+        assert!(rust.contains("    pub user_id: i64,\n"));
+        assert!(rust.contains("    #[serde(rename = \"user_id\")]\n    pub user_id_2: i64,\n"));
+        assert!(rust.contains("    #[serde(rename = \"a_b\")]\n    pub a_b_2: i64,\n"));
+        assert!(rust.contains("    #[serde(rename = \"field\")]\n    pub field_2: i64,\n"));
+
+        let go = generate("go");
+        assert!(go.contains("    UserId int `json:\"userId\"`\n"));
+        assert!(go.contains("    UserId2 int `json:\"user_id\"`\n"));
+        assert!(go.contains("    Field2 int `json:\"field\"`\n"));
+
+        let csharp = generate("csharp");
+        assert!(csharp.contains("    [JsonProperty(\"a_b\")]\n    public int AB2 { get; set; }"));
+
+        let java = generate("java");
+        assert!(java.contains("    @JsonProperty(\"user_id\")\n    private Integer userId2;\n"));
+        assert!(java.contains("    @JsonProperty(\"field\")\n    private Integer field2;\n"));
+
+        let proto = json_to_proto(input.to_string(), None).unwrap().result;
+        assert!(proto.contains("  int32 user_id = 5;\n"));
+        assert!(proto.contains("  int32 user_id_2 = 6 [json_name = \"user_id\"];\n"));
+    }
+
+    #[test]
+    fn test_json_to_class_rust_serde_attributes_and_module() {
+        let input = r#"{"userId": 1, "displayName": "x", "createdAt": "2024-01-01T00:00:00Z", "note": null}"#;
+        let options = CodegenOptions {
+            string_formats: true,
+            namespace: "api.models".to_string(),
+            ..Default::default()
+        };
+        let rust = json_to_class(
+            input.to_string(),
+            "rust".to_string(),
+            "User".to_string(),
+            Some(options),
+        )
+        .unwrap()
+        .result;
+
+        assert!(rust.starts_with("pub mod api {\n    pub mod models {\n"));
+        assert!(rust.contains("chrono (feature \"serde\")"));
+        assert!(rust
+            .contains("        #[serde(rename_all = \"camelCase\")]\n        pub struct User {\n"));
+        assert!(rust.contains("            pub created_at: chrono::DateTime<chrono::Utc>,\n"));
+        assert!(
+            rust.contains("            #[serde(default)]\n            pub note: Option<String>,\n")
+        );
+        assert!(rust.trim_end().ends_with("    }\n}"));
+    }
+
+    #[test]
+    fn test_json_to_class_field_examples() {
+        let input = r#"[{"email": "john@example.com", "note": null, "profile": {"city": "Paris"}}, {"email": "a*/b", "note": "x"}]"#;
+        let options = CodegenOptions {
+            field_examples: true,
+            ..Default::default()
+        };
+        let generate = |language: &str| {
+            json_to_class(
+                input.to_string(),
+                language.to_string(),
+                "User".to_string(),
+                Some(options.clone()),
+            )
+            .unwrap()
+            .result
+        };
+
+        let rust = generate("rust");
+        assert!(rust.contains("    /// e.g. \"john@example.com\"\n    pub email: String,\n"));
+        assert!(rust.contains("    /// e.g. \"x\"\n"));
+        assert!(rust.contains("    /// e.g. \"Paris\"\n"));
+        assert!(!rust.contains("e.g. {"));
+        assert!(generate("typescript")
+            .contains("  /** e.g. \"john@example.com\" */\n  email: string;\n"));
+        assert!(generate("python").contains("    #: e.g. \"john@example.com\"\n    email: str\n"));
+        assert!(
+            generate("haskell").contains("  { email :: Text\n    -- ^ e.g. \"john@example.com\"\n")
+        );
+
+        let plain = json_to_class(
+            input.to_string(),
+            "go".to_string(),
+            "User".to_string(),
+            None,
+        )
+        .unwrap()
+        .result;
+        assert!(!plain.contains("e.g."));
+    }
 
-        for (key, val) in map {
-            let swift_type = infer_swift_type(val, key, &mut nested_structs);
-            output.push_str(&format!("    let {}: {}\n", to_camel_case(key), swift_type));
-        }
+    #[test]
+    fn test_json_to_class_top_level_arrays_and_scalars() {
+        let generate = |input: &str, language: &str| {
+            json_to_class(
+                input.to_string(),
+                language.to_string(),
+                "User".to_string(),
+                None,
+            )
+            .map(|timed| timed.result)
+        };
 
-        output.push_str("}\n");
+        let rust = generate(r#"[{"id": 1}, {"id": 2}]"#, "rust").unwrap();
+        assert!(rust.contains("pub struct User {\n"));
+        assert!(rust
+            .ends_with("/// The top-level JSON array of User\npub type UserList = Vec<User>;\n"));
+        assert!(generate(r#"[{"id": 1}]"#, "javascript")
+            .unwrap()
+            .contains(" * @typedef {User[]} UserList\n"));
+        assert!(!generate(r#"{"id": 1}"#, "rust")
+            .unwrap()
+            .contains("UserList"));
+
+        let scalar = generate("42", "rust").unwrap_err();
+        assert!(scalar.starts_with("The top-level value is a number"));
+        let scalars = generate(r#"["a", "b"]"#, "typescript").unwrap_err();
+        assert!(scalars.starts_with("The top-level array contains a string"));
+        assert!(generate("[]", "go")
+            .unwrap_err()
+            .starts_with("The top-level array is empty"));
+    }
 
-        for (name, nested_val) in nested_structs {
-            output.push('\n');
-            output.push_str(&generate_swift_struct(&nested_val, &name)?);
-        }
+    #[test]
+    fn test_codegen_depth_limit() {
+        let nested =
+            |levels: usize| format!("{}1{}", r#"{"a":"#.repeat(levels), "}".repeat(levels));
+        let generate = |input: String, options: Option<CodegenOptions>| {
+            json_to_class(input, "rust".to_string(), "Root".to_string(), options)
+                .map(|timed| timed.result)
+        };
 
-        Ok(output)
-    } else {
-        Err("Input must be a JSON object".to_string())
+        assert_eq!(
+            generate(nested(100), None).unwrap_err(),
+            "Input is nested 100 levels deep, beyond the code generation limit of 64"
+        );
+        assert!(json_to_proto(nested(100), None).is_err());
+        let options = CodegenOptions {
+            max_depth: 120,
+            ..Default::default()
+        };
+        let code = generate(nested(100), Some(options)).unwrap();
+        assert_eq!(code.matches("pub struct ").count(), 100);
+        assert!(generate(nested(10_000), None).is_err());
     }
-}
 
-fn infer_swift_type(value: &Value, field_name: &str, nested: &mut Vec<(String, Value)>) -> String {
-    match value {
-        Value::Null => "Any?".to_string(),
-        Value::Bool(_) => "Bool".to_string(),
-        Value::Number(n) => {
-            if n.is_f64() {
-                "Double".to_string()
-            } else {
-                "Int".to_string()
-            }
-        }
-        Value::String(_) => "String".to_string(),
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                "[Any]".to_string()
-            } else {
-                let first = &arr[0];
-                if first.is_object() {
-                    let nested_name = to_pascal_case(field_name);
-                    nested.push((nested_name.clone(), first.clone()));
-                    format!("[{}]", nested_name)
-                } else {
-                    format!("[{}]", infer_swift_type(first, field_name, nested))
-                }
-            }
-        }
-        Value::Object(_) => {
-            let nested_name = to_pascal_case(field_name);
-            nested.push((nested_name.clone(), value.clone()));
-            nested_name
+    #[test]
+    fn test_json_to_class_files_and_save() {
+        let input = r#"[{"id": 1, "address": {"city": "Oslo"}, "role": "admin"}, {"id": 2, "address": {"city": "Rome"}, "role": "user"}, {"id": 3, "address": {"city": "Lima"}, "role": "user"}]"#;
+        let options = || {
+            Some(CodegenOptions {
+                enum_threshold: 2,
+                ..Default::default()
+            })
+        };
+
+        let files =
+            json_to_class_files(input.into(), "typescript".into(), "User".into(), options())
+                .unwrap()
+                .result;
+        let names: Vec<&str> = files.keys().map(String::as_str).collect();
+        assert_eq!(names, ["Address.ts", "Role.ts", "User.ts", "UserList.ts"]);
+        assert!(files["User.ts"].starts_with(
+            "import { Address } from \"./Address\";\nimport { Role } from \"./Role\";\n"
+        ));
+        assert!(files["User.ts"].contains("export interface User {"));
+        assert!(!files["Address.ts"].contains("import"));
+        assert!(files["UserList.ts"].contains("import { User } from \"./User\";"));
+
+        let files = json_to_class_files(input.into(), "python".into(), "User".into(), options())
+            .unwrap()
+            .result;
+        assert!(files["user.py"].contains("from .address import Address\n"));
+        assert!(files["__init__.py"].contains("from .user import User\n"));
+
+        let files = json_to_class_files(input.into(), "rust".into(), "User".into(), options())
+            .unwrap()
+            .result;
+        assert!(files["user.rs"].contains("use super::address::Address;\n"));
+        assert!(files["mod.rs"].contains("pub mod address;\n"));
+        assert!(files["mod.rs"].contains("pub use user_list::UserList;\n"));
+
+        // The single-file output is the same code in one piece
+        let single = json_to_class(input.into(), "go".into(), "User".into(), options())
+            .unwrap()
+            .result;
+        let files = json_to_class_files(input.into(), "go".into(), "User".into(), options())
+            .unwrap()
+            .result;
+        for code in files.values() {
+            let body = code.split_once("\n\n").unwrap().1;
+            assert!(single.contains(body.trim()), "{}", body);
         }
-    }
-}
 
-fn main() {
-    tauri::Builder::default()
-        .plugin(
-            tauri_plugin_log::Builder::default()
-                .level(log::LevelFilter::Info)
-                .build(),
-        )
-        .plugin(tauri_plugin_clipboard_manager::init())
-        .invoke_handler(tauri::generate_handler![
-            minify_json,
-            format_json,
-            json_to_string,
-            string_to_json,
-            json_to_proto,
-            proto_to_json,
-            json_to_class,
-            remove_background,
-            openssl_cert_detail,
-            openssl_cert_detail_from_url,
-            run_traceroute
-        ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
-}
+        let directory =
+            std::env::temp_dir().join(format!("json-formatter-save-{}", std::process::id()));
+        let files = BTreeMap::from([
+            ("user.go".to_string(), "package main\n".to_string()),
+            (
+                "nested/address.go".to_string(),
+                "package nested\n".to_string(),
+            ),
+        ]);
+        let written = save_generated_files(directory.display().to_string(), files).unwrap();
+        assert_eq!(written.len(), 2);
+        assert_eq!(
+            fs::read_to_string(directory.join("nested/address.go")).unwrap(),
+            "package nested\n"
+        );
+        fs::remove_dir_all(&directory).unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let outside = BTreeMap::from([("../escape.go".to_string(), String::new())]);
+        assert!(save_generated_files(directory.display().to_string(), outside).is_err());
+        assert!(save_generated_files(" ".into(), BTreeMap::new()).is_err());
+    }
 
     #[test]
-    fn test_minify_json() {
-        let input = r#"{
-  "name": "John",
-  "age": 30
-}"#
-        .to_string();
-        let result = minify_json(input).unwrap();
-        assert!(result.contains("\"name\":\"John\""));
-        assert!(result.contains("\"age\":30"));
-        assert!(!result.contains("\n"));
+    fn test_json_to_proto_package_options_and_imports() {
+        let options = CodegenOptions {
+            namespace: "acme.users.v1".into(),
+            java_package: "com.acme.users.v1".into(),
+            go_package: "github.com/acme/users/v1;usersv1".into(),
+            ..Default::default()
+        };
+        let proto = json_to_proto(r#"{"id": 1}"#.into(), Some(options.clone()))
+            .unwrap()
+            .result;
+        assert!(proto.starts_with(
+        "syntax = \"proto3\";\n\npackage acme.users.v1;\n\noption java_package = \"com.acme.users.v1\";\noption go_package = \"github.com/acme/users/v1;usersv1\";\n\nmessage Root {\n"
+    ));
+
+        // Without options the header is just the syntax line
+        let proto = json_to_proto(r#"{"id": 1}"#.into(), None).unwrap().result;
+        assert!(proto.starts_with("syntax = \"proto3\";\n\nmessage Root {\n"));
+
+        let body = "message Root {\n  google.protobuf.Timestamp at = 1;\n  google.protobuf.Struct extra = 2;\n  google.protobuf.Value raw = 3;\n}\n";
+        let header = proto_header(body, &CodegenOptions::default()).unwrap();
+        assert_eq!(
+        header,
+        "syntax = \"proto3\";\n\nimport \"google/protobuf/struct.proto\";\nimport \"google/protobuf/timestamp.proto\";\n\n"
+    );
+
+        let invalid = CodegenOptions {
+            namespace: "acme-users".into(),
+            ..Default::default()
+        };
+        assert!(json_to_proto(r#"{"id": 1}"#.into(), Some(invalid)).is_err());
     }
 
     #[test]
-    fn test_format_json() {
-        let input = r#"{"name":"John","age":30}"#.to_string();
-        let result = format_json(input).unwrap();
-        assert!(result.contains("  \"name\""));
-        assert!(result.contains("  \"age\""));
+    fn test_json_to_proto_well_known_types() {
+        let input = r#"[{"id": 1, "createdAt": "2024-01-01T10:00:00Z", "note": null, "meta": null, "counts": {"1": 3, "2": 4}}, {"id": 2, "createdAt": "2024-01-02T10:00:00Z", "note": "x", "meta": null, "counts": {}}]"#;
+        let options = CodegenOptions {
+            string_formats: true,
+            ..Default::default()
+        };
+        let proto = json_to_proto(input.into(), Some(options)).unwrap().result;
+        assert!(proto.contains(
+        "import \"google/protobuf/struct.proto\";\nimport \"google/protobuf/timestamp.proto\";\n"
+    ));
+        assert!(proto.contains("  map<string, int32> counts = 1;\n"));
+        assert!(proto.contains("  google.protobuf.Timestamp created_at = 2;\n"));
+        assert!(proto.contains("  google.protobuf.Value meta = 4;\n"));
+        assert!(proto.contains("  optional string note = 5;\n"));
+
+        // Timestamps stay strings unless string formats are enabled
+        let proto = json_to_proto(input.into(), None).unwrap().result;
+        assert!(proto.contains("  string created_at = 2;\n"));
+        assert!(!proto.contains("timestamp.proto"));
+
+        let json = proto_to_json(
+            "message Root {\n  google.protobuf.Timestamp at = 1;\n}\n".into(),
+            None,
+        )
+        .unwrap()
+        .result;
+        assert!(json.contains("\"at\": \"1970-01-01T00:00:00Z\""));
     }
 
     #[test]
-    fn test_json_to_string() {
-        let input = r#"{"name":"John"}"#.to_string();
-        let result = json_to_string(input).unwrap();
-        assert_eq!(result, r#""{\"name\":\"John\"}""#);
+    fn test_json_to_proto_enum_and_oneof() {
+        let input = r#"[{"id": 1, "card": {"number": "4111"}, "status": "paid"}, {"id": 2, "wallet": "w1", "status": "paid"}, {"id": 3, "card": {"number": "4222"}, "status": "open"}]"#;
+        let options = CodegenOptions {
+            enum_threshold: 2,
+            ..Default::default()
+        };
+        let proto = json_to_proto(input.into(), Some(options)).unwrap().result;
+        assert!(proto.contains(
+        "message Root {\n  oneof card_or_wallet {\n    Card card = 1;\n    string wallet = 2;\n  }\n  int32 id = 3;\n  Status status = 4;\n}\n"
+    ));
+        assert!(proto.contains("enum Status {\n  STATUS_UNSPECIFIED = 0;\n"));
+
+        let options = CodegenOptions {
+            omit_oneofs: true,
+            ..Default::default()
+        };
+        let proto = json_to_proto(input.into(), Some(options)).unwrap().result;
+        assert!(!proto.contains("oneof"));
+        assert!(proto.contains("  optional Card card = 1;\n"));
+
+        // Fields that sometimes appear together are not exclusive
+        let input = r#"[{"a": 1}, {"b": 2}, {"a": 3, "b": 4}]"#;
+        assert!(!json_to_proto(input.into(), None)
+            .unwrap()
+            .result
+            .contains("oneof"));
     }
 
     #[test]
-    fn test_string_to_json() {
-        let input = r#""{\"name\":\"John\"}""#.to_string();
-        let result = string_to_json(input).unwrap();
-        assert!(result.contains("\"name\""));
-        assert!(result.contains("\"John\""));
+    fn test_json_to_grpc_service() {
+        let request = r#"{"userId": 7, "filter": {"active": true}}"#;
+        let response = r#"{"user": {"id": 7, "name": "Ann"}, "filter": {"applied": true}}"#;
+        let proto = json_to_grpc_service(
+            request.into(),
+            response.into(),
+            "getUser".into(),
+            Some("UserService".into()),
+            None,
+        )
+        .unwrap()
+        .result;
+        assert!(proto.starts_with(
+        "syntax = \"proto3\";\n\nservice UserService {\n  rpc GetUser(GetUserRequest) returns (GetUserResponse);\n}\n\nmessage GetUserRequest {\n"
+    ));
+        assert!(proto.contains("message GetUserResponse {\n"));
+        assert!(proto.contains("message User {\n"));
+        // Nested types of both messages share one namespace
+        assert!(proto.contains("  GetUserResponseFilter filter = 1;\n"));
+        assert!(proto.contains("message Filter {\n  bool active = 1;\n}\n"));
+
+        let proto = json_to_grpc_service(
+            String::new(),
+            response.into(),
+            "ListUsers".into(),
+            None,
+            None,
+        )
+        .unwrap()
+        .result;
+        assert!(proto.contains("import \"google/protobuf/empty.proto\";\n"));
+        assert!(proto.contains(
+        "service ListUsersService {\n  rpc ListUsers(google.protobuf.Empty) returns (ListUsersResponse);\n}\n"
+    ));
+        assert!(!proto.contains("message ListUsersRequest"));
+
+        assert!(
+            json_to_grpc_service("[1]".into(), String::new(), "Get".into(), None, None).is_err()
+        );
+        assert!(json_to_grpc_service(String::new(), String::new(), "".into(), None, None).is_err());
     }
 
     #[test]
-    fn test_string_to_json_escaped_without_wrapper_quotes() {
-        let input = r#"{\"name\":\"John\",\"age\":30}"#.to_string();
-        let result = string_to_json(input).unwrap();
-        let parsed: Value = serde_json::from_str(&result).unwrap();
-        assert_eq!(parsed["name"], "John");
-        assert_eq!(parsed["age"], 30);
+    fn test_decode_protobuf_with_schema() {
+        let schema = r#"
+syntax = "proto3";
+package demo.v1;
+import "google/protobuf/timestamp.proto";
+
+// A user record
+message User {
+  int32 id = 1;
+  string name = 2;
+  repeated int32 scores = 3;
+  Status status = 4;
+  enum Status {
+    STATUS_UNSPECIFIED = 0;
+    ACTIVE = 1;
+  }
+  map<string, int64> counters = 5;
+  google.protobuf.Timestamp created_at = 6;
+  Address address = 7 [json_name = "addr"];
+  message Address { string city = 1; }
+  sint32 delta = 8;
+}
+"#;
+        let expected = serde_json::json!({
+            "id": 150,
+            "name": "Ann",
+            "scores": [1, 2],
+            "status": "ACTIVE",
+            "counters": {"a": "7"},
+            "createdAt": "2023-11-14T22:13:20Z",
+            "addr": {"city": "Oslo"},
+            "delta": -2,
+        });
+        let decode = |payload: &str, format: Option<&str>| {
+            let output = decode_protobuf(
+                schema.into(),
+                payload.into(),
+                Some("User".into()),
+                format.map(str::to_string),
+                None,
+            )
+            .unwrap()
+            .result;
+            serde_json::from_str::<Value>(&output).unwrap()
+        };
+
+        // Field 15 is not in the schema and is kept under its number
+        let hex =
+            "0896011203416e6e1a02010220012a050a0161100732060880e2cfaa063a060a044f736c6f40037801";
+        let mut with_unknown = expected.clone();
+        with_unknown["15"] = Value::from(1);
+        assert_eq!(decode(hex, None), with_unknown);
+        assert_eq!(decode(hex, Some("hex")), with_unknown);
+
+        // Base64 of the same message inside a gRPC frame
+        let framed = "AAAAACkIlgESA0FubhoCAQIgASoFCgFhEAcyBgiA4s+qBjoGCgRPc2xvQAN4AQ==";
+        assert_eq!(decode(framed, None), with_unknown);
+
+        let text = r#"
+id: 150 name: "Ann" scores: [1, 2] status: ACTIVE
+counters { key: "a" value: 7 }
+created_at { seconds: 1700000000 }
+address < city: 'Oslo' >  # trailing comment
+delta: -2
+"#;
+        assert_eq!(decode(text, Some("text")), expected);
+
+        let error = decode_protobuf(
+            schema.into(),
+            "nickname: \"x\"".into(),
+            None,
+            Some("text".into()),
+            None,
+        )
+        .unwrap_err();
+        assert!(error.contains("Unknown field 'nickname'"), "{}", error);
+        assert!(decode_protobuf(
+            schema.into(),
+            "08".into(),
+            Some("Missing".into()),
+            None,
+            None
+        )
+        .is_err());
+        assert!(
+            decode_protobuf(schema.into(), "0896".into(), None, Some("hex".into()), None).is_err()
+        );
+
+        // Only the last member of a oneof seen in the payload is kept
+        let oneof_schema =
+            "message Pick { oneof choice { int32 number = 1; string word = 2; } int32 id = 3; }";
+        let decode_pick = |payload: &str, format: &str| {
+            let output = decode_protobuf(
+                oneof_schema.into(),
+                payload.into(),
+                None,
+                Some(format.into()),
+                None,
+            )
+            .unwrap()
+            .result;
+            serde_json::from_str::<Value>(&output).unwrap()
+        };
+        assert_eq!(
+            decode_pick("08051201611801", "hex"),
+            serde_json::json!({"id": 1, "word": "a"})
+        );
+        assert_eq!(
+            decode_pick("1201610805", "hex"),
+            serde_json::json!({"number": 5})
+        );
+        assert_eq!(
+            decode_pick("word: \"a\" number: 5 id: 1", "text"),
+            serde_json::json!({"id": 1, "number": 5})
+        );
     }
 
     #[test]
-    fn test_string_to_json_with_one_sided_quote() {
-        let input = r#"{\"name\":\"John\"}""#.to_string();
-        let result = string_to_json(input).unwrap();
-        let parsed: Value = serde_json::from_str(&result).unwrap();
-        assert_eq!(parsed["name"], "John");
+    fn test_decode_protobuf_malformed_payloads() {
+        let schema = r#"
+message Node {
+  int32 id = 1;
+  repeated sint32 deltas = 2;
+  repeated Kind kinds = 3;
+  repeated string labels = 4;
+  Node child = 5;
+  enum Kind { KIND_UNSPECIFIED = 0; LEAF = 1; BRANCH = 2; }
+}
+"#;
+        let decode = |payload: &str, format: &str| {
+            decode_protobuf(
+                schema.into(),
+                payload.into(),
+                None,
+                Some(format.into()),
+                None,
+            )
+            .map(|output| serde_json::from_str::<Value>(&output.result).unwrap())
+        };
+
+        // Truncated varints, in the key and in the value
+        let error = decode("08ff", "hex").unwrap_err();
+        assert!(
+            error.contains("truncated in the field at byte 0"),
+            "{}",
+            error
+        );
+        let error = decode("080180", "hex").unwrap_err();
+        assert!(
+            error.contains("truncated in the field at byte 2"),
+            "{}",
+            error
+        );
+        // Length prefix longer than the payload
+        assert!(decode("22056162", "hex").is_err());
+
+        // Wire types 3, 4, 6 and 7 are rejected rather than misread
+        for key in ["0b", "0c", "0e", "0f"] {
+            let error = decode(&format!("{}00", key), "hex").unwrap_err();
+            assert!(error.contains("Unsupported wire type"), "{}", error);
+        }
+        let error = decode("2801", "hex").unwrap_err();
+        assert!(error.contains("not length-delimited"), "{}", error);
+
+        // Packed sint32 and enums next to unpacked repeated strings
+        let packed = "1203030204".to_string() + "1a020102" + "2201612201" + "62";
+        assert_eq!(
+            decode(&packed, "hex").unwrap(),
+            serde_json::json!({
+                "deltas": [-2, 1, 2],
+                "kinds": ["LEAF", "BRANCH"],
+                "labels": ["a", "b"],
+            })
+        );
+        assert!(decode("1202ffff", "hex").is_err());
+
+        // gRPC frame with the 5-byte header; a wrong length leaves the bytes untouched
+        assert_eq!(
+            decode("00000000020807", "hex").unwrap(),
+            serde_json::json!({"id": 7})
+        );
+        assert!(decode("00000000090807", "hex").is_err());
+
+        // Nesting past the limit fails cleanly in every format
+        let mut nested = String::new();
+        for _ in 0..=PROTOBUF_MAX_DEPTH {
+            nested = format!("2a{:02x}{}", nested.len() / 2, nested);
+        }
+        let error = decode(&nested, "hex").unwrap_err();
+        assert!(error.contains("nested more than"), "{}", error);
+
+        let deep_text = "child { ".repeat(100_000) + &"}".repeat(100_000);
+        let error = decode(&deep_text, "text").unwrap_err();
+        assert!(error.contains("nested more than"), "{}", error);
+        let shallow_text = "child { ".repeat(3) + "id: 1" + &"}".repeat(3);
+        assert_eq!(
+            decode(&shallow_text, "text").unwrap(),
+            serde_json::json!({"child": {"child": {"child": {"id": 1}}}})
+        );
+
+        let deep_schema = "message A { ".repeat(100_000) + &"}".repeat(100_000);
+        let error =
+            decode_protobuf(deep_schema, "08".into(), None, Some("hex".into()), None).unwrap_err();
+        assert!(error.contains("nested more than"), "{}", error);
     }
 
     #[test]
     fn test_invalid_json() {
         let input = "not valid json".to_string();
-        assert!(minify_json(input.clone()).is_err());
-        assert!(format_json(input).is_err());
+        assert!(minify_json(input.clone(), None).is_err());
+        assert!(format_json(input, None).is_err());
     }
 
     #[test]
     fn test_empty_input() {
-        assert!(minify_json("".to_string()).is_err());
-        assert!(format_json("".to_string()).is_err());
+        assert!(minify_json("".to_string(), None).is_err());
+        assert!(format_json("".to_string(), None).is_err());
     }
 
     #[test]
@@ -2203,7 +18072,7 @@ mod tests {
   "email": "john@example.com"
 }"#
         .to_string();
-        let result = json_to_proto(input).unwrap();
+        let result = json_to_proto(input, None).unwrap().result;
         assert!(result.contains("syntax = \"proto3\""));
         assert!(result.contains("message Root"));
         assert!(result.contains("string name"));
@@ -2222,7 +18091,7 @@ mod tests {
   "count": 5
 }"#
         .to_string();
-        let result = json_to_proto(input).unwrap();
+        let result = json_to_proto(input, None).unwrap().result;
         assert!(result.contains("syntax = \"proto3\""));
         assert!(result.contains("message Root"));
         assert!(result.contains("User user"));
@@ -2237,7 +18106,7 @@ mod tests {
   "tags": ["rust", "tauri", "json"]
 }"#
         .to_string();
-        let result = json_to_proto(input).unwrap();
+        let result = json_to_proto(input, None).unwrap().result;
         assert!(result.contains("repeated string tags"));
     }
 
@@ -2249,7 +18118,9 @@ mod tests {
   "isActive": true
 }"#
         .to_string();
-        let result = json_to_class(input, "typescript".to_string(), "User".to_string()).unwrap();
+        let result = json_to_class(input, "typescript".to_string(), "User".to_string(), None)
+            .unwrap()
+            .result;
         assert!(result.contains("interface User"));
         assert!(result.contains("name: string;"));
         assert!(result.contains("age: number;"));
@@ -2263,12 +18134,134 @@ mod tests {
   "age": 30
 }"#
         .to_string();
-        let result = json_to_class(input, "python".to_string(), "User".to_string()).unwrap();
+        let result = json_to_class(input, "python".to_string(), "User".to_string(), None)
+            .unwrap()
+            .result;
         assert!(result.contains("class User:"));
         assert!(result.contains("name: str"));
         assert!(result.contains("age: int"));
     }
 
+    #[test]
+    fn test_json_to_pydantic_model() {
+        let input = r#"{
+  "userName": "John",
+  "nickname": null,
+  "address": { "city": "Jakarta" }
+}"#
+        .to_string();
+        let options = CodegenOptions {
+            python_style: PythonStyle::Pydantic,
+            ..Default::default()
+        };
+        let result = json_to_class(
+            input,
+            "python".to_string(),
+            "User".to_string(),
+            Some(options),
+        )
+        .unwrap()
+        .result;
+        assert_eq!(result.matches("from pydantic import").count(), 1);
+        assert!(
+            result.find("class Address(BaseModel):").unwrap()
+                < result.find("class User(BaseModel):").unwrap()
+        );
+        assert!(result.contains("    model_config = ConfigDict(populate_by_name=True)\n"));
+        assert!(result.contains("    user_name: str = Field(alias=\"userName\")\n"));
+        assert!(result.contains("    nickname: Optional[Any] = None\n"));
+    }
+
+    #[test]
+    fn test_json_to_python_typed_dict_and_attrs() {
+        let input = r#"{"userName": "John", "nickname": null, "address": {"city": "Jakarta"}}"#;
+        let generate = |style| {
+            let options = CodegenOptions {
+                python_style: style,
+                ..Default::default()
+            };
+            json_to_class(
+                input.to_string(),
+                "python".to_string(),
+                "User".to_string(),
+                Some(options),
+            )
+            .unwrap()
+            .result
+        };
+
+        let result = generate(PythonStyle::TypedDict);
+        assert!(result.contains("class User(TypedDict):\n"));
+        assert!(result.contains("    userName: str\n"));
+        assert!(
+            result.find("class Address(TypedDict)").unwrap() < result.find("class User(").unwrap()
+        );
+
+        let result = generate(PythonStyle::TypedDictPartial);
+        assert!(result.contains("class User(TypedDict, total=False):\n"));
+
+        let result = generate(PythonStyle::Attrs);
+        assert!(result.contains("@attrs.define(kw_only=True)\nclass User:\n"));
+        assert!(result.contains("    user_name: str = attrs.field(alias=\"userName\")\n"));
+        assert!(result.contains("    nickname: Optional[Any] = None\n"));
+
+        let input = r#"{"first-name": "John"}"#.to_string();
+        let options = CodegenOptions {
+            python_style: PythonStyle::TypedDict,
+            ..Default::default()
+        };
+        let result = json_to_class(
+            input,
+            "python".to_string(),
+            "User".to_string(),
+            Some(options),
+        )
+        .unwrap()
+        .result;
+        assert!(result.contains(
+            "User = TypedDict(\n    \"User\",\n    {\n        \"first-name\": str,\n    },\n)\n"
+        ));
+    }
+
+    #[test]
+    fn test_codegen_unifies_array_elements() {
+        let input = r#"{"items": [
+            {"id": 1, "price": 10, "tags": []},
+            {"id": 2, "price": 9.5, "note": "fragile", "tags": ["a"]},
+            {"id": 3, "note": null, "tags": [1, "b"]}
+        ]}"#;
+        let generate = |language: &str| {
+            json_to_class(
+                input.to_string(),
+                language.to_string(),
+                "Order".to_string(),
+                None,
+            )
+            .unwrap()
+            .result
+        };
+
+        let rust = generate("rust");
+        assert!(rust.contains("pub items: Vec<Items>,"));
+        assert!(rust.contains("pub id: i64,"));
+        assert!(rust.contains("pub price: Option<f64>,"));
+        assert!(rust.contains("pub note: Option<String>,"));
+        assert!(rust.contains("pub tags: Vec<Option<String>>,"));
+
+        let typescript = generate("typescript");
+        assert!(typescript.contains("  note?: string;\n"));
+        assert!(typescript.contains("  id: number;\n"));
+
+        let proto = json_to_proto(
+            r#"[{"id": 1}, {"id": 3000000000, "name": "x"}]"#.to_string(),
+            None,
+        )
+        .unwrap()
+        .result;
+        assert!(proto.contains("int64 id = 1;"));
+        assert!(proto.contains("optional string name = 2;"));
+    }
+
     #[test]
     fn test_json_to_rust_struct() {
         let input = r#"{
@@ -2276,24 +18269,221 @@ mod tests {
   "age": 30
 }"#
         .to_string();
-        let result = json_to_class(input, "rust".to_string(), "User".to_string()).unwrap();
+        let result = json_to_class(input, "rust".to_string(), "User".to_string(), None)
+            .unwrap()
+            .result;
         assert!(result.contains("pub struct User"));
         assert!(result.contains("pub name: String"));
         assert!(result.contains("pub age: i64"));
     }
 
+    #[test]
+    fn test_json_to_csharp_system_text_json_record() {
+        let input = r#"{"userName": "John", "address": {"city": "Jakarta"}}"#.to_string();
+        let options = CodegenOptions {
+            csharp_serializer: CSharpSerializer::SystemTextJson,
+            csharp_records: true,
+            ..Default::default()
+        };
+        let result = json_to_class(
+            input,
+            "csharp".to_string(),
+            "User".to_string(),
+            Some(options),
+        )
+        .unwrap()
+        .result;
+        assert!(result.starts_with(
+            "using System.Collections.Generic;\nusing System.Text.Json.Serialization;\n"
+        ));
+        assert!(!result.contains("Newtonsoft"));
+        assert!(result.contains("public record User\n"));
+        assert!(result.contains(
+            "    [JsonPropertyName(\"userName\")]\n    public string UserName { get; init; }\n"
+        ));
+        assert!(result.contains("public record Address\n"));
+        assert_eq!(result.matches("using ").count(), 2);
+    }
+
+    #[test]
+    fn test_json_to_kotlinx_serializable() {
+        let input = r#"{"user_name": "John", "nickname": null, "scores": [1, null], "address": {"city": "Jakarta"}}"#.to_string();
+        let options = CodegenOptions {
+            kotlin_serialization: KotlinSerialization::Kotlinx,
+            ..Default::default()
+        };
+        let result = json_to_class(
+            input,
+            "kotlin".to_string(),
+            "User".to_string(),
+            Some(options),
+        )
+        .unwrap()
+        .result;
+        assert!(result.contains("import kotlinx.serialization.json.JsonElement\n"));
+        assert!(result.contains("@Serializable\ndata class User(\n"));
+        assert!(result.contains("    @SerialName(\"user_name\")\n    val userName: String\n"));
+        assert!(result.contains("    val nickname: JsonElement? = null,\n"));
+        assert!(result.contains("    val scores: List<Int?>,\n"));
+        assert!(result.contains("@Serializable\ndata class Address(\n"));
+        assert!(!result.contains("SerializedName"));
+    }
+
     #[test]
     fn test_json_to_java_class() {
         let input = r#"{
   "name": "John"
 }"#
         .to_string();
-        let result = json_to_class(input, "java".to_string(), "User".to_string()).unwrap();
+        let result = json_to_class(input, "java".to_string(), "User".to_string(), None)
+            .unwrap()
+            .result;
         assert!(result.contains("public class User"));
         assert!(result.contains("private String name;"));
         assert!(result.contains("public String getName()"));
     }
 
+    #[test]
+    fn test_json_to_java_record_and_lombok() {
+        let input = r#"{"first_name": "John", "address": {"city": "Jakarta"}}"#;
+        let generate = |java_style, java_annotations| {
+            let options = CodegenOptions {
+                java_style,
+                java_annotations,
+                ..Default::default()
+            };
+            json_to_class(
+                input.to_string(),
+                "java".to_string(),
+                "User".to_string(),
+                Some(options),
+            )
+            .unwrap()
+            .result
+        };
+
+        let result = generate(JavaStyle::Record, JavaAnnotations::Jackson);
+        assert!(result.contains("public record User(\n    @JsonProperty(\"address\") Address address,\n    @JsonProperty(\"first_name\") String firstName\n) {}\n"));
+        assert!(result.contains("public record Address(\n"));
+        assert!(!result.contains("get"));
+
+        let result = generate(JavaStyle::Lombok, JavaAnnotations::Gson);
+        assert!(result.contains("import com.google.gson.annotations.SerializedName;\n"));
+        assert!(result.contains("@Data\n@NoArgsConstructor\npublic class User {\n"));
+        assert!(
+            result.contains("    @SerializedName(\"first_name\")\n    private String firstName;\n")
+        );
+        assert!(!result.contains("JsonProperty"));
+        assert!(!result.contains("getFirstName"));
+    }
+
+    #[test]
+    fn test_json_to_dart_class() {
+        let input = r#"{
+  "user_name": "John",
+  "address": { "city": "Jakarta" }
+}"#
+        .to_string();
+        let result = json_to_class(input, "dart".to_string(), "User".to_string(), None)
+            .unwrap()
+            .result;
+        assert_eq!(result.matches("part 'user.g.dart';").count(), 1);
+        assert!(result.contains("@JsonSerializable()\nclass User {"));
+        assert!(result.contains("@JsonKey(name: 'user_name')\n  final String userName;"));
+        assert!(result.contains("final Address address;"));
+        assert!(result.contains("required this.userName,"));
+        assert!(result.contains("factory User.fromJson(Map<String, dynamic> json)"));
+        assert!(result.contains("Map<String, dynamic> toJson() => _$UserToJson(this);"));
+        assert!(result.contains("class Address {"));
+    }
+
+    #[test]
+    fn test_json_to_cpp_struct() {
+        let input = r#"{
+  "userName": "John",
+  "nickname": null,
+  "tags": ["a"],
+  "address": { "city": "Jakarta" }
+}"#
+        .to_string();
+        let result = json_to_class(input, "cpp".to_string(), "User".to_string(), None)
+            .unwrap()
+            .result;
+        assert!(result.contains("#include <nlohmann/json.hpp>"));
+        assert!(result.find("struct Address {").unwrap() < result.find("struct User {").unwrap());
+        assert!(result.contains("    std::string user_name;"));
+        assert!(result.contains("    std::optional<nlohmann::json> nickname;"));
+        assert!(result.contains("    std::vector<std::string> tags;"));
+        assert!(result.contains("inline void to_json(nlohmann::json& j, const User& value) {"));
+        assert!(result.contains("    j.at(\"userName\").get_to(value.user_name);"));
+        assert!(result.contains("value.nickname = j.at(\"nickname\").get<nlohmann::json>();"));
+    }
+
+    #[test]
+    fn test_json_to_haskell_data() {
+        let input = r#"{
+  "user_name": "John",
+  "type": "admin",
+  "nickname": null,
+  "address": { "city": "Jakarta" }
+}"#
+        .to_string();
+        let result = json_to_class(
+            input.clone(),
+            "haskell".to_string(),
+            "User".to_string(),
+            None,
+        )
+        .unwrap()
+        .result;
+        assert!(result.starts_with("{-# LANGUAGE DeriveGeneric #-}"));
+        assert!(result.contains("data User = User\n  { address :: Address\n"));
+        assert!(result.contains("  , nickname :: Maybe Value\n"));
+        assert!(result.contains("  , type_ :: Text\n"));
+        assert!(result.contains("    rename \"userName\" = \"user_name\"\n"));
+        assert!(result.contains("instance FromJSON Address\n"));
+
+        let options = CodegenOptions {
+            field_prefix: true,
+            ..Default::default()
+        };
+        let result = json_to_class(
+            input,
+            "haskell".to_string(),
+            "User".to_string(),
+            Some(options),
+        )
+        .unwrap()
+        .result;
+        assert!(result.contains("  { userAddress :: Address\n"));
+        assert!(result.contains("    rename \"addressCity\" = \"city\"\n"));
+    }
+
+    #[test]
+    fn test_json_to_zod_schema() {
+        let input = r#"{
+  "id": 1,
+  "first-name": "John",
+  "score": 9.5,
+  "items": [{ "sku": "a", "note": null }, { "sku": "b", "note": "gift", "qty": 2 }]
+}"#
+        .to_string();
+        let result = json_to_class(input, "zod".to_string(), "Order".to_string(), None)
+            .unwrap()
+            .result;
+        assert!(result.starts_with("import { z } from \"zod\";\n"));
+        assert!(
+            result.find("export const ItemsSchema").unwrap()
+                < result.find("export const OrderSchema").unwrap()
+        );
+        assert!(result.contains("  note: z.string().nullable(),\n"));
+        assert!(result.contains("  qty: z.number().int().optional(),\n"));
+        assert!(result.contains("  \"first-name\": z.string(),\n"));
+        assert!(result.contains("  score: z.number(),\n"));
+        assert!(result.contains("  items: z.array(ItemsSchema),\n"));
+        assert!(result.contains("export type Order = z.infer<typeof OrderSchema>;"));
+    }
+
     #[test]
     fn test_json_to_class_nested() {
         let input = r#"{
@@ -2303,7 +18493,9 @@ mod tests {
   }
 }"#
         .to_string();
-        let result = json_to_class(input, "typescript".to_string(), "Root".to_string()).unwrap();
+        let result = json_to_class(input, "typescript".to_string(), "Root".to_string(), None)
+            .unwrap()
+            .result;
         assert!(result.contains("interface Root"));
         assert!(result.contains("interface User"));
         assert!(result.contains("name: string;"));
@@ -2319,7 +18511,7 @@ message Root {
   bool is_active = 3;
 }"#
         .to_string();
-        let result = proto_to_json(input).unwrap();
+        let result = proto_to_json(input, None).unwrap().result;
         assert!(result.contains("\"name\""));
         assert!(result.contains("\"age\""));
         assert!(result.contains("\"is_active\""));
@@ -2341,7 +18533,7 @@ message User {
   int32 id = 2;
 }"#
         .to_string();
-        let result = proto_to_json(input).unwrap();
+        let result = proto_to_json(input, None).unwrap().result;
         let parsed: Value = serde_json::from_str(&result).unwrap();
         assert!(parsed.get("user").is_some());
         assert!(parsed["user"].get("name").is_some());
@@ -2357,7 +18549,7 @@ message Root {
   repeated int32 numbers = 2;
 }"#
         .to_string();
-        let result = proto_to_json(input).unwrap();
+        let result = proto_to_json(input, None).unwrap().result;
         let parsed: Value = serde_json::from_str(&result).unwrap();
         assert!(parsed["tags"].is_array());
         assert!(parsed["numbers"].is_array());
@@ -2365,10 +18557,84 @@ message Root {
 
     #[test]
     fn test_proto_to_json_empty_input() {
-        let result = proto_to_json("".to_string());
+        let result = proto_to_json("".to_string(), None);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_generate_sample_json_from_json_is_seeded() {
+        let input = r#"{"email": "a@b.c", "age": 1, "tags": ["x"], "createdAt": "now"}"#;
+        let first = generate_sample_json(input.to_string(), Some(7), None, None)
+            .unwrap()
+            .result;
+        let second = generate_sample_json(input.to_string(), Some(7), None, None)
+            .unwrap()
+            .result;
+        assert_eq!(first, second);
+
+        let parsed: Value = serde_json::from_str(&first).unwrap();
+        assert!(parsed["email"].as_str().unwrap().ends_with("@example.com"));
+        assert!((18..=80).contains(&parsed["age"].as_i64().unwrap()));
+        assert!(parsed["tags"].is_array());
+        assert!(parsed["createdAt"].as_str().unwrap().ends_with('Z'));
+    }
+
+    #[test]
+    fn test_sample_integer_matches_key_words() {
+        let sample = |key: &str| sample_integer(key, &mut SampleRng::new(7));
+        let expected = |min, max| SampleRng::new(7).range(min, max);
+
+        assert_eq!(sample("age"), expected(18, 80));
+        assert_eq!(sample("userAge"), expected(18, 80));
+        assert_eq!(sample("user_id"), expected(1, 99999));
+        assert_eq!(sample("orderID"), expected(1, 99999));
+        assert_eq!(sample("messageCount"), expected(0, 100));
+        assert_eq!(sample("birth_year"), expected(1990, 2025));
+        for key in ["page", "usage", "storage", "valid", "paid"] {
+            assert_eq!(sample(key), expected(0, 1000), "{}", key);
+        }
+    }
+
+    #[test]
+    fn test_sample_string_matches_key_words() {
+        let sample = |key: &str| sample_string(key, &mut SampleRng::new(7));
+        let words = {
+            let mut rng = SampleRng::new(7);
+            rng.pick(SAMPLE_FIRST_NAMES);
+            rng.pick(SAMPLE_LAST_NAMES);
+            format!("{} {}", rng.pick(SAMPLE_WORDS), rng.pick(SAMPLE_WORDS))
+        };
+
+        assert!(SAMPLE_CITIES.contains(&sample("homeCity").as_str()));
+        assert!(sample("profile_url").starts_with("https://example.com/"));
+        assert!(sample("createdAt").ends_with('Z'));
+        assert!(SAMPLE_STATUSES.contains(&sample("orderStatus").as_str()));
+        for key in ["capacity", "velocity", "runtime", "statement", "blink"] {
+            assert_eq!(sample(key), words, "{}", key);
+        }
+    }
+
+    #[test]
+    fn test_generate_sample_json_from_proto() {
+        let input = r#"syntax = "proto3";
+
+message Root {
+  string name = 1;
+  repeated Item items = 2;
+}
+
+message Item {
+  int32 id = 1;
+}"#;
+        let result = generate_sample_json(input.to_string(), Some(1), Some(2), None)
+            .unwrap()
+            .result;
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed.as_array().unwrap().len(), 2);
+        assert!(parsed[0]["name"].as_str().unwrap().contains(' '));
+        assert!(parsed[0]["items"][0]["id"].is_i64());
+    }
+
     #[test]
     fn test_extract_pem_certificates_multiple() {
         let sample = "noise\n-----BEGIN CERTIFICATE-----\nAAA\n-----END CERTIFICATE-----\nmore\n-----BEGIN CERTIFICATE-----\nBBB\n-----END CERTIFICATE-----\n";
@@ -0,0 +1,142 @@
+//! The settings profile: output formatting, theme, language, limits, code generation
+//! defaults and the local ingest server.
+//!
+//! The profile lives in the Tauri store file `settings.json` and can be exported to and
+//! imported from a standalone JSON file. Theme, limits and the ingest server are applied
+//! to the running app; output, language and codegen defaults are kept for the frontend,
+//! which passes them with each command or applies them itself.
+
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+use log::{info, warn};
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+
+use crate::ingest::{self, IngestSettings};
+use crate::{
+    concurrency_settings, get_theme, parse_limits, set_concurrency_settings, set_parse_limits,
+    set_theme, write_file_atomically, CodegenOptions, ConcurrencySettings, OutputOptions,
+    ParseLimits, Theme, APP_HANDLE,
+};
+
+const STORE_FILE: &str = "settings.json";
+const STORE_KEY: &str = "settings";
+
+/// Output and codegen defaults of the current profile
+static SETTINGS: Mutex<Option<Settings>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    pub output: OutputOptions,
+    pub theme: Theme,
+    /// UI language code such as `id`; empty follows the system language
+    pub language: String,
+    pub concurrency: ConcurrencySettings,
+    pub parse_limits: ParseLimits,
+    pub codegen: CodegenOptions,
+    pub ingest: IngestSettings,
+}
+
+/// Validate and apply a profile to the running app; nothing changes if a limit is invalid
+fn apply_settings(settings: Settings) -> Result<Settings, String> {
+    settings.ingest.validate()?;
+    let previous_concurrency = concurrency_settings();
+    let concurrency = set_concurrency_settings(settings.concurrency)?;
+    let parse_limits = set_parse_limits(settings.parse_limits).inspect_err(|_| {
+        let _ = set_concurrency_settings(previous_concurrency);
+    })?;
+    let settings = Settings {
+        theme: set_theme(settings.theme),
+        concurrency,
+        parse_limits,
+        ..settings
+    };
+
+    *SETTINGS.lock().unwrap_or_else(|e| e.into_inner()) = Some(settings.clone());
+    Ok(settings)
+}
+
+/// Load the saved profile into the running app, keeping the defaults if there is none
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    if let Some(saved) = store.get(STORE_KEY) {
+        let settings =
+            serde_json::from_value(saved).map_err(|e| format!("Ignoring saved settings: {}", e))?;
+        let settings = apply_settings(settings)?;
+        // A busy port shouldn't cost the rest of the profile
+        if let Err(e) = ingest::configure(&settings.ingest) {
+            warn!("Ingest server not started: {}", e);
+        }
+    }
+    Ok(())
+}
+
+fn save_settings(settings: &Settings) -> Result<(), String> {
+    // Tests run without an app; the profile is still applied
+    let Some(app) = APP_HANDLE.get() else {
+        return Ok(());
+    };
+    let value = serde_json::to_value(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    store.set(STORE_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))
+}
+
+#[tauri::command]
+pub fn get_settings() -> Settings {
+    let saved = SETTINGS.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    let saved = saved.unwrap_or_default();
+    // Theme and limits can also change through their own commands, and the
+    // ingest server is only enabled once it is actually listening
+    Settings {
+        theme: get_theme(),
+        concurrency: concurrency_settings(),
+        parse_limits: parse_limits(),
+        ingest: ingest::running().unwrap_or(IngestSettings {
+            enabled: false,
+            ..saved.ingest.clone()
+        }),
+        ..saved
+    }
+}
+
+#[tauri::command]
+pub fn set_settings(settings: Settings) -> Result<Settings, String> {
+    info!("set_settings called");
+    let settings = apply_settings(settings)?;
+    ingest::configure(&settings.ingest)?;
+    save_settings(&settings)?;
+    Ok(settings)
+}
+
+/// Write the current profile to `path` as pretty JSON
+#[tauri::command]
+pub fn export_settings(path: String) -> Result<(), String> {
+    info!("export_settings called - path: {}", path);
+    let text = serde_json::to_string_pretty(&get_settings())
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    write_file_atomically(Path::new(&path), text.as_bytes())
+        .map_err(|e| format!("Failed to write {}: {}", path, e))
+}
+
+/// Replace the profile with one exported earlier; missing sections take their defaults
+#[tauri::command]
+pub fn import_settings(path: String) -> Result<Settings, String> {
+    info!("import_settings called - path: {}", path);
+    let text = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let settings = serde_json::from_str(&text).map_err(|e| {
+        warn!("import_settings: {}", e);
+        format!("Not a settings file: {}", e)
+    })?;
+    set_settings(settings)
+}
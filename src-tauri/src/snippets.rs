@@ -0,0 +1,179 @@
+//! The snippet library: named payloads with tags, kept in the Tauri store file
+//! `snippets.json` so canonical test inputs are a search away.
+
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use tauri_plugin_store::StoreExt;
+
+use crate::APP_HANDLE;
+
+const STORE_FILE: &str = "snippets.json";
+const STORE_KEY: &str = "snippets";
+
+/// Saved snippets sorted by name; loaded from the store on first use
+static SNIPPETS: Mutex<Option<Vec<Snippet>>> = Mutex::new(None);
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snippet {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub content: String,
+    /// Seconds since the Unix epoch
+    pub saved_at: u64,
+}
+
+/// A snippet as listed in the library; the content is loaded on demand
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SnippetSummary {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub bytes: usize,
+    pub saved_at: u64,
+}
+
+impl From<&Snippet> for SnippetSummary {
+    fn from(snippet: &Snippet) -> Self {
+        SnippetSummary {
+            name: snippet.name.clone(),
+            tags: snippet.tags.clone(),
+            bytes: snippet.content.len(),
+            saved_at: snippet.saved_at,
+        }
+    }
+}
+
+/// Trimmed, non-empty tags without a leading `#`, each kept once ignoring case
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim().trim_start_matches('#').trim();
+        if !tag.is_empty() && !normalized.iter().any(|kept| kept.eq_ignore_ascii_case(tag)) {
+            normalized.push(tag.to_string());
+        }
+    }
+    normalized
+}
+
+/// Whether every word of `query` appears in the name or a tag, ignoring case. A `#word`
+/// only matches a tag with exactly that name.
+pub fn snippet_matches(snippet: &Snippet, query: &str) -> bool {
+    let name = snippet.name.to_lowercase();
+    query.split_whitespace().all(|word| {
+        let word = word.to_lowercase();
+        match word.strip_prefix('#') {
+            Some(tag) => snippet.tags.iter().any(|t| t.to_lowercase() == tag),
+            None => {
+                name.contains(&word)
+                    || snippet
+                        .tags
+                        .iter()
+                        .any(|t| t.to_lowercase().contains(&word))
+            }
+        }
+    })
+}
+
+/// Apply `update` to the library, saving it to the store when `update` reports a change
+fn update_snippets<T>(update: impl FnOnce(&mut Vec<Snippet>) -> (T, bool)) -> T {
+    let mut library = SNIPPETS.lock().unwrap_or_else(|e| e.into_inner());
+    let snippets = library.get_or_insert_with(|| {
+        let store = APP_HANDLE.get().and_then(|app| app.store(STORE_FILE).ok());
+        store
+            .and_then(|store| store.get(STORE_KEY))
+            .and_then(|saved| {
+                serde_json::from_value(saved)
+                    .map_err(|e| warn!("Ignoring unreadable snippets: {}", e))
+                    .ok()
+            })
+            .unwrap_or_default()
+    });
+
+    let (result, changed) = update(snippets);
+    // Tests run without an app; the library then only lives in memory
+    if let (true, Some(app)) = (changed, APP_HANDLE.get()) {
+        let saved = serde_json::to_value(&*snippets)
+            .map_err(|e| e.to_string())
+            .and_then(|value| {
+                let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+                store.set(STORE_KEY, value);
+                store.save().map_err(|e| e.to_string())
+            });
+        if let Err(e) = saved {
+            warn!("Failed to save snippets: {}", e);
+        }
+    }
+    result
+}
+
+/// Save `content` under `name`, replacing any snippet with that name
+#[tauri::command(async)]
+pub fn save_snippet(
+    name: String,
+    tags: Vec<String>,
+    content: String,
+) -> Result<Vec<SnippetSummary>, String> {
+    let name = name.trim().to_string();
+    info!("save_snippet called - name: '{}', tags: {:?}", name, tags);
+    if name.is_empty() {
+        return Err("Snippet name is empty".to_string());
+    }
+    if content.trim().is_empty() {
+        return Err("Nothing to save; the input is empty".to_string());
+    }
+    let saved_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let snippet = Snippet {
+        name,
+        tags: normalize_tags(&tags),
+        content,
+        saved_at,
+    };
+    Ok(update_snippets(|snippets| {
+        snippets.retain(|saved| saved.name != snippet.name);
+        snippets.push(snippet);
+        snippets.sort_by_cached_key(|saved| saved.name.to_lowercase());
+        (snippets.iter().map(SnippetSummary::from).collect(), true)
+    }))
+}
+
+/// Snippets matching `query` (see `snippet_matches`), sorted by name
+#[tauri::command(async)]
+pub fn list_snippets(query: Option<String>) -> Vec<SnippetSummary> {
+    let query = query.unwrap_or_default();
+    update_snippets(|snippets| {
+        let found = snippets
+            .iter()
+            .filter(|snippet| snippet_matches(snippet, &query))
+            .map(SnippetSummary::from)
+            .collect();
+        (found, false)
+    })
+}
+
+#[tauri::command(async)]
+pub fn load_snippet(name: String) -> Result<String, String> {
+    update_snippets(|snippets| {
+        let content = snippets
+            .iter()
+            .find(|snippet| snippet.name == name)
+            .map(|snippet| snippet.content.clone())
+            .ok_or_else(|| format!("No snippet named '{}'", name));
+        (content, false)
+    })
+}
+
+#[tauri::command(async)]
+pub fn delete_snippet(name: String) -> Vec<SnippetSummary> {
+    info!("delete_snippet called - name: '{}'", name);
+    update_snippets(|snippets| {
+        let before = snippets.len();
+        snippets.retain(|snippet| snippet.name != name);
+        let summaries = snippets.iter().map(SnippetSummary::from).collect();
+        (summaries, snippets.len() != before)
+    })
+}